@@ -0,0 +1,54 @@
+//! Conversions between fields and arbitrary-precision integers.
+//!
+//! With the `bigint` feature, every field derived with [`Field`](crate::derive::Field)
+//! converts from a [`&BigUint`](BigUint) reduced modulo its modulus and back with `to_biguint()`,
+//! and so do [`Polynomial`] and [`NTTPolynomial`] over any [`Field`] coefficientwise.
+
+use alloc::vec::Vec;
+
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{Field, NTTPolynomial, Polynomial};
+
+pub use num_bigint;
+pub use num_bigint::BigUint;
+
+/// Converts `value` to a field element, reducing it modulo the modulus of `F`.
+pub fn field_from_biguint<F: Field>(value: &BigUint) -> F {
+    let modulus = BigUint::from(F::modulus_value().to_u128().unwrap());
+    let value = (value % modulus).to_u128().unwrap();
+    F::new(<F::Value as NumCast>::from(value).unwrap())
+}
+
+/// Converts the canonical value of `value` in `[0, q)` to a [`BigUint`].
+pub fn field_to_biguint<F: Field>(value: F) -> BigUint {
+    BigUint::from(value.get().to_u128().unwrap())
+}
+
+impl<F: Field> From<&[BigUint]> for Polynomial<F> {
+    /// Converts the coefficients, each reduced modulo the modulus of `F`.
+    fn from(coeffs: &[BigUint]) -> Self {
+        Polynomial::new(coeffs.iter().map(field_from_biguint).collect())
+    }
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Converts the coefficients to [`BigUint`]s.
+    pub fn to_biguints(&self) -> Vec<BigUint> {
+        self.iter().copied().map(field_to_biguint).collect()
+    }
+}
+
+impl<F: Field> From<&[BigUint]> for NTTPolynomial<F> {
+    /// Converts the values, each reduced modulo the modulus of `F`.
+    fn from(values: &[BigUint]) -> Self {
+        NTTPolynomial::new(values.iter().map(field_from_biguint).collect())
+    }
+}
+
+impl<F: Field> NTTPolynomial<F> {
+    /// Converts the values to [`BigUint`]s.
+    pub fn to_biguints(&self) -> Vec<BigUint> {
+        self.iter().copied().map(field_to_biguint).collect()
+    }
+}
@@ -1,11 +1,19 @@
 //! The basis for decomposition of the [`Field`].
 
-use crate::Field;
+use num_traits::PrimInt;
+
+use crate::{Field, ModulusConfig};
+
+/// The maximal count of levels of a [`Basis`].
+pub const MAX_DECOMPOSE_LEN: usize = 64;
 
 /// This basis struct is used for decomposition of the [`Field`].
 ///
 /// It is designed for powers of 2 basis.
 /// In this case, decomposition will become simple and efficient.
+///
+/// Every level may have its own digit size, see [`Basis::mixed`].
+/// The level `i` digit is then weighted by `2^level_shift(i)` on recomposition.
 #[derive(Debug, Clone, Copy)]
 pub struct Basis<F: Field> {
     basis: F::Value,
@@ -15,6 +23,8 @@ pub struct Basis<F: Field> {
     mask: F::Value,
     /// This basis' bits number.
     bits: u32,
+    /// The bits number of every level, from the least significant one.
+    level_bits: [u8; MAX_DECOMPOSE_LEN],
 }
 
 impl<F: Field> Default for Basis<F> {
@@ -31,11 +41,45 @@ impl<F: Field> Basis<F> {
         let basis = mask + F::ONE_INNER;
         let decompose_len = F::decompose_len(basis);
 
+        let mut level_bits = [0; MAX_DECOMPOSE_LEN];
+        level_bits[..decompose_len].fill(bits as u8);
+
         Self {
             basis,
             decompose_len,
             mask,
             bits,
+            level_bits,
+        }
+    }
+
+    /// Creates a new mixed-radix [`Basis<F>`] with the bits number of every level,
+    /// from the least significant one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a level has no bits, if there are more than [`MAX_DECOMPOSE_LEN`] levels,
+    /// or if the levels don't cover the bits of the modulus.
+    pub fn mixed(bits: &[u32]) -> Self {
+        let value_bits = F::MODULUS_INNER.count_zeros() + F::MODULUS_INNER.count_ones();
+
+        assert!(!bits.is_empty() && bits.len() <= MAX_DECOMPOSE_LEN);
+        assert!(bits.iter().all(|&b| b > 0 && b < value_bits));
+        assert!(bits.iter().sum::<u32>() >= F::MODULUS_BITS);
+
+        let mut level_bits = [0; MAX_DECOMPOSE_LEN];
+        level_bits
+            .iter_mut()
+            .zip(bits)
+            .for_each(|(l, &b)| *l = b as u8);
+
+        let mask = F::mask(bits[0]);
+        Self {
+            basis: mask + F::ONE_INNER,
+            decompose_len: bits.len(),
+            mask,
+            bits: bits[0],
+            level_bits,
         }
     }
 
@@ -48,20 +92,74 @@ impl<F: Field> Basis<F> {
     /// Returns the mask of this [`Basis<F>`].
     ///
     /// mask is a value of the `bits` 1, used for some bit-operation.
+    /// For a mixed-radix basis, this is the mask of the least significant level.
     #[inline]
-    pub fn mask(&self) -> <F as Field>::Value {
+    pub fn mask(&self) -> <F as ModulusConfig>::Value {
         self.mask
     }
 
     /// Returns the basis' bits number of this [`Basis<F>`].
+    ///
+    /// For a mixed-radix basis, this is the bits number of the least significant level.
     #[inline]
     pub fn bits(&self) -> u32 {
         self.bits
     }
 
     /// Returns the basis of this [`Basis<F>`].
+    ///
+    /// For a mixed-radix basis, this is the basis of the least significant level.
     #[inline]
-    pub fn basis(&self) -> <F as Field>::Value {
+    pub fn basis(&self) -> <F as ModulusConfig>::Value {
         self.basis
     }
+
+    /// Returns `true` if all levels of this [`Basis<F>`] have the same bits number.
+    #[inline]
+    pub fn is_uniform(&self) -> bool {
+        self.level_bits[..self.decompose_len]
+            .iter()
+            .all(|&b| b as u32 == self.bits)
+    }
+
+    /// Returns the bits number of the `level`-th level.
+    #[inline]
+    pub fn level_bits(&self, level: usize) -> u32 {
+        debug_assert!(level < self.decompose_len);
+        self.level_bits[level] as u32
+    }
+
+    /// Returns the mask of the `level`-th level.
+    #[inline]
+    pub fn level_mask(&self, level: usize) -> <F as ModulusConfig>::Value {
+        F::mask(self.level_bits(level))
+    }
+
+    /// Returns the sum of the bits numbers of the levels below `level`,
+    /// so the `level`-th digit is weighted by `2^level_shift(level)`.
+    #[inline]
+    pub fn level_shift(&self, level: usize) -> u32 {
+        debug_assert!(level <= self.decompose_len);
+        self.level_bits[..level].iter().map(|&b| b as u32).sum()
+    }
+
+    /// Returns an iterator over the mask and the bits number of every level.
+    #[inline]
+    pub fn levels(&self) -> impl Iterator<Item = (<F as ModulusConfig>::Value, u32)> + '_ {
+        self.level_bits[..self.decompose_len]
+            .iter()
+            .map(|&b| (F::mask(b as u32), b as u32))
+    }
+
+    /// Recomposes the digits of a decomposition according to this [`Basis<F>`].
+    pub fn recompose(&self, digits: &[F]) -> F {
+        debug_assert_eq!(digits.len(), self.decompose_len);
+        digits
+            .iter()
+            .zip(&self.level_bits[..self.decompose_len])
+            .rev()
+            .fold(F::ZERO, |acc, (&d, &bits)| {
+                (0..bits).fold(acc, |acc, _| acc + acc) + d
+            })
+    }
 }
@@ -1,6 +1,8 @@
 //! This module defines some errors that
 //! may occur during the execution of the library.
 
+use alloc::string::String;
+
 use thiserror::Error;
 
 /// Errors that may occur.
@@ -28,7 +30,19 @@ pub enum AlgebraError {
     /// Error that occurs when fails to generate the ntt table.
     #[error("Fail to generate the desired ntt table.")]
     NTTTableError,
+    /// Error that occurs when an ntt table fails a check of [`crate::transformation::NTTTable::self_check`].
+    #[error("The ntt table fails the self check of {0}.")]
+    NTTSelfCheckError(&'static str),
+    /// Error that occurs when a blob of precomputed tables is malformed or does not match.
+    #[error("The table blob is malformed or does not match the field and target.")]
+    TableBlobError,
     /// Error that occurs when fails to generate the distribution.
     #[error("Fail to generate the desired distribution.")]
     DistributionError,
+    /// Error that occurs when a matrix to invert or solve with is singular.
+    #[error("The matrix is singular.")]
+    SingularMatrix,
+    /// Error that occurs when the prover messages of the sumcheck protocol fail to verify.
+    #[error("The sumcheck protocol fails to verify.")]
+    SumcheckError,
 }
@@ -1,11 +1,14 @@
 //! This place defines some concrete implement of field of the algebra.
 
-use std::fmt::{Debug, Display};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use num_traits::{Inv, One, Pow, PrimInt, Zero};
+use num_traits::{Inv, One, Pow, Zero};
+use subtle::ConstantTimeEq;
 
-use crate::{Basis, ModulusConfig, Random, Widening, WrappingOps};
+use crate::{Basis, ModulusConfig, Random};
 
 mod ntt_fields;
 mod prime_fields;
@@ -20,7 +23,8 @@ pub use prime_fields::PrimeField;
 /// by any non-zero element is possible and every element except zero has an inverse.
 ///
 /// The [`Field`] trait extends various Rust standard library traits to ensure field elements
-/// can be copied, cloned, debugged, displayed, compared, and have a sense of 'zero' and 'one'.
+/// can be copied, cloned, debugged, displayed, compared for equality, and have a sense of 'zero' and 'one'.
+/// Field elements are not ordered, see [`CanonicalOrd`] for comparing their canonical values.
 /// Additionally, it supports standard arithmetic operations like addition, subtraction,
 /// multiplication, division, and exponentiation, as well as assignment versions of these operations.
 ///
@@ -41,8 +45,6 @@ pub trait Field:
     + Default
     + Eq
     + PartialEq
-    + Ord
-    + PartialOrd
     + Zero
     + One
     + Add<Self, Output = Self>
@@ -66,10 +68,8 @@ pub trait Field:
     + Pow<Self::Order, Output = Self>
     + From<Self::Value>
     + ModulusConfig
+    + ConstantTimeEq
 {
-    /// The inner type of this field.
-    type Value: Debug + Send + Sync + PrimInt + Widening + WrappingOps;
-
     /// The type of the field's order.
     type Order: Copy;
 
@@ -170,12 +170,79 @@ pub trait Field:
     /// and falling back to [0, modulus) for normal case.
     fn add_mul_assign_fast(&mut self, a: Self, b: Self);
 
+    /// Calculates `self^exp`.
+    ///
+    /// Unlike [`Pow`] with [`Field::Order`], this does not depend on the order type,
+    /// and the square-and-multiply loop only runs over the bits of `exp`.
+    #[inline]
+    fn pow_u64(self, exp: u64) -> Self {
+        if exp == 0 {
+            return Self::ONE;
+        }
+        let mut result = self;
+        for i in (0..63 - exp.leading_zeros()).rev() {
+            result *= result;
+            if (exp >> i) & 1 == 1 {
+                result *= self;
+            }
+        }
+        result
+    }
+
+    /// Calculates `self^(2^k)` by squaring `k` times.
+    #[inline]
+    fn square_n(self, k: u32) -> Self {
+        (0..k).fold(self, |acc, _| acc * acc)
+    }
+
+    /// Performs `lhs[i] += rhs[i]` over the common length.
+    #[inline]
+    fn add_assign_slice(lhs: &mut [Self], rhs: &[Self]) {
+        lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l += r);
+    }
+
+    /// Performs `lhs[i] -= rhs[i]` over the common length.
+    #[inline]
+    fn sub_assign_slice(lhs: &mut [Self], rhs: &[Self]) {
+        lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l -= r);
+    }
+
+    /// Performs `lhs[i] *= rhs[i]` over the common length.
+    #[inline]
+    fn mul_assign_slice(lhs: &mut [Self], rhs: &[Self]) {
+        lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l *= r);
+    }
+
+    /// Performs `acc[i] += a[i] * b[i]` over the common length.
+    #[inline]
+    fn add_mul_assign_slice(acc: &mut [Self], a: &[Self], b: &[Self]) {
+        acc.iter_mut()
+            .zip(a)
+            .zip(b)
+            .for_each(|((acc, &a), &b)| acc.add_mul_assign(a, b));
+    }
+
+    /// Calculates `sum(a[i] * b[i])` over the common length.
+    #[inline]
+    fn dot_product(a: &[Self], b: &[Self]) -> Self {
+        a.iter()
+            .zip(b)
+            .fold(Self::ZERO, |acc, (&a, &b)| acc.add_mul(a, b))
+    }
+
     /// cast self to [`usize`].
     fn cast_into_usize(self) -> usize;
 
     /// cast from [`usize`].
     fn cast_from_usize(value: usize) -> Self;
 
+    /// Scales `self` into the field `T`, computing `round(p * x / q) mod p` where `q` and `p`
+    /// are the moduli of this field and of `T`, with the precomputed Barrett constants of `q`.
+    ///
+    /// As `q` is odd, no value is a tie, so it agrees with scaling the centered lift of `self`.
+    /// It does not branch on `self` when `p * q < 2^64`, so it is used to decode secret values.
+    fn scale_round<T: Field>(self) -> T;
+
     /// cast inner to [`f64`].
     fn to_f64(self) -> f64;
 
@@ -216,6 +283,33 @@ pub trait Field:
     fn decompose_lsb_bits_at(&mut self, destination: &mut Self, mask: Self::Value, bits: u32);
 }
 
+/// Comparison of [`Field`] elements by their canonical values in `[0, q)`.
+///
+/// This order is unrelated to the field operations, but gives generic code a way
+/// to sort, deduplicate or key elements, whatever the internal representation.
+pub trait CanonicalOrd: Field {
+    /// Compares the canonical values of `self` and `other`.
+    fn canonical_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns the larger of `self` and `other` by canonical value.
+    #[inline]
+    fn canonical_max(self, other: Self) -> Self {
+        match self.canonical_cmp(&other) {
+            Ordering::Less => other,
+            _ => self,
+        }
+    }
+
+    /// Returns the smaller of `self` and `other` by canonical value.
+    #[inline]
+    fn canonical_min(self, other: Self) -> Self {
+        match self.canonical_cmp(&other) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+}
+
 /// A trait combine [`NTTField`] with random property.
 pub trait RandomNTTField: NTTField + Random {}
 
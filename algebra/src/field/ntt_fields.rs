@@ -1,6 +1,7 @@
 //! Define `NTTField`` trait
 
-use std::{fmt::Debug, sync::Arc};
+use alloc::{sync::Arc, vec::Vec};
+use core::fmt::Debug;
 
 use crate::transformation::{AbstractNTT, MonomialNTT};
 
@@ -21,7 +22,7 @@ pub trait NTTField: PrimeField {
     type Table: AbstractNTT<Self> + MonomialNTT<Self>;
 
     /// The type representing the roots of unity within the field.
-    type Root: Copy + Debug + Default;
+    type Root: Copy + Debug + Default + 'static;
 
     /// Degree type
     type Degree;
@@ -44,6 +45,12 @@ pub trait NTTField: PrimeField {
     /// Try to get a primitive `degree`-th root of unity reduce `p`.
     fn try_primitive_root(degree: Self::Degree) -> Result<Self, crate::AlgebraError>;
 
+    /// Get all the primitive `degree`-th roots of unity reduce `p`.
+    ///
+    /// For a power of two `degree`, these are the odd powers `ω, ω^3, ..., ω^(degree-1)`
+    /// of a primitive root `ω`, returned in this order.
+    fn primitive_roots(degree: Self::Degree) -> Result<Vec<Self>, crate::AlgebraError>;
+
     /// Try to get the minimal primitive `degree`-th root of unity reduce `p`.
     ///
     /// For ease of introduction we use `n` for `degreee` and `p` for prime number.
@@ -70,4 +77,7 @@ pub trait NTTField: PrimeField {
 
     /// Init ntt table with `log_n` slice.
     fn init_ntt_table(log_n_slice: &[u32]) -> Result<(), crate::AlgebraError>;
+
+    /// Cache a ntt table which was loaded elsewhere, e.g. from a blob, for its `log_n`.
+    fn insert_ntt_table(table: Self::Table);
 }
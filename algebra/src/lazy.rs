@@ -0,0 +1,149 @@
+//! Wrappers of lazily reduced values, in `[0, 2*modulus)` instead of `[0, modulus)`.
+//!
+//! The fast operations of [`Field`], such as [`Field::mul_fast`], skip the final correction
+//! of the reduction, so their results are wrong operands for the canonical operations.
+//! The wrappers only offer the lazy operations and an explicit `normalize`,
+//! so a lazy value can't be mixed with canonical ones by accident.
+
+use alloc::vec::Vec;
+
+use crate::{Field, NTTPolynomial};
+
+/// A lazily reduced field element in `[0, 2*modulus)`.
+///
+/// A lazy value has two representations, so it has no equality,
+/// compare the results of [`LazyField::normalize`] instead.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct LazyField<F: Field>(F);
+
+impl<F: Field> LazyField<F> {
+    /// Performs `a * b` with [`Field::mul_fast`].
+    #[inline]
+    pub fn mul(a: F, b: F) -> Self {
+        Self(a.mul_fast(b))
+    }
+
+    /// Performs `acc + a * b` with [`Field::add_mul_fast`].
+    #[inline]
+    pub fn add_mul(acc: F, a: F, b: F) -> Self {
+        Self(acc.add_mul_fast(a, b))
+    }
+
+    /// Performs `self += a * b`, accumulating onto the lazy value.
+    #[inline]
+    pub fn add_mul_assign(&mut self, a: F, b: F) {
+        self.0.add_mul_assign_fast(a, b);
+    }
+
+    /// Reduces to the canonical value in `[0, modulus)`.
+    #[inline]
+    pub fn normalize(self) -> F {
+        self.0.normalize()
+    }
+
+    /// Returns the lazy inner value, which may be in `[modulus, 2*modulus)`.
+    #[inline]
+    pub fn get_lazy(self) -> F::Value {
+        self.0.get()
+    }
+}
+
+impl<F: Field> From<F> for LazyField<F> {
+    /// A canonical value is also a lazy value.
+    #[inline]
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
+
+/// A polynomial in the NTT domain whose values are lazily reduced in `[0, 2*modulus)`,
+/// such as the accumulator of many pointwise products.
+///
+/// It is converted back into an [`NTTPolynomial`] by [`LazyPoly::normalize`].
+#[derive(Debug, Clone, Default)]
+pub struct LazyPoly<F: Field> {
+    data: Vec<F>,
+}
+
+impl<F: Field> LazyPoly<F> {
+    /// Creates the zero polynomial with `coeff_count` values.
+    #[inline]
+    pub fn zero(coeff_count: usize) -> Self {
+        Self {
+            data: alloc::vec![F::ZERO; coeff_count],
+        }
+    }
+
+    /// Performs the pointwise `a * b` with [`Field::mul_fast`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different coeff counts.
+    pub fn mul(a: &NTTPolynomial<F>, b: &NTTPolynomial<F>) -> Self {
+        assert_eq!(
+            a.coeff_count(),
+            b.coeff_count(),
+            "the coeff counts should be equal"
+        );
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_pointwise_mul(a.coeff_count());
+        Self {
+            data: a.iter().zip(b).map(|(&a, &b)| a.mul_fast(b)).collect(),
+        }
+    }
+
+    /// Performs the pointwise `self += a * b`, accumulating onto the lazy values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self`, `a` and `b` have different coeff counts.
+    pub fn add_mul_assign(&mut self, a: &NTTPolynomial<F>, b: &NTTPolynomial<F>) {
+        assert_eq!(
+            self.coeff_count(),
+            a.coeff_count(),
+            "the coeff counts should be equal"
+        );
+        assert_eq!(
+            self.coeff_count(),
+            b.coeff_count(),
+            "the coeff counts should be equal"
+        );
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_pointwise_mul(self.coeff_count());
+        self.data
+            .iter_mut()
+            .zip(a)
+            .zip(b)
+            .for_each(|((acc, &a), &b)| acc.add_mul_assign_fast(a, b));
+    }
+
+    /// Returns the coeff count.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the value at `index`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<LazyField<F>> {
+        self.data.get(index).map(|&x| LazyField(x))
+    }
+
+    /// Reduces every value to the canonical value in `[0, modulus)`.
+    #[inline]
+    pub fn normalize(mut self) -> NTTPolynomial<F> {
+        self.data.iter_mut().for_each(F::normalize_assign);
+        NTTPolynomial::new(self.data)
+    }
+}
+
+impl<F: Field> From<NTTPolynomial<F>> for LazyPoly<F> {
+    /// Canonical values are also lazy values.
+    #[inline]
+    fn from(polynomial: NTTPolynomial<F>) -> Self {
+        Self {
+            data: polynomial.data(),
+        }
+    }
+}
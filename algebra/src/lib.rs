@@ -1,24 +1,43 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
 //! Define arithmetic operations.
+//!
+//! The crate is `no_std` with `alloc` when the default `std` feature is disabled.
+
+extern crate alloc;
 
 mod decompose_basis;
 mod error;
 mod field;
+mod lazy;
+mod matrix;
 mod polynomial;
 mod primitive;
 mod random;
+mod rng;
 
+#[cfg(feature = "bigint")]
+pub mod bigint;
 pub mod derive;
+#[cfg(feature = "tracing")]
+pub mod metrics;
 pub mod modulus;
 pub mod reduce;
+pub mod simd;
+pub mod sss;
+pub mod sumcheck;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 pub mod transformation;
 pub mod utils;
 
-pub use decompose_basis::Basis;
+pub use decompose_basis::{Basis, MAX_DECOMPOSE_LEN};
 pub use error::AlgebraError;
-pub use field::{Field, NTTField, PrimeField, RandomNTTField};
+pub use field::{CanonicalOrd, Field, NTTField, PrimeField, RandomNTTField};
+pub use lazy::{LazyField, LazyPoly};
+pub use matrix::{Matrix, NegacyclicMatrix};
 pub use polynomial::multivariate::{
     DenseMultilinearExtension, ListOfProductsOfPolynomials, MultilinearExtension, PolynomialInfo,
 };
@@ -26,6 +45,91 @@ pub use polynomial::univariate::{
     ntt_add_mul_assign, ntt_add_mul_assign_fast, ntt_add_mul_inplace, ntt_mul_assign,
     ntt_mul_inplace, NTTPolynomial, Polynomial,
 };
-pub use primitive::{div_ceil, Bits, Widening, WrappingOps};
-pub use random::{FieldBinarySampler, FieldDiscreteGaussianSampler, FieldTernarySampler, Random};
+pub use primitive::{div_ceil, Bits, Widening, WrappingOps, U256};
+pub use random::{
+    FieldBinarySampler, FieldBoundedSampler, FieldDiscreteGaussianSampler, FieldTernarySampler,
+    FieldUniformSampler, Random,
+};
 pub use reduce::ModulusConfig;
+pub use rng::CsRng;
+#[cfg(feature = "rand_core_09")]
+pub use rng::RngCompat;
+pub use subtle::{Choice, ConstantTimeEq};
+
+/// Items used by the code generated by the derive macros, not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::ToString;
+    pub use alloc::sync::Arc;
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+    pub use subtle;
+}
+
+/// Implements the conversions of [`bigint`] for a derived field.
+#[cfg(feature = "bigint")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bigint {
+    ($name:ident) => {
+        impl ::core::convert::From<&$crate::bigint::BigUint> for $name {
+            /// Converts `value` reduced modulo the modulus.
+            #[inline]
+            fn from(value: &$crate::bigint::BigUint) -> Self {
+                $crate::bigint::field_from_biguint(value)
+            }
+        }
+
+        impl $name {
+            #[doc = concat!("Converts the canonical value of this [`", stringify!($name), "`] to a `BigUint`.")]
+            #[inline]
+            pub fn to_biguint(self) -> $crate::bigint::BigUint {
+                $crate::bigint::field_to_biguint(self)
+            }
+        }
+    };
+}
+
+/// Without the `bigint` feature, the derived fields have no conversions to big integers.
+#[cfg(not(feature = "bigint"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bigint {
+    ($name:ident) => {};
+}
+
+/// Implements the generators of [`test_utils`] for a derived field.
+#[cfg(feature = "test-utils")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_test_utils {
+    ($name:ident) => {
+        impl<'a> $crate::test_utils::arbitrary::Arbitrary<'a> for $name {
+            #[inline]
+            fn arbitrary(
+                u: &mut $crate::test_utils::arbitrary::Unstructured<'a>,
+            ) -> $crate::test_utils::arbitrary::Result<Self> {
+                $crate::test_utils::arbitrary_field(u)
+            }
+        }
+
+        impl $crate::test_utils::proptest::arbitrary::Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = $crate::test_utils::proptest::strategy::BoxedStrategy<Self>;
+
+            #[inline]
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                use $crate::test_utils::proptest::strategy::Strategy;
+                $crate::test_utils::field_strategy::<Self>().boxed()
+            }
+        }
+    };
+}
+
+/// Without the `test-utils` feature, the derived fields have no generators.
+#[cfg(not(feature = "test-utils"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_test_utils {
+    ($name:ident) => {};
+}
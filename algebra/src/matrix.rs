@@ -0,0 +1,362 @@
+//! Dense matrices over a [`Field`], and matrices of negacyclic multiplications.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::dot_product;
+use crate::{AlgebraError, Field, NTTField, NTTPolynomial, Polynomial};
+
+/// A dense matrix over the field `F`, stored in row-major order.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Matrix<F: Field> {
+    data: Vec<F>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<F: Field> Matrix<F> {
+    /// Creates a new [`Matrix<F>`] from its entries in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` does not have `rows * cols` entries.
+    #[inline]
+    pub fn new(rows: usize, cols: usize, data: Vec<F>) -> Self {
+        assert_eq!(data.len(), rows * cols);
+        Self { data, rows, cols }
+    }
+
+    /// Creates a new [`Matrix<F>`] from its rows, which must have the same length.
+    pub fn from_rows(rows: &[Vec<F>]) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols));
+        Self::new(rows.len(), cols, rows.concat())
+    }
+
+    /// Creates a [`Matrix<F>`] with all entries equal to zero.
+    #[inline]
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self::new(rows, cols, vec![F::ZERO; rows * cols])
+    }
+
+    /// Creates the identity [`Matrix<F>`] of size `n`.
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::zero(n, n);
+        (0..n).for_each(|i| matrix[(i, i)] = F::ONE);
+        matrix
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `true` if this matrix is square.
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the entries in row-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[F] {
+        &self.data
+    }
+
+    /// Drop self, and return the entries in row-major order.
+    #[inline]
+    pub fn data(self) -> Vec<F> {
+        self.data
+    }
+
+    /// Returns the `i`-th row.
+    #[inline]
+    pub fn row(&self, i: usize) -> &[F] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the `i`-th row mutably.
+    #[inline]
+    pub fn row_mut(&mut self, i: usize) -> &mut [F] {
+        &mut self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns an iterator over the rows.
+    #[inline]
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[F]> + '_ {
+        (0..self.rows).map(|i| self.row(i))
+    }
+
+    /// Returns the transpose of this matrix.
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            data.extend((0..self.rows).map(|i| self[(i, j)]));
+        }
+        Self::new(self.cols, self.rows, data)
+    }
+
+    /// Calculates the product `self · v` with a column vector.
+    pub fn mul_vec(&self, v: &[F]) -> Vec<F> {
+        assert_eq!(v.len(), self.cols);
+        self.iter_rows().map(|row| dot_product(row, v)).collect()
+    }
+
+    /// Calculates the product `v · self` with a row vector.
+    pub fn vec_mul(&self, v: &[F]) -> Vec<F> {
+        assert_eq!(v.len(), self.rows);
+        let mut result = vec![F::ZERO; self.cols];
+        for (row, &s) in self.iter_rows().zip(v) {
+            result
+                .iter_mut()
+                .zip(row)
+                .for_each(|(r, &a)| r.add_mul_assign(a, s));
+        }
+        result
+    }
+
+    /// Multiplies every entry by `scalar`.
+    #[inline]
+    pub fn mul_scalar_assign(&mut self, scalar: F) {
+        self.data.iter_mut().for_each(|v| *v *= scalar);
+    }
+
+    /// Turns this matrix into its reduced row echelon form by Gaussian elimination,
+    /// and returns its rank.
+    pub fn row_echelon(&mut self) -> usize {
+        let cols = self.cols;
+        let mut rank = 0;
+        for col in 0..cols {
+            if rank == self.rows {
+                break;
+            }
+            let Some(pivot) = (rank..self.rows).find(|&i| self[(i, col)] != F::ZERO) else {
+                continue;
+            };
+            self.swap_rows(pivot, rank);
+
+            let inv = self[(rank, col)].inv();
+            self.row_mut(rank)[col..].iter_mut().for_each(|v| *v *= inv);
+
+            for i in (0..self.rows).filter(|&i| i != rank) {
+                let factor = self[(i, col)];
+                if factor == F::ZERO {
+                    continue;
+                }
+                let (target, pivot_row) = self.two_rows_mut(i, rank);
+                target[col..]
+                    .iter_mut()
+                    .zip(&pivot_row[col..])
+                    .for_each(|(t, &p)| *t -= p * factor);
+            }
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Returns the rank of this matrix.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.clone().row_echelon()
+    }
+
+    /// Returns the inverse of this square matrix.
+    ///
+    /// Returns [`AlgebraError::SingularMatrix`] if the matrix is not invertible.
+    pub fn inverse(&self) -> Result<Self, AlgebraError> {
+        assert!(self.is_square());
+        let n = self.rows;
+
+        let mut augmented = self.augment(&Self::identity(n));
+        augmented.row_echelon();
+        if !augmented.has_identity_block() {
+            return Err(AlgebraError::SingularMatrix);
+        }
+
+        let data = augmented
+            .iter_rows()
+            .flat_map(|row| row[n..].iter().copied())
+            .collect();
+        Ok(Self::new(n, n, data))
+    }
+
+    /// Solves `self · x = b` for a square invertible matrix.
+    ///
+    /// Returns [`AlgebraError::SingularMatrix`] if the matrix is not invertible.
+    pub fn solve(&self, b: &[F]) -> Result<Vec<F>, AlgebraError> {
+        assert!(self.is_square());
+        assert_eq!(b.len(), self.rows);
+        let n = self.rows;
+
+        let mut augmented = self.augment(&Self::new(n, 1, b.to_vec()));
+        augmented.row_echelon();
+        if !augmented.has_identity_block() {
+            return Err(AlgebraError::SingularMatrix);
+        }
+        Ok(augmented.iter_rows().map(|row| row[n]).collect())
+    }
+
+    /// Returns the matrix `[self | other]`.
+    fn augment(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.rows, other.rows);
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for i in 0..self.rows {
+            data.extend_from_slice(self.row(i));
+            data.extend_from_slice(other.row(i));
+        }
+        Self::new(self.rows, self.cols + other.cols, data)
+    }
+
+    /// Checks that a reduced row echelon form `[A | B]` with `A` square has `A = I`,
+    /// which holds exactly when every pivot of `A` lies on the diagonal.
+    fn has_identity_block(&self) -> bool {
+        (0..self.rows).all(|i| self[(i, i)] == F::ONE)
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            let (a, b) = self.two_rows_mut(i, j);
+            a.swap_with_slice(b);
+        }
+    }
+
+    fn two_rows_mut(&mut self, i: usize, j: usize) -> (&mut [F], &mut [F]) {
+        debug_assert_ne!(i, j);
+        let cols = self.cols;
+        if i < j {
+            let (head, tail) = self.data.split_at_mut(j * cols);
+            (&mut head[i * cols..(i + 1) * cols], &mut tail[..cols])
+        } else {
+            let (head, tail) = self.data.split_at_mut(i * cols);
+            (&mut tail[..cols], &mut head[j * cols..(j + 1) * cols])
+        }
+    }
+}
+
+impl<F: Field> Index<(usize, usize)> for Matrix<F> {
+    type Output = F;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        debug_assert!(i < self.rows && j < self.cols);
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<F: Field> IndexMut<(usize, usize)> for Matrix<F> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        debug_assert!(i < self.rows && j < self.cols);
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<F: Field> Add<&Matrix<F>> for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn add(self, rhs: &Matrix<F>) -> Self::Output {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols));
+        let data = self
+            .data
+            .iter()
+            .zip(&rhs.data)
+            .map(|(&a, &b)| a + b)
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+impl<F: Field> Sub<&Matrix<F>> for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn sub(self, rhs: &Matrix<F>) -> Self::Output {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols));
+        let data = self
+            .data
+            .iter()
+            .zip(&rhs.data)
+            .map(|(&a, &b)| a - b)
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+impl<F: Field> Mul<&Matrix<F>> for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn mul(self, rhs: &Matrix<F>) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows);
+        let rhs_t = rhs.transpose();
+        let mut data = Vec::with_capacity(self.rows * rhs.cols);
+        for i in 0..self.rows {
+            let row = self.row(i);
+            data.extend((0..rhs.cols).map(|j| dot_product(row, rhs_t.row(j))));
+        }
+        Matrix::new(self.rows, rhs.cols, data)
+    }
+}
+
+/// The `n × n` matrix of the multiplication by a polynomial `a` in `F[x]/(x^n + 1)`,
+/// whose products with vectors are computed by NTT in `O(n log n)`.
+///
+/// The entry `(i, j)` is `a[i - j]` if `i >= j`, and `-a[n + i - j]` otherwise,
+/// so the product with the coefficients of `v` gives the coefficients of `a·v`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegacyclicMatrix<F: NTTField> {
+    poly: Polynomial<F>,
+    ntt_poly: NTTPolynomial<F>,
+}
+
+impl<F: NTTField> NegacyclicMatrix<F> {
+    /// Creates the [`NegacyclicMatrix<F>`] of `poly`, whose length must be a power of two.
+    pub fn new(poly: Polynomial<F>) -> Self {
+        assert!(poly.coeff_count().is_power_of_two());
+        let ntt_poly = poly.clone().into_ntt_polynomial();
+        Self { poly, ntt_poly }
+    }
+
+    /// Returns the size of this matrix.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.poly.coeff_count()
+    }
+
+    /// Returns the defining polynomial, which is the first column.
+    #[inline]
+    pub fn polynomial(&self) -> &Polynomial<F> {
+        &self.poly
+    }
+
+    /// Calculates the product `self · v` with a column vector.
+    #[inline]
+    pub fn mul_vec(&self, v: &[F]) -> Vec<F> {
+        assert_eq!(v.len(), self.size());
+        (Polynomial::from_slice(v) * &self.ntt_poly).data()
+    }
+
+    /// Returns the entries of this matrix as a [`Matrix<F>`].
+    pub fn to_dense(&self) -> Matrix<F> {
+        let n = self.size();
+        let mut matrix = Matrix::zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                matrix[(i, j)] = if i >= j {
+                    self.poly[i - j]
+                } else {
+                    -self.poly[n + i - j]
+                };
+            }
+        }
+        matrix
+    }
+}
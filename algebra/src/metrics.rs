@@ -0,0 +1,70 @@
+//! Operation counters for profiling.
+//!
+//! The counters are global and shared by all threads, so take a snapshot with [`op_counts`]
+//! before and after the code under measurement and subtract with [`OpCounts::since`].
+//! Each transform also emits a `TRACE` event, which subscribers attribute to the current span.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+static NTT_FORWARD: AtomicU64 = AtomicU64::new(0);
+static NTT_INVERSE: AtomicU64 = AtomicU64::new(0);
+static POINTWISE_MUL: AtomicU64 = AtomicU64::new(0);
+
+/// A snapshot of the operation counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpCounts {
+    /// The number of forward number theory transforms.
+    pub ntt_forward: u64,
+    /// The number of inverse number theory transforms.
+    pub ntt_inverse: u64,
+    /// The number of coefficient-wise modular multiplications of polynomials in NTT form,
+    /// each of which performs a modular reduction.
+    pub pointwise_mul: u64,
+}
+
+impl OpCounts {
+    /// Returns the operations performed between `earlier` and `self`.
+    #[inline]
+    pub fn since(&self, earlier: &OpCounts) -> OpCounts {
+        OpCounts {
+            ntt_forward: self.ntt_forward.wrapping_sub(earlier.ntt_forward),
+            ntt_inverse: self.ntt_inverse.wrapping_sub(earlier.ntt_inverse),
+            pointwise_mul: self.pointwise_mul.wrapping_sub(earlier.pointwise_mul),
+        }
+    }
+}
+
+/// Returns the current operation counters.
+#[inline]
+pub fn op_counts() -> OpCounts {
+    OpCounts {
+        ntt_forward: NTT_FORWARD.load(Ordering::Relaxed),
+        ntt_inverse: NTT_INVERSE.load(Ordering::Relaxed),
+        pointwise_mul: POINTWISE_MUL.load(Ordering::Relaxed),
+    }
+}
+
+/// Resets all operation counters to zero.
+#[inline]
+pub fn reset_op_counts() {
+    NTT_FORWARD.store(0, Ordering::Relaxed);
+    NTT_INVERSE.store(0, Ordering::Relaxed);
+    POINTWISE_MUL.store(0, Ordering::Relaxed);
+}
+
+#[inline]
+pub(crate) fn record_ntt_forward(coeff_count: usize) {
+    NTT_FORWARD.fetch_add(1, Ordering::Relaxed);
+    tracing::trace!(coeff_count, "ntt forward");
+}
+
+#[inline]
+pub(crate) fn record_ntt_inverse(coeff_count: usize) {
+    NTT_INVERSE.fetch_add(1, Ordering::Relaxed);
+    tracing::trace!(coeff_count, "ntt inverse");
+}
+
+#[inline]
+pub(crate) fn record_pointwise_mul(coeff_count: usize) {
+    POINTWISE_MUL.fetch_add(coeff_count as u64, Ordering::Relaxed);
+}
@@ -74,6 +74,46 @@ macro_rules! impl_barrett_modulus {
             pub const fn bit_count(&self) -> u32 {
                 <$SelfT>::BITS - self.value.leading_zeros()
             }
+
+            /// Calculates the quotient and the remainder `(⌊x / value⌋, x mod value)`.
+            ///
+            /// The quotient is the `q3` of the reduction, corrected at most once,
+            /// so it reuses the precomputed ratio instead of a native division.
+            #[inline]
+            pub const fn div_rem(&self, x: $SelfT) -> ($SelfT, $SelfT) {
+                let tmp = (x as $WideT * self.ratio[0] as $WideT) >> <$SelfT>::BITS;
+                let quotient = ((x as $WideT * self.ratio[1] as $WideT + tmp) >> <$SelfT>::BITS) as $SelfT;
+                let remainder = x.wrapping_sub(quotient.wrapping_mul(self.value));
+
+                if remainder >= self.value {
+                    (quotient + 1, remainder - self.value)
+                } else {
+                    (quotient, remainder)
+                }
+            }
+
+            /// Calculates the quotient `⌊x / value⌋`, see [`Self::div_rem`].
+            #[inline]
+            pub const fn div(&self, x: $SelfT) -> $SelfT {
+                self.div_rem(x).0
+            }
+
+            /// Calculates [`Self::div_rem`] without branching on `x`,
+            /// the correction is selected in constant time for secret values.
+            #[inline]
+            pub fn div_rem_ct(&self, x: $SelfT) -> ($SelfT, $SelfT) {
+                use subtle::{ConditionallySelectable, ConstantTimeLess};
+
+                let tmp = (x as $WideT * self.ratio[0] as $WideT) >> <$SelfT>::BITS;
+                let quotient = ((x as $WideT * self.ratio[1] as $WideT + tmp) >> <$SelfT>::BITS) as $SelfT;
+                let remainder = x.wrapping_sub(quotient.wrapping_mul(self.value));
+
+                let correct = !remainder.ct_lt(&self.value);
+                (
+                    <$SelfT>::conditional_select(&quotient, &quotient.wrapping_add(1), correct),
+                    <$SelfT>::conditional_select(&remainder, &remainder.wrapping_sub(self.value), correct),
+                )
+            }
         }
 
         impl $crate::reduce::LazyReduce<BarrettModulus<Self>> for $SelfT {
@@ -485,3 +525,33 @@ macro_rules! impl_barrett_modulus {
         }
     };
 }
+
+macro_rules! impl_barrett_signed_reduce {
+    (impl BarrettModulus<$SelfT:ty>; $($SignedT:ty),*) => {$(
+        impl $crate::reduce::SignedReduce<BarrettModulus<$SelfT>> for $SignedT {
+            type Output = $SelfT;
+
+            /// Caculates `self (mod modulus)` in `[0, modulus)`.
+            ///
+            /// The absolute value is split into limbs and reduced,
+            /// then negated modulo `modulus` for a negative `self`.
+            #[inline]
+            fn signed_reduce(self, modulus: BarrettModulus<$SelfT>) -> Self::Output {
+                use $crate::reduce::Reduce;
+
+                const LIMBS: usize = (<$SignedT>::BITS / <$SelfT>::BITS) as usize;
+
+                let abs = self.unsigned_abs();
+                let limbs: [$SelfT; LIMBS] =
+                    core::array::from_fn(|i| (abs >> (i as u32 * <$SelfT>::BITS)) as $SelfT);
+                let r = limbs.as_slice().reduce(modulus);
+
+                if self < 0 && r != 0 {
+                    modulus.value() - r
+                } else {
+                    r
+                }
+            }
+        }
+    )*};
+}
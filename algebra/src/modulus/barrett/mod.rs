@@ -23,6 +23,7 @@
 #[macro_use]
 mod internal_macros;
 mod ops;
+mod wide;
 
 /// A modulus, using barrett reduction algorithm.
 ///
@@ -57,11 +58,17 @@ impl_barrett_modulus!(impl BarrettModulus<u16>; WideType: u32);
 impl_barrett_modulus!(impl BarrettModulus<u32>; WideType: u64);
 impl_barrett_modulus!(impl BarrettModulus<u64>; WideType: u128);
 
+impl_barrett_signed_reduce!(impl BarrettModulus<u8>; i64, i128);
+impl_barrett_signed_reduce!(impl BarrettModulus<u16>; i64, i128);
+impl_barrett_signed_reduce!(impl BarrettModulus<u32>; i64, i128);
+impl_barrett_signed_reduce!(impl BarrettModulus<u64>; i64, i128);
+impl_barrett_signed_reduce!(impl BarrettModulus<u128>; i128);
+
 #[cfg(test)]
 mod tests {
     use rand::prelude::*;
 
-    use crate::reduce::Reduce;
+    use crate::reduce::{Reduce, SignedReduce};
 
     use super::*;
 
@@ -86,6 +93,30 @@ mod tests {
         assert_eq!(v.reduce(modulus), v % m);
     }
 
+    #[test]
+    fn test_barrett_div_rem() {
+        let mut rng = thread_rng();
+
+        let m: u64 = rng.gen_range(2..=(u64::MAX >> 2));
+        let modulus = BarrettModulus::<u64>::new(m);
+        for v in [0, 1, m - 1, m, m + 1, u64::MAX, rng.gen()] {
+            assert_eq!(modulus.div_rem(v), (v / m, v % m));
+            assert_eq!(modulus.div_rem_ct(v), (v / m, v % m));
+        }
+
+        let modulus = BarrettModulus::<u32>::new(61);
+        for _ in 0..1000 {
+            let v: u32 = rng.gen();
+            assert_eq!(modulus.div_rem(v), (v / 61, v % 61));
+            assert_eq!(modulus.div(v), v / 61);
+            assert_eq!(modulus.div_rem_ct(v), (v / 61, v % 61));
+        }
+
+        const M: BarrettModulus<u16> = BarrettModulus::<u16>::new(61);
+        const QR: (u16, u16) = M.div_rem(u16::MAX);
+        assert_eq!(QR, (u16::MAX / 61, u16::MAX % 61));
+    }
+
     #[test]
     fn test_barret_reduce_128() {
         let mut rng = thread_rng();
@@ -100,6 +131,70 @@ mod tests {
         assert_eq!((lw64, hw64).reduce(modulus), (v % (m as u128)) as u64);
     }
 
+    #[test]
+    fn test_barrett_u128() {
+        let mut rng = thread_rng();
+
+        let m: u128 = rng.gen_range(2..=(u128::MAX >> 2));
+        let modulus = BarrettModulus::<u128>::new(m);
+        for v in [0, 1, m - 1, m, m + 1, u128::MAX, rng.gen()] {
+            assert_eq!(v.reduce(modulus), v % m);
+            assert_eq!(modulus.div_rem(v), (v / m, v % m));
+            assert_eq!(modulus.div_rem_ct(v), (v / m, v % m));
+        }
+
+        // `x * y mod m` by double-and-add, as the product doesn't fit in `u128`
+        let (x, y): (u128, u128) = (rng.gen_range(0..m), rng.gen_range(0..m));
+        let expected = (0..u128::BITS).rev().fold(0, |acc, i| {
+            let acc = (acc << 1) % m;
+            if (y >> i) & 1 == 1 {
+                (acc + x) % m
+            } else {
+                acc
+            }
+        });
+        let (low, high) = crate::Widening::widen_mul(x, y);
+        assert_eq!([low, high].reduce(modulus), expected);
+        assert_eq!((low, high).reduce(modulus), expected);
+
+        let v: i128 = rng.gen();
+        assert_eq!(v.signed_reduce(modulus), v.rem_euclid(m as i128) as u128);
+
+        const M: BarrettModulus<u128> = BarrettModulus::<u128>::new(1 << 100);
+        assert_eq!(
+            M.div_rem(u128::MAX),
+            (u128::MAX >> 100, u128::MAX % (1 << 100))
+        );
+    }
+
+    #[test]
+    fn test_barrett_signed_reduce() {
+        let mut rng = thread_rng();
+
+        let m: u64 = rng.gen_range(2..=(u64::MAX >> 2));
+        let modulus = BarrettModulus::<u64>::new(m);
+        let v: i128 = rng.gen();
+        assert_eq!(v.signed_reduce(modulus), v.rem_euclid(m as i128) as u64);
+        let v: i64 = rng.gen();
+        assert_eq!(v.signed_reduce(modulus), v.rem_euclid(m as i64) as u64);
+
+        let m: u32 = rng.gen_range(2..=(u32::MAX >> 2));
+        let modulus = BarrettModulus::<u32>::new(m);
+        let v: i64 = rng.gen();
+        assert_eq!(v.signed_reduce(modulus), v.rem_euclid(m as i64) as u32);
+
+        assert_eq!((-1i64).signed_reduce(modulus), m - 1);
+        assert_eq!((-(m as i64)).signed_reduce(modulus), 0);
+        assert_eq!(
+            i64::MIN.signed_reduce(modulus),
+            i64::MIN.rem_euclid(m as i64) as u32
+        );
+        assert_eq!(
+            i128::MIN.signed_reduce(BarrettModulus::<u8>::new(61)),
+            61 - 6
+        );
+    }
+
     #[test]
     fn test_barrett_const() {
         const MODULUS1: BarrettModulus<u32> = BarrettModulus::<u32>::new(17);
@@ -1,4 +1,4 @@
-use std::ops::ShrAssign;
+use core::ops::ShrAssign;
 
 use num_traits::{One, PrimInt};
 
@@ -0,0 +1,237 @@
+//! [`BarrettModulus<u128>`], whose products don't fit in a primitive type,
+//! so the reduction works on [`U256`] instead of casting to a wide type.
+
+use crate::reduce::{LazyReduce, LazyReduceAssign, Reduce, ReduceAssign};
+use crate::U256;
+
+use super::BarrettModulus;
+
+impl BarrettModulus<u128> {
+    /// Creates a [`BarrettModulus<T>`] instance.
+    ///
+    /// - `value`: The value of the modulus.
+    ///
+    /// # Panics
+    ///
+    /// The `value`'s `bit_count` should be at most 126, others will panic.
+    pub const fn new(value: u128) -> Self {
+        match value {
+            0 | 1 => panic!("modulus can't be 0 or 1."),
+            _ => {
+                let bit_count = u128::BITS - value.leading_zeros();
+                assert!(bit_count < u128::BITS - 1);
+
+                // `⌊(b^2 - 1) / value⌋` is one less than `⌊b^2 / value⌋`
+                // exactly when `value` divides `b^2`.
+                let (mut ratio, _) = U256::MAX.div_rem(value);
+                if value.is_power_of_two() {
+                    ratio = ratio.overflowing_add(U256::new(1, 0)).0;
+                }
+
+                Self {
+                    value,
+                    ratio: [ratio.low(), ratio.high()],
+                }
+            }
+        }
+    }
+
+    /// Returns the bit count of this [`BarrettModulus<T>`].
+    #[inline]
+    pub const fn bit_count(&self) -> u32 {
+        u128::BITS - self.value.leading_zeros()
+    }
+
+    /// Calculates the `q3` of the reduction, see [`Self::div_rem`].
+    #[inline]
+    const fn quotient(&self, x: u128) -> u128 {
+        let tmp = U256::widening_mul(x, self.ratio[0]).high();
+        U256::widening_mul(x, self.ratio[1])
+            .overflowing_add(U256::new(tmp, 0))
+            .0
+            .high()
+    }
+
+    /// Calculates the quotient and the remainder `(⌊x / value⌋, x mod value)`.
+    ///
+    /// The quotient is the `q3` of the reduction, corrected at most once,
+    /// so it reuses the precomputed ratio instead of a long division.
+    #[inline]
+    pub const fn div_rem(&self, x: u128) -> (u128, u128) {
+        let quotient = self.quotient(x);
+        let remainder = x.wrapping_sub(quotient.wrapping_mul(self.value));
+
+        if remainder >= self.value {
+            (quotient + 1, remainder - self.value)
+        } else {
+            (quotient, remainder)
+        }
+    }
+
+    /// Calculates the quotient `⌊x / value⌋`, see [`Self::div_rem`].
+    #[inline]
+    pub const fn div(&self, x: u128) -> u128 {
+        self.div_rem(x).0
+    }
+
+    /// Calculates [`Self::div_rem`] without branching on `x`,
+    /// the correction is selected in constant time for secret values.
+    #[inline]
+    pub fn div_rem_ct(&self, x: u128) -> (u128, u128) {
+        use subtle::{ConditionallySelectable, ConstantTimeLess};
+
+        let quotient = self.quotient(x);
+        let remainder = x.wrapping_sub(quotient.wrapping_mul(self.value));
+
+        let correct = !remainder.ct_lt(&self.value);
+        (
+            u128::conditional_select(&quotient, &quotient.wrapping_add(1), correct),
+            u128::conditional_select(&remainder, &remainder.wrapping_sub(self.value), correct),
+        )
+    }
+}
+
+impl LazyReduce<BarrettModulus<u128>> for u128 {
+    type Output = Self;
+
+    /// Caculates `self (mod 2*modulus)`, the same procedure as for the narrower moduli.
+    #[inline]
+    fn lazy_reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        self.wrapping_sub(modulus.quotient(self).wrapping_mul(modulus.value()))
+    }
+}
+
+impl Reduce<BarrettModulus<u128>> for u128 {
+    type Output = Self;
+
+    /// Caculates `self (mod modulus)`.
+    #[inline]
+    fn reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        let tmp = self.lazy_reduce(modulus);
+
+        if tmp >= modulus.value() {
+            tmp - modulus.value()
+        } else {
+            tmp
+        }
+    }
+}
+
+impl LazyReduce<BarrettModulus<u128>> for [u128; 2] {
+    type Output = u128;
+
+    /// Caculates `self (mod 2*modulus)`, the same procedure as for the narrower moduli.
+    ///
+    /// Only the low 128 bits of `q3` are needed, so the carry out of
+    /// the middle column and the high half of `value[1] * ratio[1]` are dropped.
+    #[inline]
+    fn lazy_reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        let ratio = modulus.ratio();
+
+        let a = U256::widening_mul(ratio[0], self[0]);
+        let b_plus_a_left = U256::widening_mul(ratio[1], self[0])
+            .overflowing_add(U256::new(a.high(), 0))
+            .0;
+
+        let c = U256::widening_mul(ratio[0], self[1]);
+        let d = ratio[1].wrapping_mul(self[1]);
+
+        let tmp = d.wrapping_add(b_plus_a_left.overflowing_add(c).0.high());
+
+        self[0].wrapping_sub(tmp.wrapping_mul(modulus.value()))
+    }
+}
+
+impl Reduce<BarrettModulus<u128>> for [u128; 2] {
+    type Output = u128;
+
+    /// Caculates `self (mod modulus)`.
+    #[inline]
+    fn reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        let r = self.lazy_reduce(modulus);
+
+        if r >= modulus.value() {
+            r - modulus.value()
+        } else {
+            r
+        }
+    }
+}
+
+impl LazyReduce<BarrettModulus<u128>> for (u128, u128) {
+    type Output = u128;
+
+    /// Caculates `self (mod 2*modulus)`.
+    #[inline]
+    fn lazy_reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        [self.0, self.1].lazy_reduce(modulus)
+    }
+}
+
+impl Reduce<BarrettModulus<u128>> for (u128, u128) {
+    type Output = u128;
+
+    /// Caculates `self (mod modulus)`.
+    #[inline]
+    fn reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        [self.0, self.1].reduce(modulus)
+    }
+}
+
+impl LazyReduce<BarrettModulus<u128>> for &[u128] {
+    type Output = u128;
+
+    /// Caculates `self (mod 2*modulus)` when value's length > 0.
+    fn lazy_reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        match self {
+            &[] => unreachable!(),
+            &[v] => {
+                if v < modulus.value() {
+                    v
+                } else {
+                    v.lazy_reduce(modulus)
+                }
+            }
+            [other @ .., last] => other
+                .iter()
+                .rfold(*last, |acc, &x| [x, acc].lazy_reduce(modulus)),
+        }
+    }
+}
+
+impl Reduce<BarrettModulus<u128>> for &[u128] {
+    type Output = u128;
+
+    /// Caculates `self (mod modulus)` when value's length > 0.
+    fn reduce(self, modulus: BarrettModulus<u128>) -> Self::Output {
+        match self {
+            &[] => unreachable!(),
+            &[v] => {
+                if v < modulus.value() {
+                    v
+                } else {
+                    v.reduce(modulus)
+                }
+            }
+            [other @ .., last] => other
+                .iter()
+                .rfold(*last, |acc, &x| [x, acc].reduce(modulus)),
+        }
+    }
+}
+
+impl LazyReduceAssign<BarrettModulus<u128>> for u128 {
+    /// Caculates `self (mod 2*modulus)`.
+    #[inline]
+    fn lazy_reduce_assign(&mut self, modulus: BarrettModulus<u128>) {
+        *self = (*self).lazy_reduce(modulus);
+    }
+}
+
+impl ReduceAssign<BarrettModulus<u128>> for u128 {
+    /// Caculates `self (mod modulus)`.
+    #[inline]
+    fn reduce_assign(&mut self, modulus: BarrettModulus<u128>) {
+        *self = (*self).reduce(modulus);
+    }
+}
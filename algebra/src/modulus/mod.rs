@@ -2,9 +2,11 @@
 //! modular arithmetic.
 
 mod barrett;
+mod native;
 mod powof2;
 mod shoup;
 
 pub use barrett::BarrettModulus;
+pub use native::NativeRing;
 pub use powof2::PowOf2Modulus;
 pub use shoup::ShoupFactor;
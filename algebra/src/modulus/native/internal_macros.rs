@@ -0,0 +1,159 @@
+macro_rules! impl_native_ring {
+    (impl NativeRing for $SelfT:ty; $SignedT:ty) => {
+        impl $crate::reduce::Reduce<NativeRing> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn reduce(self, _modulus: NativeRing) -> Self::Output {
+                self
+            }
+        }
+
+        impl $crate::reduce::ReduceAssign<NativeRing> for $SelfT {
+            #[inline]
+            fn reduce_assign(&mut self, _modulus: NativeRing) {}
+        }
+
+        impl $crate::reduce::SignedReduce<NativeRing> for $SignedT {
+            type Output = $SelfT;
+
+            /// Caculates `self (mod 2^k)`, which is the two's complement bits of `self`.
+            #[inline]
+            fn signed_reduce(self, _modulus: NativeRing) -> Self::Output {
+                self as $SelfT
+            }
+        }
+
+        impl $crate::reduce::AddReduce<NativeRing> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn add_reduce(self, rhs: Self, _modulus: NativeRing) -> Self::Output {
+                self.wrapping_add(rhs)
+            }
+        }
+
+        impl $crate::reduce::AddReduceAssign<NativeRing> for $SelfT {
+            #[inline]
+            fn add_reduce_assign(&mut self, rhs: Self, _modulus: NativeRing) {
+                *self = self.wrapping_add(rhs);
+            }
+        }
+
+        impl $crate::reduce::SubReduce<NativeRing> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn sub_reduce(self, rhs: Self, _modulus: NativeRing) -> Self::Output {
+                self.wrapping_sub(rhs)
+            }
+        }
+
+        impl $crate::reduce::SubReduceAssign<NativeRing> for $SelfT {
+            #[inline]
+            fn sub_reduce_assign(&mut self, rhs: Self, _modulus: NativeRing) {
+                *self = self.wrapping_sub(rhs);
+            }
+        }
+
+        impl $crate::reduce::NegReduce<NativeRing> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn neg_reduce(self, _modulus: NativeRing) -> Self::Output {
+                self.wrapping_neg()
+            }
+        }
+
+        impl $crate::reduce::NegReduceAssign<NativeRing> for $SelfT {
+            #[inline]
+            fn neg_reduce_assign(&mut self, _modulus: NativeRing) {
+                *self = self.wrapping_neg();
+            }
+        }
+
+        impl $crate::reduce::MulReduce<NativeRing> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn mul_reduce(self, rhs: Self, _modulus: NativeRing) -> Self::Output {
+                self.wrapping_mul(rhs)
+            }
+        }
+
+        impl $crate::reduce::MulReduceAssign<NativeRing> for $SelfT {
+            #[inline]
+            fn mul_reduce_assign(&mut self, rhs: Self, _modulus: NativeRing) {
+                *self = self.wrapping_mul(rhs);
+            }
+        }
+
+        impl<E> $crate::reduce::PowReduce<NativeRing, E> for $SelfT
+        where
+            E: ::num_traits::PrimInt + ::core::ops::ShrAssign<u32> + $crate::Bits,
+        {
+            /// Calculates `self^exp (mod 2^k)` by square-and-multiply.
+            fn pow_reduce(self, mut exp: E, _modulus: NativeRing) -> Self {
+                let mut power: Self = self;
+                let mut intermediate: Self = 1;
+                while !exp.is_zero() {
+                    if !(exp & E::one()).is_zero() {
+                        intermediate = intermediate.wrapping_mul(power);
+                    }
+                    exp >>= 1;
+                    power = power.wrapping_mul(power);
+                }
+                intermediate
+            }
+        }
+
+        impl $crate::reduce::InvReduce<NativeRing> for $SelfT {
+            /// Calculates the inverse of an odd `self` by Newton's iteration,
+            /// every step doubling the count of correct low bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is even.
+            fn inv_reduce(self, _modulus: NativeRing) -> Self {
+                assert!(
+                    self & 1 == 1,
+                    "even values have no inverse modulo a power of two"
+                );
+
+                // `self * self = 1 (mod 8)` for an odd `self`
+                let mut inv = self;
+                let mut correct_bits = 3;
+                while correct_bits < <$SelfT>::BITS {
+                    inv = inv.wrapping_mul((2 as $SelfT).wrapping_sub(self.wrapping_mul(inv)));
+                    correct_bits <<= 1;
+                }
+                inv
+            }
+        }
+
+        impl $crate::reduce::InvReduceAssign<NativeRing> for $SelfT {
+            #[inline]
+            fn inv_reduce_assign(&mut self, modulus: NativeRing) {
+                use $crate::reduce::InvReduce;
+                *self = self.inv_reduce(modulus);
+            }
+        }
+
+        impl $crate::reduce::TryInvReduce<NativeRing> for $SelfT {
+            #[inline]
+            fn try_inv_reduce(self, modulus: NativeRing) -> Result<Self, $crate::AlgebraError> {
+                use alloc::string::ToString;
+                use $crate::reduce::InvReduce;
+
+                if self & 1 == 1 {
+                    Ok(self.inv_reduce(modulus))
+                } else {
+                    Err($crate::AlgebraError::NoReduceInverse {
+                        value: self.to_string(),
+                        modulus: alloc::format!("2^{}", <$SelfT>::BITS),
+                    })
+                }
+            }
+        }
+    };
+}
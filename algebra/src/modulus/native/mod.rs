@@ -0,0 +1,79 @@
+//! The ring `Z_(2^k)` of a whole machine word, where `k` is the bit count of the word.
+//!
+//! Its modulus `2^k` is just past the largest value of the word, so it can't be
+//! a [`PowOf2Modulus`](super::PowOf2Modulus), and needs no reduction at all:
+//! every operation is the plain wrapping one of the word.
+
+#[macro_use]
+mod internal_macros;
+
+/// The modulus `2^k` of the native ring of a `k`-bit word, e.g. `q = 2^64` for `u64`.
+///
+/// The operations of the reduce traits are the wrapping operations of the word,
+/// so it replaces [`PowOf2Modulus`](super::PowOf2Modulus) when the modulus is the whole word,
+/// as in LWE-style accumulation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NativeRing;
+
+impl_native_ring!(impl NativeRing for u8; i8);
+impl_native_ring!(impl NativeRing for u16; i16);
+impl_native_ring!(impl NativeRing for u32; i32);
+impl_native_ring!(impl NativeRing for u64; i64);
+impl_native_ring!(impl NativeRing for u128; i128);
+impl_native_ring!(impl NativeRing for usize; isize);
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use crate::modulus::PowOf2Modulus;
+    use crate::reduce::*;
+
+    use super::*;
+
+    #[test]
+    fn test_native_reduce() {
+        let mut rng = thread_rng();
+
+        let a: u64 = rng.gen();
+        let b: u64 = rng.gen();
+        assert_eq!(a.reduce(NativeRing), a);
+        assert_eq!(a.add_reduce(b, NativeRing), a.wrapping_add(b));
+        assert_eq!(a.sub_reduce(b, NativeRing), a.wrapping_sub(b));
+        assert_eq!(a.mul_reduce(b, NativeRing), a.wrapping_mul(b));
+        assert_eq!(a.neg_reduce(NativeRing).add_reduce(a, NativeRing), 0);
+        assert_eq!(
+            a.pow_reduce(3u32, NativeRing),
+            a.wrapping_mul(a).wrapping_mul(a)
+        );
+
+        let v: i64 = rng.gen();
+        assert_eq!(v.signed_reduce(NativeRing), v as u64);
+        assert_eq!((-1i64).signed_reduce(NativeRing), u64::MAX);
+
+        // the same results as a power-of-two modulus below the word, after masking
+        let modulus = PowOf2Modulus::<u32>::new(1 << 20);
+        let (a, b): (u32, u32) = (rng.gen(), rng.gen());
+        assert_eq!(
+            a.mul_reduce(b, NativeRing) & modulus.mask(),
+            (a & modulus.mask()).mul_reduce(b & modulus.mask(), modulus)
+        );
+    }
+
+    #[test]
+    fn test_native_inv_reduce() {
+        let mut rng = thread_rng();
+
+        let a: u64 = rng.gen::<u64>() | 1;
+        let inv = a.inv_reduce(NativeRing);
+        assert_eq!(a.wrapping_mul(inv), 1);
+
+        let a: u128 = rng.gen::<u128>() | 1;
+        assert_eq!(a.try_inv_reduce(NativeRing).unwrap().wrapping_mul(a), 1);
+        assert!((a - 1).try_inv_reduce(NativeRing).is_err());
+
+        let mut b: u8 = 3;
+        b.inv_reduce_assign(NativeRing);
+        assert_eq!(b, 171);
+    }
+}
@@ -99,7 +99,7 @@ macro_rules! impl_powof2_modulus {
 
         impl<E> $crate::reduce::PowReduce<PowOf2Modulus<Self>, E> for $SelfT
         where
-            E: ::num_traits::PrimInt + ::std::ops::ShrAssign<u32> + $crate::Bits,
+            E: ::num_traits::PrimInt + ::core::ops::ShrAssign<u32> + $crate::Bits,
         {
             fn pow_reduce(self, mut exp: E, modulus: PowOf2Modulus<Self>) -> Self {
                 use $crate::reduce::MulReduce;
@@ -134,5 +134,57 @@ macro_rules! impl_powof2_modulus {
                 intermediate
             }
         }
+
+        impl $crate::reduce::InvReduce<PowOf2Modulus<Self>> for $SelfT {
+            /// Calculates the inverse of an odd `self` by Newton's iteration,
+            /// every step doubling the count of correct low bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `self` is even.
+            fn inv_reduce(self, modulus: PowOf2Modulus<Self>) -> Self {
+                assert!(
+                    self & 1 == 1,
+                    "even values have no inverse modulo a power of two"
+                );
+
+                // `self * self = 1 (mod 8)` for an odd `self`
+                let mut inv = self;
+                let mut correct_bits = 3;
+                while correct_bits < <$SelfT>::BITS {
+                    inv = inv.wrapping_mul((2 as $SelfT).wrapping_sub(self.wrapping_mul(inv)));
+                    correct_bits <<= 1;
+                }
+                inv & modulus.mask()
+            }
+        }
+
+        impl $crate::reduce::InvReduceAssign<PowOf2Modulus<Self>> for $SelfT {
+            #[inline]
+            fn inv_reduce_assign(&mut self, modulus: PowOf2Modulus<Self>) {
+                use $crate::reduce::InvReduce;
+                *self = self.inv_reduce(modulus);
+            }
+        }
+
+        impl $crate::reduce::TryInvReduce<PowOf2Modulus<Self>> for $SelfT {
+            #[inline]
+            fn try_inv_reduce(
+                self,
+                modulus: PowOf2Modulus<Self>,
+            ) -> Result<Self, $crate::AlgebraError> {
+                use alloc::string::ToString;
+                use $crate::reduce::InvReduce;
+
+                if self & 1 == 1 {
+                    Ok(self.inv_reduce(modulus))
+                } else {
+                    Err($crate::AlgebraError::NoReduceInverse {
+                        value: self.to_string(),
+                        modulus: modulus.value().to_string(),
+                    })
+                }
+            }
+        }
     };
 }
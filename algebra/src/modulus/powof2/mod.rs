@@ -24,6 +24,7 @@ impl_powof2_modulus!(impl PowOf2Modulus<u16>);
 impl_powof2_modulus!(impl PowOf2Modulus<u32>);
 impl_powof2_modulus!(impl PowOf2Modulus<u64>);
 impl_powof2_modulus!(impl PowOf2Modulus<u128>);
+impl_powof2_modulus!(impl PowOf2Modulus<usize>);
 
 #[cfg(test)]
 mod tests {
@@ -42,6 +43,8 @@ mod tests {
         let _m = PowOf2Modulus::<u16>::new(rng.gen_range(2..=(u16::MAX >> 2)).next_power_of_two());
         let _m = PowOf2Modulus::<u32>::new(rng.gen_range(2..=(u32::MAX >> 2)).next_power_of_two());
         let _m = PowOf2Modulus::<u64>::new(rng.gen_range(2..=(u64::MAX >> 2)).next_power_of_two());
+        let _m =
+            PowOf2Modulus::<usize>::new(rng.gen_range(2..=(usize::MAX >> 2)).next_power_of_two());
     }
 
     #[test]
@@ -92,4 +95,28 @@ mod tests {
 
         assert_eq!(0.neg_reduce(modulus), 0);
     }
+
+    #[test]
+    fn test_inv_reduce() {
+        let mut rng = thread_rng();
+
+        let m: u128 = 1 << rng.gen_range(1..128);
+        let modulus = PowOf2Modulus::<u128>::new(m);
+        let a: u128 = rng.gen::<u128>() & modulus.mask() | 1;
+        let inv = a.inv_reduce(modulus);
+        assert_eq!(a.mul_reduce(inv, modulus), 1);
+        assert_eq!(
+            a.pow_reduce(3u32, modulus),
+            a.wrapping_mul(a).wrapping_mul(a) & modulus.mask()
+        );
+
+        let modulus = PowOf2Modulus::<usize>::new(1 << 20);
+        let a: usize = rng.gen_range(0..1 << 19) * 2 + 1;
+        assert_eq!(a.try_inv_reduce(modulus).unwrap().mul_reduce(a, modulus), 1);
+        assert!((a - 1).try_inv_reduce(modulus).is_err());
+
+        let mut b: u8 = 3;
+        b.inv_reduce_assign(PowOf2Modulus::<u8>::new(128));
+        assert_eq!(b, 43);
+    }
 }
@@ -30,6 +30,110 @@ macro_rules! impl_shoup_factor {
                 self.set_modulus(modulus);
             }
 
+            /// Precomputes the [`ShoupFactor`]s of `values`,
+            /// for operands which will multiply many others.
+            ///
+            /// * every value must be less than `modulus`.
+            pub fn precompute_slice(
+                values: &[$SelfT],
+                modulus: $SelfT,
+            ) -> $crate::__private::Vec<Self> {
+                values
+                    .iter()
+                    .map(|&value| Self::new(value, modulus))
+                    .collect()
+            }
+
+            /// Precomputes the [`ShoupFactor`]s of a fixed array of `values` in const contexts,
+            /// so the factors can be baked into constants.
+            ///
+            /// * every value must be less than `modulus`.
+            pub const fn precompute_array<const N: usize>(
+                values: [$SelfT; N],
+                modulus: $SelfT,
+            ) -> [Self; N] {
+                let mut factors = [Self {
+                    value: 0,
+                    quotient: 0,
+                }; N];
+                let mut i = 0;
+                while i < N {
+                    factors[i] = Self::new(values[i], modulus);
+                    i += 1;
+                }
+                factors
+            }
+
+            /// Calculates `values[i] = values[i] * factors[i].value mod modulus`.
+            ///
+            /// The results are in `[0, modulus)`.
+            ///
+            /// # Correctness
+            ///
+            /// `factors[i].value` must be less than `modulus`.
+            #[inline]
+            pub fn mul_reduce_slice(factors: &[Self], values: &mut [$SelfT], modulus: $SelfT) {
+                debug_assert_eq!(factors.len(), values.len());
+                for (value, factor) in values.iter_mut().zip(factors) {
+                    let tmp = factor.mul_reduce_lazy(*value, modulus);
+                    *value = if tmp >= modulus { tmp - modulus } else { tmp };
+                }
+            }
+
+            /// Calculates `values[i] = values[i] * factors[i].value mod modulus`
+            /// without the final correction, as the butterflies of the NTT do.
+            ///
+            /// The results are in `[0, 2 * modulus)`.
+            ///
+            /// # Correctness
+            ///
+            /// `factors[i].value` must be less than `modulus`.
+            #[inline]
+            pub fn mul_reduce_lazy_slice(factors: &[Self], values: &mut [$SelfT], modulus: $SelfT) {
+                debug_assert_eq!(factors.len(), values.len());
+                for (value, factor) in values.iter_mut().zip(factors) {
+                    *value = factor.mul_reduce_lazy(*value, modulus);
+                }
+            }
+
+            /// Calculates `values[i] = values[i] * self.value mod modulus`.
+            ///
+            /// The results are in `[0, modulus)`.
+            #[inline]
+            pub fn mul_reduce_scalar_slice(self, values: &mut [$SelfT], modulus: $SelfT) {
+                for value in values.iter_mut() {
+                    let tmp = self.mul_reduce_lazy(*value, modulus);
+                    *value = if tmp >= modulus { tmp - modulus } else { tmp };
+                }
+            }
+
+            /// Calculates `acc[i] = acc[i] + values[i] * factors[i].value mod modulus`,
+            /// the inner loop of key switching with precomputed keys.
+            ///
+            /// The results are in `[0, modulus)`.
+            ///
+            /// # Correctness
+            ///
+            /// - `acc[i]` must be less than `modulus`.
+            /// - `factors[i].value` must be less than `modulus`.
+            #[inline]
+            pub fn add_mul_reduce_slice(
+                factors: &[Self],
+                values: &[$SelfT],
+                acc: &mut [$SelfT],
+                modulus: $SelfT,
+            ) {
+                debug_assert_eq!(factors.len(), values.len());
+                debug_assert_eq!(factors.len(), acc.len());
+                for ((acc, &value), factor) in acc.iter_mut().zip(values).zip(factors) {
+                    let tmp = factor.mul_reduce_lazy(value, modulus);
+                    let tmp = if tmp >= modulus { tmp - modulus } else { tmp };
+                    // `acc + tmp < 2 * modulus` doesn't overflow as `modulus` is below `2^(BITS-1)`
+                    let sum = *acc + tmp;
+                    *acc = if sum >= modulus { sum - modulus } else { sum };
+                }
+            }
+
             /// Calculates `rhs * self.value mod modulus`.
             ///
             /// The result is in [0, 2 * `modulus`).
@@ -5,7 +5,10 @@ mod internal_macros;
 ///
 /// This is efficient if many operations are multiplied by
 /// the same number and then reduced with the same modulus.
+///
+/// The layout is `repr(C)`, so tables of factors can be stored in flat blobs.
 #[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
 pub struct ShoupFactor<T: Copy> {
     /// value
     value: T,
@@ -42,12 +45,83 @@ impl_shoup_factor_ops!(impl ShoupFactor<u64>);
 mod tests {
     use crate::{
         modulus::BarrettModulus,
-        reduce::{MulReduce, Reduce},
+        reduce::{AddReduce, MulReduce, Reduce},
     };
 
     use super::*;
     use rand::prelude::*;
 
+    #[test]
+    fn test_shoup_factor_slice() {
+        let mut rng = thread_rng();
+
+        let modulus_value: u32 = rng.gen_range(2..=(u32::MAX >> 2));
+        let modulus = BarrettModulus::<u32>::new(modulus_value);
+
+        let a: Vec<u32> = (0..33).map(|_| rng.gen_range(0..modulus_value)).collect();
+        let b: Vec<u32> = (0..33).map(|_| rng.gen_range(0..modulus_value)).collect();
+
+        let factors = <ShoupFactor<u32>>::precompute_slice(&a, modulus_value);
+        let mut c = b.clone();
+        <ShoupFactor<u32>>::mul_reduce_slice(&factors, &mut c, modulus_value);
+
+        for ((&a, &b), &c) in a.iter().zip(&b).zip(&c) {
+            assert_eq!(c, a.mul_reduce(b, modulus));
+        }
+    }
+
+    #[test]
+    fn test_shoup_factor_slice_kernels() {
+        let mut rng = thread_rng();
+
+        let modulus_value: u32 = rng.gen_range(2..=(u32::MAX >> 2));
+        let modulus = BarrettModulus::<u32>::new(modulus_value);
+
+        let a: Vec<u32> = (0..33).map(|_| rng.gen_range(0..modulus_value)).collect();
+        let b: Vec<u32> = (0..33).map(|_| rng.gen_range(0..modulus_value)).collect();
+        let acc: Vec<u32> = (0..33).map(|_| rng.gen_range(0..modulus_value)).collect();
+        let scalar = rng.gen_range(0..modulus_value);
+
+        let factors = <ShoupFactor<u32>>::precompute_slice(&a, modulus_value);
+
+        let mut c = b.clone();
+        <ShoupFactor<u32>>::mul_reduce_lazy_slice(&factors, &mut c, modulus_value);
+        for ((&a, &b), &c) in a.iter().zip(&b).zip(&c) {
+            assert!(c < 2 * modulus_value);
+            assert_eq!(c.reduce(modulus), a.mul_reduce(b, modulus));
+        }
+
+        let mut c = b.clone();
+        <ShoupFactor<u32>>::new(scalar, modulus_value)
+            .mul_reduce_scalar_slice(&mut c, modulus_value);
+        for (&b, &c) in b.iter().zip(&c) {
+            assert_eq!(c, scalar.mul_reduce(b, modulus));
+        }
+
+        let mut c = acc.clone();
+        <ShoupFactor<u32>>::add_mul_reduce_slice(&factors, &b, &mut c, modulus_value);
+        for (((&a, &b), &acc), &c) in a.iter().zip(&b).zip(&acc).zip(&c) {
+            assert_eq!(c, acc.add_reduce(a.mul_reduce(b, modulus), modulus_value));
+        }
+    }
+
+    #[test]
+    fn test_shoup_factor_const() {
+        const MODULUS: u32 = 132120577;
+        const FACTORS: [ShoupFactor<u32>; 3] =
+            <ShoupFactor<u32>>::precompute_array([1, 2, MODULUS - 1], MODULUS);
+        const FACTOR: ShoupFactor<u64> = <ShoupFactor<u64>>::new(3, 7);
+
+        assert_eq!(
+            FACTORS.map(|f| (f.value(), f.quotient())),
+            <ShoupFactor<u32>>::precompute_slice(&[1, 2, MODULUS - 1], MODULUS)
+                .iter()
+                .map(|f| (f.value(), f.quotient()))
+                .collect::<Vec<_>>()[..]
+        );
+        assert_eq!(FACTOR.mul_reduce(5, 7), 1);
+    }
+
     #[test]
     fn test_shoup_factor() {
         let mut rng = thread_rng();
@@ -1,6 +1,6 @@
 // It is derived from https://github.com/arkworks-rs/sumcheck/blob/master/src/ml_sumcheck/data_structures.rs .
 
-use std::{collections::HashMap, rc::Rc};
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
 
 use crate::{Field, Random};
 
@@ -30,7 +30,7 @@ pub struct ListOfProductsOfPolynomials<F: Field> {
     pub products: Vec<(F, Vec<usize>)>,
     /// Stores multilinear extensions in which product multiplicand can refer to.
     pub flattened_ml_extensions: Vec<Rc<DenseMultilinearExtension<F>>>,
-    raw_pointers_lookup_table: HashMap<*const DenseMultilinearExtension<F>, usize>,
+    raw_pointers_lookup_table: BTreeMap<*const DenseMultilinearExtension<F>, usize>,
 }
 
 /// Extract the max number of multiplicands and number of variables of the list of products.
@@ -64,7 +64,7 @@ impl<F: Field + Random> ListOfProductsOfPolynomials<F> {
             num_variables,
             products: Vec::new(),
             flattened_ml_extensions: Vec::new(),
-            raw_pointers_lookup_table: HashMap::new(),
+            raw_pointers_lookup_table: BTreeMap::new(),
         }
     }
 
@@ -1,8 +1,9 @@
 // It is derived from https://github.com/arkworks-rs/sumcheck.
 
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
-use std::slice::{Iter, IterMut};
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
+use core::slice::{Iter, IterMut};
 
 use num_traits::Zero;
 use rand_distr::Distribution;
@@ -81,7 +82,7 @@ impl<F: Field + Random> MultilinearExtension<F> for DenseMultilinearExtension<F>
     #[inline]
     fn random<R>(num_vars: usize, rng: &mut R) -> Self
     where
-        R: rand::Rng + rand::CryptoRng,
+        R: crate::CsRng,
     {
         Self {
             num_vars,
@@ -140,7 +141,7 @@ impl<F: Field> Index<usize> for DenseMultilinearExtension<F> {
 
 impl<F: Field> Debug for DenseMultilinearExtension<F> {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         write!(f, "DenseML(nv = {}, evaluations = [", self.num_vars)?;
         for i in 0..4.min(self.evaluations.len()) {
             write!(f, "{:?}", self.evaluations[i])?;
@@ -187,7 +188,7 @@ impl<'a, F: Field> Add<&'a DenseMultilinearExtension<F>> for DenseMultilinearExt
     }
 }
 
-impl<'a, 'b, F: Field> Add<&'a DenseMultilinearExtension<F>> for &'b DenseMultilinearExtension<F> {
+impl<'a, F: Field> Add<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
     type Output = DenseMultilinearExtension<F>;
 
     #[inline]
@@ -260,7 +261,7 @@ impl<'a, F: Field> Sub<&'a DenseMultilinearExtension<F>> for DenseMultilinearExt
     }
 }
 
-impl<'a, 'b, F: Field> Sub<&'a DenseMultilinearExtension<F>> for &'b DenseMultilinearExtension<F> {
+impl<'a, F: Field> Sub<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
     type Output = DenseMultilinearExtension<F>;
 
     #[inline]
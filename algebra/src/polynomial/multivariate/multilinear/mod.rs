@@ -1,6 +1,7 @@
 // It is derived from https://github.com/arkworks-rs/sumcheck.
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
 
 use num_traits::Zero;
 
@@ -43,7 +44,7 @@ pub trait MultilinearExtension<F: Field>:
 
     /// Outputs an `l`-variate multilinear extension where value of evaluations
     /// are sampled at random.
-    fn random<R: rand::Rng + rand::CryptoRng>(num_vars: usize, rng: &mut R) -> Self;
+    fn random<R: crate::CsRng>(num_vars: usize, rng: &mut R) -> Self;
 
     /// Reduce the number of variables of `self` by fixing the
     /// `partial_point.len()` variables at `partial_point`.
@@ -1,13 +1,14 @@
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
-use std::slice::{Iter, IterMut, SliceIndex};
-use std::vec::IntoIter;
+use alloc::vec;
+use alloc::vec::{IntoIter, Vec};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::slice::{ChunksExact, ChunksExactMut, Iter, IterMut, SliceIndex};
 
-use rand::{CryptoRng, Rng};
 use rand_distr::Distribution;
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::transformation::AbstractNTT;
-use crate::{Basis, Field, FieldDiscreteGaussianSampler, NTTField, Random};
+use crate::{Basis, CsRng, Field, FieldDiscreteGaussianSampler, NTTField, Random};
 
 use super::NTTPolynomial;
 
@@ -44,9 +45,13 @@ impl<F: NTTField> From<NTTPolynomial<F>> for Polynomial<F> {
     fn from(ntt_polynomial: NTTPolynomial<F>) -> Self {
         debug_assert!(ntt_polynomial.coeff_count().is_power_of_two());
 
+        ntt_polynomial.check_canonical();
+
         let ntt_table = F::get_ntt_table(ntt_polynomial.coeff_count().trailing_zeros()).unwrap();
 
-        ntt_table.inverse_transform_inplace(ntt_polynomial)
+        let polynomial = ntt_table.inverse_transform_inplace(ntt_polynomial);
+        polynomial.check_canonical();
+        polynomial
     }
 }
 
@@ -137,22 +142,120 @@ impl<F: Field> Polynomial<F> {
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter(&self) -> Iter<F> {
+    pub fn iter(&self) -> Iter<'_, F> {
         self.data.iter()
     }
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn copied_iter(&self) -> std::iter::Copied<Iter<'_, F>> {
+    pub fn copied_iter(&self) -> core::iter::Copied<Iter<'_, F>> {
         self.data.iter().copied()
     }
 
     /// Returns an iterator that allows modifying each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<F> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, F> {
         self.data.iter_mut()
     }
 
+    /// Returns `true` if every value is below the modulus, i.e. no lazily reduced value
+    /// in `[q, 2q)` escaped a fast path.
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        self.data.iter().all(|x| x.get() < F::MODULUS_INNER)
+    }
+
+    /// Asserts in debug builds that every value is below the modulus, see [`Self::is_canonical`].
+    #[inline]
+    #[track_caller]
+    pub fn debug_assert_canonical(&self) {
+        debug_assert!(
+            self.is_canonical(),
+            "the values should be below the modulus"
+        );
+    }
+
+    /// Asserts that every value is below the modulus with the `canonical-checks` feature,
+    /// at the boundaries of the transforms.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn check_canonical(&self) {
+        #[cfg(feature = "canonical-checks")]
+        assert!(
+            self.is_canonical(),
+            "the values should be below the modulus"
+        );
+    }
+
+    /// Returns an iterator over `chunk_size` coefficients at a time, without the remainder,
+    /// for writing blocked kernels over the coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[inline]
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<'_, F> {
+        self.data.chunks_exact(chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` mutable coefficients at a time, without the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[inline]
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<'_, F> {
+        self.data.chunks_exact_mut(chunk_size)
+    }
+
+    /// Returns a parallel iterator over `chunk_size` coefficients at a time,
+    /// the last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub fn par_chunks(&self, chunk_size: usize) -> rayon::slice::Chunks<'_, F> {
+        use rayon::slice::ParallelSlice;
+        self.data.par_chunks(chunk_size)
+    }
+
+    /// Returns a parallel iterator over `chunk_size` mutable coefficients at a time,
+    /// the last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub fn par_chunks_mut(&mut self, chunk_size: usize) -> rayon::slice::ChunksMut<'_, F> {
+        use rayon::slice::ParallelSliceMut;
+        self.data.par_chunks_mut(chunk_size)
+    }
+
+    /// Performs `self[i] = f(self[i], other[i])` for every coefficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different coeff count.
+    #[inline]
+    pub fn zip_map_inplace<FN>(&mut self, other: impl AsRef<[F]>, mut f: FN)
+    where
+        FN: FnMut(F, F) -> F,
+    {
+        let other = other.as_ref();
+        assert_eq!(
+            self.data.len(),
+            other.len(),
+            "the coeff counts should be equal"
+        );
+        self.data
+            .iter_mut()
+            .zip(other)
+            .for_each(|(a, &b)| *a = f(*a, b));
+    }
+
     /// Resize the coefficient count of the polynomial.
     #[inline]
     pub fn resize(&mut self, new_degree: usize, value: F) {
@@ -187,7 +290,7 @@ impl<F: Field> Polynomial<F> {
     #[inline]
     pub fn random_with_binary<R>(n: usize, mut rng: R) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
     {
         Self::new(crate::utils::sample_binary_field_vec(n, &mut rng))
     }
@@ -196,11 +299,29 @@ impl<F: Field> Polynomial<F> {
     #[inline]
     pub fn random_with_ternary<R>(n: usize, mut rng: R) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
     {
         Self::new(crate::utils::sample_ternary_field_vec(n, &mut rng))
     }
 
+    /// Generate a random sparse ternary [`Polynomial<F>`] with exactly `hamming_weight`
+    /// nonzero coefficients, each `1` or `-1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hamming_weight` is larger than `n`.
+    #[inline]
+    pub fn random_sparse<R>(n: usize, hamming_weight: usize, mut rng: R) -> Self
+    where
+        R: CsRng,
+    {
+        Self::new(crate::utils::sample_ternary_field_vec_with_weight(
+            n,
+            hamming_weight,
+            &mut rng,
+        ))
+    }
+
     /// Generate a random [`Polynomial<F>`] with discrete gaussian distribution.
     #[inline]
     pub fn random_with_gaussian<R>(
@@ -209,13 +330,40 @@ impl<F: Field> Polynomial<F> {
         gaussian: FieldDiscreteGaussianSampler,
     ) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
+        FieldDiscreteGaussianSampler: Distribution<F>,
+    {
+        Self::new(crate::utils::sample_gaussian_field_vec(
+            n, gaussian, &mut rng,
+        ))
+    }
+
+    /// Overwrite `self` with a random ternary polynomial, reusing its allocation.
+    #[inline]
+    pub fn random_with_ternary_inplace<R>(&mut self, mut rng: R)
+    where
+        R: CsRng,
+    {
+        crate::utils::sample_ternary_field_slice(self.as_mut_slice(), &mut rng);
+    }
+
+    /// Overwrite `self` with a random polynomial with discrete gaussian distribution,
+    /// reusing its allocation.
+    #[inline]
+    pub fn random_with_gaussian_inplace<R>(
+        &mut self,
+        mut rng: R,
+        gaussian: FieldDiscreteGaussianSampler,
+    ) where
+        R: CsRng,
         FieldDiscreteGaussianSampler: Distribution<F>,
     {
         if gaussian.cbd_enable() {
-            Self::new(crate::utils::sample_cbd_field_vec(n, &mut rng))
+            crate::utils::sample_cbd_field_slice(self.as_mut_slice(), &mut rng);
         } else {
-            Self::new(gaussian.sample_iter(rng).take(n).collect())
+            self.iter_mut()
+                .zip(gaussian.sample_iter(rng))
+                .for_each(|(x, v)| *x = v);
         }
     }
 }
@@ -225,7 +373,7 @@ impl<F: Field + Random> Polynomial<F> {
     #[inline]
     pub fn random<R>(n: usize, rng: R) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
     {
         Self {
             data: F::standard_distribution()
@@ -239,7 +387,7 @@ impl<F: Field + Random> Polynomial<F> {
     #[inline]
     pub fn random_with_distribution<R, D>(n: usize, rng: R, distribution: D) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
         D: Distribution<F>,
     {
         Self::new(distribution.sample_iter(rng).take(n).collect())
@@ -247,6 +395,29 @@ impl<F: Field + Random> Polynomial<F> {
 }
 
 impl<F: NTTField> Polynomial<F> {
+    /// Performs `self *= rhs` with the given `ntt_table` of their length,
+    /// skipping the lookup of [`NTTField::get_ntt_table`].
+    ///
+    /// Hold the table obtained once when multiplying many polynomials of the same length.
+    pub fn mul_assign_with_table(&mut self, rhs: Self, ntt_table: &F::Table) {
+        debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
+
+        let lhs = self.as_mut_slice();
+        let rhs = ntt_table.transform_inplace(rhs);
+        ntt_table.transform_slice(lhs);
+        ntt_mul_assign_fast(lhs, &rhs);
+        ntt_table.inverse_transform_slice(lhs);
+    }
+
+    /// Returns `self * rhs` with the given `ntt_table` of their length,
+    /// see [`Polynomial::mul_assign_with_table`].
+    #[inline]
+    pub fn mul_with_table(&self, rhs: &Self, ntt_table: &F::Table) -> Self {
+        let mut result = self.clone();
+        result.mul_assign_with_table(rhs.clone(), ntt_table);
+        result
+    }
+
     /// Convert `self` from [`Polynomial<F>`] to [`NTTPolynomial<F>`].
     #[inline]
     pub fn into_ntt_polynomial(self) -> NTTPolynomial<F> {
@@ -273,11 +444,9 @@ impl<F: Field, I: SliceIndex<[F]>> Index<I> for Polynomial<F> {
 impl<F: NTTField> Polynomial<F> {
     /// Decompose `self` according to `basis`.
     pub fn decompose(mut self, basis: Basis<F>) -> Vec<Self> {
-        let mask = basis.mask();
-        let bits = basis.bits();
-
-        (0..basis.decompose_len())
-            .map(|_| {
+        basis
+            .levels()
+            .map(|(mask, bits)| {
                 let data: Vec<F> = self
                     .iter_mut()
                     .map(|v| v.decompose_lsb_bits(mask, bits))
@@ -295,21 +464,21 @@ impl<F: NTTField> Polynomial<F> {
     pub fn decompose_inplace(&mut self, basis: Basis<F>, destination: &mut [Self]) {
         assert_eq!(destination.len(), basis.decompose_len());
 
-        let mask = basis.mask();
-        let bits = basis.bits();
-
-        destination.iter_mut().for_each(|d_poly| {
-            debug_assert_eq!(d_poly.coeff_count(), self.coeff_count());
-            d_poly
-                .into_iter()
-                .zip(self.iter_mut())
-                .for_each(|(d_i, p_i)| {
-                    p_i.decompose_lsb_bits_at(d_i, mask, bits);
-                })
-        });
-    }
-
-    /// Decompose `self` according to `basis`.
+        destination
+            .iter_mut()
+            .zip(basis.levels())
+            .for_each(|(d_poly, (mask, bits))| {
+                debug_assert_eq!(d_poly.coeff_count(), self.coeff_count());
+                d_poly
+                    .into_iter()
+                    .zip(self.iter_mut())
+                    .for_each(|(d_i, p_i)| {
+                        p_i.decompose_lsb_bits_at(d_i, mask, bits);
+                    })
+            });
+    }
+
+    /// Decompose the least significant level of `self` according to `basis`.
     ///
     /// # Attention
     ///
@@ -346,6 +515,14 @@ impl<F: Field> AsMut<[F]> for Polynomial<F> {
     }
 }
 
+/// Compares the coefficients in constant time, only the lengths may leak.
+impl<F: Field> ConstantTimeEq for Polynomial<F> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
 impl<F: Field> IntoIterator for Polynomial<F> {
     type Item = F;
 
@@ -383,7 +560,7 @@ impl<F: Field> AddAssign<Self> for Polynomial<F> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l += r);
+        F::add_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -391,7 +568,7 @@ impl<F: Field> AddAssign<&Self> for Polynomial<F> {
     #[inline]
     fn add_assign(&mut self, rhs: &Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l += r);
+        F::add_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -431,8 +608,9 @@ impl<F: Field> Add<&Polynomial<F>> for &Polynomial<F> {
     #[inline]
     fn add(self, rhs: &Polynomial<F>) -> Self::Output {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        let polynomial: Vec<F> = self.iter().zip(rhs).map(|(&l, r)| l + r).collect();
-        <Polynomial<F>>::new(polynomial)
+        let mut polynomial = self.clone();
+        F::add_assign_slice(polynomial.as_mut_slice(), rhs.as_slice());
+        polynomial
     }
 }
 
@@ -440,14 +618,14 @@ impl<F: Field> SubAssign<Self> for Polynomial<F> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l -= r);
+        F::sub_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 impl<F: Field> SubAssign<&Self> for Polynomial<F> {
     #[inline]
     fn sub_assign(&mut self, rhs: &Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l -= r);
+        F::sub_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -488,25 +666,20 @@ impl<F: Field> Sub<&Polynomial<F>> for &Polynomial<F> {
     #[inline]
     fn sub(self, rhs: &Polynomial<F>) -> Self::Output {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        let polynomial: Vec<F> = self.iter().zip(rhs).map(|(&l, r)| l - r).collect();
-        <Polynomial<F>>::new(polynomial)
+        let mut polynomial = self.clone();
+        F::sub_assign_slice(polynomial.as_mut_slice(), rhs.as_slice());
+        polynomial
     }
 }
 
 impl<F: NTTField> MulAssign<Self> for Polynomial<F> {
+    #[inline]
     fn mul_assign(&mut self, rhs: Self) {
         let coeff_count = self.coeff_count();
-        debug_assert_eq!(coeff_count, rhs.coeff_count());
         debug_assert!(coeff_count.is_power_of_two());
 
-        let log_n = coeff_count.trailing_zeros();
-        let ntt_table = F::get_ntt_table(log_n).unwrap();
-
-        let lhs = self.as_mut_slice();
-        let rhs = ntt_table.transform_inplace(rhs);
-        ntt_table.transform_slice(lhs);
-        ntt_mul_assign_fast(lhs, &rhs);
-        ntt_table.inverse_transform_slice(lhs);
+        let ntt_table = F::get_ntt_table(coeff_count.trailing_zeros()).unwrap();
+        self.mul_assign_with_table(rhs, &ntt_table);
     }
 }
 
@@ -656,6 +829,8 @@ impl<F: Field> Neg for &Polynomial<F> {
 /// and fall back to [0, modulus) for normal case.
 #[inline]
 fn ntt_mul_assign_fast<F: NTTField>(lhs: &mut [F], rhs: &NTTPolynomial<F>) {
+    #[cfg(feature = "tracing")]
+    crate::metrics::record_pointwise_mul(lhs.len());
     lhs.iter_mut()
         .zip(rhs)
         .for_each(|(l, &r)| l.mul_assign_fast(r));
@@ -1,12 +1,13 @@
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
-use std::slice::{Iter, IterMut, SliceIndex};
-use std::vec::IntoIter;
+use alloc::vec;
+use alloc::vec::{IntoIter, Vec};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::slice::{ChunksExact, ChunksExactMut, Iter, IterMut, SliceIndex};
 
-use rand::{CryptoRng, Rng};
 use rand_distr::Distribution;
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::transformation::AbstractNTT;
-use crate::{Field, NTTField, Random};
+use crate::{CsRng, Field, NTTField, Random};
 
 use super::Polynomial;
 
@@ -39,9 +40,13 @@ impl<F: NTTField> From<Polynomial<F>> for NTTPolynomial<F> {
     fn from(polynomial: Polynomial<F>) -> Self {
         debug_assert!(polynomial.coeff_count().is_power_of_two());
 
+        polynomial.check_canonical();
+
         let ntt_table = F::get_ntt_table(polynomial.coeff_count().trailing_zeros()).unwrap();
 
-        ntt_table.transform_inplace(polynomial)
+        let ntt_polynomial = ntt_table.transform_inplace(polynomial);
+        ntt_polynomial.check_canonical();
+        ntt_polynomial
     }
 }
 
@@ -132,22 +137,120 @@ impl<F: Field> NTTPolynomial<F> {
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter(&self) -> Iter<F> {
+    pub fn iter(&self) -> Iter<'_, F> {
         self.data.iter()
     }
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn copied_iter(&self) -> std::iter::Copied<Iter<'_, F>> {
+    pub fn copied_iter(&self) -> core::iter::Copied<Iter<'_, F>> {
         self.data.iter().copied()
     }
 
     /// Returns an iterator that allows modifying each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<F> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, F> {
         self.data.iter_mut()
     }
 
+    /// Returns `true` if every value is below the modulus, i.e. no lazily reduced value
+    /// in `[q, 2q)` escaped a fast path.
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        self.data.iter().all(|x| x.get() < F::MODULUS_INNER)
+    }
+
+    /// Asserts in debug builds that every value is below the modulus, see [`Self::is_canonical`].
+    #[inline]
+    #[track_caller]
+    pub fn debug_assert_canonical(&self) {
+        debug_assert!(
+            self.is_canonical(),
+            "the values should be below the modulus"
+        );
+    }
+
+    /// Asserts that every value is below the modulus with the `canonical-checks` feature,
+    /// at the boundaries of the transforms.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn check_canonical(&self) {
+        #[cfg(feature = "canonical-checks")]
+        assert!(
+            self.is_canonical(),
+            "the values should be below the modulus"
+        );
+    }
+
+    /// Returns an iterator over `chunk_size` coefficients at a time, without the remainder,
+    /// for writing blocked kernels over the coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[inline]
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<'_, F> {
+        self.data.chunks_exact(chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` mutable coefficients at a time, without the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[inline]
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<'_, F> {
+        self.data.chunks_exact_mut(chunk_size)
+    }
+
+    /// Returns a parallel iterator over `chunk_size` coefficients at a time,
+    /// the last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub fn par_chunks(&self, chunk_size: usize) -> rayon::slice::Chunks<'_, F> {
+        use rayon::slice::ParallelSlice;
+        self.data.par_chunks(chunk_size)
+    }
+
+    /// Returns a parallel iterator over `chunk_size` mutable coefficients at a time,
+    /// the last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub fn par_chunks_mut(&mut self, chunk_size: usize) -> rayon::slice::ChunksMut<'_, F> {
+        use rayon::slice::ParallelSliceMut;
+        self.data.par_chunks_mut(chunk_size)
+    }
+
+    /// Performs `self[i] = f(self[i], other[i])` for every coefficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different coeff count.
+    #[inline]
+    pub fn zip_map_inplace<FN>(&mut self, other: impl AsRef<[F]>, mut f: FN)
+    where
+        FN: FnMut(F, F) -> F,
+    {
+        let other = other.as_ref();
+        assert_eq!(
+            self.data.len(),
+            other.len(),
+            "the coeff counts should be equal"
+        );
+        self.data
+            .iter_mut()
+            .zip(other)
+            .for_each(|(a, &b)| *a = f(*a, b));
+    }
+
     /// Alter the coefficient count of the polynomial.
     #[inline]
     pub fn resize(&mut self, new_degree: usize, value: F) {
@@ -175,7 +278,7 @@ impl<F: Field + Random> NTTPolynomial<F> {
     #[inline]
     pub fn random<R>(n: usize, rng: R) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
     {
         Self {
             data: F::standard_distribution()
@@ -189,7 +292,7 @@ impl<F: Field + Random> NTTPolynomial<F> {
     #[inline]
     pub fn random_with_distribution<R, D>(n: usize, rng: R, distribution: D) -> Self
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
         D: Distribution<F>,
     {
         Self::new(distribution.sample_iter(rng).take(n).collect())
@@ -202,6 +305,41 @@ impl<F: NTTField> NTTPolynomial<F> {
     pub fn into_native_polynomial(self) -> Polynomial<F> {
         <Polynomial<F>>::from(self)
     }
+
+    /// Treats `self` as the ntt form of a function `f`. Given `x`, outputs `f(x)`.
+    ///
+    /// It interpolates from the ntt values, so an occasional evaluation
+    /// does not pay for an inverse transform.
+    #[inline]
+    pub fn evaluate(&self, x: F) -> F {
+        let ntt_table = F::get_ntt_table(self.coeff_count().trailing_zeros()).unwrap();
+        self.evaluate_with_table(x, &ntt_table)
+    }
+
+    /// Evaluates `self` at `x` with the given `ntt_table` of its length,
+    /// see [`NTTPolynomial::evaluate`].
+    #[inline]
+    pub fn evaluate_with_table(&self, x: F, ntt_table: &F::Table) -> F {
+        ntt_table.evaluate_slice(self.as_slice(), x)
+    }
+
+    /// Precompute the roots of `self`, i.e. the [`ShoupFactor`](crate::modulus::ShoupFactor)s
+    /// of the derived fields, for a polynomial which will multiply many others.
+    #[inline]
+    pub fn to_roots(&self) -> Vec<F::Root> {
+        self.iter().map(|&v| v.to_root()).collect()
+    }
+
+    /// Performs `self[i] *= roots[i]` with roots from [`NTTPolynomial::to_roots`].
+    #[inline]
+    pub fn mul_roots_assign(&mut self, roots: &[F::Root]) {
+        debug_assert_eq!(self.coeff_count(), roots.len());
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_pointwise_mul(self.coeff_count());
+        self.iter_mut()
+            .zip(roots)
+            .for_each(|(v, &root)| v.mul_root_assign(root));
+    }
 }
 
 impl<F: Field, I: SliceIndex<[F]>> IndexMut<I> for NTTPolynomial<F> {
@@ -241,6 +379,14 @@ impl<F: Field> AsMut<[F]> for NTTPolynomial<F> {
     }
 }
 
+/// Compares the values in constant time, only the lengths may leak.
+impl<F: Field> ConstantTimeEq for NTTPolynomial<F> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
 impl<F: Field> IntoIterator for NTTPolynomial<F> {
     type Item = F;
 
@@ -278,7 +424,7 @@ impl<F: Field> AddAssign<Self> for NTTPolynomial<F> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l += r);
+        F::add_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -286,7 +432,7 @@ impl<F: Field> AddAssign<&Self> for NTTPolynomial<F> {
     #[inline]
     fn add_assign(&mut self, rhs: &Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l += r);
+        F::add_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -326,8 +472,9 @@ impl<F: Field> Add<&NTTPolynomial<F>> for &NTTPolynomial<F> {
     #[inline]
     fn add(self, rhs: &NTTPolynomial<F>) -> Self::Output {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        let data: Vec<F> = self.iter().zip(rhs).map(|(&l, r)| l + r).collect();
-        <NTTPolynomial<F>>::new(data)
+        let mut data = self.clone();
+        F::add_assign_slice(data.as_mut_slice(), rhs.as_slice());
+        data
     }
 }
 
@@ -335,14 +482,14 @@ impl<F: Field> SubAssign<Self> for NTTPolynomial<F> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l -= r);
+        F::sub_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 impl<F: Field> SubAssign<&Self> for NTTPolynomial<F> {
     #[inline]
     fn sub_assign(&mut self, rhs: &Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l -= r);
+        F::sub_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -383,8 +530,9 @@ impl<F: Field> Sub<&NTTPolynomial<F>> for &NTTPolynomial<F> {
     #[inline]
     fn sub(self, rhs: &NTTPolynomial<F>) -> Self::Output {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        let data: Vec<F> = self.iter().zip(rhs).map(|(&l, r)| l - r).collect();
-        <NTTPolynomial<F>>::new(data)
+        let mut data = self.clone();
+        F::sub_assign_slice(data.as_mut_slice(), rhs.as_slice());
+        data
     }
 }
 
@@ -392,7 +540,9 @@ impl<F: Field> MulAssign<Self> for NTTPolynomial<F> {
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l *= r);
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_pointwise_mul(self.coeff_count());
+        F::mul_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -400,7 +550,9 @@ impl<F: Field> MulAssign<&Self> for NTTPolynomial<F> {
     #[inline]
     fn mul_assign(&mut self, rhs: &Self) {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut().zip(rhs).for_each(|(l, r)| *l *= r);
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_pointwise_mul(self.coeff_count());
+        F::mul_assign_slice(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
@@ -440,8 +592,9 @@ impl<F: Field> Mul<&NTTPolynomial<F>> for &NTTPolynomial<F> {
     #[inline]
     fn mul(self, rhs: &NTTPolynomial<F>) -> Self::Output {
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        let data = self.iter().zip(rhs).map(|(&l, r)| l * r).collect();
-        <NTTPolynomial<F>>::new(data)
+        let mut data = self.clone();
+        MulAssign::mul_assign(&mut data, rhs);
+        data
     }
 }
 
@@ -531,7 +684,9 @@ impl<F: Field> Neg for &NTTPolynomial<F> {
 /// Performs enrty-wise mul operation.
 #[inline]
 pub fn ntt_mul_assign<F: NTTField>(x: &mut NTTPolynomial<F>, y: &NTTPolynomial<F>) {
-    x.iter_mut().zip(y).for_each(|(a, b)| *a *= b);
+    #[cfg(feature = "tracing")]
+    crate::metrics::record_pointwise_mul(x.coeff_count());
+    F::mul_assign_slice(x.as_mut_slice(), y.as_slice());
 }
 
 /// Performs enrty-wise mul operation.
@@ -541,6 +696,8 @@ pub fn ntt_mul_inplace<F: NTTField>(
     y: &NTTPolynomial<F>,
     des: &mut NTTPolynomial<F>,
 ) {
+    #[cfg(feature = "tracing")]
+    crate::metrics::record_pointwise_mul(des.coeff_count());
     des.iter_mut()
         .zip(x)
         .zip(y)
@@ -558,10 +715,9 @@ pub fn ntt_add_mul_assign<F: NTTField>(
     y: &NTTPolynomial<F>,
     z: &NTTPolynomial<F>,
 ) {
-    x.into_iter()
-        .zip(y)
-        .zip(z)
-        .for_each(|((a, &b), &c)| a.add_mul_assign(b, c));
+    #[cfg(feature = "tracing")]
+    crate::metrics::record_pointwise_mul(x.coeff_count());
+    F::add_mul_assign_slice(x.as_mut_slice(), y.as_slice(), z.as_slice());
 }
 
 /// Performs enrty-wise add_mul operation.
@@ -576,6 +732,8 @@ pub fn ntt_add_mul_inplace<F: NTTField>(
     z: &NTTPolynomial<F>,
     des: &mut NTTPolynomial<F>,
 ) {
+    #[cfg(feature = "tracing")]
+    crate::metrics::record_pointwise_mul(des.coeff_count());
     des.into_iter()
         .zip(x)
         .zip(y)
@@ -591,12 +749,15 @@ pub fn ntt_add_mul_inplace<F: NTTField>(
 ///
 /// The result coefficients may be in [0, 2*modulus) for some case,
 /// and fall back to [0, modulus) for normal case.
+/// [`crate::LazyPoly`] keeps such results apart from canonical polynomials.
 #[inline]
 pub fn ntt_add_mul_assign_fast<F: NTTField>(
     x: &mut NTTPolynomial<F>,
     y: &NTTPolynomial<F>,
     z: &NTTPolynomial<F>,
 ) {
+    #[cfg(feature = "tracing")]
+    crate::metrics::record_pointwise_mul(x.coeff_count());
     x.into_iter()
         .zip(y)
         .zip(z)
@@ -84,6 +84,224 @@ uint_widening_impl! { u16, u32 }
 uint_widening_impl! { u32, u64 }
 uint_widening_impl! { u64, u128 }
 
+impl Widening for u128 {
+    type WideT = U256;
+
+    #[inline]
+    fn carry_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+        let (a, b) = self.overflowing_add(rhs);
+        let (c, d) = a.overflowing_add(carry as Self);
+        (c, b || d)
+    }
+
+    #[inline]
+    fn borrow_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let (a, b) = self.overflowing_sub(rhs);
+        let (c, d) = a.overflowing_sub(borrow as Self);
+        (c, b || d)
+    }
+
+    #[inline]
+    fn widen_mul(self, rhs: Self) -> (Self, Self) {
+        let wide = U256::widening_mul(self, rhs);
+        (wide.low(), wide.high())
+    }
+
+    #[inline]
+    fn carry_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+        let (low, high) = self.widen_mul(rhs);
+        let (low, overflow) = low.overflowing_add(carry);
+        (low, high + overflow as Self)
+    }
+}
+
+/// A minimal unsigned 256-bit integer, the wide type of `u128`.
+///
+/// It only provides what is needed to multiply and reduce `u128` values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct U256 {
+    low: u128,
+    high: u128,
+}
+
+impl U256 {
+    /// 0
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// 2^256 - 1
+    pub const MAX: Self = Self::new(u128::MAX, u128::MAX);
+
+    /// Creates a new [`U256`] equal to `high * 2^128 + low`.
+    #[inline]
+    pub const fn new(low: u128, high: u128) -> Self {
+        Self { low, high }
+    }
+
+    /// Returns the low 128 bits.
+    #[inline]
+    pub const fn low(self) -> u128 {
+        self.low
+    }
+
+    /// Returns the high 128 bits.
+    #[inline]
+    pub const fn high(self) -> u128 {
+        self.high
+    }
+
+    /// Calculates the complete product `lhs * rhs`, with 64-bit limbs.
+    pub const fn widening_mul(lhs: u128, rhs: u128) -> Self {
+        const MASK: u128 = u64::MAX as u128;
+
+        let (a0, a1) = (lhs & MASK, lhs >> 64);
+        let (b0, b1) = (rhs & MASK, rhs >> 64);
+
+        let p00 = a0 * b0;
+        let p01 = a0 * b1;
+        let p10 = a1 * b0;
+        let p11 = a1 * b1;
+
+        // each partial sum fits in 128 bits
+        let middle = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+        let low = (p00 & MASK) | (middle << 64);
+        let high = p11 + (p01 >> 64) + (p10 >> 64) + (middle >> 64);
+
+        Self { low, high }
+    }
+
+    /// Calculates `self + rhs`, and returns whether an overflow occurred.
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (low, carry) = self.low.overflowing_add(rhs.low);
+        let (high, overflow_a) = self.high.overflowing_add(rhs.high);
+        let (high, overflow_b) = high.overflowing_add(carry as u128);
+        (Self { low, high }, overflow_a || overflow_b)
+    }
+
+    /// Calculates `self - rhs`, and returns whether an overflow occurred.
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (low, borrow) = self.low.overflowing_sub(rhs.low);
+        let (high, overflow_a) = self.high.overflowing_sub(rhs.high);
+        let (high, overflow_b) = high.overflowing_sub(borrow as u128);
+        (Self { low, high }, overflow_a || overflow_b)
+    }
+
+    /// Shifts left by `shift` bits, `shift` must be less than 256.
+    #[inline]
+    pub const fn shl(self, shift: u32) -> Self {
+        match shift {
+            0 => self,
+            1..=127 => Self {
+                low: self.low << shift,
+                high: (self.high << shift) | (self.low >> (128 - shift)),
+            },
+            _ => Self {
+                low: 0,
+                high: self.low << (shift - 128),
+            },
+        }
+    }
+
+    /// Shifts right by `shift` bits, `shift` must be less than 256.
+    #[inline]
+    pub const fn shr(self, shift: u32) -> Self {
+        match shift {
+            0 => self,
+            1..=127 => Self {
+                low: (self.low >> shift) | (self.high << (128 - shift)),
+                high: self.high >> shift,
+            },
+            _ => Self {
+                low: self.high >> (shift - 128),
+                high: 0,
+            },
+        }
+    }
+
+    /// Calculates the quotient and the remainder of `self / divisor` by bitwise long division.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `divisor` is zero.
+    pub const fn div_rem(self, divisor: u128) -> (Self, u128) {
+        assert!(divisor != 0, "division by zero");
+
+        let (high_quotient, mut remainder) = (self.high / divisor, self.high % divisor);
+        let mut low_quotient = 0u128;
+        let mut i = 128;
+        while i > 0 {
+            i -= 1;
+            // `remainder < divisor`, so the doubled value needs 129 bits
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | ((self.low >> i) & 1);
+            if carry == 1 || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                low_quotient |= 1 << i;
+            }
+        }
+
+        (Self::new(low_quotient, high_quotient), remainder)
+    }
+
+    /// Calculates [`Self::div_rem`] in constant time for a secret `self` and a public `divisor`.
+    ///
+    /// Every bit of both halves goes through the same steps, and the subtractions
+    /// are selected instead of branched on, so it costs twice the long division.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `divisor` is zero.
+    pub fn div_rem_ct(self, divisor: u128) -> (Self, u128) {
+        use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+
+        assert!(divisor != 0, "division by zero");
+
+        let mut quotient = [0u128; 2];
+        let mut remainder = 0u128;
+        for (word, quotient) in [self.high, self.low]
+            .into_iter()
+            .zip(quotient.iter_mut().rev())
+        {
+            for i in (0..128).rev() {
+                // `remainder < divisor`, so the doubled value needs 129 bits
+                let carry = Choice::from((remainder >> 127) as u8);
+                remainder = (remainder << 1) | ((word >> i) & 1);
+                let subtract = carry | !remainder.ct_lt(&divisor);
+                remainder = u128::conditional_select(
+                    &remainder,
+                    &remainder.wrapping_sub(divisor),
+                    subtract,
+                );
+                *quotient |= (subtract.unwrap_u8() as u128) << i;
+            }
+        }
+
+        (Self::new(quotient[0], quotient[1]), remainder)
+    }
+}
+
+impl From<u128> for U256 {
+    #[inline]
+    fn from(value: u128) -> Self {
+        Self::new(value, 0)
+    }
+}
+
+impl PartialOrd for U256 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.high, self.low).cmp(&(other.high, other.low))
+    }
+}
+
 /// Extension trait to provide access to bits of integers.
 pub trait Bits {
     /// The number of bits this type has.
@@ -189,3 +407,63 @@ macro_rules! wrapping_impl {
 }
 
 wrapping_impl!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_u128_widening() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let (a, b, c) = (rng.gen::<u64>(), rng.gen::<u64>(), rng.gen::<u64>());
+            // products of 64-bit values are checked against u128
+            let (low, high) = (a as u128).carry_mul(b as u128, c as u128);
+            assert_eq!(high, 0);
+            assert_eq!(low, a as u128 * b as u128 + c as u128);
+
+            let (a, b) = (rng.gen::<u128>(), rng.gen::<u128>());
+            let (low, high) = a.widen_mul(b);
+            let (quotient, remainder) = U256::new(low, high).div_rem(a.max(1));
+            if a != 0 {
+                assert_eq!(quotient, U256::from(b));
+                assert_eq!(remainder, 0);
+            }
+        }
+
+        assert_eq!(u128::MAX.widen_mul(u128::MAX), (1, u128::MAX - 1));
+        assert_eq!(u128::MAX.carry_mul(u128::MAX, u128::MAX), (0, u128::MAX));
+    }
+
+    #[test]
+    fn test_u256() {
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let x = U256::new(rng.gen(), rng.gen());
+            let divisor = (rng.gen::<u128>() >> rng.gen_range(0..127)).max(1);
+            let (quotient, remainder) = x.div_rem(divisor);
+            assert!(remainder < divisor);
+            assert_eq!(x.div_rem_ct(divisor), (quotient, remainder));
+
+            let product = U256::widening_mul(quotient.low(), divisor);
+            let (high, _) = quotient.high().widen_mul(divisor);
+            let (sum, overflow) = product
+                .overflowing_add(U256::new(0, high))
+                .0
+                .overflowing_add(U256::from(remainder));
+            assert!(!overflow);
+            assert_eq!(sum, x);
+
+            assert_eq!(x.shl(1), x.overflowing_add(x).0);
+            assert_eq!(x.shl(1).shr(1), U256::new(x.low(), x.high() << 1 >> 1));
+        }
+
+        let one = U256::from(1);
+        assert_eq!(one.shl(200), U256::new(0, 1 << 72));
+        assert_eq!(one.shl(200).shr(200), one);
+        assert_eq!(U256::MAX.shr(255), one);
+        assert_eq!(U256::ZERO.overflowing_sub(one), (U256::MAX, true));
+    }
+}
@@ -24,6 +24,7 @@ use crate::AlgebraError;
 /// * `standard_distribution()`: Returns an instance of the standard distribution type.
 /// * `binary_sampler()`: Returns an instance of the binary sampler type.
 /// * `ternary_sampler()`: Returns an instance of the ternary sampler type.
+/// * `bounded_sampler(bound)`: Returns an instance of the bounded uniform sampler type, which may fail if `bound` is too large.
 /// * `gaussian_sampler(mean, std_dev)`: Returns an instance of the gaussian sampler type, parameterized by the specified mean and standard deviation.
 ///   This method may fail, indicated by returning an `AlgebraError`, if the parameters do not result in a valid sampler.
 pub trait Random: Sized + SampleUniform {
@@ -39,6 +40,9 @@ pub trait Random: Sized + SampleUniform {
     /// Get the ternary sampler.
     fn ternary_sampler() -> FieldTernarySampler;
 
+    /// Get the uniform sampler over `[-bound, bound]`.
+    fn bounded_sampler(bound: u64) -> Result<FieldBoundedSampler, AlgebraError>;
+
     /// Get the gaussian sampler.
     fn gaussian_sampler(
         mean: f64,
@@ -53,6 +57,13 @@ pub trait Random: Sized + SampleUniform {
     ) -> Result<FieldDiscreteGaussianSampler, AlgebraError>;
 }
 
+/// The uniform distribution over all values of Field, the standard distribution of the derived fields.
+///
+/// It draws the bits of the modulus at once and rejects the values not less than the modulus,
+/// so every sample costs one draw of the word and the expected count of draws is below `2`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FieldUniformSampler;
+
 /// The binary distribution for Field.
 ///
 /// prob\[1] = prob\[0] = 0.5
@@ -67,6 +78,35 @@ pub struct FieldBinarySampler;
 #[derive(Clone, Copy, Debug)]
 pub struct FieldTernarySampler;
 
+/// The uniform distribution over the integers of `[-bound, bound]` for Field,
+/// where the negative values wrap around the modulus.
+///
+/// It draws the noise of noise flooding and the masks of rejection sampling proofs,
+/// whose analysis needs the exact bounded distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldBoundedSampler {
+    bound: u64,
+}
+
+impl FieldBoundedSampler {
+    /// Construct, from the bound of the absolute values.
+    ///
+    /// `bound` must be less than `2^63`.
+    #[inline]
+    pub fn new(bound: u64) -> Result<FieldBoundedSampler, AlgebraError> {
+        if bound >= 1 << 63 {
+            return Err(AlgebraError::DistributionError);
+        }
+        Ok(FieldBoundedSampler { bound })
+    }
+
+    /// Returns the bound of the absolute values.
+    #[inline]
+    pub fn bound(&self) -> u64 {
+        self.bound
+    }
+}
+
 /// The gaussian distribution `N(mean, std_dev**2)` for Field.
 #[derive(Clone, Copy, Debug)]
 pub struct FieldDiscreteGaussianSampler {
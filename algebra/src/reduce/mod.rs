@@ -4,18 +4,35 @@ mod lazy_ops;
 mod ops;
 
 mod primitive;
+mod slice;
 
 pub use lazy_ops::*;
 pub use ops::*;
+pub use slice::*;
+
+use core::fmt::Debug;
+
+use num_traits::PrimInt;
+
+use crate::{Widening, WrappingOps};
 
 /// A helper trait to get the modulus of the field.
 pub trait ModulusConfig {
+    /// The inner type of the field.
+    type Value: Debug + Send + Sync + PrimInt + Widening + WrappingOps + 'static;
+
     /// Modulus type
     type Modulus;
 
     /// The modulus of the field.
     const MODULUS: Self::Modulus;
 
+    /// The value of the modulus.
+    const MODULUS_VALUE: Self::Value;
+
+    /// The bit count of the modulus, for branching on its size at compile time.
+    const MODULUS_BITS: u32;
+
     /// Get the modulus of the field.
     #[inline]
     fn modulus() -> Self::Modulus {
@@ -9,6 +9,17 @@ pub trait Reduce<Modulus>: Sized {
     fn reduce(self, modulus: Modulus) -> Self::Output;
 }
 
+/// The modulo operation for signed values.
+pub trait SignedReduce<Modulus>: Sized {
+    /// Output type.
+    type Output;
+
+    /// Caculates `self (mod modulus)` in `[0, modulus)`, also for a negative `self`.
+    ///
+    /// This maps centered values, e.g. sampled noise or differences, into the field.
+    fn signed_reduce(self, modulus: Modulus) -> Self::Output;
+}
+
 /// The modulo assignment operation.
 pub trait ReduceAssign<Modulus>: Sized {
     /// Caculates `self (mod modulus)`.
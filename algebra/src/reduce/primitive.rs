@@ -1,3 +1,5 @@
+use alloc::string::ToString;
+
 macro_rules! impl_reduce_ops_for_primitive {
     ($($t:ty),*) => {$(
         impl $crate::reduce::AddReduce<Self> for $t {
@@ -113,4 +115,4 @@ macro_rules! impl_reduce_ops_for_primitive {
     )*};
 }
 
-impl_reduce_ops_for_primitive!(u8, u16, u32, u64);
+impl_reduce_ops_for_primitive!(u8, u16, u32, u64, u128);
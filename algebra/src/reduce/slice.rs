@@ -0,0 +1,128 @@
+//! Kernels applying the modular operations to slices of raw values.
+//!
+//! They work on the values of residues directly, e.g. `u32` with a [`BarrettModulus<u32>`],
+//! so no field element is constructed. Every kernel is a single pass over equally long
+//! slices without bounds checks in the loop, which leaves it to the compiler to vectorize.
+//!
+//! [`BarrettModulus<u32>`]: crate::modulus::BarrettModulus
+
+use super::{
+    AddReduceAssign, LazyMulReduceAssign, MulReduce, MulReduceAssign, NegReduceAssign,
+    SubReduceAssign,
+};
+
+/// Performs `a[i] = a[i] + b[i] (mod modulus)`.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+pub fn batch_add_reduce<T, M>(a: &mut [T], b: &[T], modulus: M)
+where
+    T: Copy + AddReduceAssign<M>,
+    M: Copy,
+{
+    assert_eq!(a.len(), b.len(), "the length of a and b should be equal");
+    a.iter_mut()
+        .zip(b)
+        .for_each(|(a, &b)| a.add_reduce_assign(b, modulus));
+}
+
+/// Performs `a[i] = a[i] - b[i] (mod modulus)`.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+pub fn batch_sub_reduce<T, M>(a: &mut [T], b: &[T], modulus: M)
+where
+    T: Copy + SubReduceAssign<M>,
+    M: Copy,
+{
+    assert_eq!(a.len(), b.len(), "the length of a and b should be equal");
+    a.iter_mut()
+        .zip(b)
+        .for_each(|(a, &b)| a.sub_reduce_assign(b, modulus));
+}
+
+/// Performs `a[i] = -a[i] (mod modulus)`.
+#[inline]
+pub fn batch_neg_reduce<T, M>(a: &mut [T], modulus: M)
+where
+    T: NegReduceAssign<M>,
+    M: Copy,
+{
+    a.iter_mut().for_each(|a| a.neg_reduce_assign(modulus));
+}
+
+/// Performs `a[i] = a[i] * b[i] (mod modulus)`.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+pub fn batch_mul_reduce<T, M>(a: &mut [T], b: &[T], modulus: M)
+where
+    T: Copy + MulReduceAssign<M>,
+    M: Copy,
+{
+    assert_eq!(a.len(), b.len(), "the length of a and b should be equal");
+    a.iter_mut()
+        .zip(b)
+        .for_each(|(a, &b)| a.mul_reduce_assign(b, modulus));
+}
+
+/// Performs `a[i] = a[i] * b[i] (mod 2*modulus)`, see [`LazyMulReduceAssign`].
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+pub fn batch_lazy_mul_reduce<T, M>(a: &mut [T], b: &[T], modulus: M)
+where
+    T: Copy + LazyMulReduceAssign<M>,
+    M: Copy,
+{
+    assert_eq!(a.len(), b.len(), "the length of a and b should be equal");
+    a.iter_mut()
+        .zip(b)
+        .for_each(|(a, &b)| a.lazy_mul_reduce_assign(b, modulus));
+}
+
+/// Performs `a[i] = a[i] * scalar (mod modulus)`.
+#[inline]
+pub fn batch_scalar_mul_reduce<T, M>(a: &mut [T], scalar: T, modulus: M)
+where
+    T: Copy + MulReduceAssign<M>,
+    M: Copy,
+{
+    a.iter_mut()
+        .for_each(|a| a.mul_reduce_assign(scalar, modulus));
+}
+
+/// Performs `acc[i] = acc[i] + a[i] * b[i] (mod modulus)`.
+///
+/// # Panics
+///
+/// Panics if `acc`, `a` and `b` have different lengths.
+#[inline]
+pub fn batch_add_mul_reduce<T, M>(acc: &mut [T], a: &[T], b: &[T], modulus: M)
+where
+    T: Copy + MulReduce<M, Output = T> + AddReduceAssign<M>,
+    M: Copy,
+{
+    assert_eq!(
+        acc.len(),
+        a.len(),
+        "the length of acc and a should be equal"
+    );
+    assert_eq!(
+        acc.len(),
+        b.len(),
+        "the length of acc and b should be equal"
+    );
+    acc.iter_mut()
+        .zip(a)
+        .zip(b)
+        .for_each(|((acc, &a), &b)| acc.add_reduce_assign(a.mul_reduce(b, modulus), modulus));
+}
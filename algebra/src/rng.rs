@@ -0,0 +1,44 @@
+//! Define the bound of the random number generators taken by the algebra.
+
+use rand::{CryptoRng, RngCore};
+
+/// A cryptographically secure random number generator.
+///
+/// All the functions taking a random number generator are bounded by this trait,
+/// which is implemented for every [`RngCore`] + [`CryptoRng`] of `rand` 0.8.
+/// Generators of `rand_core` 0.9 can be passed through [`RngCompat`] with the `rand_core_09` feature.
+pub trait CsRng: RngCore + CryptoRng {}
+
+impl<R: RngCore + CryptoRng + ?Sized> CsRng for R {}
+
+/// An adapter turning a `rand_core` 0.9 cryptographic generator into a [`CsRng`].
+#[cfg(feature = "rand_core_09")]
+#[derive(Debug, Clone)]
+pub struct RngCompat<R>(pub R);
+
+#[cfg(feature = "rand_core_09")]
+impl<R: rand_core_09::CryptoRng> RngCore for RngCompat<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core_09")]
+impl<R: rand_core_09::CryptoRng> CryptoRng for RngCompat<R> {}
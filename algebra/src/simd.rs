@@ -0,0 +1,200 @@
+//! Pointwise kernels over slices of fields with `u32` values.
+//!
+//! The derive macros route [`Field::add_assign_slice`], [`Field::sub_assign_slice`],
+//! [`Field::mul_assign_slice`] and [`Field::add_mul_assign_slice`] of `u32` fields here.
+//! With the `simd` feature on `x86_64` targets compiled with AVX2, eight lanes are processed
+//! at once for moduli below `2^30`; otherwise the kernels fall back to the scalar field operations.
+
+use crate::Field;
+
+/// Performs `lhs[i] += rhs[i]`.
+#[inline]
+pub fn add_assign_slice<F: Field<Value = u32>>(lhs: &mut [F], rhs: &[F]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    if let Some(kernel) = avx2::Kernel::new(F::MODULUS_INNER) {
+        return kernel.apply(lhs, rhs, None, avx2::Op::Add);
+    }
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l += r);
+}
+
+/// Performs `lhs[i] -= rhs[i]`.
+#[inline]
+pub fn sub_assign_slice<F: Field<Value = u32>>(lhs: &mut [F], rhs: &[F]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    if let Some(kernel) = avx2::Kernel::new(F::MODULUS_INNER) {
+        return kernel.apply(lhs, rhs, None, avx2::Op::Sub);
+    }
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l -= r);
+}
+
+/// Performs `lhs[i] *= rhs[i]`.
+#[inline]
+pub fn mul_assign_slice<F: Field<Value = u32>>(lhs: &mut [F], rhs: &[F]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    if let Some(kernel) = avx2::Kernel::new(F::MODULUS_INNER) {
+        return kernel.apply(lhs, rhs, None, avx2::Op::Mul);
+    }
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l *= r);
+}
+
+/// Performs `acc[i] += a[i] * b[i]`.
+#[inline]
+pub fn add_mul_assign_slice<F: Field<Value = u32>>(acc: &mut [F], a: &[F], b: &[F]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+    if let Some(kernel) = avx2::Kernel::new(F::MODULUS_INNER) {
+        return kernel.apply(acc, a, Some(b), avx2::Op::AddMul);
+    }
+    acc.iter_mut()
+        .zip(a)
+        .zip(b)
+        .for_each(|((acc, &a), &b)| acc.add_mul_assign(a, b));
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "avx2"))]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    use crate::Field;
+
+    const LANES: usize = 8;
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub(super) enum Op {
+        Add,
+        Sub,
+        Mul,
+        AddMul,
+    }
+
+    /// The broadcast modulus and the constants of the barrett reduction
+    /// `x - floor(floor(x / 2^(k-1)) * mu / 2^(k+1)) * q` with `mu = floor(2^(2k) / q)`,
+    /// whose result is below `3q` for `x < q^2`.
+    pub(super) struct Kernel {
+        modulus: __m256i,
+        ratio: __m256i,
+        shift_low: __m128i,
+        shift_high: __m128i,
+    }
+
+    impl Kernel {
+        /// Returns `None` if the modulus is too big for the lanes.
+        #[inline]
+        pub(super) fn new(modulus: u32) -> Option<Self> {
+            if !(2..1 << 30).contains(&modulus) {
+                return None;
+            }
+            let k = 32 - modulus.leading_zeros();
+            let ratio = (1u64 << (2 * k)) / modulus as u64;
+            // SAFETY: the module is only compiled with AVX2 enabled.
+            unsafe {
+                Some(Self {
+                    modulus: _mm256_set1_epi32(modulus as i32),
+                    ratio: _mm256_set1_epi64x(ratio as i64),
+                    shift_low: _mm_cvtsi32_si128(k as i32 - 1),
+                    shift_high: _mm_cvtsi32_si128(k as i32 + 1),
+                })
+            }
+        }
+
+        /// Applies `op` to eight lanes at a time, and the scalar operations to the remainder.
+        #[inline]
+        pub(super) fn apply<F: Field<Value = u32>>(
+            &self,
+            lhs: &mut [F],
+            rhs: &[F],
+            other: Option<&[F]>,
+            op: Op,
+        ) {
+            let len = lhs.len().min(rhs.len());
+            let len = other.map_or(len, |other| len.min(other.len()));
+            let body = len - len % LANES;
+
+            for start in (0..body).step_by(LANES) {
+                let x = load(&lhs[start..start + LANES]);
+                let y = load(&rhs[start..start + LANES]);
+                // SAFETY: the module is only compiled with AVX2 enabled.
+                let r = unsafe {
+                    match op {
+                        Op::Add => self.add(x, y),
+                        Op::Sub => self.sub(x, y),
+                        Op::Mul => self.mul(x, y),
+                        Op::AddMul => {
+                            let z = load(&other.unwrap()[start..start + LANES]);
+                            self.add(x, self.mul(y, z))
+                        }
+                    }
+                };
+                store(&mut lhs[start..start + LANES], r);
+            }
+
+            let tail = lhs[body..len].iter_mut().zip(&rhs[body..len]);
+            match (op, other) {
+                (Op::AddMul, Some(other)) => tail
+                    .zip(&other[body..len])
+                    .for_each(|((l, &r), &o)| l.add_mul_assign(r, o)),
+                (Op::Add, _) => tail.for_each(|(l, &r)| *l += r),
+                (Op::Sub, _) => tail.for_each(|(l, &r)| *l -= r),
+                _ => tail.for_each(|(l, &r)| *l *= r),
+            }
+        }
+
+        /// Subtracts the modulus from the lanes not below it, for lanes below `2^32 - q`.
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn reduce_once(&self, x: __m256i) -> __m256i {
+            _mm256_min_epu32(x, _mm256_sub_epi32(x, self.modulus))
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn add(&self, x: __m256i, y: __m256i) -> __m256i {
+            self.reduce_once(_mm256_add_epi32(x, y))
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn sub(&self, x: __m256i, y: __m256i) -> __m256i {
+            let d = _mm256_sub_epi32(x, y);
+            _mm256_min_epu32(d, _mm256_add_epi32(d, self.modulus))
+        }
+
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn mul(&self, x: __m256i, y: __m256i) -> __m256i {
+            let even = self.mul_even(x, y);
+            let odd = self.mul_even(_mm256_srli_epi64(x, 32), _mm256_srli_epi64(y, 32));
+            _mm256_blend_epi32(even, _mm256_slli_epi64(odd, 32), 0b1010_1010)
+        }
+
+        /// Multiplies the even lanes, leaving the reduced products in the even lanes.
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn mul_even(&self, x: __m256i, y: __m256i) -> __m256i {
+            let product = _mm256_mul_epu32(x, y);
+            let quotient = _mm256_srl_epi64(
+                _mm256_mul_epu32(_mm256_srl_epi64(product, self.shift_low), self.ratio),
+                self.shift_high,
+            );
+            let r = _mm256_sub_epi64(product, _mm256_mul_epu32(quotient, self.modulus));
+            self.reduce_once(self.reduce_once(r))
+        }
+    }
+
+    #[inline]
+    fn load<F: Field<Value = u32>>(values: &[F]) -> __m256i {
+        let lanes: [u32; LANES] = core::array::from_fn(|i| values[i].get());
+        // SAFETY: `lanes` is 32 bytes, and the load is unaligned.
+        unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) }
+    }
+
+    #[inline]
+    fn store<F: Field<Value = u32>>(values: &mut [F], x: __m256i) {
+        let mut lanes = [0u32; LANES];
+        // SAFETY: `lanes` is 32 bytes, and the store is unaligned.
+        unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), x) };
+        values
+            .iter_mut()
+            .zip(lanes)
+            .for_each(|(v, lane)| *v = F::new(lane));
+    }
+}
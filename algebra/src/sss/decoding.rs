@@ -0,0 +1,142 @@
+//! Reed-Solomon decoding of shares with the Berlekamp-Welch algorithm.
+
+use alloc::{vec, vec::Vec};
+
+use crate::Field;
+
+/// Decode the polynomial with fewer than `coeff_count` coefficients passing through most of the points.
+///
+/// Up to `(xs.len() - coeff_count) / 2` wrong values in `ys` are corrected.
+/// Return the coefficients of the polynomial and the positions of the wrong values,
+/// or `None` if there are too many wrong values.
+pub(crate) fn berlekamp_welch<F: Field>(
+    xs: &[F],
+    ys: &[F],
+    coeff_count: usize,
+) -> Option<(Vec<F>, Vec<usize>)> {
+    let n = xs.len();
+    debug_assert_eq!(n, ys.len());
+    debug_assert!(n >= coeff_count);
+
+    let e = (n - coeff_count) / 2;
+    let q_count = e + coeff_count;
+
+    // Unknowns are the coefficients of Q(x) (`q_count` of them) followed by the non-leading
+    // coefficients of the monic error locator E(x) (`e` of them), and each point gives
+    // Q(x_i) - y_i * (E(x_i) - x_i^e) = y_i * x_i^e.
+    let mut matrix: Vec<Vec<F>> = xs
+        .iter()
+        .zip(ys)
+        .map(|(&x, &y)| {
+            let mut row = Vec::with_capacity(q_count + e + 1);
+            let mut power = F::ONE;
+            for _ in 0..q_count {
+                row.push(power);
+                power *= x;
+            }
+            let mut power = F::ONE;
+            for _ in 0..e {
+                row.push(-(y * power));
+                power *= x;
+            }
+            row.push(y * power);
+            row
+        })
+        .collect();
+
+    let solution = solve(&mut matrix, q_count + e)?;
+    let (q, locator) = solution.split_at(q_count);
+    let mut locator = locator.to_vec();
+    locator.push(F::ONE);
+
+    let p = divide_exact(q, &locator)?;
+    if p.len() > coeff_count {
+        return None;
+    }
+
+    let errors: Vec<usize> = xs
+        .iter()
+        .zip(ys)
+        .enumerate()
+        .filter(|(_, (&x, &y))| evaluate(&p, x) != y)
+        .map(|(i, _)| i)
+        .collect();
+    if errors.len() > e {
+        return None;
+    }
+
+    let mut p = p;
+    p.resize(coeff_count, F::ZERO);
+    Some((p, errors))
+}
+
+/// Solve the linear system given by the augmented `matrix` with `unknowns` unknowns,
+/// setting free variables to zero.
+fn solve<F: Field>(matrix: &mut [Vec<F>], unknowns: usize) -> Option<Vec<F>> {
+    let mut pivots = Vec::with_capacity(unknowns);
+    let mut row = 0;
+
+    for col in 0..unknowns {
+        let Some(pivot) = (row..matrix.len()).find(|&r| matrix[r][col] != F::ZERO) else {
+            continue;
+        };
+        matrix.swap(row, pivot);
+
+        let inv = F::ONE / matrix[row][col];
+        matrix[row].iter_mut().for_each(|v| *v *= inv);
+
+        let pivot_row = matrix[row].clone();
+        for (r, other) in matrix.iter_mut().enumerate() {
+            let factor = other[col];
+            if r != row && factor != F::ZERO {
+                other
+                    .iter_mut()
+                    .zip(&pivot_row)
+                    .skip(col)
+                    .for_each(|(v, &p)| *v -= p * factor);
+            }
+        }
+
+        pivots.push(col);
+        row += 1;
+        if row == matrix.len() {
+            break;
+        }
+    }
+
+    if matrix[row..].iter().any(|r| r[unknowns] != F::ZERO) {
+        return None;
+    }
+
+    let mut solution = vec![F::ZERO; unknowns];
+    for (r, &col) in pivots.iter().enumerate() {
+        solution[col] = matrix[r][unknowns];
+    }
+    Some(solution)
+}
+
+/// Divide `numerator` by the monic `divisor`, return `None` if the remainder is not zero.
+/// Coefficients are in ascending order.
+fn divide_exact<F: Field>(numerator: &[F], divisor: &[F]) -> Option<Vec<F>> {
+    let d = divisor.len() - 1;
+    let mut remainder = numerator.to_vec();
+    if remainder.len() <= d {
+        return remainder.iter().all(|v| *v == F::ZERO).then(Vec::new);
+    }
+
+    let mut quotient = vec![F::ZERO; remainder.len() - d];
+    for i in (0..quotient.len()).rev() {
+        let coeff = remainder[i + d];
+        quotient[i] = coeff;
+        for (j, &v) in divisor.iter().enumerate() {
+            remainder[i + j] -= coeff * v;
+        }
+    }
+
+    remainder.iter().all(|v| *v == F::ZERO).then_some(quotient)
+}
+
+#[inline]
+fn evaluate<F: Field>(poly: &[F], x: F) -> F {
+    poly.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c)
+}
@@ -0,0 +1,494 @@
+//! Shamir secret sharing over any [`Field`].
+//!
+//! A secret polynomial is shared coefficient-wise: the `i`-th coefficient of every share is
+//! the evaluation of an independent sharing polynomial whose constant term is the `i`-th
+//! coefficient of the secret, so a share has the coeff count of the secret.
+//! A single field element is shared as a polynomial with one coefficient.
+//!
+//! Resharing to a new set of indices needs nothing more: each holder [`share`]s its own share
+//! to the new indices, and each new holder [`reconstruct`]s the sub-shares it received
+//! at the old indices, see [`combine_reshares`].
+
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::{CsRng, Field, Polynomial, Random};
+
+mod decoding;
+
+/// Compute the lagrange coefficients for evaluating the shared polynomial at `0`.
+///
+/// # Panics
+///
+/// Panics if `indices` contains `0` or a repeated index.
+pub fn lagrange_coeffs<F: Field>(indices: &[F]) -> Vec<F> {
+    assert!(!indices.contains(&F::ZERO), "indices should not contain 0");
+    lagrange_coeffs_at(F::ZERO, indices)
+}
+
+/// Compute the lagrange coefficients for evaluating the shared polynomial at `point`.
+///
+/// The numerators are taken from prefix and suffix products of `point - x_j`,
+/// and the denominators are inverted together with a single field inversion.
+///
+/// # Panics
+///
+/// Panics if `indices` contains a repeated index.
+pub fn lagrange_coeffs_at<F: Field>(point: F, indices: &[F]) -> Vec<F> {
+    let mut weights = barycentric_weights(indices);
+    assert!(
+        batch_inverse(&mut weights),
+        "indices should not contain repeated index"
+    );
+    let mut coeffs = products_without_each(indices.iter().map(|&x| point - x).collect());
+    coeffs.iter_mut().zip(weights).for_each(|(c, w)| *c *= w);
+    coeffs
+}
+
+/// The minimal number of indices from which the barycentric weights are computed across threads.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 7;
+
+/// Compute `w_i = prod_{j != i} (x_i - x_j)` of every index, which is `0` for repeated indices.
+fn barycentric_weights<F: Field>(indices: &[F]) -> Vec<F> {
+    let weight = |i: usize, x: F| {
+        indices
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .fold(F::ONE, |acc, (_, &y)| acc * (x - y))
+    };
+
+    #[cfg(feature = "parallel")]
+    if indices.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        return indices
+            .par_iter()
+            .enumerate()
+            .map(|(i, &x)| weight(i, x))
+            .collect();
+    }
+
+    indices
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| weight(i, x))
+        .collect()
+}
+
+/// Compute the product of all values but the `i`-th for every `i`, without any division.
+fn products_without_each<F: Field>(values: Vec<F>) -> Vec<F> {
+    // prefix products first, then multiplied by the suffix products from the back
+    let mut products = Vec::with_capacity(values.len());
+    let mut prefix = F::ONE;
+    for &v in values.iter() {
+        products.push(prefix);
+        prefix *= v;
+    }
+    let mut suffix = F::ONE;
+    for (p, v) in products.iter_mut().zip(values).rev() {
+        *p *= suffix;
+        suffix *= v;
+    }
+    products
+}
+
+/// Invert every value in place with a single field inversion (Montgomery's trick).
+///
+/// Return `false` and leave `values` unchanged if any of them is `0`.
+fn batch_inverse<F: Field>(values: &mut [F]) -> bool {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = F::ONE;
+    for &v in values.iter() {
+        prefix.push(acc);
+        acc *= v;
+    }
+    if acc == F::ZERO {
+        return false;
+    }
+
+    let mut inv = acc.inv();
+    for (v, p) in values.iter_mut().zip(prefix).rev() {
+        let v_inv = inv * p;
+        inv *= *v;
+        *v = v_inv;
+    }
+    true
+}
+
+/// The lagrange coefficients of a set of indices for evaluating at a fixed point,
+/// kept up to date as single indices join or leave the set.
+///
+/// It stores the barycentric weights `w_i = prod_{j != i} (x_i - x_j)`, which take `O(k)`
+/// to update for `k` indices instead of `O(k^2)` to recompute, so that services whose
+/// committee churns don't rebuild the coefficients from scratch. The coefficients
+/// are then derived in `O(k)` with a single inversion, see [`LagrangeBasis::coeffs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeBasis<F: Field> {
+    point: F,
+    indices: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: Field> LagrangeBasis<F> {
+    /// Creates the lagrange basis of `indices` for evaluating at `point`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` contains a repeated index.
+    pub fn new(point: F, indices: &[F]) -> Self {
+        let weights = barycentric_weights(indices);
+        assert!(
+            !weights.contains(&F::ZERO),
+            "indices should not contain repeated index"
+        );
+        Self {
+            point,
+            indices: indices.to_vec(),
+            weights,
+        }
+    }
+
+    /// Returns the point the coefficients evaluate at.
+    #[inline]
+    pub fn point(&self) -> F {
+        self.point
+    }
+
+    /// Returns the current indices, in the order of the coefficients.
+    #[inline]
+    pub fn indices(&self) -> &[F] {
+        &self.indices
+    }
+
+    /// Returns the number of indices.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if there is no index.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Adds `index` at the end of the indices.
+    ///
+    /// Returns `false` and leaves the basis unchanged if `index` is already present.
+    pub fn insert(&mut self, index: F) -> bool {
+        if self.indices.contains(&index) {
+            return false;
+        }
+        let mut weight = F::ONE;
+        self.weights
+            .iter_mut()
+            .zip(&self.indices)
+            .for_each(|(w, &x)| {
+                *w *= x - index;
+                weight *= index - x;
+            });
+        self.indices.push(index);
+        self.weights.push(weight);
+        true
+    }
+
+    /// Removes `index`, keeping the order of the other indices.
+    ///
+    /// Returns `false` and leaves the basis unchanged if `index` is not present.
+    pub fn remove(&mut self, index: F) -> bool {
+        let Some(pos) = self.indices.iter().position(|&x| x == index) else {
+            return false;
+        };
+        self.indices.remove(pos);
+        self.weights.remove(pos);
+
+        let mut diffs: Vec<F> = self.indices.iter().map(|&x| x - index).collect();
+        // the indices are distinct, so no difference is 0
+        let invertible = batch_inverse(&mut diffs);
+        debug_assert!(invertible);
+        self.weights
+            .iter_mut()
+            .zip(diffs)
+            .for_each(|(w, d)| *w *= d);
+        true
+    }
+
+    /// Computes the lagrange coefficients of the current indices,
+    /// equal to [`lagrange_coeffs_at`] of [`LagrangeBasis::point`] and [`LagrangeBasis::indices`].
+    pub fn coeffs(&self) -> Vec<F> {
+        let mut weights = self.weights.clone();
+        let invertible = batch_inverse(&mut weights);
+        debug_assert!(invertible);
+        let mut coeffs =
+            products_without_each(self.indices.iter().map(|&x| self.point - x).collect());
+        coeffs.iter_mut().zip(weights).for_each(|(c, w)| *c *= w);
+        coeffs
+    }
+}
+
+/// Share `secret` to `indices`, so that any `threshold` shares reconstruct it
+/// and fewer learn nothing about it, yielding the share of one index at a time.
+///
+/// Only the `threshold - 1` random coefficients of the sharing polynomials are kept,
+/// so the memory does not grow with the number of indices.
+///
+/// # Panics
+///
+/// Panics if `threshold` is `0`.
+pub fn share<'a, F, R>(
+    secret: &'a Polynomial<F>,
+    threshold: usize,
+    indices: &'a [F],
+    rng: &mut R,
+) -> ShareStream<'a, F>
+where
+    F: Field + Random,
+    R: CsRng,
+{
+    assert!(threshold >= 1, "threshold should be at least 1");
+    let randomness = (1..threshold)
+        .map(|_| Polynomial::<F>::random(secret.coeff_count(), &mut *rng))
+        .collect();
+    ShareStream {
+        indices,
+        secret,
+        randomness,
+        next: 0,
+    }
+}
+
+/// Evaluate the shared polynomial at `point` from `shares` at `indices`,
+/// which needs at least `threshold` shares.
+pub fn interpolate_at<F: Field>(
+    point: F,
+    shares: &[Polynomial<F>],
+    indices: &[F],
+) -> Polynomial<F> {
+    assert_eq!(
+        shares.len(),
+        indices.len(),
+        "the length of shares and indices should be equal"
+    );
+    assert!(!shares.is_empty(), "shares should not be empty");
+    let coeff_count = shares[0].coeff_count();
+    let mut value = Polynomial::<F>::zero(coeff_count);
+    for (share, coeff) in shares.iter().zip(lagrange_coeffs_at(point, indices)) {
+        assert_eq!(
+            share.coeff_count(),
+            coeff_count,
+            "shares should have the same coeff count"
+        );
+        value
+            .iter_mut()
+            .zip(share.iter())
+            .for_each(|(v, &s)| v.add_mul_assign(s, coeff));
+    }
+    value
+}
+
+/// Reconstruct the secret from `shares` at `indices`, which needs at least `threshold` shares.
+///
+/// The shares are not checked, see [`verify_shares`] and [`reconstruct_with_correction`].
+#[inline]
+pub fn reconstruct<F: Field>(shares: &[Polynomial<F>], indices: &[F]) -> Polynomial<F> {
+    assert!(!indices.contains(&F::ZERO), "indices should not contain 0");
+    interpolate_at(F::ZERO, shares, indices)
+}
+
+/// Check that `shares` at `indices` are consistent with a sharing of `threshold`,
+/// that is the sharing polynomials of all coefficients have degree less than `threshold`.
+///
+/// Any `threshold` or fewer shares are consistent, so only more shares are able to
+/// detect a wrong share.
+pub fn verify_shares<F: Field>(shares: &[Polynomial<F>], indices: &[F], threshold: usize) -> bool {
+    assert_eq!(
+        shares.len(),
+        indices.len(),
+        "the length of shares and indices should be equal"
+    );
+    if shares.len() <= threshold {
+        return true;
+    }
+    let (base, rest) = shares.split_at(threshold);
+    let (base_indices, rest_indices) = indices.split_at(threshold);
+    rest.iter()
+        .zip(rest_indices)
+        .all(|(share, &index)| interpolate_at(index, base, base_indices) == *share)
+}
+
+/// Reconstruct the secret from `shares` at `indices`, correcting corrupted shares.
+///
+/// With `n` shares, up to `(n - threshold) / 2` corrupted shares are corrected
+/// by Berlekamp-Welch decoding, and the indices of the corrupted shares are returned
+/// along with the secret. Return `None` if there are more corrupted shares than that.
+pub fn reconstruct_with_correction<F: Field>(
+    shares: &[Polynomial<F>],
+    indices: &[F],
+    threshold: usize,
+) -> Option<(Polynomial<F>, Vec<F>)> {
+    assert_eq!(
+        shares.len(),
+        indices.len(),
+        "the length of shares and indices should be equal"
+    );
+    assert!(
+        shares.len() >= threshold,
+        "the number of shares is less than threshold"
+    );
+    let coeff_count = shares[0].coeff_count();
+    assert!(
+        shares.iter().all(|s| s.coeff_count() == coeff_count),
+        "shares should have the same coeff count"
+    );
+
+    let mut secret = Vec::with_capacity(coeff_count);
+    let mut corrupted = vec![false; shares.len()];
+    let mut ys = vec![F::ZERO; shares.len()];
+    for i in 0..coeff_count {
+        ys.iter_mut()
+            .zip(shares)
+            .for_each(|(y, share)| *y = share[i]);
+        let (poly, errors) = decoding::berlekamp_welch(indices, &ys, threshold)?;
+        errors.into_iter().for_each(|j| corrupted[j] = true);
+        secret.push(poly[0]);
+    }
+
+    let corrupted_indices = indices
+        .iter()
+        .zip(corrupted)
+        .filter_map(|(&index, c)| c.then_some(index))
+        .collect();
+    Some((Polynomial::new(secret), corrupted_indices))
+}
+
+/// Combine the sub-shares a new holder received when resharing,
+/// where `sub_shares[i]` is from the old holder at `old_indices[i]`,
+/// into its share of the original secret.
+///
+/// It needs sub-shares from at least the old threshold of holders,
+/// the new threshold is the one each old holder used to [`share`] its share.
+#[inline]
+pub fn combine_reshares<F: Field>(
+    sub_shares: &[Polynomial<F>],
+    old_indices: &[F],
+) -> Polynomial<F> {
+    reconstruct(sub_shares, old_indices)
+}
+
+/// The share of one index of a Shamir secret sharing.
+///
+/// The `Debug` output only shows the coeff count,
+/// the share itself is accessed with [`Share::expose_secret`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share<F: Field>(Polynomial<F>);
+
+impl<F: Field> Share<F> {
+    /// Create an instance from the share polynomial.
+    #[inline]
+    pub fn new(share: Polynomial<F>) -> Self {
+        Self(share)
+    }
+
+    /// Return the number of coefficients of the share.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.0.coeff_count()
+    }
+
+    /// Returns the reference of the share.
+    #[inline]
+    pub fn expose_secret(&self) -> &Polynomial<F> {
+        &self.0
+    }
+
+    /// Returns the share, e.g. to encrypt it without a copy.
+    #[inline]
+    pub fn into_secret(self) -> Polynomial<F> {
+        self.0
+    }
+}
+
+impl<F: Field> fmt::Debug for Share<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("coeff_count", &self.coeff_count())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The shares of a Shamir secret sharing, produced one index at a time, see [`share`].
+///
+/// The `Debug` output only shows the number of indices and the coeff count,
+/// and so does the `Debug` output of the [`Share`]s it yields.
+#[derive(Clone)]
+pub struct ShareStream<'a, F: Field> {
+    indices: &'a [F],
+    secret: &'a Polynomial<F>,
+    randomness: Vec<Polynomial<F>>,
+    next: usize,
+}
+
+impl<F: Field> fmt::Debug for ShareStream<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShareStream")
+            .field("parties", &self.indices.len())
+            .field("coeff_count", &self.coeff_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Field> ShareStream<'_, F> {
+    /// Return the number of coefficients of every share.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.secret.coeff_count()
+    }
+
+    /// Write the share of the `party`-th index into `share`, which is reused
+    /// instead of allocating a new polynomial.
+    pub fn share_into(&self, party: usize, share: &mut [F]) {
+        assert_eq!(
+            share.len(),
+            self.coeff_count(),
+            "share should have the coeff count of the secret"
+        );
+        let point = self.indices[party];
+
+        // Horner's rule, from the highest coefficient of the sharing polynomials
+        share.fill(F::ZERO);
+        self.randomness
+            .iter()
+            .rev()
+            .chain(core::iter::once(self.secret))
+            .for_each(|c| {
+                share
+                    .iter_mut()
+                    .zip(c.iter())
+                    .for_each(|(s, &c)| *s = c.add_mul(*s, point));
+            });
+    }
+}
+
+impl<F: Field> Iterator for ShareStream<'_, F> {
+    type Item = Share<F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.indices.len() {
+            return None;
+        }
+        let mut share = Polynomial::zero(self.coeff_count());
+        self.share_into(self.next, share.as_mut_slice());
+        self.next += 1;
+        Some(Share(share))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.indices.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<F: Field> ExactSizeIterator for ShareStream<'_, F> {}
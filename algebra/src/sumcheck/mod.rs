@@ -0,0 +1,20 @@
+//! Interactive sumcheck protocol for multilinear polynomials.
+//! It is derived from https://github.com/arkworks-rs/sumcheck/tree/master/src/ml_sumcheck/protocol .
+//!
+//! Unlike the original, the verifier's challenges are injected by the caller,
+//! so that the protocol can be made non-interactive with any transcript.
+
+use core::marker::PhantomData;
+
+use crate::Field;
+
+mod prover;
+mod verifier;
+
+pub use prover::{ProverMsg, ProverState};
+pub use verifier::{SubClaim, VerifierState};
+
+/// Interactive Proof for Multilinear Sumcheck
+pub struct IPForMLSumcheck<F: Field> {
+    _marker: PhantomData<F>,
+}
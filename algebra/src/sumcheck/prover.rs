@@ -0,0 +1,115 @@
+//! Prover of the sumcheck protocol.
+//! It is derived from https://github.com/arkworks-rs/sumcheck/blob/master/src/ml_sumcheck/protocol/prover.rs .
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    DenseMultilinearExtension, Field, ListOfProductsOfPolynomials, MultilinearExtension, Random,
+};
+
+use super::IPForMLSumcheck;
+
+/// Prover Message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProverMsg<F: Field> {
+    /// evaluations on P(0), P(1), P(2), ...
+    pub evaluations: Vec<F>,
+}
+
+/// Prover State
+pub struct ProverState<F: Field> {
+    /// sampled randomness given by the verifier
+    pub randomness: Vec<F>,
+    /// Stores the list of products that is meant to be added together. Each multiplicand is represented by
+    /// the index in flattened_ml_extensions
+    pub list_of_products: Vec<(F, Vec<usize>)>,
+    /// Stores a list of multilinear extensions in which `self.list_of_products` points to
+    pub flattened_ml_extensions: Vec<DenseMultilinearExtension<F>>,
+    /// Number of variables
+    pub num_vars: usize,
+    /// Max number of multiplicands in a product
+    pub max_multiplicands: usize,
+    /// The current round number
+    pub round: usize,
+}
+
+impl<F: Field + Random> IPForMLSumcheck<F> {
+    /// Initialize the prover to argue for the sum of polynomial over {0,1}^`num_vars`
+    pub fn prover_init(polynomial: &ListOfProductsOfPolynomials<F>) -> ProverState<F> {
+        assert!(
+            polynomial.num_variables != 0,
+            "Attempt to prove a constant."
+        );
+
+        // create a deep copy of all unique MLExtensions
+        let flattened_ml_extensions = polynomial
+            .flattened_ml_extensions
+            .iter()
+            .map(|x| x.as_ref().clone())
+            .collect();
+
+        ProverState {
+            randomness: Vec::with_capacity(polynomial.num_variables),
+            list_of_products: polynomial.products.clone(),
+            flattened_ml_extensions,
+            num_vars: polynomial.num_variables,
+            max_multiplicands: polynomial.max_multiplicands,
+            round: 0,
+        }
+    }
+
+    /// Receive the challenge of the last round (`None` in the first round),
+    /// and compute the prover message of the current round.
+    pub fn prove_round(prover_state: &mut ProverState<F>, challenge: Option<F>) -> ProverMsg<F> {
+        if let Some(r) = challenge {
+            assert!(
+                prover_state.round != 0,
+                "first round should be prover first."
+            );
+            prover_state.randomness.push(r);
+
+            // fix the first variable of all multilinear extensions at r
+            for multiplicand in prover_state.flattened_ml_extensions.iter_mut() {
+                *multiplicand = multiplicand.fix_variables(&[r]);
+            }
+        } else {
+            assert!(prover_state.round == 0, "verifier message is empty");
+        }
+
+        prover_state.round += 1;
+        assert!(
+            prover_state.round <= prover_state.num_vars,
+            "Prover is not active"
+        );
+
+        let i = prover_state.round;
+        let nv = prover_state.num_vars;
+        let degree = prover_state.max_multiplicands;
+
+        let mut products_sum = vec![F::ZERO; degree + 1];
+        let mut product = vec![F::ZERO; degree + 1];
+
+        // generate sum
+        for b in 0..1 << (nv - i) {
+            for (coefficient, products) in prover_state.list_of_products.iter() {
+                product.fill(*coefficient);
+                for &jth_product in products {
+                    let table = &prover_state.flattened_ml_extensions[jth_product];
+                    let mut start = table[b << 1];
+                    let step = table[(b << 1) + 1] - start;
+                    for p in product.iter_mut() {
+                        *p *= start;
+                        start += step;
+                    }
+                }
+                for (sum, &p) in products_sum.iter_mut().zip(product.iter()) {
+                    *sum += p;
+                }
+            }
+        }
+
+        ProverMsg {
+            evaluations: products_sum,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Verifier of the sumcheck protocol.
+//! It is derived from https://github.com/arkworks-rs/sumcheck/blob/master/src/ml_sumcheck/protocol/verifier.rs .
+
+use alloc::vec::Vec;
+
+use crate::{AlgebraError, Field, PolynomialInfo};
+
+use super::{IPForMLSumcheck, ProverMsg};
+
+/// Verifier State
+pub struct VerifierState<F: Field> {
+    round: usize,
+    num_vars: usize,
+    max_multiplicands: usize,
+    finished: bool,
+    /// a list storing the univariate polynomial in evaluation form sent by the prover at each round
+    polynomials_received: Vec<Vec<F>>,
+    /// a list storing the randomness sampled by the verifier at each round
+    randomness: Vec<F>,
+}
+
+/// Subclaim when verifier is convinced
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubClaim<F: Field> {
+    /// the multi-dimensional point that this multilinear extension is evaluated to
+    pub point: Vec<F>,
+    /// the expected evaluation
+    pub expected_evaluation: F,
+}
+
+impl<F: Field> IPForMLSumcheck<F> {
+    /// Initialize the verifier
+    pub fn verifier_init(index_info: &PolynomialInfo) -> VerifierState<F> {
+        VerifierState {
+            round: 1,
+            num_vars: index_info.num_variables,
+            max_multiplicands: index_info.max_multiplicands,
+            finished: false,
+            polynomials_received: Vec::with_capacity(index_info.num_variables),
+            randomness: Vec::with_capacity(index_info.num_variables),
+        }
+    }
+
+    /// Run verifier at current round, given the prover message and the `challenge` of this round,
+    /// which should be sent to the prover afterwards.
+    ///
+    /// Normally, this function should perform actual verification. Instead, `verify_round` only records
+    /// the prover message, and verifications are deferred to `check_and_generate_subclaim` at the end.
+    pub fn verify_round(
+        prover_msg: &ProverMsg<F>,
+        verifier_state: &mut VerifierState<F>,
+        challenge: F,
+    ) {
+        assert!(
+            !verifier_state.finished,
+            "Incorrect verifier state: Verifier is already finished."
+        );
+
+        verifier_state.randomness.push(challenge);
+        verifier_state
+            .polynomials_received
+            .push(prover_msg.evaluations.clone());
+
+        if verifier_state.round == verifier_state.num_vars {
+            // accept and close
+            verifier_state.finished = true;
+        } else {
+            verifier_state.round += 1;
+        }
+    }
+
+    /// Verify the sumcheck phase, and generate the subclaim.
+    ///
+    /// If the asserted sum is correct, then the multilinear polynomial evaluated at `subclaim.point`
+    /// is `subclaim.expected_evaluation`. Otherwise, it is highly unlikely that those two will be equal.
+    /// Larger field size guarantees smaller soundness error.
+    pub fn check_and_generate_subclaim(
+        verifier_state: VerifierState<F>,
+        asserted_sum: F,
+    ) -> Result<SubClaim<F>, AlgebraError> {
+        if !verifier_state.finished {
+            panic!("Verifier has not finished.");
+        }
+
+        let mut expected = asserted_sum;
+        if verifier_state.polynomials_received.len() != verifier_state.num_vars {
+            return Err(AlgebraError::SumcheckError);
+        }
+        for (evaluations, &r) in verifier_state
+            .polynomials_received
+            .iter()
+            .zip(verifier_state.randomness.iter())
+        {
+            if evaluations.len() != verifier_state.max_multiplicands + 1 {
+                return Err(AlgebraError::SumcheckError);
+            }
+
+            let p0 = evaluations[0];
+            let p1 = evaluations[1];
+            if p0 + p1 != expected {
+                return Err(AlgebraError::SumcheckError);
+            }
+            expected = interpolate_uni_poly(evaluations, r);
+        }
+
+        Ok(SubClaim {
+            point: verifier_state.randomness,
+            expected_evaluation: expected,
+        })
+    }
+}
+
+/// Interpolate the univariate polynomial given by its evaluations at `0, 1, ..., len - 1`,
+/// and evaluate it at `eval_at`.
+pub(crate) fn interpolate_uni_poly<F: Field>(p_i: &[F], eval_at: F) -> F {
+    let len = p_i.len();
+    let points: Vec<F> = (0..len).map(F::cast_from_usize).collect();
+
+    // the evaluation point is one of the points
+    if let Some(i) = points.iter().position(|&x| x == eval_at) {
+        return p_i[i];
+    }
+
+    // prod = \prod_j (eval_at - j)
+    let prod = points.iter().fold(F::ONE, |acc, &x| acc * (eval_at - x));
+
+    let mut res = F::ZERO;
+    for (i, &x_i) in points.iter().enumerate() {
+        // denominator = (eval_at - i) * \prod_{j != i} (i - j)
+        let denominator = points
+            .iter()
+            .filter(|&&x_j| x_j != x_i)
+            .fold(eval_at - x_i, |acc, &x_j| acc * (x_i - x_j));
+        res += p_i[i] * prod / denominator;
+    }
+    res
+}
@@ -0,0 +1,62 @@
+//! Generators of structured inputs for fuzzing and property tests.
+//!
+//! With the `test-utils` feature, every field derived with [`Field`](crate::derive::Field)
+//! implements [`arbitrary::Arbitrary`] and [`proptest::arbitrary::Arbitrary`],
+//! and so do [`Polynomial`] and [`NTTPolynomial`] over any [`Field`].
+
+use alloc::vec::Vec;
+
+use arbitrary::{Arbitrary, Unstructured};
+use num_traits::{NumCast, ToPrimitive};
+use proptest::collection::vec;
+use proptest::strategy::Strategy;
+
+use crate::{Field, NTTPolynomial, Polynomial};
+
+pub use arbitrary;
+pub use proptest;
+
+/// Generates a field element uniformly from the canonical values `[0, q)`.
+pub fn arbitrary_field<F: Field>(u: &mut Unstructured<'_>) -> arbitrary::Result<F> {
+    let max = modulus_u128::<F>() - 1;
+    let value = u.int_in_range(0..=max)?;
+    Ok(F::new(<F::Value as NumCast>::from(value).unwrap()))
+}
+
+/// Returns a strategy generating field elements uniformly from `[0, q)`.
+pub fn field_strategy<F: Field>() -> impl Strategy<Value = F> {
+    (0..modulus_u128::<F>()).prop_map(|value| F::new(<F::Value as NumCast>::from(value).unwrap()))
+}
+
+/// Returns a strategy generating polynomials with `coeff_count` coefficients.
+pub fn polynomial_strategy<F: Field>(coeff_count: usize) -> impl Strategy<Value = Polynomial<F>> {
+    vec(field_strategy::<F>(), coeff_count).prop_map(Polynomial::new)
+}
+
+/// Returns a strategy generating polynomials with `coeff_count` values in NTT form.
+pub fn ntt_polynomial_strategy<F: Field>(
+    coeff_count: usize,
+) -> impl Strategy<Value = NTTPolynomial<F>> {
+    vec(field_strategy::<F>(), coeff_count).prop_map(NTTPolynomial::new)
+}
+
+fn modulus_u128<F: Field>() -> u128 {
+    F::modulus_value().to_u128().unwrap()
+}
+
+fn arbitrary_field_vec<F: Field>(u: &mut Unstructured<'_>) -> arbitrary::Result<Vec<F>> {
+    let len = u.arbitrary_len::<u64>()?;
+    (0..len).map(|_| arbitrary_field(u)).collect()
+}
+
+impl<'a, F: Field> Arbitrary<'a> for Polynomial<F> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        arbitrary_field_vec(u).map(Polynomial::new)
+    }
+}
+
+impl<'a, F: Field> Arbitrary<'a> for NTTPolynomial<F> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        arbitrary_field_vec(u).map(NTTPolynomial::new)
+    }
+}
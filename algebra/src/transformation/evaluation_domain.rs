@@ -0,0 +1,186 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use crate::modulus::ShoupFactor;
+use crate::utils::bit_reverse_permute;
+use crate::{AlgebraError, Field, ModulusConfig, NTTField, Polynomial};
+
+use super::{AbstractNTT, NTTTable, NTTVariant};
+
+/// A multiplicative subgroup `{ω^i}` of size `2^log_size`, or its coset `{g·ω^i}`,
+/// over which polynomials are evaluated and interpolated.
+///
+/// The transforms reuse the negacyclic [`NTTTable`] of the same size:
+/// with `ψ` the `2n`-th root of the table, which evaluates at `ψ·ω^i`,
+/// scaling the coefficients by `(g·ψ^(-1))^j` beforehand moves the evaluation points to `g·ω^i`.
+///
+/// Evaluations are always in natural order, i.e. the `i`-th one is at the `i`-th element.
+#[derive(Debug, Clone)]
+pub struct EvaluationDomain<F>
+where
+    F: NTTField<Table = NTTTable<F>, Root = ShoupFactor<<F as ModulusConfig>::Value>>,
+{
+    log_size: u32,
+    size: usize,
+    size_inv: F,
+    generator: F,
+    generator_inv: F,
+    offset: F,
+    offset_inv: F,
+    table: Arc<NTTTable<F>>,
+}
+
+impl<F> EvaluationDomain<F>
+where
+    F: NTTField<Table = NTTTable<F>, Root = ShoupFactor<<F as ModulusConfig>::Value>>,
+{
+    /// Creates the subgroup of size `2^log_size`, with `log_size` at least 1.
+    pub fn new(log_size: u32) -> Result<Self, AlgebraError> {
+        if log_size == 0 {
+            return Err(AlgebraError::NTTTableError);
+        }
+        let table = F::get_ntt_table(log_size)?;
+
+        let root = table.root();
+        let generator = root * root;
+        let inv_root = table.inv_root();
+
+        Ok(Self {
+            log_size,
+            size: 1 << log_size,
+            size_inv: F::from_root(table.inv_degree()),
+            generator,
+            generator_inv: inv_root * inv_root,
+            offset: F::ONE,
+            offset_inv: F::ONE,
+            table,
+        })
+    }
+
+    /// Returns the coset of this subgroup shifted by a nonzero `offset`.
+    pub fn coset(&self, offset: F) -> Self {
+        debug_assert!(offset != F::ZERO);
+        Self {
+            offset,
+            offset_inv: offset.inv(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the size of this domain.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the logarithm of the size of this domain.
+    #[inline]
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the inverse of the size of this domain.
+    #[inline]
+    pub fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    /// Returns the generator `ω` of the subgroup.
+    #[inline]
+    pub fn generator(&self) -> F {
+        self.generator
+    }
+
+    /// Returns the inverse of the generator of the subgroup.
+    #[inline]
+    pub fn generator_inv(&self) -> F {
+        self.generator_inv
+    }
+
+    /// Returns the coset offset `g`, which is one for the subgroup itself.
+    #[inline]
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    /// Returns the `i`-th element `g·ω^i`.
+    #[inline]
+    pub fn element(&self, i: usize) -> F {
+        self.offset * self.generator.pow_u64((i % self.size) as u64)
+    }
+
+    /// Returns an iterator over the elements `g·ω^i` in natural order.
+    pub fn elements(&self) -> impl Iterator<Item = F> + '_ {
+        let generator = self.generator;
+        core::iter::successors(Some(self.offset), move |&x| Some(x * generator)).take(self.size)
+    }
+
+    /// Evaluates the vanishing polynomial `X^n - g^n` of this domain at `x`.
+    #[inline]
+    pub fn evaluate_vanishing_polynomial(&self, x: F) -> F {
+        x.square_n(self.log_size) - self.offset.square_n(self.log_size)
+    }
+
+    /// Evaluates `polynomial` over the subgroup, ignoring the coset offset.
+    ///
+    /// The polynomial must have at most [`size`](Self::size) coefficients.
+    #[inline]
+    pub fn fft(&self, polynomial: &Polynomial<F>) -> Vec<F> {
+        self.shifted_fft(polynomial, F::ONE)
+    }
+
+    /// Interpolates the evaluations over the subgroup, ignoring the coset offset.
+    #[inline]
+    pub fn ifft(&self, evaluations: &[F]) -> Polynomial<F> {
+        self.shifted_ifft(evaluations, F::ONE)
+    }
+
+    /// Evaluates `polynomial` over the coset.
+    ///
+    /// The polynomial must have at most [`size`](Self::size) coefficients.
+    #[inline]
+    pub fn coset_fft(&self, polynomial: &Polynomial<F>) -> Vec<F> {
+        self.shifted_fft(polynomial, self.offset)
+    }
+
+    /// Interpolates the evaluations over the coset.
+    #[inline]
+    pub fn coset_ifft(&self, evaluations: &[F]) -> Polynomial<F> {
+        self.shifted_ifft(evaluations, self.offset_inv)
+    }
+
+    fn shifted_fft(&self, polynomial: &Polynomial<F>, shift: F) -> Vec<F> {
+        assert!(polynomial.coeff_count() <= self.size);
+
+        let mut values = polynomial.as_slice().to_vec();
+        values.resize(self.size, F::ZERO);
+        scale_by_powers(&mut values, shift * self.table.inv_root());
+
+        self.table.transform_slice(&mut values);
+        if self.table.variant() == NTTVariant::CooleyTukey {
+            bit_reverse_permute(&mut values, self.log_size);
+        }
+        values
+    }
+
+    fn shifted_ifft(&self, evaluations: &[F], shift_inv: F) -> Polynomial<F> {
+        assert_eq!(evaluations.len(), self.size);
+
+        let mut values = evaluations.to_vec();
+        if self.table.variant() == NTTVariant::CooleyTukey {
+            bit_reverse_permute(&mut values, self.log_size);
+        }
+        self.table.inverse_transform_slice(&mut values);
+
+        scale_by_powers(&mut values, shift_inv * self.table.root());
+        Polynomial::new(values)
+    }
+}
+
+/// Performs `values[j] *= factor^j`.
+fn scale_by_powers<F: Field>(values: &mut [F], factor: F) {
+    let mut power = F::ONE;
+    for v in values {
+        *v *= power;
+        power *= factor;
+    }
+}
@@ -4,9 +4,18 @@
 //! Using this module, you can speed up multiplication
 //! of polynomials, large integers, and so on.
 
+mod evaluation_domain;
 mod ntt_table;
+mod ntt_table_cache;
+mod stockham;
+mod table_blob;
+mod twist;
 
-pub use ntt_table::NTTTable;
+pub use evaluation_domain::EvaluationDomain;
+pub use ntt_table::{NTTTable, NTTVariant};
+pub use ntt_table_cache::{NTTTableCache, MAX_CACHED_LOG_N};
+pub use table_blob::BlobValue;
+pub use twist::TwistFactors;
 
 use crate::{NTTField, NTTPolynomial, Polynomial};
 
@@ -19,6 +28,7 @@ pub trait AbstractNTT<F: NTTField> {
     /// # Arguments
     ///
     /// * `polynomial` - inputs in normal order, outputs in bit-reversed order
+    ///   (in normal order for [`NTTVariant::Stockham`] tables)
     fn transform(&self, polynomial: &Polynomial<F>) -> NTTPolynomial<F>;
 
     /// Perform a fast number theory transform in place.
@@ -67,6 +77,14 @@ pub trait AbstractNTT<F: NTTField> {
     ///
     /// * `ntt_polynomial_slice` - inputs in bit-reversed order, outputs in normal order
     fn inverse_transform_slice(&self, ntt_polynomial_slice: &mut [F]);
+
+    /// Evaluate the polynomial of a [`NTTPolynomial<F>`] slice at `x`
+    /// directly from its ntt values, without an inverse transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `ntt_polynomial_slice` - inputs in the order of the transform outputs
+    fn evaluate_slice(&self, ntt_polynomial_slice: &[F], x: F) -> F;
 }
 
 /// Number theory transform for monomial.
@@ -1,8 +1,33 @@
+use alloc::{vec, vec::Vec};
+
 use crate::modulus::ShoupFactor;
 use crate::utils::ReverseLsbs;
-use crate::{Field, NTTField, NTTPolynomial, Polynomial, Widening, WrappingOps};
+use num_traits::ToPrimitive;
+
+use crate::{
+    AlgebraError, Field, ModulusConfig, NTTField, NTTPolynomial, Polynomial, Widening, WrappingOps,
+};
 
-use super::{AbstractNTT, MonomialNTT};
+use super::table_blob::{
+    write_factor_section, write_section, BlobValue, Header, SectionReader, TableData, TableKind,
+};
+use super::{stockham, AbstractNTT, MonomialNTT};
+
+/// The formulation of the transforms of an [`NTTTable`].
+///
+/// The variants agree on the evaluations but not on their order, so the ntt polynomials
+/// of tables with different variants must not be mixed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NTTVariant {
+    /// In-place Cooley-Tukey and Gentleman-Sande butterflies with lazy reduction,
+    /// the ntt values are in bit-reversed order.
+    #[default]
+    CooleyTukey,
+    /// Out-of-place Stockham auto-sort stages over a scratch buffer, without any
+    /// bit-reversal bookkeeping and with the same access pattern at every stage,
+    /// the ntt values are in natural order.
+    Stockham,
+}
 
 /// This struct store the pre-computed data for number theory transform and
 /// inverse number theory transform.
@@ -26,6 +51,11 @@ use super::{AbstractNTT, MonomialNTT};
 /// scrambled order:     0  1  5  3  7  2  6  4
 ///                         ----------  ----  -
 /// ```
+///
+/// A table can be stored with [`NTTTable::to_blob`] and loaded back without copying
+/// with [`NTTTable::from_blob`].
+///
+/// The formulation of the transforms is selected with [`NTTTable::with_variant`].
 #[derive(Debug)]
 pub struct NTTTable<F>
 where
@@ -36,10 +66,11 @@ where
     coeff_count_power: u32,
     coeff_count: usize,
     inv_degree: <F as NTTField>::Root,
-    root_powers: Vec<<F as NTTField>::Root>,
-    inv_root_powers: Vec<<F as NTTField>::Root>,
-    ordinal_root_powers: Vec<<F as NTTField>::Root>,
-    reverse_lsbs: Vec<usize>,
+    root_powers: TableData<<F as NTTField>::Root>,
+    inv_root_powers: TableData<<F as NTTField>::Root>,
+    ordinal_root_powers: TableData<<F as NTTField>::Root>,
+    reverse_lsbs: TableData<usize>,
+    variant: NTTVariant,
 }
 
 impl<F> NTTTable<F>
@@ -92,10 +123,11 @@ where
             coeff_count_power,
             coeff_count,
             inv_degree,
-            root_powers,
-            inv_root_powers,
-            ordinal_root_powers,
-            reverse_lsbs,
+            root_powers: root_powers.into(),
+            inv_root_powers: inv_root_powers.into(),
+            ordinal_root_powers: ordinal_root_powers.into(),
+            reverse_lsbs: reverse_lsbs.into(),
+            variant: NTTVariant::default(),
         }
     }
 
@@ -132,13 +164,13 @@ where
     /// Returns a reference to the root powers of this [`NTTTable<F>`].
     #[inline]
     pub fn root_powers(&self) -> &[<F as NTTField>::Root] {
-        self.root_powers.as_ref()
+        &self.root_powers
     }
 
     /// Returns a reference to the inverse elements of the root powers of this [`NTTTable<F>`].
     #[inline]
     pub fn inv_root_powers(&self) -> &[<F as NTTField>::Root] {
-        self.inv_root_powers.as_ref()
+        &self.inv_root_powers
     }
 
     /// Returns a reference to the ordinal root powers of this [`NTTTable<F>`].
@@ -146,6 +178,212 @@ where
     pub fn ordinal_root_powers(&self) -> &[<F as NTTField>::Root] {
         &self.ordinal_root_powers
     }
+
+    /// Returns the variant of the transforms of this [`NTTTable<F>`].
+    #[inline]
+    pub fn variant(&self) -> NTTVariant {
+        self.variant
+    }
+
+    /// Selects the variant of the transforms of this [`NTTTable<F>`].
+    #[inline]
+    pub fn with_variant(mut self, variant: NTTVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Returns `i` such that the `k`-th ntt value is the evaluation at `root^(2i+1)`.
+    #[inline]
+    fn point(&self, k: usize) -> usize {
+        match self.variant {
+            NTTVariant::CooleyTukey => self.reverse_lsbs[k],
+            NTTVariant::Stockham => k,
+        }
+    }
+}
+
+impl<F> NTTTable<F>
+where
+    F: NTTField<Table = Self, Root = ShoupFactor<<F as ModulusConfig>::Value>>,
+    <F as ModulusConfig>::Value: BlobValue,
+{
+    /// Serializes this [`NTTTable<F>`] into a flat blob.
+    ///
+    /// The blob holds the values in the native byte order and width,
+    /// so it can only be loaded on a target of the same kind.
+    pub fn to_blob(&self) -> Vec<u8> {
+        let mut blob = Vec::new();
+        Header {
+            kind: TableKind::Ntt,
+            value_size: core::mem::size_of::<F::Value>() as u8,
+            log_n: self.coeff_count_power,
+            modulus: F::MODULUS_INNER.into_u64(),
+            root: self.root.get().into_u64(),
+            inv_root: self.inv_root.get().into_u64(),
+        }
+        .write(&mut blob);
+
+        write_factor_section(&mut blob, &[self.inv_degree]);
+        write_factor_section(&mut blob, &self.root_powers);
+        write_factor_section(&mut blob, &self.inv_root_powers);
+        write_factor_section(&mut blob, &self.ordinal_root_powers);
+        write_section(&mut blob, &self.reverse_lsbs);
+        blob
+    }
+
+    /// Loads an [`NTTTable<F>`] from a blob of [`NTTTable::to_blob`] without copying the powers.
+    ///
+    /// The blob must start at an address aligned to 8 bytes, which holds for memory mapped
+    /// files, and must live for the whole process, e.g. a leaked mapping or a static.
+    /// Fails if the blob is malformed, misaligned, or was written for another field or target.
+    pub fn from_blob(blob: &'static [u8]) -> Result<Self, AlgebraError> {
+        let header = Header::read(blob)?;
+        if header.kind != TableKind::Ntt
+            || header.value_size as usize != core::mem::size_of::<F::Value>()
+            || header.modulus != F::MODULUS_INNER.into_u64()
+            || !(1..usize::BITS).contains(&header.log_n)
+        {
+            return Err(AlgebraError::TableBlobError);
+        }
+        let element = |value: u64| {
+            F::Value::from_u64(value)
+                .filter(|&v| v < F::MODULUS_INNER)
+                .map(F::new)
+                .ok_or(AlgebraError::TableBlobError)
+        };
+        let root = element(header.root)?;
+        let inv_root = element(header.inv_root)?;
+        if root * inv_root != F::ONE {
+            return Err(AlgebraError::TableBlobError);
+        }
+
+        let coeff_count = 1usize << header.log_n;
+        let mut reader = SectionReader::new(blob);
+        let inv_degree = reader.factor_section(1)?[0];
+        let root_powers = reader.factor_section(coeff_count)?;
+        let inv_root_powers = reader.factor_section(coeff_count)?;
+        let ordinal_root_powers = reader.factor_section(coeff_count * 2)?;
+        let reverse_lsbs = reader.section(coeff_count)?;
+        if !reader.is_finished() {
+            return Err(AlgebraError::TableBlobError);
+        }
+
+        Ok(Self {
+            root,
+            inv_root,
+            coeff_count_power: header.log_n,
+            coeff_count,
+            inv_degree,
+            root_powers: TableData::Borrowed(root_powers),
+            inv_root_powers: TableData::Borrowed(inv_root_powers),
+            ordinal_root_powers: TableData::Borrowed(ordinal_root_powers),
+            reverse_lsbs: TableData::Borrowed(reverse_lsbs),
+            variant: NTTVariant::default(),
+        })
+    }
+}
+
+impl<F> NTTTable<F>
+where
+    F: NTTField<Table = Self, Root = ShoupFactor<<F as ModulusConfig>::Value>>,
+{
+    /// Verifies the precomputed data and the transforms of this [`NTTTable<F>`], so deployments
+    /// can reject a miscompiled or misconfigured table, e.g. a loaded blob, before using it.
+    ///
+    /// It checks that the root is a primitive `2n`-th root of unity and its inverse, that every
+    /// power and Shoup quotient matches its definition, and that the product of two pseudo-random
+    /// polynomials through the transforms agrees with schoolbook multiplication modulo `X^n + 1`.
+    /// The inputs come from a fixed seed, so a passing table always passes.
+    pub fn self_check(&self) -> Result<(), AlgebraError> {
+        let log_n = self.coeff_count_power;
+        let n = self.coeff_count;
+        let ordinal = &self.ordinal_root_powers;
+        let check = |ok: bool, name| {
+            if ok {
+                Ok(())
+            } else {
+                Err(AlgebraError::NTTSelfCheckError(name))
+            }
+        };
+
+        check(
+            n == 1 << log_n
+                && self.root_powers.len() == n
+                && self.inv_root_powers.len() == n
+                && ordinal.len() == 2 * n
+                && self.reverse_lsbs.len() == n,
+            "the lengths",
+        )?;
+
+        let factor = |r: ShoupFactor<F::Value>| {
+            r.value() < F::MODULUS_INNER && F::from_root(r).to_root().quotient() == r.quotient()
+        };
+        check(
+            factor(self.inv_degree)
+                && self.root_powers.iter().all(|&r| factor(r))
+                && self.inv_root_powers[1..].iter().all(|&r| factor(r))
+                && ordinal.iter().all(|&r| factor(r)),
+            "the shoup factors",
+        )?;
+
+        // `root^n = -1` makes the order of `root` exactly `2n`
+        let power = |i: usize| F::from_root(ordinal[i]);
+        check(
+            power(0) == F::ONE
+                && power(1) == self.root
+                && (1..2 * n).all(|i| power(i) == power(i - 1) * self.root)
+                && power(n) == F::NEG_ONE
+                && self.root * self.inv_root == F::ONE
+                && power(2 * n - 1) == self.inv_root,
+            "the root order",
+        )?;
+
+        check(
+            F::from_root(self.inv_degree) * F::cast_from_usize(n) == F::ONE
+                && (0..n).all(|i| self.reverse_lsbs[i] == i.reverse_lsbs(log_n))
+                && (0..n).all(|i| F::from_root(self.root_powers[self.reverse_lsbs[i]]) == power(i))
+                && (0..n - 1).all(|i| {
+                    F::from_root(self.inv_root_powers[self.reverse_lsbs[i] + 1])
+                        == power(2 * n - 1 - i)
+                }),
+            "the inverse relations",
+        )?;
+
+        // xorshift64 from a fixed seed
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let modulus = F::MODULUS_INNER.to_u64().unwrap();
+        let mut sample = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            F::new(num_traits::cast(state % modulus).unwrap())
+        };
+        let a = Polynomial::new((0..n).map(|_| sample()).collect());
+        let b = Polynomial::new((0..n).map(|_| sample()).collect());
+
+        let (ntt_a, ntt_b) = (self.transform(&a), self.transform(&b));
+        check(self.inverse_transform(&ntt_a) == a, "the round trip")?;
+
+        // the schoolbook coefficients at a spread of positions cost `O(n)` each
+        let c = self.inverse_transform_inplace(ntt_a * ntt_b);
+        let schoolbook = |k: usize| {
+            (0..n).fold(F::ZERO, |acc, i| {
+                if i <= k {
+                    acc + a[i] * b[k - i]
+                } else {
+                    acc - a[i] * b[n + k - i]
+                }
+            })
+        };
+        let step = (n / 32).max(1);
+        check(
+            (0..n)
+                .step_by(step)
+                .chain([n - 1])
+                .all(|k| c[k] == schoolbook(k)),
+            "the convolution",
+        )
+    }
 }
 
 impl<F> MonomialNTT<F> for NTTTable<F>
@@ -169,30 +407,20 @@ where
         let mask = usize::MAX >> (usize::BITS - log_n - 1);
 
         if coeff == F::ONE {
-            values
-                .iter_mut()
-                .zip(&self.reverse_lsbs)
-                .for_each(|(v, &i)| {
-                    let index = ((2 * i + 1) * degree) & mask;
-                    *v = F::from_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) });
-                })
+            values.iter_mut().enumerate().for_each(|(k, v)| {
+                let index = ((2 * self.point(k) + 1) * degree) & mask;
+                *v = F::from_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) });
+            })
         } else if coeff == F::NEG_ONE {
-            values
-                .iter_mut()
-                .zip(&self.reverse_lsbs)
-                .for_each(|(v, &i)| {
-                    let index = ((2 * i + 1) * degree) & mask;
-                    *v = F::from_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) })
-                        .neg();
-                })
+            values.iter_mut().enumerate().for_each(|(k, v)| {
+                let index = ((2 * self.point(k) + 1) * degree) & mask;
+                *v = F::from_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) }).neg();
+            })
         } else {
-            values
-                .iter_mut()
-                .zip(&self.reverse_lsbs)
-                .for_each(|(v, &i)| {
-                    let index = ((2 * i + 1) * degree) & mask;
-                    *v = coeff.mul_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) });
-                })
+            values.iter_mut().enumerate().for_each(|(k, v)| {
+                let index = ((2 * self.point(k) + 1) * degree) & mask;
+                *v = coeff.mul_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) });
+            })
         }
     }
 
@@ -207,19 +435,16 @@ where
 
         let mask = usize::MAX >> (usize::BITS - log_n - 1);
 
-        values
-            .iter_mut()
-            .zip(&self.reverse_lsbs)
-            .for_each(|(v, &i)| {
-                let index = ((2 * i + 1) * degree) & mask;
-                *v = F::from_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) });
-            })
+        values.iter_mut().enumerate().for_each(|(k, v)| {
+            let index = ((2 * self.point(k) + 1) * degree) & mask;
+            *v = F::from_root(unsafe { *self.ordinal_root_powers.get_unchecked(index) });
+        })
     }
 }
 
 impl<F> AbstractNTT<F> for NTTTable<F>
 where
-    F: NTTField<Table = Self, Root = ShoupFactor<<F as Field>::Value>>,
+    F: NTTField<Table = Self, Root = ShoupFactor<<F as ModulusConfig>::Value>>,
 {
     #[inline]
     fn transform(&self, polynomial: &Polynomial<F>) -> NTTPolynomial<F> {
@@ -247,6 +472,12 @@ where
         let log_n = self.coeff_count_power();
 
         debug_assert_eq!(values.len(), 1 << log_n);
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_ntt_forward(values.len());
+
+        if self.variant == NTTVariant::Stockham {
+            return stockham::transform(self, values);
+        }
 
         let roots = self.root_powers();
         let mut root_iter = roots[1..].iter().copied();
@@ -255,7 +486,7 @@ where
             for vc in values.chunks_exact_mut(gap << 1) {
                 let root = root_iter.next().unwrap();
                 let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in std::iter::zip(v0, v1) {
+                for (i, j) in core::iter::zip(v0, v1) {
                     let u = guard(*i);
                     let v = mul_root_fast(*j, root);
                     *i = add_no_reduce(u, v);
@@ -271,6 +502,12 @@ where
         let log_n = self.coeff_count_power();
 
         debug_assert_eq!(values.len(), 1 << log_n);
+        #[cfg(feature = "tracing")]
+        crate::metrics::record_ntt_inverse(values.len());
+
+        if self.variant == NTTVariant::Stockham {
+            return stockham::inverse_transform(self, values);
+        }
 
         let roots = self.inv_root_powers();
         let mut root_iter = roots[1..].iter().copied();
@@ -279,7 +516,7 @@ where
             for vc in values.chunks_exact_mut(gap << 1) {
                 let root = root_iter.next().unwrap();
                 let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in std::iter::zip(v0, v1) {
+                for (i, j) in core::iter::zip(v0, v1) {
                     let u = *i;
                     let v = *j;
                     *i = add_fast(u, v);
@@ -296,7 +533,7 @@ where
             .mul_root(scalar)
             .to_root();
         let (v0, v1) = values.split_at_mut(gap);
-        for (i, j) in std::iter::zip(v0, v1) {
+        for (i, j) in core::iter::zip(v0, v1) {
             let u = *i;
             let v = *j;
             *i = mul_root_fast(add_no_reduce(u, v), scalar);
@@ -305,6 +542,31 @@ where
 
         values.iter_mut().for_each(intt_normalize_assign);
     }
+
+    /// The ntt values `y_k` are the evaluations at the roots `w_k` of `X^n + 1`,
+    /// so by the barycentric formula with `(X^n + 1)'(w_k) = -n / w_k`,
+    ///
+    /// `f(x) = -(x^n + 1) / n * sum(y_k * w_k / (x - w_k))`.
+    ///
+    /// The sum is accumulated as a single fraction, which costs one inversion.
+    fn evaluate_slice(&self, values: &[F], x: F) -> F {
+        debug_assert_eq!(values.len(), self.coeff_count);
+
+        let mut numerator = F::ZERO;
+        let mut denominator = F::ONE;
+        for (k, &y) in values.iter().enumerate() {
+            let w = F::from_root(self.ordinal_root_powers[2 * self.point(k) + 1]);
+            let d = x - w;
+            if d == F::ZERO {
+                return y;
+            }
+            numerator = numerator * d + y * w * denominator;
+            denominator *= d;
+        }
+
+        let x_n = (0..self.coeff_count_power).fold(x, |acc, _| acc * acc);
+        -(x_n + F::ONE) * F::from_root(self.inv_degree) * numerator / denominator
+    }
 }
 
 #[inline]
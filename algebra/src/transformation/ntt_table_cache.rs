@@ -0,0 +1,95 @@
+use alloc::sync::Arc;
+
+#[cfg(not(feature = "std"))]
+use spin::RwLock;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+use crate::AlgebraError;
+
+/// The largest `log_n` a [`NTTTableCache`] holds a table for.
+pub const MAX_CACHED_LOG_N: u32 = 32;
+
+type Slot<T> = RwLock<Option<Arc<T>>>;
+
+/// A thread-safe cache of ntt tables indexed by `log_n`, up to [`MAX_CACHED_LOG_N`].
+///
+/// The derived [`NTTField`](crate::NTTField) implementations keep one static cache per field.
+/// Every `log_n` has its own slot, so a lookup is an array index and an uncontended read lock,
+/// guarded by [`std::sync::RwLock`] with the `std` feature, and by a spin lock otherwise.
+#[derive(Debug)]
+pub struct NTTTableCache<T> {
+    slots: [Slot<T>; MAX_CACHED_LOG_N as usize + 1],
+}
+
+impl<T> NTTTableCache<T> {
+    /// Create an empty cache.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { RwLock::new(None) }; MAX_CACHED_LOG_N as usize + 1],
+        }
+    }
+
+    /// Return the cached table for `log_n`.
+    #[inline]
+    pub fn get(&self, log_n: u32) -> Option<Arc<T>> {
+        let slot = self.slots.get(log_n as usize)?;
+
+        #[cfg(feature = "std")]
+        let table = slot.read().unwrap();
+        #[cfg(not(feature = "std"))]
+        let table = slot.read();
+
+        table.clone()
+    }
+
+    /// Cache `table` for `log_n`, replacing the table cached before.
+    ///
+    /// Tables handed out before keep being valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_n` is larger than [`MAX_CACHED_LOG_N`].
+    pub fn insert(&self, log_n: u32, table: T) {
+        assert!(log_n <= MAX_CACHED_LOG_N, "log_n exceeds MAX_CACHED_LOG_N");
+        let slot = &self.slots[log_n as usize];
+
+        #[cfg(feature = "std")]
+        let mut cached = slot.write().unwrap();
+        #[cfg(not(feature = "std"))]
+        let mut cached = slot.write();
+
+        *cached = Some(Arc::new(table));
+    }
+
+    /// Generate and cache the tables for the `log_ns` which are not cached yet.
+    pub fn insert_with<G>(&self, log_ns: &[u32], mut generate: G) -> Result<(), AlgebraError>
+    where
+        G: FnMut(u32) -> Result<T, AlgebraError>,
+    {
+        for &log_n in log_ns {
+            let slot = self
+                .slots
+                .get(log_n as usize)
+                .ok_or(AlgebraError::NTTTableError)?;
+
+            #[cfg(feature = "std")]
+            let mut cached = slot.write().unwrap();
+            #[cfg(not(feature = "std"))]
+            let mut cached = slot.write();
+
+            if cached.is_none() {
+                *cached = Some(Arc::new(generate(log_n)?));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Default for NTTTableCache<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
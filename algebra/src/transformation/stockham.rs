@@ -0,0 +1,83 @@
+//! The Stockham auto-sort formulation of the negacyclic transforms.
+//!
+//! The inputs are twisted by the powers of the `2n`-th root `ψ`, which turns the negacyclic
+//! transform into a cyclic one with `ω = ψ^2`. Every stage then reads `x[q + s*p]` and
+//! `x[q + s*(p + n/2)]` and writes `y[q + s*2p]` and `y[q + s*(2p + 1)]` for the current
+//! size `n` and stride `s`, so the evaluations come out in natural order.
+
+use alloc::vec;
+
+use crate::NTTField;
+
+use super::NTTTable;
+
+/// Performs the forward transform, inputs and outputs in natural order.
+pub(super) fn transform<F>(table: &NTTTable<F>, values: &mut [F])
+where
+    F: NTTField<Table = NTTTable<F>>,
+{
+    let powers = table.ordinal_root_powers();
+
+    values
+        .iter_mut()
+        .zip(powers)
+        .for_each(|(v, &power)| v.mul_root_assign(power));
+
+    stages(values, |k| powers[2 * k]);
+}
+
+/// Performs the inverse transform, inputs and outputs in natural order.
+pub(super) fn inverse_transform<F>(table: &NTTTable<F>, values: &mut [F])
+where
+    F: NTTField<Table = NTTTable<F>>,
+{
+    let powers = table.ordinal_root_powers();
+    let order = powers.len();
+
+    stages(values, |k| powers[(order - 2 * k) % order]);
+
+    let inv_degree = table.inv_degree();
+    values.iter_mut().enumerate().for_each(|(j, v)| {
+        v.mul_root_assign(powers[(order - j) % order]);
+        v.mul_root_assign(inv_degree);
+    });
+}
+
+/// Runs the radix-2 stages of a cyclic transform, `twiddle(k)` returning the `k`-th power
+/// of the root of unity of order `values.len()`.
+fn stages<F, T>(values: &mut [F], twiddle: T)
+where
+    F: NTTField,
+    T: Fn(usize) -> F::Root,
+{
+    let mut scratch = vec![F::ZERO; values.len()];
+    let mut in_scratch = false;
+
+    {
+        let mut x = &mut *values;
+        let mut y = scratch.as_mut_slice();
+
+        let mut n = x.len();
+        let mut s = 1;
+        while n > 1 {
+            let m = n >> 1;
+            for p in 0..m {
+                let w = twiddle(p * s);
+                for q in 0..s {
+                    let a = x[q + s * p];
+                    let b = x[q + s * (p + m)];
+                    y[q + s * 2 * p] = a + b;
+                    y[q + s * (2 * p + 1)] = (a - b).mul_root(w);
+                }
+            }
+            core::mem::swap(&mut x, &mut y);
+            in_scratch = !in_scratch;
+            n = m;
+            s <<= 1;
+        }
+    }
+
+    if in_scratch {
+        values.copy_from_slice(&scratch);
+    }
+}
@@ -0,0 +1,224 @@
+//! Flat binary blobs of precomputed tables.
+//!
+//! A blob is a 48 bytes header followed by the sections of the table, each one starting
+//! at a multiple of 8 bytes. All integers are stored in the native byte order and width,
+//! so a blob is only accepted by a process of the same target and its sections can be
+//! reinterpreted in place, e.g. from a memory mapped file.
+//!
+//! ```plain
+//! |magic(4)|byte_order(2)|version(1)|kind(1)|value_size(1)|usize_size(1)|reserved(2)|
+//! |log_n(4)|modulus(8)|root(8)|inv_root(8)|reserved(8)|sections...|
+//! ```
+
+use alloc::vec::Vec;
+use core::mem::{align_of, size_of};
+use core::ops::Deref;
+
+use crate::modulus::ShoupFactor;
+use crate::AlgebraError;
+
+pub(super) const MAGIC: [u8; 4] = *b"ZLTB";
+pub(super) const BYTE_ORDER: u16 = 0x0102;
+pub(super) const VERSION: u8 = 1;
+pub(super) const HEADER_SIZE: usize = 48;
+pub(super) const SECTION_ALIGN: usize = 8;
+
+/// The kinds of tables stored in blobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(super) enum TableKind {
+    Ntt = 1,
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Plain integers which can be stored in a table blob.
+///
+/// Every bit pattern of these types is a valid value, so they can be read in place.
+/// The trait is sealed and implemented for `u8`, `u16`, `u32` and `u64`.
+pub trait BlobValue: Copy + sealed::Sealed {
+    /// Convert from the `u64` stored in the header.
+    fn from_u64(value: u64) -> Option<Self>;
+
+    /// Convert into the `u64` stored in the header.
+    fn into_u64(self) -> u64;
+}
+
+macro_rules! impl_blob_value {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+        impl BlobValue for $t {
+            #[inline]
+            fn from_u64(value: u64) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            #[inline]
+            fn into_u64(self) -> u64 {
+                self as u64
+            }
+        }
+    )*};
+}
+
+impl_blob_value!(u8, u16, u32, u64);
+
+impl sealed::Sealed for usize {}
+
+/// The storage of a table, either owned or borrowed from a blob living for the whole process.
+#[derive(Debug, Clone)]
+pub(super) enum TableData<T: 'static> {
+    Owned(Vec<T>),
+    Borrowed(&'static [T]),
+}
+
+impl<T> Deref for TableData<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        match self {
+            TableData::Owned(values) => values,
+            TableData::Borrowed(values) => values,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for TableData<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        TableData::Owned(values)
+    }
+}
+
+/// The fixed part of a blob header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Header {
+    pub(super) kind: TableKind,
+    pub(super) value_size: u8,
+    pub(super) log_n: u32,
+    pub(super) modulus: u64,
+    pub(super) root: u64,
+    pub(super) inv_root: u64,
+}
+
+impl Header {
+    pub(super) fn write(&self, blob: &mut Vec<u8>) {
+        blob.extend(MAGIC);
+        blob.extend(BYTE_ORDER.to_ne_bytes());
+        blob.push(VERSION);
+        blob.push(self.kind as u8);
+        blob.push(self.value_size);
+        blob.push(size_of::<usize>() as u8);
+        blob.extend([0; 2]);
+        blob.extend(self.log_n.to_ne_bytes());
+        blob.extend(self.modulus.to_ne_bytes());
+        blob.extend(self.root.to_ne_bytes());
+        blob.extend(self.inv_root.to_ne_bytes());
+        blob.extend([0; 8]);
+        debug_assert_eq!(blob.len(), HEADER_SIZE);
+    }
+
+    pub(super) fn read(blob: &[u8]) -> Result<Self, AlgebraError> {
+        let header = blob
+            .get(..HEADER_SIZE)
+            .ok_or(AlgebraError::TableBlobError)?;
+        let u32_at = |i: usize| u32::from_ne_bytes(header[i..i + 4].try_into().unwrap());
+        let u64_at = |i: usize| u64::from_ne_bytes(header[i..i + 8].try_into().unwrap());
+
+        if header[..4] != MAGIC
+            || header[4..6] != BYTE_ORDER.to_ne_bytes()
+            || header[6] != VERSION
+            || header[9] as usize != size_of::<usize>()
+        {
+            return Err(AlgebraError::TableBlobError);
+        }
+        let kind = match header[7] {
+            1 => TableKind::Ntt,
+            _ => return Err(AlgebraError::TableBlobError),
+        };
+
+        Ok(Self {
+            kind,
+            value_size: header[8],
+            log_n: u32_at(12),
+            modulus: u64_at(16),
+            root: u64_at(24),
+            inv_root: u64_at(32),
+        })
+    }
+}
+
+/// Append `values` as a section, padded to the section alignment.
+pub(super) fn write_section<T: Copy + sealed::Sealed>(blob: &mut Vec<u8>, values: &[T]) {
+    // SAFETY: the sealed types have no padding and are plain integers.
+    let bytes = unsafe {
+        core::slice::from_raw_parts(values.as_ptr().cast::<u8>(), core::mem::size_of_val(values))
+    };
+    blob.extend_from_slice(bytes);
+    blob.resize(blob.len().next_multiple_of(SECTION_ALIGN), 0);
+}
+
+/// Append `factors` as a section of interleaved values and quotients.
+pub(super) fn write_factor_section<T: BlobValue>(blob: &mut Vec<u8>, factors: &[ShoupFactor<T>]) {
+    // SAFETY: `ShoupFactor` is `repr(C)` with two fields of the same integer type.
+    let values =
+        unsafe { core::slice::from_raw_parts(factors.as_ptr().cast::<T>(), factors.len() * 2) };
+    write_section(blob, values);
+}
+
+/// Reads sections from a blob, checking bounds and alignment.
+pub(super) struct SectionReader {
+    blob: &'static [u8],
+    offset: usize,
+}
+
+impl SectionReader {
+    #[inline]
+    pub(super) fn new(blob: &'static [u8]) -> Self {
+        Self {
+            blob,
+            offset: HEADER_SIZE,
+        }
+    }
+
+    /// Borrow the next section of `len` values.
+    pub(super) fn section<T: Copy + sealed::Sealed>(
+        &mut self,
+        len: usize,
+    ) -> Result<&'static [T], AlgebraError> {
+        let size = len
+            .checked_mul(size_of::<T>())
+            .ok_or(AlgebraError::TableBlobError)?;
+        let bytes = self
+            .blob
+            .get(self.offset..)
+            .and_then(|rest| rest.get(..size))
+            .ok_or(AlgebraError::TableBlobError)?;
+        if bytes.as_ptr().align_offset(align_of::<T>()) != 0 {
+            return Err(AlgebraError::TableBlobError);
+        }
+        self.offset = (self.offset + size).next_multiple_of(SECTION_ALIGN);
+        // SAFETY: the bytes are in bounds and aligned, and every bit pattern is a valid `T`.
+        Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<T>(), len) })
+    }
+
+    /// Borrow the next section of `len` interleaved values and quotients.
+    pub(super) fn factor_section<T: BlobValue>(
+        &mut self,
+        len: usize,
+    ) -> Result<&'static [ShoupFactor<T>], AlgebraError> {
+        let values = self.section::<T>(len.checked_mul(2).ok_or(AlgebraError::TableBlobError)?)?;
+        // SAFETY: `ShoupFactor` is `repr(C)` with two fields of the same integer type,
+        // so it has the alignment of `T` and the size of two of them.
+        Ok(unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), len) })
+    }
+
+    /// Return `true` if the whole blob has been read.
+    #[inline]
+    pub(super) fn is_finished(&self) -> bool {
+        self.offset == self.blob.len()
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+
+use crate::modulus::ShoupFactor;
+use crate::{ModulusConfig, NTTField};
+
+use super::{AbstractNTT, NTTTable};
+
+/// Weights `τ_j` applied to the coefficients before a transform and removed after
+/// the inverse transform.
+///
+/// The transforms of an [`NTTTable`] evaluate at the roots `ψ·ω^i` of `x^n + 1`.
+/// Weighting the `j`-th coefficient by `(α·ψ^(-1))^j` moves the evaluation points to `α·ω^i`,
+/// the roots of `x^n - α^n`, so pointwise products become convolutions modulo `x^n - α^n`,
+/// see [`TwistFactors::for_root`]. Arbitrary nonzero weights are accepted as well.
+#[derive(Debug, Clone)]
+pub struct TwistFactors<F: NTTField> {
+    forward: Vec<F::Root>,
+    inverse: Vec<F::Root>,
+}
+
+impl<F: NTTField> TwistFactors<F> {
+    /// Creates the [`TwistFactors`] with the given nonzero weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is zero.
+    pub fn new(weights: &[F]) -> Self {
+        assert!(weights.iter().all(|&w| w != F::ZERO));
+
+        // batch inversion with a single field inversion
+        let mut prefix = Vec::with_capacity(weights.len());
+        let mut acc = F::ONE;
+        for &w in weights {
+            prefix.push(acc);
+            acc *= w;
+        }
+        let mut acc_inv = acc.inv();
+        let mut inverse = Vec::with_capacity(weights.len());
+        for (&w, &p) in weights.iter().zip(&prefix).rev() {
+            inverse.push((acc_inv * p).to_root());
+            acc_inv *= w;
+        }
+        inverse.reverse();
+
+        Self {
+            forward: weights.iter().map(|w| w.to_root()).collect(),
+            inverse,
+        }
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.forward.len()
+    }
+
+    /// Returns `true` if there are no weights.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.forward.is_empty()
+    }
+
+    /// Returns the weights.
+    #[inline]
+    pub fn forward(&self) -> &[F::Root] {
+        &self.forward
+    }
+
+    /// Returns the inverses of the weights.
+    #[inline]
+    pub fn inverse(&self) -> &[F::Root] {
+        &self.inverse
+    }
+}
+
+impl<F> TwistFactors<F>
+where
+    F: NTTField<Table = NTTTable<F>>,
+{
+    /// Creates the [`TwistFactors`] turning the transforms of `table` into evaluations
+    /// at the roots `alpha·ω^i` of `x^n - alpha^n`, for a nonzero `alpha`.
+    pub fn for_root(table: &NTTTable<F>, alpha: F) -> Self {
+        let ratio = alpha * table.inv_root();
+        let weights: Vec<F> = core::iter::successors(Some(F::ONE), |&w| Some(w * ratio))
+            .take(table.coeff_count())
+            .collect();
+        Self::new(&weights)
+    }
+}
+
+impl<F> NTTTable<F>
+where
+    F: NTTField<Table = Self, Root = ShoupFactor<<F as ModulusConfig>::Value>>,
+{
+    /// Performs the transform of `values` weighted by `twist`.
+    pub fn twisted_transform_slice(&self, twist: &TwistFactors<F>, values: &mut [F]) {
+        debug_assert_eq!(twist.len(), values.len());
+        values
+            .iter_mut()
+            .zip(twist.forward())
+            .for_each(|(v, &w)| v.mul_root_assign(w));
+        self.transform_slice(values);
+    }
+
+    /// Performs the inverse transform of `values` and removes the weights of `twist`.
+    pub fn twisted_inverse_transform_slice(&self, twist: &TwistFactors<F>, values: &mut [F]) {
+        debug_assert_eq!(twist.len(), values.len());
+        self.inverse_transform_slice(values);
+        values
+            .iter_mut()
+            .zip(twist.inverse())
+            .for_each(|(v, &w)| v.mul_root_assign(w));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::Field;
+
+/// The minimal length from which [`dot_product`] is split across threads.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 14;
+
+/// Calculates the inner product `sum(a[i] * b[i])` of two slices of the same length.
+///
+/// The products are accumulated in double width and reduced lazily, see
+/// [`Field::dot_product`]. With the `parallel` feature, long slices are split across threads.
+///
+/// ```
+/// # use algebra::{derive::{Field, Prime}, utils::dot_product, Field};
+/// #[derive(Field, Prime)]
+/// #[modulus = 132120577]
+/// pub struct Fp32(u32);
+///
+/// let a = [Fp32::new(1), Fp32::new(2), Fp32::new(3)];
+/// let b = [Fp32::new(4), Fp32::new(5), Fp32::new(6)];
+/// assert_eq!(dot_product(&a, &b), Fp32::new(32));
+/// ```
+#[inline]
+pub fn dot_product<F: Field>(a: &[F], b: &[F]) -> F {
+    debug_assert_eq!(a.len(), b.len());
+
+    #[cfg(feature = "parallel")]
+    if a.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        return a
+            .par_chunks(PARALLEL_THRESHOLD)
+            .zip(b.par_chunks(PARALLEL_THRESHOLD))
+            .map(|(a, b)| F::dot_product(a, b))
+            .reduce(|| F::ZERO, |x, y| x + y);
+    }
+
+    F::dot_product(a, b)
+}
@@ -132,6 +132,7 @@ impl_extended_gcd!(impl ExtendedGCD for u8; SignedType: i8);
 impl_extended_gcd!(impl ExtendedGCD for u16; SignedType: i16);
 impl_extended_gcd!(impl ExtendedGCD for u32; SignedType: i32);
 impl_extended_gcd!(impl ExtendedGCD for u64; SignedType: i64);
+impl_extended_gcd!(impl ExtendedGCD for u128; SignedType: i128);
 
 #[cfg(test)]
 mod tests {
@@ -1,11 +1,15 @@
 //! Implemention of some number theory operation.
 
+mod dot;
 mod gcd;
+#[cfg(feature = "std")]
 mod prime;
 mod reverse;
 mod sample;
 
+pub use dot::*;
 pub use gcd::*;
+#[cfg(feature = "std")]
 pub use prime::*;
 pub use reverse::*;
 pub use sample::*;
@@ -107,6 +107,8 @@ macro_rules! impl_prime_check {
     };
 }
 
+impl_prime_check!(impl Prime for BarrettModulus<u128>);
+
 impl_prime_check!(impl Prime for BarrettModulus<u64>);
 
 impl_prime_check!(impl Prime for BarrettModulus<u32>);
@@ -6,7 +6,9 @@ pub trait ReverseLsbs {
     ///
     /// # Examples
     ///
-    /// ```ignore
+    /// ```
+    /// use algebra::utils::ReverseLsbs;
+    ///
     /// assert_eq!(0b00001101u8.reverse_lsbs(4), 0b00001011u8);
     /// assert_eq!(0b01101101u8.reverse_lsbs(4), 0b00001011u8);
     /// ```
@@ -18,7 +20,7 @@ macro_rules! impl_reverse_lsbs_for_unsigned {
         $(impl ReverseLsbs for $T {
             #[inline]
             fn reverse_lsbs(self, bits: u32) -> Self {
-                debug_assert!(bits < Self::BITS);
+                debug_assert!(bits <= Self::BITS);
                 if self == 0 || bits == 0 {
                     0
                 } else {
@@ -30,3 +32,33 @@ macro_rules! impl_reverse_lsbs_for_unsigned {
 }
 
 impl_reverse_lsbs_for_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Permutes `values` from normal order into bit-reversed order, or back.
+///
+/// The element at index `i` is swapped with the one at `i.reverse_lsbs(log_n)`,
+/// which converts between the input order and the output order of
+/// [`AbstractNTT::transform`](crate::transformation::AbstractNTT::transform).
+///
+/// # Panics
+///
+/// Panics if the length of `values` is not `2^log_n`.
+///
+/// # Examples
+///
+/// ```
+/// use algebra::utils::bit_reverse_permute;
+///
+/// let mut values = [0, 1, 2, 3, 4, 5, 6, 7];
+/// bit_reverse_permute(&mut values, 3);
+/// assert_eq!(values, [0, 4, 2, 6, 1, 5, 3, 7]);
+/// ```
+pub fn bit_reverse_permute<T>(values: &mut [T], log_n: u32) {
+    assert_eq!(values.len(), 1 << log_n);
+
+    for i in 0..values.len() {
+        let j = i.reverse_lsbs(log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
@@ -1,44 +1,134 @@
+use alloc::{vec, vec::Vec};
+
 use num_traits::NumCast;
-use rand::{CryptoRng, Rng};
+use rand::Rng;
+use rand_distr::Distribution;
+
+use crate::transformation::AbstractNTT;
+use crate::{CsRng, Field, FieldDiscreteGaussianSampler, Matrix, NTTField, NTTPolynomial, Random};
+
+/// Sample a uniformly random vector whose values are [`Field`] `F`.
+pub fn sample_uniform_field_vec<F, R>(length: usize, rng: &mut R) -> Vec<F>
+where
+    F: Field + Random,
+    R: CsRng,
+{
+    F::standard_distribution()
+        .sample_iter(rng)
+        .take(length)
+        .collect()
+}
+
+/// Sample a uniformly random `rows × cols` [`Matrix`], such as the public matrix `A` of LWE.
+#[inline]
+pub fn sample_uniform_matrix<F, R>(rows: usize, cols: usize, rng: &mut R) -> Matrix<F>
+where
+    F: Field + Random,
+    R: CsRng,
+{
+    Matrix::new(rows, cols, sample_uniform_field_vec(rows * cols, rng))
+}
 
-use crate::Field;
+/// Sample a uniformly random nonzero element of [`Field`] `F`, invertible as the modulus is prime.
+pub fn sample_nonzero_field<F, R>(rng: &mut R) -> F
+where
+    F: Field + Random,
+    R: CsRng,
+{
+    let distr = F::standard_distribution();
+    loop {
+        let x = distr.sample(rng);
+        if x != F::ZERO {
+            return x;
+        }
+    }
+}
+
+/// Sample a vector of uniformly random nonzero elements of [`Field`] `F`.
+pub fn sample_nonzero_field_vec<F, R>(length: usize, rng: &mut R) -> Vec<F>
+where
+    F: Field + Random,
+    R: CsRng,
+{
+    (0..length).map(|_| sample_nonzero_field(rng)).collect()
+}
+
+/// Sample a vector of `length` distinct uniformly random nonzero elements of [`Field`] `F`,
+/// such as the indices of the parties or the evaluation points of a secret sharing.
+///
+/// # Panics
+///
+/// Panics if `length` is larger than the number of nonzero elements.
+pub fn sample_distinct_nonzero_field_vec<F, R>(length: usize, rng: &mut R) -> Vec<F>
+where
+    F: Field + Random,
+    R: CsRng,
+{
+    assert!(
+        length <= F::NEG_ONE.cast_into_usize(),
+        "length exceeds the number of nonzero elements"
+    );
+    let mut v = Vec::with_capacity(length);
+    while v.len() < length {
+        let x = sample_nonzero_field(rng);
+        if !v.contains(&x) {
+            v.push(x);
+        }
+    }
+    v
+}
 
 /// Sample a binary vector whose values are [`Field`] `F`.
 pub fn sample_binary_field_vec<F, R>(length: usize, rng: &mut R) -> Vec<F>
 where
     F: Field,
-    R: Rng + CryptoRng,
+    R: CsRng,
 {
     let mut v = vec![F::ZERO; length];
-    let mut iter = v.chunks_exact_mut(32);
+    sample_binary_field_slice(&mut v, rng);
+    v
+}
+
+/// Overwrite `values` with a binary vector whose values are [`Field`] `F`.
+pub fn sample_binary_field_slice<F, R>(values: &mut [F], rng: &mut R)
+where
+    F: Field,
+    R: CsRng,
+{
+    let mut iter = values.chunks_exact_mut(32);
     for chunk in &mut iter {
         let mut r = rng.next_u32();
         for elem in chunk.iter_mut() {
-            if r & 0b1 == 1 {
-                *elem = F::ONE;
-            }
+            *elem = if r & 0b1 == 1 { F::ONE } else { F::ZERO };
             r >>= 1;
         }
     }
     let mut r = rng.next_u32();
     for elem in iter.into_remainder() {
-        if r & 0b1 == 1 {
-            *elem = F::ONE;
-        }
+        *elem = if r & 0b1 == 1 { F::ONE } else { F::ZERO };
         r >>= 1;
     }
-    v
 }
 
 /// Sample a ternary vector whose values are [`Field`] `F`.
 pub fn sample_ternary_field_vec<F, R>(length: usize, rng: &mut R) -> Vec<F>
 where
     F: Field,
-    R: Rng + CryptoRng,
+    R: CsRng,
 {
-    let s = [F::ZERO, F::ZERO, F::ONE, F::NEG_ONE];
     let mut v = vec![F::ZERO; length];
-    let mut iter = v.chunks_exact_mut(16);
+    sample_ternary_field_slice(&mut v, rng);
+    v
+}
+
+/// Overwrite `values` with a ternary vector whose values are [`Field`] `F`.
+pub fn sample_ternary_field_slice<F, R>(values: &mut [F], rng: &mut R)
+where
+    F: Field,
+    R: CsRng,
+{
+    let s = [F::ZERO, F::ZERO, F::ONE, F::NEG_ONE];
+    let mut iter = values.chunks_exact_mut(16);
     for chunk in &mut iter {
         let mut r = rng.next_u32();
         for elem in chunk.iter_mut() {
@@ -51,14 +141,73 @@ where
         *elem = s[(r & 0b11) as usize];
         r >>= 2;
     }
+}
+
+/// Sample a ternary vector with exactly `weight` nonzero values, each `1` or `-1` with
+/// equal probability, whose values are [`Field`] `F`.
+///
+/// # Panics
+///
+/// Panics if `weight` is larger than `length`.
+pub fn sample_ternary_field_vec_with_weight<F, R>(
+    length: usize,
+    weight: usize,
+    rng: &mut R,
+) -> Vec<F>
+where
+    F: Field,
+    R: CsRng,
+{
+    assert!(weight <= length);
+    let mut v = vec![F::ZERO; length];
+    // the last `weight` steps of a Fisher-Yates shuffle pick the nonzero positions
+    let mut positions: Vec<usize> = (0..length).collect();
+    for i in (length - weight..length).rev() {
+        let j = rng.gen_range(0..=i);
+        positions.swap(i, j);
+        v[positions[i]] = if rng.gen::<bool>() {
+            F::ONE
+        } else {
+            F::NEG_ONE
+        };
+    }
     v
 }
 
+/// Sample a discrete gaussian vector whose values are [`Field`] `F`, such as the error of LWE.
+pub fn sample_gaussian_field_vec<F, R>(
+    length: usize,
+    gaussian: FieldDiscreteGaussianSampler,
+    rng: &mut R,
+) -> Vec<F>
+where
+    F: Field,
+    R: CsRng,
+    FieldDiscreteGaussianSampler: Distribution<F>,
+{
+    if gaussian.cbd_enable() {
+        sample_cbd_field_vec(length, rng)
+    } else {
+        gaussian.sample_iter(rng).take(length).collect()
+    }
+}
+
 /// Sample a centered binomial distribution vector whose values are [`Field`] `F`.
 pub fn sample_cbd_field_vec<F, R>(length: usize, rng: &mut R) -> Vec<F>
 where
     F: Field,
-    R: Rng + CryptoRng,
+    R: CsRng,
+{
+    let mut v = vec![F::ZERO; length];
+    sample_cbd_field_slice(&mut v, rng);
+    v
+}
+
+/// Overwrite `values` with a centered binomial distribution vector whose values are [`Field`] `F`.
+pub fn sample_cbd_field_slice<F, R>(values: &mut [F], rng: &mut R)
+where
+    F: Field,
+    R: CsRng,
 {
     let modulus = F::modulus_value();
     let mut cbd = || {
@@ -75,5 +224,56 @@ where
         }
     };
 
-    (0..length).map(|_| cbd()).collect()
+    values.iter_mut().for_each(|v| *v = cbd());
+}
+
+/// Sample a ternary polynomial with `n` coefficients and return its transform by `table`,
+/// such as the secret of keygen or the randomness of encryption.
+pub fn sample_ternary_ntt_poly<F, R>(n: usize, table: &F::Table, rng: &mut R) -> NTTPolynomial<F>
+where
+    F: NTTField,
+    R: CsRng,
+{
+    let mut poly = NTTPolynomial::zero(n);
+    sample_ternary_ntt_poly_into(&mut poly, table, rng);
+    poly
+}
+
+/// Overwrite `poly` with the transform by `table` of a ternary polynomial, reusing its allocation.
+///
+/// The coefficients are sampled into the buffer and transformed in place,
+/// without the intermediate native polynomial.
+pub fn sample_ternary_ntt_poly_into<F, R>(
+    poly: &mut NTTPolynomial<F>,
+    table: &F::Table,
+    rng: &mut R,
+) where
+    F: NTTField,
+    R: CsRng,
+{
+    sample_ternary_field_slice(poly.as_mut_slice(), rng);
+    table.transform_slice(poly.as_mut_slice());
+}
+
+/// Sample a centered binomial distribution polynomial with `n` coefficients
+/// and return its transform by `table`, such as the noise of keygen.
+pub fn sample_cbd_ntt_poly<F, R>(n: usize, table: &F::Table, rng: &mut R) -> NTTPolynomial<F>
+where
+    F: NTTField,
+    R: CsRng,
+{
+    let mut poly = NTTPolynomial::zero(n);
+    sample_cbd_ntt_poly_into(&mut poly, table, rng);
+    poly
+}
+
+/// Overwrite `poly` with the transform by `table` of a centered binomial distribution polynomial,
+/// reusing its allocation.
+pub fn sample_cbd_ntt_poly_into<F, R>(poly: &mut NTTPolynomial<F>, table: &F::Table, rng: &mut R)
+where
+    F: NTTField,
+    R: CsRng,
+{
+    sample_cbd_field_slice(poly.as_mut_slice(), rng);
+    table.transform_slice(poly.as_mut_slice());
 }
@@ -0,0 +1,42 @@
+#![cfg(feature = "test-utils")]
+
+use algebra::{
+    derive::{Field, Prime},
+    test_utils::{
+        arbitrary::{Arbitrary, Unstructured},
+        polynomial_strategy,
+        proptest::prelude::*,
+    },
+    Field, Polynomial,
+};
+
+#[derive(Field, Prime)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+#[test]
+fn test_arbitrary() {
+    let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&bytes);
+
+    for _ in 0..100 {
+        let a = Fp32::arbitrary(&mut u).unwrap();
+        assert!(a.get() < Fp32::modulus_value());
+    }
+    let poly = Polynomial::<Fp32>::arbitrary(&mut u).unwrap();
+    assert!(poly.iter().all(|c| c.get() < Fp32::modulus_value()));
+}
+
+proptest! {
+    #[test]
+    fn test_field_strategy(a: Fp32, b: Fp32) {
+        prop_assert!(a.get() < Fp32::modulus_value());
+        prop_assert_eq!((a + b) - b, a);
+    }
+
+    #[test]
+    fn test_polynomial_strategy(poly in polynomial_strategy::<Fp32>(8)) {
+        prop_assert_eq!(poly.coeff_count(), 8);
+        prop_assert_eq!(-(-poly.clone()), poly);
+    }
+}
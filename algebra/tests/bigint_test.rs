@@ -0,0 +1,49 @@
+#![cfg(feature = "bigint")]
+
+use algebra::{
+    bigint::BigUint,
+    derive::{Field, Prime, Random},
+    Field, NTTPolynomial, Polynomial,
+};
+use num_traits::Pow;
+use rand::thread_rng;
+
+#[derive(Field, Random, Prime)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 1267650600228229401496703205361]
+pub struct Fp100(u128);
+
+#[test]
+fn test_field_biguint() {
+    let mut rng = thread_rng();
+
+    let a = Fp32::random(&mut rng);
+    assert_eq!(a.to_biguint(), BigUint::from(a.get()));
+    assert_eq!(Fp32::from(&a.to_biguint()), a);
+
+    let a = Fp100::random(&mut rng);
+    assert_eq!(Fp100::from(&a.to_biguint()), a);
+
+    // reduced modulo the modulus
+    let q = BigUint::from(132120577u32);
+    assert_eq!(Fp32::from(&(&q * 3u32 + 5u32)), Fp32::new(5));
+    let large = BigUint::from(2u32).pow(300u32) - 1u32;
+    assert_eq!(Fp32::from(&large).to_biguint(), large % q,);
+}
+
+#[test]
+fn test_polynomial_biguint() {
+    let mut rng = thread_rng();
+
+    let poly = Polynomial::<Fp100>::random(8, &mut rng);
+    let coeffs = poly.to_biguints();
+    assert_eq!(Polynomial::<Fp100>::from(coeffs.as_slice()), poly);
+
+    let ntt_poly = NTTPolynomial::<Fp32>::random(8, &mut rng);
+    let values = ntt_poly.to_biguints();
+    assert_eq!(values[3], ntt_poly[3].to_biguint());
+    assert_eq!(NTTPolynomial::<Fp32>::from(values.as_slice()), ntt_poly);
+}
@@ -4,6 +4,18 @@ use algebra::derive::{Field, Prime, Random, NTT};
 #[modulus = 132120577]
 pub struct Fp32(u32);
 
+#[derive(Field, Random, Prime)]
+#[modulus = 61]
+pub struct Fp16(u16);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 1073479681]
+pub struct Fp30(u32);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 1267650600228229401496703205361]
+pub struct Fp100(u128);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,9 +35,46 @@ mod tests {
     type T = u32;
     type W = u64;
 
+    /// `round(p * x / q) mod p` with `x` lifted to `(-q/2, q/2]`.
+    fn centered_scale_round<S: Field, D: Field>(x: S) -> D {
+        let q = x.cast_into_usize() as u128;
+        let q_modulus = S::NEG_ONE.cast_into_usize() as u128 + 1;
+        let p = D::NEG_ONE.cast_into_usize() as u128 + 1;
+        if q > q_modulus / 2 {
+            let rounded = (p * (q_modulus - q) + q_modulus / 2) / q_modulus;
+            D::cast_from_usize(((p - rounded) % p) as usize)
+        } else {
+            D::cast_from_usize(((p * q + q_modulus / 2) / q_modulus % p) as usize)
+        }
+    }
+
+    #[test]
+    fn test_scale_round() {
+        let mut rng = thread_rng();
+        for v in 0..61 {
+            let x = Fp16::new(v);
+            assert_eq!(x.scale_round::<FF>(), centered_scale_round::<_, FF>(x));
+            assert_eq!(x.scale_round::<Fp30>(), centered_scale_round::<_, Fp30>(x));
+        }
+        for _ in 0..1000 {
+            let x = FF::random(&mut rng);
+            assert_eq!(x.scale_round::<Fp16>(), centered_scale_round::<_, Fp16>(x));
+            assert_eq!(x.scale_round::<Fp30>(), centered_scale_round::<_, Fp30>(x));
+            let y = Fp30::random(&mut rng);
+            assert_eq!(y.scale_round::<FF>(), centered_scale_round::<_, FF>(y));
+        }
+        assert_eq!(FF::NEG_ONE.scale_round::<Fp16>(), Fp16::ZERO);
+        assert_eq!(
+            Fp16::ONE.scale_round::<FF>().scale_round::<Fp16>(),
+            Fp16::ONE
+        );
+    }
+
     #[test]
     fn test_fp() {
         let p = FF::MODULUS.value();
+        assert_eq!(FF::MODULUS_VALUE, p);
+        assert_eq!(FF::MODULUS_BITS, FF::MODULUS.bit_count());
 
         let distr = Uniform::new(0, p);
         let mut rng = thread_rng();
@@ -125,6 +174,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_standard_distribution() {
+        use algebra::Random;
+
+        let rng = &mut thread_rng();
+
+        // every value of the 6-bit `Fp16` is drawn, and none of `[61, 64)`
+        let mut hits = [0usize; 64];
+        for _ in 0..64 * 200 {
+            let a: Fp16 = rng.sample(Fp16::standard_distribution());
+            hits[a.get() as usize] += 1;
+        }
+        assert!(hits[..61].iter().all(|&n| n > 0));
+        assert!(hits[61..].iter().all(|&n| n == 0));
+
+        for _ in 0..1000 {
+            let a: FF = rng.sample(FF::standard_distribution());
+            assert!(a.get() < FF::MODULUS_VALUE);
+        }
+    }
+
     #[test]
     fn test_decompose() {
         const BITS: u32 = 2;
@@ -143,4 +213,149 @@ mod tests {
 
         assert_eq!(compose, a);
     }
+
+    #[test]
+    fn test_decompose_mixed() {
+        let rng = &mut thread_rng();
+
+        // 27 bits in total for the 27-bit modulus
+        let basis = <Basis<Fp32>>::mixed(&[3, 4, 8, 12]);
+        assert_eq!(basis.decompose_len(), 4);
+        assert!(!basis.is_uniform());
+        assert_eq!(basis.level_shift(3), 15);
+        assert_eq!(basis.level_mask(2), 0xff);
+
+        let a: FF = rng.gen();
+        let decompose = a.decompose(basis);
+        for (i, d) in decompose.iter().enumerate() {
+            assert!(d.get() <= basis.level_mask(i));
+        }
+        assert_eq!(basis.recompose(&decompose), a);
+
+        let mut destination = vec![FF::new(0); basis.decompose_len()];
+        a.decompose_at(basis, &mut destination);
+        assert_eq!(destination, decompose);
+
+        assert!(<Basis<Fp32>>::new(3).is_uniform());
+    }
+
+    #[test]
+    fn test_primitive_roots() {
+        use algebra::NTTField;
+
+        const DEGREE: T = 16;
+        let roots = FF::primitive_roots(DEGREE).unwrap();
+        assert_eq!(roots.len(), DEGREE as usize / 2);
+
+        for (i, &root) in roots.iter().enumerate() {
+            assert!(FF::is_primitive_root(root, DEGREE));
+            assert!(roots[..i].iter().all(|&other| other != root));
+        }
+        let minimal = FF::try_minimal_primitive_root(DEGREE).unwrap();
+        assert_eq!(roots.iter().min(), Some(&minimal));
+
+        assert!(FF::primitive_roots(1 << 30).is_err());
+    }
+
+    #[test]
+    fn test_canonical_ord() {
+        use algebra::CanonicalOrd;
+        use core::cmp::Ordering;
+
+        fn sort_canonical<F: CanonicalOrd>(values: &mut [F]) {
+            values.sort_by(F::canonical_cmp);
+        }
+
+        let mut values = vec![FF::NEG_ONE, FF::new(3), FF::ZERO, FF::new(2)];
+        sort_canonical(&mut values);
+        assert_eq!(values, [FF::ZERO, FF::new(2), FF::new(3), FF::NEG_ONE]);
+
+        assert_eq!(FF::ZERO.canonical_cmp(&FF::NEG_ONE), Ordering::Less);
+        assert_eq!(FF::ONE.canonical_max(FF::NEG_ONE), FF::NEG_ONE);
+        assert_eq!(FF::ONE.canonical_min(FF::NEG_ONE), FF::ONE);
+    }
+
+    #[test]
+    fn test_pow_u64() {
+        use num_traits::Pow;
+
+        let mut rng = thread_rng();
+        let a = FF::new(rng.gen_range(0..FF::MODULUS.value()));
+
+        assert_eq!(a.pow_u64(0), FF::ONE);
+        assert_eq!(a.pow_u64(1), a);
+        assert_eq!(a.pow_u64(5), a * a * a * a * a);
+        for _ in 0..16 {
+            let exp: T = rng.gen();
+            assert_eq!(a.pow_u64(exp as u64), a.pow(exp));
+        }
+        // Fermat's little theorem, with an exponent wider than the order type
+        let p = FF::MODULUS.value() as u64;
+        assert_eq!(a.pow_u64(p * p), a);
+
+        assert_eq!(a.square_n(0), a);
+        assert_eq!(a.square_n(4), a.pow_u64(16));
+    }
+
+    #[test]
+    fn test_u128_field() {
+        use num_traits::Pow;
+
+        const P: u128 = (1 << 100) - 15;
+
+        // `a * b mod P` by double-and-add, as the product doesn't fit in `u128`
+        fn mul_mod(a: u128, b: u128) -> u128 {
+            (0..100).rev().fold(0, |acc, i| {
+                let acc = (acc << 1) % P;
+                if (b >> i) & 1 == 1 {
+                    (acc + a) % P
+                } else {
+                    acc
+                }
+            })
+        }
+
+        assert_eq!(Fp100::MODULUS_VALUE, P);
+        assert_eq!(Fp100::MODULUS_BITS, 100);
+        assert!(Fp100::is_prime_field());
+
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let a = Fp100::random(&mut rng);
+            let b = Fp100::random(&mut rng);
+            let (x, y) = (a.get(), b.get());
+            assert!(x < P && y < P);
+
+            assert_eq!((a + b).get(), (x + y) % P);
+            assert_eq!((a - b).get(), (x + P - y) % P);
+            assert_eq!((-a).get(), (P - x) % P);
+            assert_eq!((a * b).get(), mul_mod(x, y));
+            assert_eq!(a.mul_fast(b).normalize(), a * b);
+            assert_eq!(Fp100::ONE.add_mul(a, b), a * b + Fp100::ONE);
+            assert_eq!(Fp100::dot_product(&[a, b], &[b, a]), a * b + b * a);
+            if a != Fp100::ZERO {
+                assert_eq!(a * a.inv(), Fp100::ONE);
+                assert_eq!(a.pow(P - 1), Fp100::ONE);
+            }
+        }
+        assert_eq!(Fp100::checked_new(P + 1), Fp100::ONE);
+        assert_eq!(Fp100::cast_from_usize(usize::MAX).get(), usize::MAX as u128);
+
+        for v in 0..61 {
+            let x = Fp16::new(v);
+            let expected = if v > 30 {
+                (P - (P * (61 - v as u128) + 30) / 61) % P
+            } else {
+                (P * v as u128 + 30) / 61
+            };
+            assert_eq!(x.scale_round::<Fp100>().get(), expected);
+        }
+        assert_eq!(Fp100::NEG_ONE.scale_round::<Fp16>(), Fp16::ZERO);
+        assert_eq!(
+            Fp16::ONE.scale_round::<Fp100>().scale_round::<Fp16>(),
+            Fp16::ONE
+        );
+        let x = FF::random(&mut rng);
+        assert_eq!(x.scale_round::<Fp100>().scale_round::<FF>(), x);
+    }
 }
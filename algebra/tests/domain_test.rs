@@ -0,0 +1,67 @@
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    transformation::EvaluationDomain,
+    Field, Polynomial,
+};
+use rand::thread_rng;
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+type FF = Fp32;
+
+const LOG_N: u32 = 4;
+const N: usize = 1 << LOG_N;
+
+#[test]
+fn test_domain_elements() {
+    let domain = EvaluationDomain::<FF>::new(LOG_N).unwrap();
+    let elements: Vec<FF> = domain.elements().collect();
+
+    assert_eq!(elements.len(), N);
+    assert_eq!(elements[0], FF::ONE);
+    assert_eq!(domain.element(N), FF::ONE);
+    assert_eq!(domain.generator() * domain.generator_inv(), FF::ONE);
+    assert_eq!(domain.size_inv() * FF::new(N as u32), FF::ONE);
+    for (i, &x) in elements.iter().enumerate() {
+        assert_eq!(domain.element(i), x);
+        assert!(elements[..i].iter().all(|&y| y != x));
+        assert_eq!(domain.evaluate_vanishing_polynomial(x), FF::ZERO);
+    }
+
+    let coset = domain.coset(FF::new(7));
+    for x in coset.elements() {
+        assert_eq!(coset.evaluate_vanishing_polynomial(x), FF::ZERO);
+        assert_ne!(domain.evaluate_vanishing_polynomial(x), FF::ZERO);
+    }
+
+    assert!(EvaluationDomain::<FF>::new(0).is_err());
+}
+
+#[test]
+fn test_domain_fft() {
+    let mut rng = thread_rng();
+    let subgroup = EvaluationDomain::<FF>::new(LOG_N).unwrap();
+    let domain = subgroup.coset(FF::new(5));
+
+    let poly = Polynomial::<FF>::random(N, &mut rng);
+
+    let evaluations = domain.fft(&poly);
+    for (i, &e) in evaluations.iter().enumerate() {
+        assert_eq!(e, poly.evaluate(subgroup.element(i)));
+    }
+    assert_eq!(domain.ifft(&evaluations), poly);
+
+    let coset_evaluations = domain.coset_fft(&poly);
+    for (x, &e) in domain.elements().zip(&coset_evaluations) {
+        assert_eq!(e, poly.evaluate(x));
+    }
+    assert_eq!(domain.coset_ifft(&coset_evaluations), poly);
+
+    // shorter polynomials are padded
+    let short = Polynomial::<FF>::random(3, &mut rng);
+    let mut padded = short.clone();
+    padded.resize(N, FF::ZERO);
+    assert_eq!(domain.coset_fft(&short), domain.coset_fft(&padded));
+}
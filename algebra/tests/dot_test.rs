@@ -0,0 +1,51 @@
+use algebra::{
+    derive::{Field, Prime, Random},
+    utils::dot_product,
+    Field, Random,
+};
+use rand::{distributions::Distribution, thread_rng};
+
+#[derive(Field, Random, Prime)]
+#[modulus = 61]
+pub struct Fp8(u8);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 12289]
+pub struct Fp16(u16);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 132120577]
+pub struct Fp64(u64);
+
+fn check<F: Field + Random>(len: usize) {
+    let mut rng = thread_rng();
+    let distr = F::standard_distribution();
+    let a: Vec<F> = distr.sample_iter(&mut rng).take(len).collect();
+    let b: Vec<F> = distr.sample_iter(&mut rng).take(len).collect();
+
+    let naive = a
+        .iter()
+        .zip(&b)
+        .fold(F::ZERO, |acc, (&x, &y)| acc.add_mul(x, y));
+    assert_eq!(dot_product(&a, &b), naive);
+    assert_eq!(F::dot_product(&a, &b), naive);
+
+    // the largest values carry into the top limb as often as possible
+    let max = vec![F::NEG_ONE; len];
+    let expected = (0..len).fold(F::ZERO, |acc, _| acc + F::ONE);
+    assert_eq!(dot_product(&max, &max), expected);
+}
+
+#[test]
+fn test_dot_product() {
+    for len in [0, 1, 7, 1000, 70000] {
+        check::<Fp8>(len);
+        check::<Fp16>(len);
+        check::<Fp32>(len);
+        check::<Fp64>(len);
+    }
+}
@@ -0,0 +1,68 @@
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    Field, LazyField, LazyPoly, NTTPolynomial,
+};
+use rand::thread_rng;
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+type FF = Fp32;
+
+const N: usize = 64;
+
+#[test]
+fn test_lazy_field() {
+    let mut rng = thread_rng();
+    let (a, b, c) = (
+        FF::random(&mut rng),
+        FF::random(&mut rng),
+        FF::random(&mut rng),
+    );
+
+    let lazy = LazyField::mul(a, b);
+    assert!(lazy.get_lazy() < 2 * FF::MODULUS_INNER);
+    assert_eq!(lazy.normalize(), a * b);
+
+    let mut acc = LazyField::add_mul(c, a, b);
+    assert_eq!(acc.normalize(), c + a * b);
+    for _ in 0..10 {
+        acc.add_mul_assign(a, b);
+        assert!(acc.get_lazy() < 2 * FF::MODULUS_INNER);
+    }
+    assert_eq!(acc.normalize(), c + a * b * FF::new(11));
+
+    assert_eq!(LazyField::from(c).normalize(), c);
+}
+
+#[test]
+fn test_lazy_poly() {
+    let mut rng = thread_rng();
+    let a = NTTPolynomial::<FF>::random(N, &mut rng);
+    let b = NTTPolynomial::<FF>::random(N, &mut rng);
+    let c = NTTPolynomial::<FF>::random(N, &mut rng);
+
+    assert_eq!(LazyPoly::mul(&a, &b).normalize(), &a * &b);
+
+    let mut acc = LazyPoly::from(c.clone());
+    for _ in 0..3 {
+        acc.add_mul_assign(&a, &b);
+    }
+    assert!((0..N).all(|i| acc.get(i).unwrap().get_lazy() < 2 * FF::MODULUS_INNER));
+    let acc = acc.normalize();
+    assert!(acc.is_canonical());
+    assert_eq!(acc, c + (&a * &b).mul_scalar(FF::new(3)));
+
+    let mut acc = LazyPoly::zero(N);
+    acc.add_mul_assign(&a, &b);
+    assert_eq!(acc.normalize(), &a * &b);
+}
+
+#[test]
+#[should_panic]
+fn test_lazy_poly_length_mismatch() {
+    let a = NTTPolynomial::<FF>::zero(N);
+    let b = NTTPolynomial::<FF>::zero(N / 2);
+    LazyPoly::mul(&a, &b);
+}
@@ -0,0 +1,94 @@
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    AlgebraError, Field, Matrix, NegacyclicMatrix, Polynomial, Random,
+};
+use rand::{distributions::Distribution, thread_rng};
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+type FF = Fp32;
+
+fn random_matrix(rows: usize, cols: usize) -> Matrix<FF> {
+    let data = FF::standard_distribution()
+        .sample_iter(thread_rng())
+        .take(rows * cols)
+        .collect();
+    Matrix::new(rows, cols, data)
+}
+
+fn random_vec(len: usize) -> Vec<FF> {
+    FF::standard_distribution()
+        .sample_iter(thread_rng())
+        .take(len)
+        .collect()
+}
+
+#[test]
+fn test_matrix_products() {
+    let a = random_matrix(3, 5);
+    let b = random_matrix(5, 4);
+    let v = random_vec(4);
+    let w = random_vec(3);
+
+    let ab = &a * &b;
+    assert_eq!((ab.rows(), ab.cols()), (3, 4));
+    assert_eq!(
+        ab[(1, 2)],
+        (0..5).fold(FF::ZERO, |acc, k| acc + a[(1, k)] * b[(k, 2)])
+    );
+
+    // (AB)v = A(Bv) and w(AB) = (wA)B
+    assert_eq!(ab.mul_vec(&v), a.mul_vec(&b.mul_vec(&v)));
+    assert_eq!(ab.vec_mul(&w), b.vec_mul(&a.vec_mul(&w)));
+
+    // (AB)^T = B^T A^T
+    assert_eq!(ab.transpose(), &b.transpose() * &a.transpose());
+    assert_eq!(a.transpose().transpose(), a);
+
+    assert_eq!(&(&a + &a) - &a, a);
+    assert_eq!(&Matrix::identity(3) * &a, a);
+}
+
+#[test]
+fn test_matrix_inverse() {
+    let a = random_matrix(6, 6);
+    let inv = a.inverse().unwrap();
+    assert_eq!(&a * &inv, Matrix::identity(6));
+    assert_eq!(&inv * &a, Matrix::identity(6));
+
+    let b = random_vec(6);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(a.mul_vec(&x), b);
+
+    // the last row is the sum of the first two
+    let mut singular = random_matrix(3, 3);
+    for j in 0..3 {
+        singular[(2, j)] = singular[(0, j)] + singular[(1, j)];
+    }
+    assert_eq!(singular.rank(), 2);
+    assert!(matches!(
+        singular.inverse(),
+        Err(AlgebraError::SingularMatrix)
+    ));
+    assert!(singular.solve(&random_vec(3)).is_err());
+
+    let mut echelon = singular.clone();
+    assert_eq!(echelon.row_echelon(), 2);
+    assert!(echelon.row(2).iter().all(|&v| v == FF::ZERO));
+    assert_eq!(random_matrix(4, 7).rank(), 4);
+}
+
+#[test]
+fn test_negacyclic_matrix() {
+    let n = 16;
+    let mut rng = thread_rng();
+    let a = Polynomial::<FF>::random(n, &mut rng);
+    let v = Polynomial::<FF>::random(n, &mut rng);
+
+    let matrix = NegacyclicMatrix::new(a.clone());
+    let product = matrix.mul_vec(v.as_slice());
+    assert_eq!(product, (&a * &v).data());
+    assert_eq!(matrix.to_dense().mul_vec(v.as_slice()), product);
+}
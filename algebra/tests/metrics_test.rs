@@ -0,0 +1,26 @@
+#![cfg(feature = "tracing")]
+
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    metrics::op_counts,
+    Polynomial,
+};
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp(u32);
+
+#[test]
+fn op_counts_test() {
+    let mut rng = rand::thread_rng();
+    let a = Polynomial::<Fp>::random(1024, &mut rng);
+    let b = Polynomial::<Fp>::random(1024, &mut rng);
+
+    let start = op_counts();
+    let _ = a * b;
+    let counts = op_counts().since(&start);
+
+    assert_eq!(counts.ntt_forward, 2);
+    assert_eq!(counts.ntt_inverse, 1);
+    assert_eq!(counts.pointwise_mul, 1024);
+}
@@ -1,7 +1,8 @@
 use algebra::{
     derive::{Field, Prime, Random, NTT},
-    transformation::{AbstractNTT, MonomialNTT},
-    Basis, Field, ModulusConfig, NTTField, NTTPolynomial, Polynomial,
+    transformation::{AbstractNTT, MonomialNTT, NTTVariant, TwistFactors, MAX_CACHED_LOG_N},
+    utils::bit_reverse_permute,
+    Basis, ConstantTimeEq, Field, ModulusConfig, NTTField, NTTPolynomial, Polynomial,
 };
 use rand::{thread_rng, Rng};
 
@@ -32,6 +33,23 @@ fn test_transform() {
     assert_eq!(b, d);
 }
 
+#[test]
+fn test_ct_eq() {
+    let a = PolyFF::random(N, thread_rng());
+    let mut b = a.clone();
+    assert!(bool::from(a.ct_eq(&b)));
+    assert!(bool::from(a[0].ct_eq(&b[0])));
+
+    b[N - 1] += FF::ONE;
+    assert!(!bool::from(a.ct_eq(&b)));
+    assert!(!bool::from(a[N - 1].ct_eq(&b[N - 1])));
+    assert!(!bool::from(a.ct_eq(&PolyFF::zero(N + 1))));
+
+    let ntt_a = a.clone().into_ntt_polynomial();
+    assert!(bool::from(ntt_a.ct_eq(&a.into_ntt_polynomial())));
+    assert!(!bool::from(ntt_a.ct_eq(&b.into_ntt_polynomial())));
+}
+
 #[test]
 fn test_transform_monomial() {
     let mut rng = thread_rng();
@@ -116,6 +134,29 @@ fn test_native_poly_mul() {
     assert_eq!(b * a, mul_result);
 }
 
+#[test]
+fn test_native_poly_mul_with_table() {
+    let mut rng = thread_rng();
+    let table = FF::get_ntt_table(LOG_N as u32).unwrap();
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+
+    let mul_result = simple_mul(&a, &b);
+    assert_eq!(a.mul_with_table(&b, &table), mul_result);
+
+    let mut c = a.clone();
+    c.mul_assign_with_table(b, &table);
+    assert_eq!(c, mul_result);
+
+    // the cache holds one table per log_n up to MAX_CACHED_LOG_N
+    assert!(std::sync::Arc::ptr_eq(
+        &table,
+        &FF::get_ntt_table(LOG_N as u32).unwrap()
+    ));
+    assert!(FF::get_ntt_table(MAX_CACHED_LOG_N + 1).is_err());
+}
+
 fn simple_mul<F: Field>(lhs: &Polynomial<F>, rhs: &Polynomial<F>) -> Polynomial<F> {
     assert_eq!(lhs.coeff_count(), rhs.coeff_count());
     let coeff_count = lhs.coeff_count();
@@ -157,6 +198,26 @@ fn test_poly_decompose() {
     assert_eq!(compose, poly);
 }
 
+#[test]
+fn test_poly_decompose_mixed() {
+    let rng = &mut thread_rng();
+    let poly = PolyFF::random(N, rng);
+    let basis = <Basis<Fp32>>::mixed(&[2, 5, 20]);
+
+    let decompose = poly.clone().decompose(basis);
+    let compose = decompose
+        .iter()
+        .enumerate()
+        .fold(PolyFF::zero(N), |acc, (i, d)| {
+            acc + d.mul_scalar(Fp32::new(1 << basis.level_shift(i)))
+        });
+    assert_eq!(compose, poly);
+
+    let mut destination = vec![PolyFF::zero(N); basis.decompose_len()];
+    poly.clone().decompose_inplace(basis, &mut destination);
+    assert_eq!(destination, decompose);
+}
+
 #[test]
 fn test_poly_decompose_mul() {
     let mut rng = thread_rng();
@@ -177,6 +238,93 @@ fn test_poly_decompose_mul() {
     assert_eq!(compose_mul_result, mul_result);
 }
 
+#[test]
+fn test_stockham_transform() {
+    let mut rng = thread_rng();
+    let table = FF::generate_ntt_table(LOG_N as u32).unwrap();
+    let stockham = FF::generate_ntt_table(LOG_N as u32)
+        .unwrap()
+        .with_variant(NTTVariant::Stockham);
+    assert_eq!(stockham.variant(), NTTVariant::Stockham);
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+
+    let mut expected = table.transform(&a).data();
+    bit_reverse_permute(&mut expected, LOG_N as u32);
+    let a_ntt = stockham.transform(&a);
+    assert_eq!(a_ntt.as_slice(), expected.as_slice());
+    assert_eq!(stockham.inverse_transform(&a_ntt), a);
+
+    let product = stockham.inverse_transform_inplace(a_ntt * stockham.transform(&b));
+    assert_eq!(product, &a * &b);
+
+    let degree = rng.gen_range(1..N);
+    let coeff = FF::new(rng.gen_range(2..P - 1));
+    let mut monomial = PolyFF::zero(N);
+    monomial[degree] = coeff;
+    let mut values = vec![FF::new(0); N];
+    stockham.transform_monomial(coeff, degree, &mut values);
+    assert_eq!(values, stockham.transform(&monomial).data());
+}
+
+#[test]
+fn test_twisted_transform() {
+    let mut rng = thread_rng();
+    let table = FF::get_ntt_table(LOG_N as u32).unwrap();
+
+    let alpha = FF::new(rng.gen_range(2..P - 1));
+    let c = (0..N).fold(FF::new(1), |acc, _| acc * alpha);
+    let twist = TwistFactors::for_root(&table, alpha);
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+
+    // a * b mod x^n - c
+    let mut expected = vec![FF::new(0); N];
+    for i in 0..N {
+        for j in 0..N {
+            let product = a[i] * b[j];
+            if i + j < N {
+                expected[i + j] += product;
+            } else {
+                expected[i + j - N] += product * c;
+            }
+        }
+    }
+
+    let mut a_values = a.clone().data();
+    let mut b_values = b.data();
+    table.twisted_transform_slice(&twist, &mut a_values);
+    table.twisted_transform_slice(&twist, &mut b_values);
+    for (x, y) in a_values.iter_mut().zip(&b_values) {
+        *x *= *y;
+    }
+    table.twisted_inverse_transform_slice(&twist, &mut a_values);
+    assert_eq!(a_values, expected);
+
+    let mut values = a.clone().data();
+    let weights: Vec<FF> = (0..N).map(|_| FF::new(rng.gen_range(1..P))).collect();
+    let arbitrary = TwistFactors::new(&weights);
+    table.twisted_transform_slice(&arbitrary, &mut values);
+    table.twisted_inverse_transform_slice(&arbitrary, &mut values);
+    assert_eq!(values, a.data());
+}
+
+#[test]
+fn test_ntt_poly_mul_roots() {
+    let mut rng = thread_rng();
+    let a = NTTPolyFF::random(N, &mut rng);
+    let roots = a.to_roots();
+
+    for _ in 0..4 {
+        let b = NTTPolyFF::random(N, &mut rng);
+        let mut c = b.clone();
+        c.mul_roots_assign(&roots);
+        assert_eq!(c, &a * &b);
+    }
+}
+
 #[test]
 fn test_ntt_poly() {
     let a = NTTPolyFF::new(vec![FF::new(1), FF::new(P - 1)]);
@@ -224,3 +372,95 @@ fn test_poly_eval() {
         poly.iter().fold(FF::ZERO, |acc, a| acc + a)
     );
 }
+
+#[test]
+fn test_ntt_poly_eval() {
+    FF::init_ntt_table(&[LOG_N as u32]).unwrap();
+    let mut rng = thread_rng();
+    let poly = PolyFF::random(N, &mut rng);
+    let ntt_poly = poly.clone().into_ntt_polynomial();
+
+    let x = FF::random(&mut rng);
+    assert_eq!(ntt_poly.evaluate(x), poly.evaluate(x));
+    assert_eq!(ntt_poly.evaluate(FF::ZERO), poly[0]);
+
+    // at one of the roots the ntt value itself is returned
+    let table = FF::get_ntt_table(LOG_N as u32).unwrap();
+    let root = table.root();
+    assert_eq!(ntt_poly.evaluate(root), poly.evaluate(root));
+
+    let stockham = FF::generate_ntt_table(LOG_N as u32)
+        .unwrap()
+        .with_variant(NTTVariant::Stockham);
+    let stockham_poly = stockham.transform(&poly);
+    assert_eq!(
+        stockham_poly.evaluate_with_table(x, &stockham),
+        poly.evaluate(x)
+    );
+    assert_eq!(
+        stockham_poly.evaluate_with_table(root, &stockham),
+        poly.evaluate(root)
+    );
+}
+
+#[test]
+fn test_random_sparse() {
+    let rng = &mut thread_rng();
+    for weight in [0, 1, 64, 1024] {
+        let poly = PolyFF::random_sparse(1024, weight, &mut *rng);
+        assert_eq!(poly.coeff_count(), 1024);
+        assert_eq!(poly.iter().filter(|&&x| x != FF::ZERO).count(), weight);
+        assert!(poly
+            .iter()
+            .all(|&x| x == FF::ZERO || x == FF::ONE || x == FF::NEG_ONE));
+    }
+}
+
+#[test]
+fn test_chunks_and_zip_map() {
+    let rng = &mut thread_rng();
+    let a = PolyFF::random(N * 4 + 3, &mut *rng);
+    let b = PolyFF::random(N * 4 + 3, &mut *rng);
+
+    assert_eq!(a.chunks_exact(N).count(), 4);
+    assert!(a
+        .chunks_exact(N)
+        .enumerate()
+        .all(|(i, chunk)| chunk == &a[i * N..(i + 1) * N]));
+
+    let mut c = a.clone();
+    c.chunks_exact_mut(N)
+        .for_each(|chunk| chunk.iter_mut().for_each(|x| *x = -*x));
+    assert!(c[..N * 4].iter().zip(a.iter()).all(|(&c, &a)| c == -a));
+    assert_eq!(c[N * 4..], a[N * 4..]);
+
+    let mut c = a.clone();
+    c.zip_map_inplace(&b, |x, y| x * y + x);
+    assert!(c
+        .iter()
+        .zip(a.iter().zip(b.iter()))
+        .all(|(&c, (&a, &b))| c == a * b + a));
+}
+
+#[test]
+#[should_panic]
+fn test_zip_map_length_mismatch() {
+    let mut a = PolyFF::zero(N);
+    a.zip_map_inplace(PolyFF::zero(N + 1), |x, y| x + y);
+}
+
+#[test]
+fn test_canonical() {
+    let rng = &mut thread_rng();
+    let poly = PolyFF::random(N, &mut *rng);
+    assert!(poly.is_canonical());
+    poly.debug_assert_canonical();
+
+    let ntt_poly = poly.clone().into_ntt_polynomial();
+    assert!(ntt_poly.is_canonical());
+
+    // a lazily reduced value in [q, 2q)
+    let mut lazy = poly;
+    lazy[0] = FF::new(FF::MODULUS_INNER);
+    assert!(!lazy.is_canonical());
+}
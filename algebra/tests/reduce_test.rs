@@ -0,0 +1,78 @@
+use algebra::modulus::BarrettModulus;
+use algebra::reduce::*;
+use rand::{distributions::Uniform, prelude::*};
+
+const P: u32 = 132120577;
+const N: usize = 1027;
+
+fn random_values(rng: &mut impl Rng) -> Vec<u32> {
+    rng.sample_iter(Uniform::new(0, P)).take(N).collect()
+}
+
+#[test]
+fn test_batch_reduce() {
+    let rng = &mut thread_rng();
+    let modulus = BarrettModulus::<u32>::new(P);
+    let a = random_values(rng);
+    let b = random_values(rng);
+    let c = random_values(rng);
+    let scalar = rng.gen_range(0..P);
+
+    let mul = |x: u32, y: u32| ((x as u64 * y as u64) % P as u64) as u32;
+
+    let mut v = a.clone();
+    batch_add_reduce(&mut v, &b, modulus);
+    assert!(v
+        .iter()
+        .zip(&a)
+        .zip(&b)
+        .all(|((&v, &a), &b)| v == (a + b) % P));
+
+    let mut v = a.clone();
+    batch_sub_reduce(&mut v, &b, modulus);
+    assert!(v
+        .iter()
+        .zip(&a)
+        .zip(&b)
+        .all(|((&v, &a), &b)| v == (a + P - b) % P));
+
+    let mut v = a.clone();
+    batch_neg_reduce(&mut v, modulus);
+    assert!(v.iter().zip(&a).all(|(&v, &a)| v == (P - a) % P));
+
+    let mut v = a.clone();
+    batch_mul_reduce(&mut v, &b, modulus);
+    assert!(v
+        .iter()
+        .zip(&a)
+        .zip(&b)
+        .all(|((&v, &a), &b)| v == mul(a, b)));
+
+    let mut v = a.clone();
+    batch_lazy_mul_reduce(&mut v, &b, modulus);
+    assert!(v
+        .iter()
+        .zip(&a)
+        .zip(&b)
+        .all(|((&v, &a), &b)| v % P == mul(a, b)));
+
+    let mut v = a.clone();
+    batch_scalar_mul_reduce(&mut v, scalar, modulus);
+    assert!(v.iter().zip(&a).all(|(&v, &a)| v == mul(a, scalar)));
+
+    let mut v = c.clone();
+    batch_add_mul_reduce(&mut v, &a, &b, modulus);
+    assert!(v
+        .iter()
+        .zip(&c)
+        .zip(a.iter().zip(&b))
+        .all(|((&v, &c), (&a, &b))| v == (c + mul(a, b)) % P));
+}
+
+#[test]
+#[should_panic]
+fn test_batch_reduce_length_mismatch() {
+    let modulus = BarrettModulus::<u32>::new(P);
+    let mut a = vec![1; 4];
+    batch_mul_reduce(&mut a, &[2; 3], modulus);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "rand_core_09")]
+
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    Polynomial, RngCompat,
+};
+use rand::{thread_rng, RngCore};
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp(u32);
+
+/// A `rand_core` 0.9 generator backed by `thread_rng`.
+struct NewRng(rand::rngs::ThreadRng);
+
+impl rand_core_09::RngCore for NewRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+}
+
+impl rand_core_09::CryptoRng for NewRng {}
+
+#[test]
+fn rng_compat_test() {
+    let mut rng = RngCompat(NewRng(thread_rng()));
+    let a = Polynomial::<Fp>::random(64, &mut rng);
+    let b = Polynomial::<Fp>::random(64, &mut rng);
+    assert_eq!(a.coeff_count(), 64);
+    assert_ne!(a, b);
+    let _ = Fp::random(&mut rng);
+}
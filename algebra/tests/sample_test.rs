@@ -0,0 +1,137 @@
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    transformation::AbstractNTT,
+    utils::{
+        sample_cbd_ntt_poly, sample_distinct_nonzero_field_vec, sample_gaussian_field_vec,
+        sample_nonzero_field_vec, sample_ternary_field_vec_with_weight, sample_ternary_ntt_poly,
+        sample_ternary_ntt_poly_into, sample_uniform_matrix,
+    },
+    Field, FieldBoundedSampler, NTTField, NTTPolynomial, Random,
+};
+use rand::{distributions::Distribution, thread_rng};
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+type FF = Fp32;
+
+#[test]
+fn test_sample_uniform_matrix() {
+    let mut rng = thread_rng();
+    let a = sample_uniform_matrix::<FF, _>(4, 9, &mut rng);
+    assert_eq!((a.rows(), a.cols()), (4, 9));
+    // a uniform matrix of this size is full rank except with negligible probability
+    assert_eq!(a.rank(), 4);
+}
+
+#[derive(Field, Random, Prime)]
+#[modulus = 61]
+pub struct Fp16(u16);
+
+#[test]
+fn test_sample_nonzero() {
+    let mut rng = thread_rng();
+    let v = sample_nonzero_field_vec::<Fp16, _>(1000, &mut rng);
+    assert!(v.iter().all(|&x| x != Fp16::ZERO));
+    assert!(v.iter().all(|&x| x * (Fp16::ONE / x) == Fp16::ONE));
+
+    // all the nonzero elements
+    let mut v = sample_distinct_nonzero_field_vec::<Fp16, _>(60, &mut rng);
+    v.sort_by_key(|x| x.get());
+    assert_eq!(v, (1..61).map(Fp16::new).collect::<Vec<_>>());
+
+    let v = sample_distinct_nonzero_field_vec::<FF, _>(100, &mut rng);
+    assert!(v
+        .iter()
+        .enumerate()
+        .all(|(i, x)| *x != FF::ZERO && !v[..i].contains(x)));
+}
+
+#[test]
+#[should_panic]
+fn test_sample_distinct_too_long() {
+    sample_distinct_nonzero_field_vec::<Fp16, _>(61, &mut thread_rng());
+}
+
+#[test]
+fn test_sample_ternary_with_weight() {
+    let mut rng = thread_rng();
+    for weight in [0, 1, 64, 256] {
+        let s = sample_ternary_field_vec_with_weight::<FF, _>(256, weight, &mut rng);
+        assert_eq!(s.len(), 256);
+        assert!(s
+            .iter()
+            .all(|&v| v == FF::ZERO || v == FF::ONE || v == FF::NEG_ONE));
+        assert_eq!(s.iter().filter(|&&v| v != FF::ZERO).count(), weight);
+    }
+}
+
+#[test]
+fn test_sample_gaussian_vec() {
+    let mut rng = thread_rng();
+    let std_dev = 3.2;
+    let gaussian = FF::gaussian_sampler(0.0, std_dev).unwrap();
+    let e = sample_gaussian_field_vec::<FF, _>(1024, gaussian, &mut rng);
+    assert_eq!(e.len(), 1024);
+
+    let modulus = FF::modulus_value();
+    let bound = (std_dev * 12.0) as u32;
+    assert!(e
+        .iter()
+        .all(|v| v.get() <= bound || modulus - v.get() <= bound));
+}
+
+#[test]
+fn test_sample_bounded() {
+    let mut rng = thread_rng();
+    let modulus = FF::modulus_value();
+    let bound = 5;
+    let sampler = FF::bounded_sampler(bound).unwrap();
+    let mut counts = [0usize; 11];
+    for _ in 0..11000 {
+        let v: FF = sampler.sample(&mut rng);
+        let centered = if v.get() <= bound as u32 {
+            v.get() as i64
+        } else {
+            -((modulus - v.get()) as i64)
+        };
+        assert!(centered.unsigned_abs() <= bound);
+        counts[(centered + bound as i64) as usize] += 1;
+    }
+    assert!(counts.iter().all(|&c| c > 0));
+
+    let zero = FF::bounded_sampler(0).unwrap();
+    assert_eq!(Distribution::<FF>::sample(&zero, &mut rng), FF::ZERO);
+
+    // wraps around the small modulus
+    let wide = Fp16::bounded_sampler(1000).unwrap();
+    assert!((0..1000).all(|_| Distribution::<Fp16>::sample(&wide, &mut rng).get() < 61));
+
+    assert!(FieldBoundedSampler::new(1 << 63).is_err());
+}
+
+#[test]
+fn test_sample_ntt_poly() {
+    let mut rng = thread_rng();
+    let n = 1 << 10;
+    let table = FF::get_ntt_table(10).unwrap();
+
+    let s = sample_ternary_ntt_poly::<FF, _>(n, &*table, &mut rng);
+    let s = table.inverse_transform(&s);
+    assert!(s
+        .iter()
+        .all(|&v| v == FF::ZERO || v == FF::ONE || v == FF::NEG_ONE));
+
+    let mut reused = NTTPolynomial::zero(n);
+    sample_ternary_ntt_poly_into(&mut reused, &*table, &mut rng);
+    let u = table.inverse_transform(&reused);
+    assert!(u
+        .iter()
+        .all(|&v| v == FF::ZERO || v == FF::ONE || v == FF::NEG_ONE));
+    assert_eq!(table.transform(&u), reused);
+
+    let e = table.inverse_transform(&sample_cbd_ntt_poly::<FF, _>(n, &*table, &mut rng));
+    let modulus = FF::modulus_value();
+    assert!(e.iter().all(|v| v.get() <= 21 || modulus - v.get() <= 21));
+}
@@ -0,0 +1,54 @@
+use algebra::{
+    derive::{Field, Prime, Random},
+    Field,
+};
+use rand::{distributions::Distribution, thread_rng};
+
+#[derive(Field, Random, Prime)]
+#[modulus = 132120577]
+pub struct Fp(u32);
+
+#[derive(Field, Random, Prime)]
+#[modulus = 1073741789]
+pub struct Fq(u32);
+
+fn check_slice_kernels<F: Field<Value = u32> + algebra::Random>() {
+    let mut rng = thread_rng();
+    let q = F::MODULUS_INNER as u64;
+    let distr = F::standard_distribution();
+
+    for len in 0..40 {
+        let mut sample = || -> Vec<F> { distr.sample_iter(&mut rng).take(len).collect() };
+        let (a, b, c) = (sample(), sample(), sample());
+        let value = |x: &F| x.get() as u64;
+
+        let mut sum = a.clone();
+        F::add_assign_slice(&mut sum, &b);
+        let mut difference = a.clone();
+        F::sub_assign_slice(&mut difference, &b);
+        let mut product = a.clone();
+        F::mul_assign_slice(&mut product, &b);
+        let mut acc = c.clone();
+        F::add_mul_assign_slice(&mut acc, &a, &b);
+
+        for i in 0..len {
+            let (x, y, z) = (value(&a[i]), value(&b[i]), value(&c[i]));
+            assert_eq!(value(&sum[i]), (x + y) % q);
+            assert_eq!(value(&difference[i]), (x + q - y) % q);
+            assert_eq!(value(&product[i]), x * y % q);
+            assert_eq!(value(&acc[i]), (z + x * y) % q);
+        }
+    }
+
+    // the extreme values
+    let max = F::new(F::MODULUS_INNER - 1);
+    let mut product = vec![max; 9];
+    F::mul_assign_slice(&mut product, &[max; 9]);
+    assert!(product.iter().all(|x| *x == F::ONE));
+}
+
+#[test]
+fn slice_kernels_test() {
+    check_slice_kernels::<Fp>();
+    check_slice_kernels::<Fq>();
+}
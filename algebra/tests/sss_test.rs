@@ -0,0 +1,152 @@
+use algebra::{
+    derive::{Field, Prime, Random},
+    sss, Field, Polynomial,
+};
+use rand::thread_rng;
+
+#[derive(Field, Random, Prime)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+type FF = Fp32;
+
+fn indices(n: u32) -> Vec<FF> {
+    (1..=n).map(FF::new).collect()
+}
+
+#[test]
+fn test_share_reconstruct() {
+    let mut rng = thread_rng();
+    let secret = Polynomial::<FF>::random(8, &mut rng);
+    let indices = indices(5);
+    let shares: Vec<_> = sss::share(&secret, 3, &indices, &mut rng).collect();
+    assert_eq!(shares.len(), 5);
+    // the shares are redacted in logs
+    assert_eq!(format!("{:?}", shares[0]), "Share { coeff_count: 8, .. }");
+    let shares: Vec<_> = shares.into_iter().map(sss::Share::into_secret).collect();
+
+    assert_eq!(sss::reconstruct(&shares[..3], &indices[..3]), secret);
+    assert_eq!(sss::reconstruct(&shares[2..], &indices[2..]), secret);
+    assert_ne!(sss::reconstruct(&shares[..2], &indices[..2]), secret);
+    // any other share is interpolated from the threshold
+    assert_eq!(
+        sss::interpolate_at(indices[4], &shares[..3], &indices[..3]),
+        shares[4]
+    );
+
+    let coeffs = sss::lagrange_coeffs(&indices[1..4]);
+    assert_eq!(
+        coeffs.iter().copied().fold(FF::ZERO, |acc, c| acc + c),
+        FF::ONE
+    );
+}
+
+#[test]
+fn test_verify_and_correct() {
+    let mut rng = thread_rng();
+    let secret = Polynomial::<FF>::random(4, &mut rng);
+    let indices = indices(7);
+    let mut shares: Vec<_> = sss::share(&secret, 3, &indices, &mut rng)
+        .map(sss::Share::into_secret)
+        .collect();
+    assert!(sss::verify_shares(&shares, &indices, 3));
+
+    shares[5][1] += FF::ONE;
+    assert!(!sss::verify_shares(&shares, &indices, 3));
+    assert!(sss::verify_shares(&shares[..3], &indices[..3], 3));
+
+    let (res, corrupted) = sss::reconstruct_with_correction(&shares, &indices, 3).unwrap();
+    assert_eq!(res, secret);
+    assert_eq!(corrupted, vec![indices[5]]);
+}
+
+#[test]
+fn test_reshare() {
+    let mut rng = thread_rng();
+    let secret = Polynomial::<FF>::random(4, &mut rng);
+    let old_indices = indices(4);
+    let new_indices: Vec<FF> = (10..15).map(FF::new).collect();
+    let shares: Vec<_> = sss::share(&secret, 2, &old_indices, &mut rng)
+        .map(sss::Share::into_secret)
+        .collect();
+
+    // old holders 0, 2 and 3 reshare to a 3 of 5 committee
+    let chosen = [0, 2, 3];
+    let sub_shares: Vec<Vec<_>> = chosen
+        .iter()
+        .map(|&i| {
+            sss::share(&shares[i], 3, &new_indices, &mut rng)
+                .map(sss::Share::into_secret)
+                .collect()
+        })
+        .collect();
+    let chosen_indices: Vec<FF> = chosen.iter().map(|&i| old_indices[i]).collect();
+    let new_shares: Vec<_> = (0..new_indices.len())
+        .map(|j| {
+            let received: Vec<_> = sub_shares.iter().map(|s| s[j].clone()).collect();
+            sss::combine_reshares(&received, &chosen_indices)
+        })
+        .collect();
+
+    assert!(sss::verify_shares(&new_shares, &new_indices, 3));
+    assert_eq!(
+        sss::reconstruct(&new_shares[1..4], &new_indices[1..4]),
+        secret
+    );
+}
+
+#[test]
+fn test_lagrange_basis() {
+    let mut rng = thread_rng();
+    let secret = Polynomial::<FF>::random(4, &mut rng);
+    let all = indices(8);
+    let shares: Vec<_> = sss::share(&secret, 3, &all, &mut rng)
+        .map(sss::Share::into_secret)
+        .collect();
+
+    // coefficients through batch inversion match the quotient definition
+    let point = FF::new(100);
+    let chosen = [all[1], all[4], all[6]];
+    let coeffs = sss::lagrange_coeffs_at(point, &chosen);
+    for (i, &x) in chosen.iter().enumerate() {
+        let (num, den) = chosen
+            .iter()
+            .filter(|&&y| y != x)
+            .fold((FF::ONE, FF::ONE), |(n, d), &y| {
+                (n * (point - y), d * (x - y))
+            });
+        assert_eq!(coeffs[i], num / den);
+    }
+    // evaluating at one of the indices selects its share
+    assert_eq!(
+        sss::lagrange_coeffs_at(all[4], &chosen),
+        vec![FF::ZERO, FF::ONE, FF::ZERO]
+    );
+
+    let mut basis = sss::LagrangeBasis::new(FF::ZERO, &all[..3]);
+    assert_eq!(basis.coeffs(), sss::lagrange_coeffs(&all[..3]));
+    assert!(!basis.insert(all[1]));
+
+    // the committee churns: 2 leaves, 6 and 8 join, 1 leaves
+    assert!(basis.remove(all[1]));
+    assert!(!basis.remove(all[1]));
+    assert!(basis.insert(all[5]));
+    assert!(basis.insert(all[7]));
+    assert!(basis.remove(all[0]));
+    assert_eq!(basis.indices(), &[all[2], all[5], all[7]]);
+    assert_eq!(basis.coeffs(), sss::lagrange_coeffs(basis.indices()));
+
+    let chosen_shares = [2, 5, 7].map(|i| shares[i].clone());
+    let mut value = Polynomial::<FF>::zero(4);
+    for (share, c) in chosen_shares.iter().zip(basis.coeffs()) {
+        value += share.mul_scalar(c);
+    }
+    assert_eq!(value, secret);
+}
+
+#[test]
+#[should_panic(expected = "repeated index")]
+fn test_lagrange_repeated_index() {
+    let indices = indices(3);
+    sss::lagrange_coeffs(&[indices[0], indices[1], indices[0]]);
+}
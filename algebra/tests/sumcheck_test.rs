@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use algebra::{
+    derive::{Field, Prime, Random},
+    sumcheck::IPForMLSumcheck,
+    DenseMultilinearExtension, Field, ListOfProductsOfPolynomials, MultilinearExtension, Random,
+};
+use rand::thread_rng;
+use rand_distr::Distribution;
+
+#[derive(Field, Random, Prime)]
+#[modulus = 132120577]
+pub struct Fp32(u32);
+
+type FF = Fp32;
+
+fn random_list_of_products(
+    nv: usize,
+    num_products: usize,
+    num_multiplicands: usize,
+) -> (ListOfProductsOfPolynomials<FF>, FF) {
+    let mut rng = thread_rng();
+    let mut poly = ListOfProductsOfPolynomials::new(nv);
+    let mut sum = FF::ZERO;
+    for _ in 0..num_products {
+        let coefficient = FF::standard_distribution().sample(&mut rng);
+        let product: Vec<_> = (0..num_multiplicands)
+            .map(|_| DenseMultilinearExtension::<FF>::random(nv, &mut rng))
+            .collect();
+        sum += (0..1 << nv).fold(FF::ZERO, |acc, b| {
+            acc + product.iter().fold(coefficient, |p, m| p * m[b])
+        });
+        poly.add_product(product.into_iter().map(Rc::new), coefficient);
+    }
+    (poly, sum)
+}
+
+fn run_sumcheck(poly: &ListOfProductsOfPolynomials<FF>, asserted_sum: FF) -> bool {
+    let mut rng = thread_rng();
+    let mut prover_state = IPForMLSumcheck::prover_init(poly);
+    let mut verifier_state = IPForMLSumcheck::verifier_init(&poly.info());
+    let mut challenge = None;
+    for _ in 0..poly.num_variables {
+        let msg = IPForMLSumcheck::prove_round(&mut prover_state, challenge);
+        let r = FF::standard_distribution().sample(&mut rng);
+        IPForMLSumcheck::verify_round(&msg, &mut verifier_state, r);
+        challenge = Some(r);
+    }
+
+    match IPForMLSumcheck::check_and_generate_subclaim(verifier_state, asserted_sum) {
+        Ok(subclaim) => poly.evaluate(&subclaim.point) == subclaim.expected_evaluation,
+        Err(_) => false,
+    }
+}
+
+#[test]
+fn sumcheck_test() {
+    for (nv, num_products, num_multiplicands) in [(1, 1, 1), (5, 3, 2), (8, 2, 4)] {
+        let (poly, sum) = random_list_of_products(nv, num_products, num_multiplicands);
+        assert!(run_sumcheck(&poly, sum));
+        assert!(!run_sumcheck(&poly, sum + FF::ONE));
+    }
+}
@@ -0,0 +1,88 @@
+use algebra::{
+    derive::{Field, Prime, Random, NTT},
+    transformation::{AbstractNTT, NTTTable, NTTVariant},
+    NTTField, Polynomial,
+};
+use rand::thread_rng;
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 132120577]
+pub struct Fp(u32);
+
+#[derive(Field, Random, Prime, NTT)]
+#[modulus = 1073479681]
+pub struct Fq(u32);
+
+const LOG_N: u32 = 10;
+
+/// Leak `bytes` into a static buffer aligned to 8 bytes, as a memory mapping would be.
+fn leak_aligned(bytes: &[u8]) -> &'static [u8] {
+    let words = Vec::leak(vec![0u64; bytes.len().div_ceil(8)]);
+    // SAFETY: the words are plain integers, and the view stays within them.
+    let buffer =
+        unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), words.len() * 8) };
+    buffer[..bytes.len()].copy_from_slice(bytes);
+    &buffer[..bytes.len()]
+}
+
+#[test]
+fn table_blob_round_trip_test() {
+    let table = Fp::generate_ntt_table(LOG_N).unwrap();
+    let blob = table.to_blob();
+    let loaded = NTTTable::<Fp>::from_blob(leak_aligned(&blob)).unwrap();
+
+    assert_eq!(loaded.root(), table.root());
+    assert_eq!(loaded.coeff_count(), table.coeff_count());
+    assert_eq!(loaded.to_blob(), blob);
+
+    let poly = Polynomial::<Fp>::random(1 << LOG_N, thread_rng());
+    let ntt_poly = table.transform(&poly);
+    assert_eq!(loaded.transform(&poly), ntt_poly);
+    assert_eq!(loaded.inverse_transform(&ntt_poly), poly);
+
+    Fp::insert_ntt_table(loaded);
+    assert_eq!(Fp::get_ntt_table(LOG_N).unwrap().to_blob(), blob);
+    assert_eq!(poly.clone().into_ntt_polynomial(), ntt_poly);
+}
+
+#[test]
+fn table_blob_rejection_test() {
+    let blob = Fp::generate_ntt_table(LOG_N).unwrap().to_blob();
+
+    // another field
+    assert!(NTTTable::<Fq>::from_blob(leak_aligned(&blob)).is_err());
+
+    // truncated or trailing bytes
+    assert!(NTTTable::<Fp>::from_blob(leak_aligned(&blob[..blob.len() - 8])).is_err());
+    let mut longer = blob.clone();
+    longer.extend([0; 8]);
+    assert!(NTTTable::<Fp>::from_blob(leak_aligned(&longer)).is_err());
+
+    // misaligned sections
+    let mut shifted = vec![0];
+    shifted.extend(&blob);
+    assert!(NTTTable::<Fp>::from_blob(&leak_aligned(&shifted)[1..]).is_err());
+
+    // a corrupted header
+    let mut corrupted = blob;
+    corrupted[0] ^= 1;
+    assert!(NTTTable::<Fp>::from_blob(leak_aligned(&corrupted)).is_err());
+}
+
+#[test]
+fn table_self_check_test() {
+    let table = Fp::generate_ntt_table(LOG_N).unwrap();
+    assert!(table.self_check().is_ok());
+    let stockham = Fp::generate_ntt_table(LOG_N)
+        .unwrap()
+        .with_variant(NTTVariant::Stockham);
+    assert!(stockham.self_check().is_ok());
+    assert!(Fq::generate_ntt_table(3).unwrap().self_check().is_ok());
+
+    // a corrupted power of the root still loads, but fails the self check
+    let mut corrupted = table.to_blob();
+    let offset = corrupted.len() - (8 << LOG_N) - 16;
+    corrupted[offset] ^= 1;
+    let loaded = NTTTable::<Fp>::from_blob(leak_aligned(&corrupted)).unwrap();
+    assert!(loaded.self_check().is_err());
+}
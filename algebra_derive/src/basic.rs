@@ -19,7 +19,7 @@ pub(crate) fn basic(name: &Ident, field_ty: &syn::Type, modulus: &LitInt) -> Tok
             }
         }
 
-        impl ::std::convert::From<#field_ty> for #name {
+        impl ::core::convert::From<#field_ty> for #name {
             #[inline]
             fn from(value: #field_ty) -> Self {
                 if value < #modulus {
@@ -31,60 +31,60 @@ pub(crate) fn basic(name: &Ident, field_ty: &syn::Type, modulus: &LitInt) -> Tok
             }
         }
 
-        impl ::std::clone::Clone for #name {
+        impl ::core::clone::Clone for #name {
             #[inline]
             fn clone(&self) -> Self {
                 *self
             }
         }
 
-        impl ::std::marker::Copy for #name {}
+        impl ::core::marker::Copy for #name {}
 
-        impl ::std::fmt::Debug for #name {
+        impl ::core::fmt::Debug for #name {
             #[inline]
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 f.debug_tuple(#name_str).field(&self.0).finish()
             }
         }
 
-        impl ::std::default::Default for #name {
+        impl ::core::default::Default for #name {
             #[inline]
             fn default() -> Self {
                 Self(0)
             }
         }
 
-        impl ::std::cmp::PartialOrd for #name {
+        impl ::core::cmp::PartialOrd for #name {
             #[inline]
-            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
                 Some(self.cmp(other))
             }
         }
 
-        impl ::std::cmp::Ord for #name {
+        impl ::core::cmp::Ord for #name {
             #[inline]
-            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
                 self.0.cmp(&other.0)
             }
         }
 
-        impl ::std::cmp::PartialEq for #name {
+        impl ::core::cmp::PartialEq for #name {
             #[inline]
             fn eq(&self, other: &Self) -> bool {
                 self.0 == other.0
             }
         }
 
-        impl ::std::cmp::Eq for #name {}
+        impl ::core::cmp::Eq for #name {}
     }
 }
 
-pub(crate) fn display(name: &Ident, modulus: &LitInt) -> TokenStream {
+pub(crate) fn display(name: &Ident, field_ty: &syn::Type, modulus: &LitInt) -> TokenStream {
     quote! {
-        impl ::std::fmt::Display for #name {
+        impl ::core::fmt::Display for #name {
             #[inline]
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                write!(f, "[({})_{}]", self.0, #modulus)
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "[({})_{}]", self.0, #modulus as #field_ty)
             }
         }
     }
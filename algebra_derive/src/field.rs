@@ -70,7 +70,20 @@ fn impl_field_with_ops(input: Input) -> Result<TokenStream> {
                 if modulus_number.leading_zeros() < 2 {
                     return Err(Error::new_spanned(
                         input.field.original,
-                        "Modulus is too big! It should be smaller than `u64::MAX >> 2`.",
+                        "Modulus is too big! It should be smaller than `u64::MAX >> 2`. You can also use `u128` for inner value.",
+                    ));
+                }
+            } else if type_path.clone().into_token_stream().to_string() == "u128" {
+                let modulus_number: u128 = modulus.base10_digits().parse().map_err(|_| {
+                    Error::new_spanned(
+                        input.field.original,
+                        "It's not possible to parse modulus into u128 type.",
+                    )
+                })?;
+                if modulus_number.leading_zeros() < 2 {
+                    return Err(Error::new_spanned(
+                        input.field.original,
+                        "Modulus is too big! It should be smaller than `u128::MAX >> 2`.",
                     ));
                 }
             } else {
@@ -90,7 +103,7 @@ fn impl_field_with_ops(input: Input) -> Result<TokenStream> {
 
     let impl_basic = basic(name, field_ty, &modulus);
 
-    let impl_display = display(name, &modulus);
+    let impl_display = display(name, field_ty, &modulus);
 
     let impl_zero = impl_zero(name);
 
@@ -145,10 +158,43 @@ fn impl_field_with_ops(input: Input) -> Result<TokenStream> {
 
 #[inline]
 fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> TokenStream {
+    let slice_kernels = if field_ty.to_token_stream().to_string() == "u32" {
+        quote! {
+            #[inline]
+            fn add_assign_slice(lhs: &mut [Self], rhs: &[Self]) {
+                ::algebra::simd::add_assign_slice(lhs, rhs)
+            }
+
+            #[inline]
+            fn sub_assign_slice(lhs: &mut [Self], rhs: &[Self]) {
+                ::algebra::simd::sub_assign_slice(lhs, rhs)
+            }
+
+            #[inline]
+            fn mul_assign_slice(lhs: &mut [Self], rhs: &[Self]) {
+                ::algebra::simd::mul_assign_slice(lhs, rhs)
+            }
+
+            #[inline]
+            fn add_mul_assign_slice(acc: &mut [Self], a: &[Self], b: &[Self]) {
+                ::algebra::simd::add_mul_assign_slice(acc, a, b)
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let scale_round = scale_round(field_ty, modulus);
+
     quote! {
-        impl ::algebra::Field for #name {
-            type Value = #field_ty;
+        impl ::algebra::__private::subtle::ConstantTimeEq for #name {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> ::algebra::__private::subtle::Choice {
+                ::algebra::__private::subtle::ConstantTimeEq::ct_eq(&self.0, &other.0)
+            }
+        }
 
+        impl ::algebra::Field for #name {
             type Order = #field_ty;
 
             const ONE: Self = Self(1);
@@ -244,6 +290,28 @@ fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> T
                 self.0 = a.0.carry_mul(b.0, self.0).reduce(<Self as ::algebra::ModulusConfig>::MODULUS);
             }
 
+            fn dot_product(a: &[Self], b: &[Self]) -> Self {
+                use ::algebra::Widening;
+                use ::algebra::reduce::Reduce;
+
+                // the products are summed into three limbs and reduced once,
+                // unless the top limb is about to overflow
+                let mut result = Self(0);
+                let mut limbs: [#field_ty; 3] = [0; 3];
+                for (x, y) in a.iter().zip(b) {
+                    let (low, high) = x.0.widen_mul(y.0);
+                    let (low, carry) = limbs[0].carry_add(low, false);
+                    let (high, carry) = limbs[1].carry_add(high, carry);
+                    limbs = [low, high, limbs[2] + carry as #field_ty];
+
+                    if limbs[2] == #field_ty::MAX {
+                        result += Self(limbs.as_slice().reduce(<Self as ::algebra::ModulusConfig>::MODULUS));
+                        limbs = [0; 3];
+                    }
+                }
+                result + Self(limbs.as_slice().reduce(<Self as ::algebra::ModulusConfig>::MODULUS))
+            }
+
             #[inline]
             fn mul_fast(self, rhs: Self) -> Self {
                 use ::algebra::reduce::LazyMulReduce;
@@ -270,6 +338,8 @@ fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> T
                 self.0 = a.0.carry_mul(b.0, self.0).lazy_reduce(<Self as ::algebra::ModulusConfig>::MODULUS);
             }
 
+            #slice_kernels
+
             #[inline]
             fn cast_into_usize(self) -> usize {
                 ::num_traits::cast::<#field_ty, usize>(self.0).unwrap()
@@ -280,6 +350,11 @@ fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> T
                 Self::new(::num_traits::cast::<usize, #field_ty>(value).unwrap())
             }
 
+            #[inline]
+            fn scale_round<T: ::algebra::Field>(self) -> T {
+                #scale_round
+            }
+
             #[inline]
             fn to_f64(self) -> f64 {
                 self.0 as f64
@@ -306,16 +381,14 @@ fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> T
                 ::algebra::div_ceil(<Self as ::algebra::ModulusConfig>::MODULUS.bit_count(), basis.trailing_zeros()) as usize
             }
 
-            fn decompose(self, basis: ::algebra::Basis<Self>) -> Vec<Self> {
+            fn decompose(self, basis: ::algebra::Basis<Self>) -> ::algebra::__private::Vec<Self> {
                 let mut temp = self.0;
 
                 let len = basis.decompose_len();
-                let mask = basis.mask();
-                let bits = basis.bits();
 
-                let mut ret: Vec<Self> = vec![#name(0); len];
+                let mut ret: ::algebra::__private::Vec<Self> = ::algebra::__private::vec![#name(0); len];
 
-                for v in ret.iter_mut() {
+                for (v, (mask, bits)) in ret.iter_mut().zip(basis.levels()) {
                     if temp == 0 {
                         break;
                     }
@@ -329,10 +402,7 @@ fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> T
             fn decompose_at(self, basis: ::algebra::Basis<Self>, destination: &mut [Self]) {
                 let mut temp = self.0;
 
-                let mask = basis.mask();
-                let bits = basis.bits();
-
-                for v in destination {
+                for (v, (mask, bits)) in destination.iter_mut().zip(basis.levels()) {
                     if temp == 0 {
                         break;
                     }
@@ -354,5 +424,72 @@ fn impl_field(name: &proc_macro2::Ident, field_ty: &Type, modulus: &LitInt) -> T
                 self.0 >>= bits;
             }
         }
+
+        impl ::algebra::CanonicalOrd for #name {
+            #[inline]
+            fn canonical_cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        ::algebra::__impl_test_utils!(#name);
+
+        ::algebra::__impl_bigint!(#name);
+    }
+}
+
+/// The body of `Field::scale_round`, `⌊p * self / q⌉` for the modulus `p` of the target field.
+///
+/// A `u128` modulus on either side needs the numerator in a `U256`, divided by
+/// `U256::div_rem_ct` as `self` is secret when decrypting, others divide with
+/// the barrett modulus of `q` whenever the numerator fits in `u64`.
+fn scale_round(field_ty: &Type, modulus: &LitInt) -> TokenStream {
+    let is_u128 = field_ty.to_token_stream().to_string() == "u128";
+    let (q, value) = if is_u128 {
+        (quote!(Q), quote!(self.0))
+    } else {
+        (quote!((Q as u128)), quote!((self.0 as u128)))
+    };
+
+    let wide = quote! {
+        let numerator = ::algebra::U256::widening_mul(target, #value)
+            .overflowing_add(::algebra::U256::from(#q >> 1))
+            .0;
+        let rounded = numerator.div_rem_ct(#q).0.low();
+        // the values just below `q` are centered just below `0`, and round up to `p`
+        let rounded = u128::conditional_select(&rounded, &0, rounded.ct_eq(&target));
+        T::new(::num_traits::cast(rounded).unwrap())
+    };
+
+    if is_u128 {
+        return quote! {
+            use ::algebra::__private::subtle::{ConditionallySelectable, ConstantTimeEq};
+
+            const Q: u128 = #modulus;
+            let target = ::num_traits::ToPrimitive::to_u128(&<T as ::algebra::ModulusConfig>::MODULUS_VALUE).unwrap();
+            #wide
+        };
+    }
+
+    quote! {
+        use ::algebra::__private::subtle::{ConditionallySelectable, ConstantTimeEq};
+
+        const Q: u64 = #modulus as #field_ty as u64;
+        const MODULUS: ::algebra::modulus::BarrettModulus<u64> =
+            ::algebra::modulus::BarrettModulus::<u64>::new(Q);
+        let target = ::num_traits::ToPrimitive::to_u128(&<T as ::algebra::ModulusConfig>::MODULUS_VALUE).unwrap();
+        let Ok(target) = u64::try_from(target) else {
+            return { #wide };
+        };
+        let value = self.0 as u64;
+        // whether the numerator fits in `u64` only depends on the moduli, not on `self`
+        let rounded = if target.checked_mul(Q - 1).and_then(|x| x.checked_add(Q >> 1)).is_some() {
+            MODULUS.div_rem_ct(target * value + (Q >> 1)).0
+        } else {
+            ((target as u128 * value as u128 + (Q as u128 >> 1)) / Q as u128) as u64
+        };
+        // the values just below `q` are centered just below `0`, and round up to `p`
+        let rounded = u64::conditional_select(&rounded, &0, rounded.ct_eq(&target));
+        T::new(::num_traits::cast(rounded).unwrap())
     }
 }
@@ -19,12 +19,15 @@ use syn::{parse_macro_input, DeriveInput};
 /// This also generates some computation for it, e.g.
 /// `Add`, `Sub`, `Mul`, `Neg`, `Pow`, `Div` and `Inv`.
 ///
-/// By the way, it also generates impl of the trait `Zero`, `One`, `Display`.
+/// By the way, it also generates impl of the trait `Zero`, `One`, `Display` and `CanonicalOrd`.
 ///
 /// And it will generate impl of the trait
 /// `Clone`, `Copy`, `Debug`, `Default`, `Eq`, `PartialEq`, `PartialOrd`, `Ord`.
 ///
-/// It can used for unnamed struct with only one element of `u8`, `u16`, `u32`, `u64`.
+/// With the `test-utils` feature of `algebra`, it also implements
+/// `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary`.
+///
+/// It can used for unnamed struct with only one element of `u8`, `u16`, `u32`, `u64`, `u128`.
 ///
 /// # Example
 ///
@@ -16,17 +16,15 @@ fn impl_ntt(input: Input) -> TokenStream {
     let modulus = input.attrs.modulus.unwrap();
 
     let ntt_table = format_ident!("NTT_TABLE{}", name.to_string().to_uppercase());
-    let ntt_mutex = format_ident!("NTT_MUTEX{}", name.to_string().to_uppercase());
 
     quote! {
-        static mut #ntt_table: ::once_cell::sync::OnceCell<::std::collections::HashMap<u32, ::std::sync::Arc<<#name as ::algebra::NTTField>::Table>>>
-            = ::once_cell::sync::OnceCell::new();
-        static #ntt_mutex: ::std::sync::Mutex<()> = ::std::sync::Mutex::new(());
+        static #ntt_table: ::algebra::transformation::NTTTableCache<<#name as ::algebra::NTTField>::Table>
+            = ::algebra::transformation::NTTTableCache::new();
 
         impl ::algebra::NTTField for #name {
             type Table = ::algebra::transformation::NTTTable<Self>;
 
-            type Root = ::algebra::modulus::ShoupFactor<<Self as ::algebra::Field>::Value>;
+            type Root = ::algebra::modulus::ShoupFactor<<Self as ::algebra::ModulusConfig>::Value>;
 
             type Degree = #field_ty;
 
@@ -77,29 +75,40 @@ fn impl_ntt(input: Input) -> TokenStream {
                 // (p-1) must be divisible by n
                 if modulus_sub_one != quotient * degree {
                     return Err(::algebra::AlgebraError::NoPrimitiveRoot {
-                        degree: degree.to_string(),
-                        modulus: #modulus.to_string(),
+                        degree: ::algebra::__private::ToString::to_string(&degree),
+                        modulus: ::algebra::__private::ToString::to_string(&#modulus),
                     });
                 }
 
-                let mut rng = ::rand::thread_rng();
-                let distr = ::rand::distributions::Uniform::new_inclusive(Self(2), Self(#modulus - 1));
-
                 let mut w = Self(0);
 
-                if (0..100).any(|_| {
-                    w = ::num_traits::Pow::pow(::rand::Rng::sample(&mut rng, distr), quotient);
+                if (2..#modulus).any(|g| {
+                    w = ::num_traits::Pow::pow(Self(g), quotient);
                     Self::is_primitive_root(w, degree)
                 }) {
                     Ok(w)
                 } else {
                     Err(::algebra::AlgebraError::NoPrimitiveRoot {
-                        degree: degree.to_string(),
-                        modulus: #modulus.to_string(),
+                        degree: ::algebra::__private::ToString::to_string(&degree),
+                        modulus: ::algebra::__private::ToString::to_string(&#modulus),
                     })
                 }
             }
 
+            fn primitive_roots(degree: Self::Degree) -> Result<::algebra::__private::Vec<Self>, ::algebra::AlgebraError> {
+                let root = Self::try_primitive_root(degree)?;
+                let root_sq = (root * root).to_root();
+
+                let mut power = root;
+                Ok((0..degree >> 1)
+                    .map(|_| {
+                        let current = power;
+                        power.mul_root_assign(root_sq);
+                        current
+                    })
+                    .collect())
+            }
+
             fn try_minimal_primitive_root(degree: Self::Degree) -> Result<Self, ::algebra::AlgebraError> {
                 let mut root = Self::try_primitive_root(degree)?;
 
@@ -127,7 +136,7 @@ fn impl_ntt(input: Input) -> TokenStream {
                 let root_factor = root.to_root();
                 let mut power = root;
 
-                let mut ordinal_root_powers = vec![Self::Root::default(); n * 2];
+                let mut ordinal_root_powers = ::algebra::__private::vec![Self::Root::default(); n * 2];
                 let mut iter = ordinal_root_powers.iter_mut();
                 *iter.next().unwrap() = root_one;
                 *iter.next().unwrap() = root_factor;
@@ -143,50 +152,21 @@ fn impl_ntt(input: Input) -> TokenStream {
                 ))
             }
 
-            fn get_ntt_table(log_n: u32) -> Result<::std::sync::Arc<Self::Table>, ::algebra::AlgebraError> {
-                if let Some(tables) = unsafe { #ntt_table.get() } {
-                    if let Some(t) = tables.get(&log_n) {
-                        return Ok(::std::sync::Arc::clone(t));
-                    }
+            fn get_ntt_table(log_n: u32) -> Result<::algebra::__private::Arc<Self::Table>, ::algebra::AlgebraError> {
+                if let Some(table) = #ntt_table.get(log_n) {
+                    return Ok(table);
                 }
 
                 Self::init_ntt_table(&[log_n])?;
-                Ok(::std::sync::Arc::clone(unsafe {
-                    #ntt_table.get().unwrap().get(&log_n).unwrap()
-                }))
+                #ntt_table.get(log_n).ok_or(::algebra::AlgebraError::NTTTableError)
             }
 
             fn init_ntt_table(log_ns: &[u32]) -> Result<(), ::algebra::AlgebraError> {
-                let _g = #ntt_mutex.lock().unwrap();
-                match unsafe { #ntt_table.get_mut() } {
-                    Some(tables) => {
-                        let new_log_ns: ::std::collections::HashSet<u32> = log_ns.iter().copied().collect();
-                        let old_log_ns: ::std::collections::HashSet<u32> = tables.keys().copied().collect();
-                        let difference = new_log_ns.difference(&old_log_ns);
-
-                        for &log_n in difference {
-                            let temp_table = Self::generate_ntt_table(log_n)?;
-                            tables.insert(log_n, ::std::sync::Arc::new(temp_table));
-                        }
-
-                        Ok(())
-                    }
-                    None => {
-                        let log_ns: ::std::collections::HashSet<u32> = log_ns.iter().copied().collect();
-                        let mut map = ::std::collections::HashMap::with_capacity(log_ns.len());
-
-                        for log_n in log_ns {
-                            let temp_table = Self::generate_ntt_table(log_n)?;
-                            map.insert(log_n, ::std::sync::Arc::new(temp_table));
-                        }
-
-                        if unsafe { #ntt_table.set(map).is_err() } {
-                            Err(::algebra::AlgebraError::NTTTableError)
-                        } else {
-                            Ok(())
-                        }
-                    }
-                }
+                #ntt_table.insert_with(log_ns, Self::generate_ntt_table)
+            }
+
+            fn insert_ntt_table(table: Self::Table) {
+                #ntt_table.insert(table.coeff_count_power(), table)
             }
         }
     }
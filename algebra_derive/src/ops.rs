@@ -5,15 +5,18 @@ use syn::{LitInt, Type};
 pub(crate) fn barrett(name: &Ident, field_ty: &Type, modulus: &LitInt) -> TokenStream {
     quote! {
         impl ::algebra::ModulusConfig for #name {
+            type Value = #field_ty;
             type Modulus = ::algebra::modulus::BarrettModulus<#field_ty>;
             const MODULUS: Self::Modulus = Self::Modulus::new(#modulus);
+            const MODULUS_VALUE: Self::Value = #modulus;
+            const MODULUS_BITS: u32 = <#field_ty>::BITS - (#modulus as #field_ty).leading_zeros();
         }
     }
 }
 
 pub(crate) fn add_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
     quote! {
-        impl ::std::ops::Add<Self> for #name {
+        impl ::core::ops::Add<Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -23,7 +26,7 @@ pub(crate) fn add_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
             }
         }
 
-        impl ::std::ops::Add<&Self> for #name {
+        impl ::core::ops::Add<&Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -33,7 +36,7 @@ pub(crate) fn add_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
             }
         }
 
-        impl ::std::ops::AddAssign<Self> for #name {
+        impl ::core::ops::AddAssign<Self> for #name {
             #[inline]
             fn add_assign(&mut self, rhs: Self) {
                 use ::algebra::reduce::AddReduceAssign;
@@ -41,7 +44,7 @@ pub(crate) fn add_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
             }
         }
 
-        impl ::std::ops::AddAssign<&Self> for #name {
+        impl ::core::ops::AddAssign<&Self> for #name {
             #[inline]
             fn add_assign(&mut self, rhs: &Self) {
                 use ::algebra::reduce::AddReduceAssign;
@@ -53,7 +56,7 @@ pub(crate) fn add_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
 
 pub(crate) fn sub_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
     quote! {
-        impl ::std::ops::Sub<Self> for #name {
+        impl ::core::ops::Sub<Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -63,7 +66,7 @@ pub(crate) fn sub_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
             }
         }
 
-        impl ::std::ops::Sub<&Self> for #name {
+        impl ::core::ops::Sub<&Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -73,7 +76,7 @@ pub(crate) fn sub_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
             }
         }
 
-        impl ::std::ops::SubAssign<Self> for #name {
+        impl ::core::ops::SubAssign<Self> for #name {
             #[inline]
             fn sub_assign(&mut self, rhs: Self) {
                 use ::algebra::reduce::SubReduceAssign;
@@ -81,7 +84,7 @@ pub(crate) fn sub_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
             }
         }
 
-        impl ::std::ops::SubAssign<&Self> for #name {
+        impl ::core::ops::SubAssign<&Self> for #name {
             #[inline]
             fn sub_assign(&mut self, rhs: &Self) {
                 use ::algebra::reduce::SubReduceAssign;
@@ -93,7 +96,7 @@ pub(crate) fn sub_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
 
 pub(crate) fn mul_reduce_ops(name: &Ident) -> TokenStream {
     quote! {
-        impl ::std::ops::Mul<Self> for #name {
+        impl ::core::ops::Mul<Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -103,7 +106,7 @@ pub(crate) fn mul_reduce_ops(name: &Ident) -> TokenStream {
             }
         }
 
-        impl ::std::ops::Mul<&Self> for #name {
+        impl ::core::ops::Mul<&Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -113,7 +116,7 @@ pub(crate) fn mul_reduce_ops(name: &Ident) -> TokenStream {
             }
         }
 
-        impl ::std::ops::MulAssign<Self> for #name {
+        impl ::core::ops::MulAssign<Self> for #name {
             #[inline]
             fn mul_assign(&mut self, rhs: Self) {
                 use ::algebra::reduce::MulReduceAssign;
@@ -121,7 +124,7 @@ pub(crate) fn mul_reduce_ops(name: &Ident) -> TokenStream {
             }
         }
 
-        impl ::std::ops::MulAssign<&Self> for #name {
+        impl ::core::ops::MulAssign<&Self> for #name {
             #[inline]
             fn mul_assign(&mut self, rhs: &Self) {
                 use ::algebra::reduce::MulReduceAssign;
@@ -133,7 +136,7 @@ pub(crate) fn mul_reduce_ops(name: &Ident) -> TokenStream {
 
 pub(crate) fn neg_reduce_ops(name: &Ident, modulus: &LitInt) -> TokenStream {
     quote! {
-        impl ::std::ops::Neg for #name {
+        impl ::core::ops::Neg for #name {
             type Output = Self;
 
             #[inline]
@@ -161,7 +164,7 @@ pub(crate) fn pow_reduce_ops(name: &Ident) -> TokenStream {
 
 pub(crate) fn div_reduce_ops(name: &Ident) -> TokenStream {
     quote! {
-        impl ::std::ops::Div<Self> for #name {
+        impl ::core::ops::Div<Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -171,7 +174,7 @@ pub(crate) fn div_reduce_ops(name: &Ident) -> TokenStream {
             }
         }
 
-        impl ::std::ops::Div<&Self> for #name {
+        impl ::core::ops::Div<&Self> for #name {
             type Output = Self;
 
             #[inline]
@@ -181,7 +184,7 @@ pub(crate) fn div_reduce_ops(name: &Ident) -> TokenStream {
             }
         }
 
-        impl ::std::ops::DivAssign<Self> for #name {
+        impl ::core::ops::DivAssign<Self> for #name {
             #[inline]
             fn div_assign(&mut self, rhs: Self) {
                 use ::algebra::reduce::DivReduceAssign;
@@ -189,7 +192,7 @@ pub(crate) fn div_reduce_ops(name: &Ident) -> TokenStream {
             }
         }
 
-        impl ::std::ops::DivAssign<&Self> for #name {
+        impl ::core::ops::DivAssign<&Self> for #name {
             #[inline]
             fn div_assign(&mut self, rhs: &Self) {
                 use ::algebra::reduce::DivReduceAssign;
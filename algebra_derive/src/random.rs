@@ -10,15 +10,32 @@ pub(super) fn derive(input: &DeriveInput) -> Result<TokenStream> {
     Ok(impl_random(input))
 }
 
-fn standard(name: &Ident, standard_name: &Ident) -> TokenStream {
+fn standard(name: &Ident) -> TokenStream {
     quote! {
-        static #standard_name: ::once_cell::sync::Lazy<::rand::distributions::Uniform<#name>> =
-            ::once_cell::sync::Lazy::new(|| ::rand::distributions::Uniform::new_inclusive(#name(0), #name::max()));
-
         impl ::rand::distributions::Distribution<#name> for ::rand::distributions::Standard {
             #[inline]
             fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #name {
-                #standard_name.sample(rng)
+                let distr = <#name as ::algebra::Random>::standard_distribution();
+                ::rand::distributions::Distribution::sample(&distr, rng)
+            }
+        }
+    }
+}
+
+fn uniform_masked(name: &Ident, field_ty: &syn::Type, modulus: &syn::LitInt) -> TokenStream {
+    quote! {
+        impl ::rand::distributions::Distribution<#name> for ::algebra::FieldUniformSampler {
+            #[inline]
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #name {
+                const MODULUS: #field_ty = #modulus;
+                // the bits of `MODULUS - 1`, which is the largest value
+                const MASK: #field_ty = <#field_ty>::MAX >> (MODULUS - 1).leading_zeros();
+                loop {
+                    let value = ::rand::Rng::gen::<#field_ty>(rng) & MASK;
+                    if value < MODULUS {
+                        return #name(value);
+                    }
+                }
             }
         }
     }
@@ -46,6 +63,26 @@ fn ternary(name: &Ident, modulus: &syn::LitInt) -> TokenStream {
     }
 }
 
+fn bounded(name: &Ident, field_ty: &syn::Type, modulus: &syn::LitInt) -> TokenStream {
+    quote! {
+        impl ::rand::distributions::Distribution<#name> for ::algebra::FieldBoundedSampler {
+            #[inline]
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #name {
+                let bound = self.bound();
+                // the bound is a `u64`, while the modulus may only fit in `u128`
+                let modulus = #modulus as u128;
+                let value = ::rand::Rng::gen_range(rng, 0..=2 * bound);
+                if value >= bound {
+                    #name(((value - bound) as u128 % modulus) as #field_ty)
+                } else {
+                    let minus_value = (bound - value) as u128 % modulus;
+                    #name(((modulus - minus_value) % modulus) as #field_ty)
+                }
+            }
+        }
+    }
+}
+
 fn uniform(name: &Ident, field_ty: &syn::Type, modulus: &syn::LitInt) -> TokenStream {
     let sample_name = format_ident!("Uniform{}", name);
     quote! {
@@ -100,11 +137,11 @@ fn gaussian(name: &Ident, field_ty: &syn::Type, modulus: &syn::LitInt) -> TokenS
                 let mean = self.mean();
                 let gaussian = self.gaussian();
                 loop {
-                    let value = gaussian.sample(rng);
-                    if (value - mean).abs() < self.max_std_dev() {
-                        let round = value.round();
+                    let value = ::rand::distributions::Distribution::sample(&gaussian, rng);
+                    if ::num_traits::Float::abs(value - mean) < self.max_std_dev() {
+                        let round = ::num_traits::Float::round(value);
                         if round < 0. {
-                            return #name((#modulus as f64 + value) as #field_ty);
+                            return #name((#modulus as #field_ty as f64 + value) as #field_ty);
                         } else {
                             return #name(value as #field_ty);
                         }
@@ -120,21 +157,25 @@ fn impl_random(input: Input) -> TokenStream {
     let modulus = input.attrs.modulus.unwrap();
     let field_ty = input.field.ty;
 
-    let standard_name = format_ident!("STANDARD_{}", name.to_string().to_uppercase());
-
-    let impl_standard = standard(name, &standard_name);
+    let impl_standard = standard(name);
+    let impl_uniform_masked = uniform_masked(name, field_ty, &modulus);
     let impl_binary = binary(name, field_ty);
     let impl_ternary = ternary(name, &modulus);
+    let impl_bounded = bounded(name, field_ty, &modulus);
     let impl_uniform = uniform(name, field_ty, &modulus);
     let impl_gaussian = gaussian(name, field_ty, &modulus);
 
     quote! {
         #impl_standard
 
+        #impl_uniform_masked
+
         #impl_binary
 
         #impl_ternary
 
+        #impl_bounded
+
         #impl_uniform
 
         #impl_gaussian
@@ -144,19 +185,19 @@ fn impl_random(input: Input) -> TokenStream {
             #[inline]
             pub fn random<R>(rng: &mut R) -> Self
             where
-                R: ::rand::Rng + ::rand::CryptoRng,
+                R: ::algebra::CsRng,
             {
                 use ::rand::distributions::Distribution;
-                #standard_name.sample(rng)
+                <Self as ::algebra::Random>::standard_distribution().sample(rng)
             }
         }
 
         impl ::algebra::Random for #name {
-            type StandardDistribution = ::rand::distributions::Uniform<#name>;
+            type StandardDistribution = ::algebra::FieldUniformSampler;
 
             #[inline]
             fn standard_distribution() -> Self::StandardDistribution {
-                #standard_name.clone()
+                ::algebra::FieldUniformSampler
             }
 
             #[inline]
@@ -169,6 +210,11 @@ fn impl_random(input: Input) -> TokenStream {
                 ::algebra::FieldTernarySampler
             }
 
+            #[inline]
+            fn bounded_sampler(bound: u64) -> Result<::algebra::FieldBoundedSampler, ::algebra::AlgebraError> {
+                ::algebra::FieldBoundedSampler::new(bound)
+            }
+
             #[inline]
             fn gaussian_sampler(
                 mean: f64,
@@ -16,22 +16,22 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     let msg = BFVPlaintext(msg);
     c.bench_function("encrypt", |b| {
         b.iter(|| {
-            BFVScheme::encrypt(&ctx, &pk, &msg);
+            BFVScheme::encrypt(&ctx, &pk, &msg).unwrap();
         });
     });
 
-    let ctxt = BFVScheme::encrypt(&ctx, &pk, &msg);
+    let ctxt = BFVScheme::encrypt(&ctx, &pk, &msg).unwrap();
 
     c.bench_function("decrypt", |b| {
         b.iter(|| {
-            BFVScheme::decrypt(&ctx, &sk, &ctxt);
+            BFVScheme::decrypt(&ctx, &sk, &ctxt).unwrap();
         });
     });
 
     let msg2 = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
     let msg2 = BFVPlaintext(msg2);
 
-    let ctxt2 = BFVScheme::encrypt(&ctx, &pk, &msg2);
+    let ctxt2 = BFVScheme::encrypt(&ctx, &pk, &msg2).unwrap();
 
     c.bench_function("add", |b| {
         b.iter(|| {
@@ -60,11 +60,11 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     }
     let ctxts: Vec<BFVCiphertext> = msgs
         .iter()
-        .map(|m| BFVScheme::encrypt(&ctx, &pk, m))
+        .map(|m| BFVScheme::encrypt(&ctx, &pk, m).unwrap())
         .collect();
     c.bench_function("inner-product-20", |b| {
         b.iter(|| {
-            BFVScheme::evaluate_inner_product(&ctx, &ctxts, &scalars);
+            BFVScheme::evaluate_inner_product(&ctx, &ctxts, &scalars).unwrap();
         });
     });
 }
@@ -1,5 +1,5 @@
 use algebra::{Field, Polynomial};
-use bfv::{BFVPlaintext, PlainField, ThresholdPKE};
+use bfv::{BFVPlaintext, CombineProof, PlainField, ThresholdPKE};
 use criterion::{criterion_group, criterion_main, Criterion};
 
 pub fn criterion_benchmark(c: &mut Criterion) {
@@ -32,29 +32,44 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     let msg = BFVPlaintext(msg);
 
     c.bench_function("tpke_encrypt_(2,3)", |b| {
-        b.iter(|| ThresholdPKE::encrypt(&ctx, &pks, &msg));
+        b.iter(|| ThresholdPKE::encrypt(&ctx, &pks, &msg).unwrap());
     });
 
-    let ctxt = ThresholdPKE::encrypt(&ctx, &pks, &msg);
+    let ctxt = ThresholdPKE::encrypt(&ctx, &pks, &msg).unwrap();
 
     c.bench_function("tpke_re_encrypt_(2,3)", |b| {
-        b.iter(|| ThresholdPKE::re_encrypt(&ctx, &ctxt[0], &sk0, &pk));
+        b.iter(|| ThresholdPKE::re_encrypt(&ctx, &ctxt[0], &sk0, &pk).unwrap());
     });
 
-    let c0 = ThresholdPKE::re_encrypt(&ctx, &ctxt[0], &sk0, &pk);
-    let c1 = ThresholdPKE::re_encrypt(&ctx, &ctxt[1], &sk1, &pk);
+    let c0 = ThresholdPKE::re_encrypt(&ctx, &ctxt[0], &sk0, &pk).unwrap();
+    let c1 = ThresholdPKE::re_encrypt(&ctx, &ctxt[1], &sk1, &pk).unwrap();
 
     let ctxts = [c0, c1].to_vec();
     let chosen_indices = [F::new(1), F::new(2)].to_vec();
 
     c.bench_function("tpke_combine_(2,3)", |b| {
-        b.iter(|| ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices));
+        b.iter(|| ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices).unwrap());
     });
 
-    let ctxt = ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices);
+    let (ctxt, proof) = ThresholdPKE::combine_with_proof(&ctx, &ctxts, &chosen_indices).unwrap();
+
+    // the verifier holds the shares, so the baseline is to recompute the combination
+    c.bench_function("tpke_recompute_combine_(2,3)", |b| {
+        b.iter(|| ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices).unwrap() == ctxt);
+    });
+
+    c.bench_function("tpke_verify_combine_(2,3)", |b| {
+        b.iter(|| ThresholdPKE::verify_combine(&ctx, &ctxts, &chosen_indices, &ctxt, &proof));
+    });
+
+    // the checks left to a verifier who settles the claim on the shares otherwise
+    let digest = CombineProof::shares_digest(&ctxts);
+    c.bench_function("tpke_reduce_combine_(2,3)", |b| {
+        b.iter(|| proof.reduce(&digest, &chosen_indices, &ctxt).unwrap());
+    });
 
     c.bench_function("tpke_decrypt_(2,3)", |b| {
-        b.iter(|| ThresholdPKE::decrypt(&ctx, &sk, &ctxt));
+        b.iter(|| ThresholdPKE::decrypt(&ctx, &sk, &ctxt).unwrap());
     });
 }
 
@@ -0,0 +1,68 @@
+//! Define the audit log of the threshold operations of a decryption session.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{ThresholdPKEContext, Transcript};
+
+/// An entry of an [`AuditLog`], the SHA-256 digest of a consumed object with its label.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// What the object is, such as `"share"` or `"combined"`.
+    pub label: String,
+    /// The SHA-256 digest of the canonical encoding of the object.
+    pub digest: [u8; 32],
+}
+
+/// A log of the parameters, shares, proofs and results consumed by the audited
+/// combinations of [`crate::ThresholdPKE`], such as [`crate::ThresholdPKE::aggregate_audited`].
+///
+/// The entries and the final [`digest`](Self::digest) can be published: a third party holding
+/// the shares recomputes the entries, and the digest binds all of them in order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLog {
+    entries: Vec<AuditEntry>,
+}
+
+impl AuditLog {
+    /// Start the log of the session `session_id`, recording the parameters of `ctx`.
+    pub fn new(ctx: &ThresholdPKEContext, session_id: &[u8]) -> Self {
+        let mut log = Self {
+            entries: Vec::new(),
+        };
+        log.record("session", session_id);
+        log.entries.push(AuditEntry {
+            label: "parameters".into(),
+            digest: ctx.parameter_digest(),
+        });
+        log
+    }
+
+    /// Record the canonical encoding `bytes` of an object under `label`.
+    pub fn record(&mut self, label: &str, bytes: &[u8]) {
+        self.entries.push(AuditEntry {
+            label: label.into(),
+            digest: Sha256::digest(bytes).into(),
+        });
+    }
+
+    /// Returns the entries in the order they are recorded.
+    #[inline]
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Returns the digest of the transcript of all the entries.
+    pub fn digest(&self) -> [u8; 32] {
+        Self::digest_entries(&self.entries)
+    }
+
+    /// Returns the digest of the transcript of `entries`, for verifying a published log.
+    pub fn digest_entries(entries: &[AuditEntry]) -> [u8; 32] {
+        let mut transcript = Transcript::new(b"threshold-zk-lhe audit log");
+        for entry in entries {
+            transcript.append_message(entry.label.as_bytes(), &entry.digest);
+        }
+        transcript.challenge_bytes(b"digest")
+    }
+}
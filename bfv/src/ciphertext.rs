@@ -1,10 +1,15 @@
 //! Define the ciphertext of BFV.
 use algebra::{
     derive::{Field, Prime, Random, NTT},
-    Polynomial,
+    NTTPolynomial, Polynomial,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    protocol::Reader, scheme::scale_plaintext_into, BFVContext, BFVError, BFVPlaintext,
+    LweCiphertext,
+};
+
 /// The default rlwe dimension.
 pub const DIMENSION_N: usize = 1024;
 
@@ -13,65 +18,268 @@ pub const DIMENSION_N: usize = 1024;
 #[modulus = 132120577]
 pub struct CipherField(u32);
 impl CipherField {
-    ///
+    /// Convert `self` into big-endian bytes.
     pub fn to_bytes(&self) -> [u8; 4] {
         self.0.to_be_bytes()
     }
-    ///
+    /// Create a new instance from big-endian bytes.
     pub fn from_bytes(v: [u8; 4]) -> Self {
         Self(u32::from_be_bytes(v))
     }
 }
 
+/// The domain in which the polynomials of a ciphertext or a public key are serialized,
+/// marked by a flag byte.
+///
+/// A receiver who will operate homomorphically in the NTT domain takes the evaluations,
+/// so no transform is paid on either side when both keep the NTT form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Domain {
+    /// The coefficients, flag `0`.
+    Coefficient,
+    /// The evaluations of the NTT form, flag `1`.
+    Evaluation,
+}
+
+impl Domain {
+    #[inline]
+    pub(crate) fn flag(self) -> u8 {
+        match self {
+            Self::Coefficient => 0,
+            Self::Evaluation => 1,
+        }
+    }
+
+    #[inline]
+    fn from_flag(flag: u8) -> Option<Self> {
+        match flag {
+            0 => Some(Self::Coefficient),
+            1 => Some(Self::Evaluation),
+            _ => None,
+        }
+    }
+}
+
 /// Define the ciphertext of BFV.
+///
+/// It decrypts to the noisy scaled message `c1 + c2 * s`.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct BFVCiphertext(pub [Polynomial<CipherField>; 2]);
+pub struct BFVCiphertext {
+    /// The component carrying the message.
+    pub c1: Polynomial<CipherField>,
+    /// The component multiplied by the secret key.
+    pub c2: Polynomial<CipherField>,
+}
 
 impl BFVCiphertext {
+    /// Creates a new instance from the components `(c1, c2)`.
+    #[inline]
+    pub fn new(c1: Polynomial<CipherField>, c2: Polynomial<CipherField>) -> Self {
+        Self { c1, c2 }
+    }
+
+    /// Returns the components `[c1, c2]`, in the serialized order,
+    /// for the checks and maps which treat both alike.
+    #[inline]
+    pub(crate) fn polys(&self) -> [&Polynomial<CipherField>; 2] {
+        [&self.c1, &self.c2]
+    }
+
+    /// Returns the components as slices, in the serialized order.
+    #[inline]
+    fn slices(&self) -> [&[CipherField]; 2] {
+        self.polys().map(|p| p.as_slice())
+    }
+
     /// Serialize to `Vec<u8>`
     pub fn to_vec(&self) -> Vec<u8> {
-        // layout: |len0,len1|data0,data1|
         let mut bytes = vec![];
+        write_polynomial_pair(&mut bytes, self.slices());
+        bytes
+    }
 
-        // length(2)
-        for polys in self.0.iter() {
-            let len = polys.coeff_count() as u32;
-            bytes.extend(len.to_be_bytes());
+    /// Serialize to `Vec<u8>` in `domain`, with the layout `|flag|len0,len1|data0,data1|`.
+    pub fn to_vec_with_domain(&self, domain: Domain) -> Vec<u8> {
+        match domain {
+            Domain::Coefficient => {
+                let mut bytes = vec![domain.flag()];
+                write_polynomial_pair(&mut bytes, self.slices());
+                bytes
+            }
+            Domain::Evaluation => BFVNTTCiphertext::from(self.clone()).to_vec_with_domain(domain),
         }
+    }
 
-        // data
-        for polys in self.0.iter() {
-            for data in polys.iter() {
-                bytes.extend(data.to_bytes());
+    /// Deserialize from [u8] serialized by [`Self::to_vec_with_domain`] in either domain.
+    pub fn from_vec_with_domain(bytes: &[u8]) -> Result<Self, BFVError> {
+        match read_flagged_pair(bytes, Domain::Coefficient) {
+            Some((Domain::Coefficient, [c1, c2])) => {
+                Ok(Self::new(Polynomial::new(c1), Polynomial::new(c2)))
             }
+            Some((Domain::Evaluation, [c1, c2])) => {
+                Ok(BFVNTTCiphertext::new(NTTPolynomial::new(c1), NTTPolynomial::new(c2)).into())
+            }
+            None => Err(BFVError::SerializationError("ciphertext")),
         }
+    }
 
-        bytes
+    /// Encrypt `m` trivially as `(round(q * m / t), 0)`, without randomness nor noise.
+    ///
+    /// It decrypts under any secret key and hides nothing, for public constants of homomorphic expressions.
+    pub fn trivial_encrypt(ctx: &BFVContext, m: &BFVPlaintext) -> Self {
+        let n = ctx.rlwe_dimension();
+        assert_eq!(
+            m.0.coeff_count(),
+            n,
+            "the length of the message should be the rlwe dimension"
+        );
+        let mut c1 = Polynomial::zero(n);
+        scale_plaintext_into(&m.0, &mut c1);
+        Self::new(c1, Polynomial::zero(n))
+    }
+
+    /// Extract the LWE ciphertext of the `index`-th coefficient of the message.
+    ///
+    /// The `index`-th coefficient of `c2 * s` is `<a, s>` with `a[k] = c2[index - k]` for `k <= index`
+    /// and `a[k] = -c2[n + index - k]` otherwise, so `(a, c1[index])` decrypts under the coefficients of `s`.
+    pub fn extract_lwe(&self, index: usize) -> LweCiphertext {
+        let Self { c1, c2 } = self;
+        let n = c2.coeff_count();
+        assert!(
+            index < n,
+            "the index should be less than the rlwe dimension"
+        );
+
+        let a = (0..n)
+            .map(|k| {
+                if k <= index {
+                    c2[index - k]
+                } else {
+                    -c2[n + index - k]
+                }
+            })
+            .collect();
+        LweCiphertext::new(a, c1[index])
     }
 
     /// Deserialize from [u8]
-    pub fn from_vec(bytes: &[u8]) -> Self {
-        let mut iter = bytes
-            .chunks_exact(4)
-            .map(|chunk| <[u8; 4]>::try_from(chunk).unwrap());
-
-        // length(2)
-        let len0 = u32::from_be_bytes(iter.next().unwrap());
-        let len1 = u32::from_be_bytes(iter.next().unwrap());
-
-        // data
-        let mut data0 = vec![];
-        let mut data1 = vec![];
-        for _ in 0..len0 {
-            data0.push(CipherField::from_bytes(iter.next().unwrap()));
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        read_polynomial_pair(bytes)
+            .map(|[c1, c2]| Self::new(c1, c2))
+            .ok_or(BFVError::SerializationError("ciphertext"))
+    }
+}
+
+/// Write the layout `|len0,len1|data0,data1|` of a pair of polynomials.
+pub(crate) fn write_polynomial_pair(bytes: &mut Vec<u8>, polys: [&[CipherField]; 2]) {
+    for poly in polys {
+        bytes.extend((poly.len() as u32).to_be_bytes());
+    }
+    for poly in polys {
+        for data in poly {
+            bytes.extend(data.to_bytes());
+        }
+    }
+}
+
+/// Read the layout `|flag|len0,len1|data0,data1|` of a pair of polynomials in the domain of `flag`,
+/// to be kept in the domain `target`.
+///
+/// Unless both domains are the coefficient domain, the values are transformed or kept transformed,
+/// so they are only accepted with both lengths [`DIMENSION_N`],
+/// the only dimension whose transforms the parameters support.
+pub(crate) fn read_flagged_pair(
+    bytes: &[u8],
+    target: Domain,
+) -> Option<(Domain, [Vec<CipherField>; 2])> {
+    let (&flag, rest) = bytes.split_first()?;
+    let domain = Domain::from_flag(flag)?;
+    let polys = read_polynomial_pair(rest)?;
+    if (domain == Domain::Evaluation || target == Domain::Evaluation)
+        && polys.iter().any(|p| p.coeff_count() != DIMENSION_N)
+    {
+        return None;
+    }
+    Some((domain, polys.map(Polynomial::data)))
+}
+
+/// Read the layout `|len0,len1|data0,data1|` of a pair of polynomials,
+/// return `None` if the lengths do not match or a coefficient is not reduced.
+pub(crate) fn read_polynomial_pair(bytes: &[u8]) -> Option<[Polynomial<CipherField>; 2]> {
+    let mut reader = Reader::new(bytes);
+    let len0 = reader.u32()? as usize;
+    let len1 = reader.u32()? as usize;
+    let data_len = len0.checked_add(len1)?.checked_mul(4)?;
+    if reader.remaining() != data_len {
+        return None;
+    }
+
+    let mut poly = |len| {
+        (0..len)
+            .map(|_| reader.cipher())
+            .collect::<Option<Vec<_>>>()
+            .map(Polynomial::new)
+    };
+    Some([poly(len0)?, poly(len1)?])
+}
+
+/// Define the ciphertext of BFV in the NTT (evaluation) domain.
+///
+/// Additions and scalar multiplications act on it directly, and decryption saves the
+/// forward transform of the second component, so a chain of homomorphic evaluations
+/// only pays the transforms when converting from and to [`BFVCiphertext`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BFVNTTCiphertext {
+    /// The NTT form of [`BFVCiphertext::c1`].
+    pub c1: NTTPolynomial<CipherField>,
+    /// The NTT form of [`BFVCiphertext::c2`].
+    pub c2: NTTPolynomial<CipherField>,
+}
+
+impl BFVNTTCiphertext {
+    /// Creates a new instance from the components `(c1, c2)` in NTT form.
+    #[inline]
+    pub fn new(c1: NTTPolynomial<CipherField>, c2: NTTPolynomial<CipherField>) -> Self {
+        Self { c1, c2 }
+    }
+
+    /// Serialize to `Vec<u8>` in `domain`, see [`BFVCiphertext::to_vec_with_domain`].
+    pub fn to_vec_with_domain(&self, domain: Domain) -> Vec<u8> {
+        match domain {
+            Domain::Coefficient => BFVCiphertext::from(self.clone()).to_vec_with_domain(domain),
+            Domain::Evaluation => {
+                let mut bytes = vec![domain.flag()];
+                write_polynomial_pair(&mut bytes, [self.c1.as_slice(), self.c2.as_slice()]);
+                bytes
+            }
         }
-        for _ in 0..len1 {
-            data1.push(CipherField::from_bytes(iter.next().unwrap()));
+    }
+
+    /// Deserialize from [u8] serialized in either domain,
+    /// without any transform for the evaluation domain.
+    pub fn from_vec_with_domain(bytes: &[u8]) -> Result<Self, BFVError> {
+        match read_flagged_pair(bytes, Domain::Evaluation) {
+            Some((Domain::Coefficient, [c1, c2])) => {
+                Ok(BFVCiphertext::new(Polynomial::new(c1), Polynomial::new(c2)).into())
+            }
+            Some((Domain::Evaluation, [c1, c2])) => {
+                Ok(Self::new(NTTPolynomial::new(c1), NTTPolynomial::new(c2)))
+            }
+            None => Err(BFVError::SerializationError("ciphertext")),
         }
+    }
+}
+
+impl From<BFVCiphertext> for BFVNTTCiphertext {
+    #[inline]
+    fn from(BFVCiphertext { c1, c2 }: BFVCiphertext) -> Self {
+        Self::new(c1.into_ntt_polynomial(), c2.into_ntt_polynomial())
+    }
+}
 
-        Self([
-            Polynomial::<CipherField>::new(data0),
-            Polynomial::<CipherField>::new(data1),
-        ])
+impl From<BFVNTTCiphertext> for BFVCiphertext {
+    #[inline]
+    fn from(BFVNTTCiphertext { c1, c2 }: BFVNTTCiphertext) -> Self {
+        Self::new(c1.into_native_polynomial(), c2.into_native_polynomial())
     }
 }
@@ -0,0 +1,293 @@
+//! Define the proof of correct homomorphic combination.
+
+use std::rc::Rc;
+
+use algebra::{
+    sumcheck::{IPForMLSumcheck, ProverMsg},
+    DenseMultilinearExtension, Field, ListOfProductsOfPolynomials, MultilinearExtension,
+    PolynomialInfo,
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{BFVCiphertext, CipherField, PlainField, ThresholdPKE, Transcript};
+
+type F = CipherField;
+
+/// A proof that a combined ciphertext is the inner product of the ciphertext shares
+/// with the lagrange coefficients of the chosen indices, as computed by [`ThresholdPKE::combine`].
+///
+/// The verifier reduces the claim on every coefficient of the combined ciphertext to
+/// a claim on a random linear combination of them, which is then proved by the sumcheck protocol
+/// over the shares. The soundness error is about `(log(2n) + 2 log(k)) / q`
+/// for `k` shares of ciphertexts with dimension `n`.
+///
+/// The shares are only bound by their digest, see [`CombineProof::shares_digest`],
+/// and [`CombineProof::reduce`] checks the proof without them in `O(n + k)`,
+/// leaving a [`ShareClaim`] on the evaluation of the shares at a single random point.
+/// A verifier holding the shares settles it with [`ShareClaim::verify`] in `O(nk)`,
+/// and would rather recompute the combination, which is the same pass with smaller constants.
+/// The proof pays off when the claim is settled by an evaluation proof of a commitment to the shares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombineProof {
+    sumcheck_msgs: Vec<Vec<F>>,
+}
+
+impl CombineProof {
+    /// Prove that `combined` is the combination of `ctxts` at `chosen_indices`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn prove(
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[PlainField],
+        combined: &BFVCiphertext,
+    ) -> Self {
+        assert_eq!(
+            ctxts.len(),
+            chosen_indices.len(),
+            "the length of ctxts and chosen_indices should be equal"
+        );
+        let statement = Statement::new(
+            ctxts[0].c1.coeff_count() * 2,
+            chosen_indices,
+            Self::shares_digest(ctxts),
+        )
+        .expect("chosen_indices should be distinct nonzero indices");
+        let mut transcript = statement.transcript(combined);
+        let r_coeff: Vec<F> =
+            transcript.challenge_fields(b"coefficient point", statement.coeff_vars);
+
+        let shares = shares_extension(ctxts, statement.coeff_vars, statement.share_vars);
+        let shares = Rc::new(shares.fix_variables(&r_coeff));
+        let mut poly = ListOfProductsOfPolynomials::new(statement.share_vars);
+        poly.add_product([Rc::clone(&statement.lagrange), shares], F::ONE);
+
+        let mut state = IPForMLSumcheck::prover_init(&poly);
+        let mut challenge = None;
+        let mut sumcheck_msgs = Vec::with_capacity(statement.share_vars);
+        for _ in 0..statement.share_vars {
+            let ProverMsg { evaluations } = IPForMLSumcheck::prove_round(&mut state, challenge);
+            append_sumcheck_msg(&mut transcript, &evaluations);
+            challenge = Some(transcript.challenge_field(b"sumcheck"));
+            sumcheck_msgs.push(evaluations);
+        }
+
+        Self { sumcheck_msgs }
+    }
+
+    /// Verify that `combined` is the combination of `ctxts` at `chosen_indices`.
+    ///
+    /// It is [`CombineProof::reduce`] followed by [`ShareClaim::verify`],
+    /// so it rejects repeated or zero indices.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn verify(
+        &self,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[PlainField],
+        combined: &BFVCiphertext,
+    ) -> bool {
+        if ctxts.len() != chosen_indices.len() {
+            return false;
+        }
+        self.reduce(&Self::shares_digest(ctxts), chosen_indices, combined)
+            .is_some_and(|claim| claim.verify(ctxts))
+    }
+
+    /// Check the proof that `combined` is the combination at `chosen_indices` of
+    /// the shares of digest `shares_digest`, without the shares themselves.
+    ///
+    /// Return the claim on the shares left to check, or `None` if the proof is rejected
+    /// or `chosen_indices` are not distinct nonzero indices.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn reduce(
+        &self,
+        shares_digest: &[u8; 32],
+        chosen_indices: &[PlainField],
+        combined: &BFVCiphertext,
+    ) -> Option<ShareClaim> {
+        let coeff_count = combined.c1.coeff_count() * 2;
+        if chosen_indices.is_empty()
+            || !coeff_count.is_power_of_two()
+            || combined.c2.coeff_count() * 2 != coeff_count
+        {
+            return None;
+        }
+        let statement = Statement::new(coeff_count, chosen_indices, *shares_digest)?;
+        if self.sumcheck_msgs.len() != statement.share_vars {
+            return None;
+        }
+
+        let mut transcript = statement.transcript(combined);
+        let r_coeff: Vec<F> =
+            transcript.challenge_fields(b"coefficient point", statement.coeff_vars);
+        let asserted_sum = flatten(combined).evaluate(&r_coeff);
+
+        let info = PolynomialInfo {
+            max_multiplicands: 2,
+            num_variables: statement.share_vars,
+        };
+        let mut state = IPForMLSumcheck::verifier_init(&info);
+        for evaluations in self.sumcheck_msgs.iter() {
+            append_sumcheck_msg(&mut transcript, evaluations);
+            let challenge = transcript.challenge_field(b"sumcheck");
+            let msg = ProverMsg {
+                evaluations: evaluations.clone(),
+            };
+            IPForMLSumcheck::verify_round(&msg, &mut state, challenge);
+        }
+
+        let subclaim = IPForMLSumcheck::check_and_generate_subclaim(state, asserted_sum).ok()?;
+
+        Some(ShareClaim {
+            share_count: chosen_indices.len(),
+            coeff_vars: statement.coeff_vars,
+            lagrange_evaluation: statement.lagrange.evaluate(&subclaim.point),
+            point: r_coeff.into_iter().chain(subclaim.point).collect(),
+            expected_evaluation: subclaim.expected_evaluation,
+        })
+    }
+
+    /// Return the digest of the ciphertext shares, which binds them to the proof.
+    pub fn shares_digest(ctxts: &[BFVCiphertext]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(b"threshold-zk-lhe combine proof shares");
+        for share in ctxts.iter().map(BFVCiphertext::to_vec) {
+            hasher.update((share.len() as u64).to_be_bytes());
+            hasher.update(share);
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// The claim left by [`CombineProof::reduce`] on the ciphertext shares:
+/// the multilinear extension of the shares, flattened with the coefficients in the low variables
+/// and padded with zero shares to a power of two, evaluates at [`ShareClaim::point`]
+/// to a value accepted by [`ShareClaim::check`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareClaim {
+    share_count: usize,
+    coeff_vars: usize,
+    point: Vec<F>,
+    lagrange_evaluation: F,
+    expected_evaluation: F,
+}
+
+impl ShareClaim {
+    /// Return the point at which the extension of the shares is evaluated.
+    #[inline]
+    pub fn point(&self) -> &[F] {
+        &self.point
+    }
+
+    /// Check the claim given the evaluation of the extension of the shares at [`ShareClaim::point`].
+    #[inline]
+    pub fn check(&self, shares_evaluation: F) -> bool {
+        self.lagrange_evaluation * shares_evaluation == self.expected_evaluation
+    }
+
+    /// Check the claim by evaluating the extension of `ctxts`, which must be the shares
+    /// whose digest was passed to [`CombineProof::reduce`].
+    pub fn verify(&self, ctxts: &[BFVCiphertext]) -> bool {
+        if ctxts.len() != self.share_count
+            || ctxts.iter().any(|c| {
+                c.polys()
+                    .iter()
+                    .any(|p| p.coeff_count() * 2 != 1 << self.coeff_vars)
+            })
+        {
+            return false;
+        }
+        let share_vars = self.point.len() - self.coeff_vars;
+        self.check(shares_extension(ctxts, self.coeff_vars, share_vars).evaluate(&self.point))
+    }
+}
+
+/// The public statement shared by the prover and the verifier.
+struct Statement {
+    /// number of variables indexing the coefficients of a ciphertext
+    coeff_vars: usize,
+    /// number of variables indexing the shares
+    share_vars: usize,
+    /// the lagrange coefficients
+    lagrange: Rc<DenseMultilinearExtension<F>>,
+    chosen_indices: Vec<PlainField>,
+    shares_digest: [u8; 32],
+}
+
+impl Statement {
+    /// Return `None` if `chosen_indices` are not distinct nonzero indices,
+    /// for which the lagrange coefficients are not defined.
+    fn new(
+        coeff_count: usize,
+        chosen_indices: &[PlainField],
+        shares_digest: [u8; 32],
+    ) -> Option<Self> {
+        let coeff_vars = coeff_count.trailing_zeros() as usize;
+        let share_vars = chosen_indices
+            .len()
+            .next_power_of_two()
+            .max(2)
+            .trailing_zeros() as usize;
+
+        let mut lagrange: Vec<F> = ThresholdPKE::gen_lagrange_coeffs(chosen_indices)
+            .ok()?
+            .into_iter()
+            .map(|l| F::new(l.cast_into_usize() as u32))
+            .collect();
+        lagrange.resize(1 << share_vars, F::ZERO);
+        let lagrange = Rc::new(DenseMultilinearExtension::from_evaluations_vec(
+            share_vars, lagrange,
+        ));
+
+        Some(Self {
+            coeff_vars,
+            share_vars,
+            lagrange,
+            chosen_indices: chosen_indices.to_vec(),
+            shares_digest,
+        })
+    }
+
+    fn transcript(&self, combined: &BFVCiphertext) -> Transcript {
+        let mut transcript = Transcript::new(b"threshold-zk-lhe combine proof");
+        for index in self.chosen_indices.iter() {
+            transcript.append_message(b"index", &index.get().to_be_bytes());
+        }
+        transcript.append_message(b"shares", &self.shares_digest);
+        transcript.append_message(b"combined", &combined.to_vec());
+        transcript
+    }
+}
+
+/// The extension of the shares, where the first `coeff_vars` variables index the coefficients.
+fn shares_extension(
+    ctxts: &[BFVCiphertext],
+    coeff_vars: usize,
+    share_vars: usize,
+) -> DenseMultilinearExtension<F> {
+    let coeff_count = 1 << coeff_vars;
+    assert!(
+        ctxts
+            .iter()
+            .all(|c| c.polys().iter().all(|p| p.coeff_count() * 2 == coeff_count)),
+        "ctxts should have the same power-of-two dimension"
+    );
+
+    let mut evaluations = Vec::with_capacity(coeff_count << share_vars);
+    for c in ctxts {
+        evaluations.extend(flatten(c).evaluations);
+    }
+    evaluations.resize(coeff_count << share_vars, F::ZERO);
+    DenseMultilinearExtension::from_evaluations_vec(coeff_vars + share_vars, evaluations)
+}
+
+/// Flatten a ciphertext into the multilinear extension of its coefficients.
+fn flatten(c: &BFVCiphertext) -> DenseMultilinearExtension<F> {
+    let evaluations: Vec<F> = c.polys().iter().flat_map(|p| p.iter().copied()).collect();
+    let num_vars = evaluations.len().trailing_zeros() as usize;
+    DenseMultilinearExtension::from_evaluations_vec(num_vars, evaluations)
+}
+
+fn append_sumcheck_msg(transcript: &mut Transcript, evaluations: &[F]) {
+    let bytes: Vec<u8> = evaluations.iter().flat_map(|v| v.to_bytes()).collect();
+    transcript.append_message(b"sumcheck message", &bytes);
+}
@@ -0,0 +1,109 @@
+//! Define the signed public description of a committee.
+
+use algebra::Field;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::{BFVError, BFVPublicKey, ThresholdPKEContext, ThresholdPolicy};
+
+/// The public description of a committee in an epoch, its policy and the ids and the public
+/// keys of its nodes, signed by the authority which set up the committee.
+///
+/// Encryptors verify the signature before encrypting shares to `pks`,
+/// and the signature covers the parameter digest and the epoch, so verifiers can pin both.
+#[derive(Debug, Clone)]
+pub struct CommitteeDescriptor {
+    /// The policy of the committee.
+    pub policy: ThresholdPolicy,
+    /// The ids of the nodes, in the order of the indices of the policy.
+    pub party_ids: Vec<Vec<u8>>,
+    /// The public keys of the nodes, in the order of the indices of the policy.
+    pub pks: Vec<BFVPublicKey>,
+    /// The parameter digest of the context, see [`ThresholdPKEContext::parameter_digest`].
+    pub parameter_digest: [u8; 32],
+    /// The epoch of the committee.
+    pub epoch: u64,
+    /// The signature of the authority over the descriptor.
+    pub sig: Signature,
+}
+
+impl CommitteeDescriptor {
+    /// Describe the committee of `ctx` in `epoch` and sign it.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if there is not one id and one public key
+    /// per node of the policy.
+    pub fn sign(
+        signing_key: &SigningKey,
+        ctx: &ThresholdPKEContext,
+        party_ids: Vec<Vec<u8>>,
+        pks: Vec<BFVPublicKey>,
+        epoch: u64,
+    ) -> Result<Self, BFVError> {
+        let total_number = ctx.policy().total_number();
+        if party_ids.len() != total_number || pks.len() != total_number {
+            return Err(BFVError::ParameterMismatch(
+                "the length of party_ids and pks should be total_number",
+            ));
+        }
+        let mut descriptor = Self {
+            policy: ctx.policy().clone(),
+            party_ids,
+            pks,
+            parameter_digest: ctx.parameter_digest(),
+            epoch,
+            sig: Signature::from_bytes(&[0; 64]),
+        };
+        descriptor.sig = signing_key.sign(&descriptor.digest());
+        Ok(descriptor)
+    }
+
+    /// Verify the signature against the authority's `verifying_key`,
+    /// and that the descriptor is for `parameter_digest` and `epoch`.
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey,
+        parameter_digest: &[u8; 32],
+        epoch: u64,
+    ) -> bool {
+        self.parameter_digest == *parameter_digest
+            && self.epoch == epoch
+            && verifying_key.verify(&self.digest(), &self.sig).is_ok()
+    }
+
+    /// The canonical hash of the descriptor without the signature, which is signed.
+    ///
+    /// Every variable length field is prefixed with its length, so distinct descriptors
+    /// never hash the same bytes.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(b"threshold-zk-lhe committee descriptor");
+        hasher.update(self.parameter_digest);
+        hasher.update(self.epoch.to_be_bytes());
+        hasher.update((self.policy.total_number() as u64).to_be_bytes());
+        hasher.update((self.policy.threshold_number() as u64).to_be_bytes());
+        hasher.update((self.policy.pack_number() as u64).to_be_bytes());
+        for index in self.policy.indices() {
+            hasher.update(index.get().to_be_bytes());
+        }
+        hasher.update((self.party_ids.len() as u64).to_be_bytes());
+        for id in &self.party_ids {
+            hasher.update((id.len() as u64).to_be_bytes());
+            hasher.update(id);
+        }
+        hasher.update((self.pks.len() as u64).to_be_bytes());
+        for pk in &self.pks {
+            let bytes = pk.to_vec();
+            hasher.update((bytes.len() as u64).to_be_bytes());
+            hasher.update(bytes);
+        }
+        hasher.finalize().into()
+    }
+
+    /// Return the public key of the node with `party_id`.
+    pub fn pk_of(&self, party_id: &[u8]) -> Option<&BFVPublicKey> {
+        self.party_ids
+            .iter()
+            .position(|id| id == party_id)
+            .map(|i| &self.pks[i])
+    }
+}
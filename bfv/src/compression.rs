@@ -0,0 +1,147 @@
+//! Define the compressed ciphertext of BFV.
+//!
+//! A coefficient `x` of a ciphertext is compressed to `round(x * 2^d / q) mod 2^d`, keeping its
+//! `d` high bits, and decompressed to `round(y * q / 2^d)`. This adds an error of at most
+//! `q / 2^(d + 1)` to each coefficient, which is multiplied by the secret key for the second component,
+//! so `d` trades the wire size against the noise budget.
+
+use algebra::{Field, ModulusConfig, Polynomial};
+use serde::{Deserialize, Serialize};
+
+use crate::{protocol::Reader, BFVCiphertext, BFVError, CipherField};
+
+/// The bits number of the modulus of [`CipherField`], the largest compression target.
+pub const CIPHER_BITS: u32 = CipherField::MODULUS_BITS;
+
+/// Define the ciphertext of BFV with the coefficients compressed to `bits` bits.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCiphertext {
+    bits: u32,
+    coeffs: [Vec<u32>; 2],
+}
+
+impl CompressedCiphertext {
+    /// Returns the bits number of each coefficient.
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns the rlwe dimension.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.coeffs[0].len()
+    }
+
+    /// Decompress into a ciphertext, whose coefficients are the nearest multiples of `q / 2^bits`.
+    pub fn decompress(&self) -> BFVCiphertext {
+        let q = CipherField::modulus_value() as u64;
+        let half = 1u64 << (self.bits - 1);
+        let [c1, c2] = self.coeffs.each_ref().map(|coeffs| {
+            Polynomial::new(
+                coeffs
+                    .iter()
+                    .map(|&y| CipherField::new(((y as u64 * q + half) >> self.bits) as u32))
+                    .collect(),
+            )
+        });
+        BFVCiphertext::new(c1, c2)
+    }
+
+    /// Serialize to `Vec<u8>`, packing the coefficients tightly.
+    pub fn to_vec(&self) -> Vec<u8> {
+        // layout: |bits,len0,len1|packed data0,data1|
+        let mut bytes = vec![self.bits as u8];
+        for coeffs in &self.coeffs {
+            bytes.extend((coeffs.len() as u32).to_be_bytes());
+        }
+
+        let (mut acc, mut acc_bits) = (0u64, 0);
+        for &y in self.coeffs.iter().flatten() {
+            acc |= (y as u64) << acc_bits;
+            acc_bits += self.bits;
+            while acc_bits >= 8 {
+                bytes.push(acc as u8);
+                acc >>= 8;
+                acc_bits -= 8;
+            }
+        }
+        if acc_bits > 0 {
+            bytes.push(acc as u8);
+        }
+        bytes
+    }
+
+    /// Deserialize from [u8]
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        Self::read(bytes).ok_or(BFVError::SerializationError("compressed ciphertext"))
+    }
+
+    fn read(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::new(bytes);
+        let bits = reader.u8()? as u32;
+        if !(1..=CIPHER_BITS).contains(&bits) {
+            return None;
+        }
+        let len0 = reader.u32()? as usize;
+        let len1 = reader.u32()? as usize;
+        let total_bits = len0.checked_add(len1)?.checked_mul(bits as usize)?;
+        let data = reader.bytes(total_bits.div_ceil(8))?;
+        if !reader.is_empty() {
+            return None;
+        }
+
+        let mask = (1u64 << bits) - 1;
+        let (mut acc, mut acc_bits) = (0u64, 0);
+        let mut data = data.iter();
+        let mut values = Vec::with_capacity(len0 + len1);
+        for _ in 0..len0 + len1 {
+            while acc_bits < bits {
+                acc |= (*data.next()? as u64) << acc_bits;
+                acc_bits += 8;
+            }
+            values.push((acc & mask) as u32);
+            acc >>= bits;
+            acc_bits -= bits;
+        }
+        // the padding bits should be zero
+        if acc != 0 {
+            return None;
+        }
+
+        let coeffs1 = values.split_off(len0);
+        Some(Self {
+            bits,
+            coeffs: [values, coeffs1],
+        })
+    }
+}
+
+impl BFVCiphertext {
+    /// Compress the coefficients to their `target_bits` high bits by modulus reduction with rounding.
+    ///
+    /// The compressed ciphertext still decrypts correctly as long as the rounding error
+    /// fits the noise budget, see [`crate::BFVScheme::decrypt_compressed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_bits` is not in `1..=CIPHER_BITS`.
+    pub fn compress(&self, target_bits: u32) -> CompressedCiphertext {
+        assert!(
+            (1..=CIPHER_BITS).contains(&target_bits),
+            "the target bits should be between 1 and the bits of the modulus"
+        );
+
+        let q = CipherField::modulus_value() as u64;
+        let mask = (1u64 << target_bits) - 1;
+        let coeffs = self.polys().map(|poly| {
+            poly.iter()
+                .map(|x| (((((x.get() as u64) << target_bits) + q / 2) / q) & mask) as u32)
+                .collect()
+        });
+        CompressedCiphertext {
+            bits: target_bits,
+            coeffs,
+        }
+    }
+}
@@ -1,36 +1,116 @@
 //! Context of BFV
 
-use algebra::FieldDiscreteGaussianSampler;
+use algebra::{FieldDiscreteGaussianSampler, NTTPolynomial, Polynomial};
 use rand::SeedableRng;
 use rand_chacha::ChaCha12Rng;
 use std::cell::RefCell;
+use std::fmt;
 
-use crate::DIMENSION_N;
+use crate::{BFVParameters, CipherField, SecretKeyDistribution};
+
+/// Polynomials reused across encryptions and decryptions to avoid allocating them on every call.
+///
+/// Their contents are overwritten by each operation and carry no meaning in between.
+#[derive(Debug, Clone)]
+pub struct BFVScratch {
+    pub(crate) e1: Polynomial<CipherField>,
+    pub(crate) e2: Polynomial<CipherField>,
+    pub(crate) m: Polynomial<CipherField>,
+    pub(crate) ntt: NTTPolynomial<CipherField>,
+}
+
+impl BFVScratch {
+    /// Create scratch space for polynomials with `dimension` coefficients.
+    #[inline]
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            e1: Polynomial::zero(dimension),
+            e2: Polynomial::zero(dimension),
+            m: Polynomial::zero(dimension),
+            ntt: NTTPolynomial::zero(dimension),
+        }
+    }
+}
 
 /// Define the context of BFV scheme.
 #[derive(Debug, Clone)]
 pub struct BFVContext {
-    rlwe_dimension: usize,
+    params: BFVParameters,
     csrng: RefCell<ChaCha12Rng>,
     sampler: FieldDiscreteGaussianSampler,
+    scratch: RefCell<BFVScratch>,
 }
 
 impl BFVContext {
-    /// Create a new instance.
+    /// Create a new instance with the default parameters.
     #[inline]
     pub fn new() -> Self {
-        let csrng = ChaCha12Rng::from_entropy();
+        Self::with_parameters(BFVParameters::new())
+    }
+
+    /// Create a new instance with the given parameters.
+    pub fn with_parameters(params: BFVParameters) -> Self {
+        Self::with_rng(params, ChaCha12Rng::from_entropy())
+    }
+
+    /// Create a new instance drawing its randomness from `csrng`.
+    pub(crate) fn with_rng(params: BFVParameters, csrng: ChaCha12Rng) -> Self {
         Self {
-            rlwe_dimension: DIMENSION_N,
+            params,
             csrng: RefCell::new(csrng),
-            sampler: FieldDiscreteGaussianSampler::new(0.0, 3.2).unwrap(),
+            sampler: params.sampler(),
+            scratch: RefCell::new(BFVScratch::new(params.rlwe_dimension())),
         }
     }
 
+    /// Returns the parameters.
+    #[inline]
+    pub fn parameters(&self) -> &BFVParameters {
+        &self.params
+    }
+
     /// Returns the rlwe_dimension.
     #[inline]
     pub fn rlwe_dimension(&self) -> usize {
-        self.rlwe_dimension
+        self.params.rlwe_dimension()
+    }
+
+    /// Returns the distribution of the secret key.
+    #[inline]
+    pub fn secret_key_distribution(&self) -> SecretKeyDistribution {
+        self.params.secret_key_distribution()
+    }
+
+    /// Returns the modulus `q` of the ciphertexts.
+    #[inline]
+    pub fn cipher_modulus(&self) -> u32 {
+        self.params.cipher_modulus()
+    }
+
+    /// Returns the modulus `t` of the plaintexts.
+    #[inline]
+    pub fn plain_modulus(&self) -> u16 {
+        self.params.plain_modulus()
+    }
+
+    /// Returns the standard deviation of the error distribution.
+    #[inline]
+    pub fn noise_std_dev(&self) -> f64 {
+        self.params.noise_std_dev()
+    }
+
+    /// Returns the bound on the noise for a correct decryption,
+    /// see [`BFVParameters::noise_bound`].
+    #[inline]
+    pub fn noise_bound(&self) -> f64 {
+        self.params.noise_bound()
+    }
+
+    /// Returns the largest advised length of an inner product of fresh ciphertexts,
+    /// see [`BFVParameters::max_inner_product_len`].
+    #[inline]
+    pub fn max_inner_product_len(&self) -> usize {
+        self.params.max_inner_product_len()
     }
 
     /// Returns the sampler.
@@ -44,6 +124,19 @@ impl BFVContext {
     pub fn csrng_mut(&self) -> std::cell::RefMut<'_, ChaCha12Rng> {
         self.csrng.borrow_mut()
     }
+
+    /// Returns the scratch space of [`BFVContext`].
+    #[inline]
+    pub fn scratch_mut(&self) -> std::cell::RefMut<'_, BFVScratch> {
+        self.scratch.borrow_mut()
+    }
+}
+
+impl fmt::Display for BFVContext {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.params, f)
+    }
 }
 
 impl Default for BFVContext {
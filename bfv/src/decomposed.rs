@@ -0,0 +1,58 @@
+//! Define the decomposed ciphertext of BFV, for multiplications by large scalars.
+
+use algebra::{Basis, Field};
+
+use crate::{BFVCiphertext, PlainField};
+
+/// Define the encryptions of `B^i * m` for the powers of a basis `B`.
+///
+/// Multiplying a ciphertext by a scalar `k` multiplies its noise by `k`, up to `t - 1`.
+/// Multiplying the `i`-th ciphertext by the `i`-th digit of `k` in basis `B` and accumulating
+/// bounds the noise by `(B - 1)` times the number of digits instead,
+/// at the cost of one encryption and one scalar multiplication per digit.
+#[derive(Clone, Debug)]
+pub struct DecomposedCiphertext {
+    basis: Basis<PlainField>,
+    cts: Vec<BFVCiphertext>,
+}
+
+impl DecomposedCiphertext {
+    /// Create a new instance from the encryptions `cts[i]` of `B^i * m`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of ciphertexts is not the decomposition length of `basis`.
+    #[inline]
+    pub fn new(basis: Basis<PlainField>, cts: Vec<BFVCiphertext>) -> Self {
+        assert_eq!(
+            cts.len(),
+            basis.decompose_len(),
+            "the number of ciphertexts should be the decomposition length"
+        );
+        Self { basis, cts }
+    }
+
+    /// Returns the decomposition basis.
+    #[inline]
+    pub fn basis(&self) -> Basis<PlainField> {
+        self.basis
+    }
+
+    /// Returns the ciphertexts, the `i`-th one encrypting `B^i * m`.
+    #[inline]
+    pub fn ciphertexts(&self) -> &[BFVCiphertext] {
+        &self.cts
+    }
+
+    /// Returns the encryption of `m` itself.
+    #[inline]
+    pub fn ciphertext(&self) -> &BFVCiphertext {
+        &self.cts[0]
+    }
+
+    /// Returns the digits of `scalar` in the basis, from the least significant one.
+    #[inline]
+    pub(crate) fn digits(&self, scalar: PlainField) -> Vec<PlainField> {
+        scalar.decompose(self.basis)
+    }
+}
@@ -0,0 +1,47 @@
+//! This module defines the errors that may occur in the BFV scheme and the threshold pke.
+
+use thiserror::Error;
+
+/// Errors that may occur.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BFVError {
+    /// Error that occurs when the inputs do not match each other or the policy.
+    #[error("Parameter mismatch: {0}.")]
+    ParameterMismatch(&'static str),
+    /// Error that occurs when fewer shares are given than required.
+    #[error("{given} shares are given, but {required} are required.")]
+    NotEnoughShares {
+        /// The number of shares required.
+        required: usize,
+        /// The number of shares given.
+        given: usize,
+    },
+    /// Error that occurs when a share is repeated, is not from a node of the policy,
+    /// or fails verification.
+    #[error("A share is repeated, is not from a node of the policy or fails verification.")]
+    InvalidShare,
+    /// Error that occurs when the decrypted or reconstructed message is not well-formed.
+    #[error("Fail to decrypt the ciphertext.")]
+    DecryptionFailure,
+    /// Error that occurs when the symmetric encryption or decryption fails.
+    #[error("The symmetric encryption or decryption fails.")]
+    AeadFailure,
+    /// Error that occurs when the estimated security of the parameters is below the minimum.
+    #[error("The parameters have {estimated} bits of security, but {required} are required.")]
+    InsecureParameters {
+        /// The estimated bits of security, rounded down.
+        estimated: u32,
+        /// The minimum bits of security.
+        required: u32,
+    },
+    /// Error that occurs when the bytes of an object are malformed.
+    #[error("Fail to deserialize the {0}.")]
+    SerializationError(&'static str),
+}
+
+impl From<chacha20poly1305::aead::Error> for BFVError {
+    #[inline]
+    fn from(_: chacha20poly1305::aead::Error) -> Self {
+        Self::AeadFailure
+    }
+}
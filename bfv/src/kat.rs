@@ -0,0 +1,142 @@
+//! Deterministic entry points and known-answer tests.
+//!
+//! Every random choice is drawn from a ChaCha12 generator seeded with the SHA-256 digest of
+//! [`SEED_DOMAIN`] and a byte string, so the key generation, encryption and secret sharing
+//! below are reproducible bit-for-bit. [`known_answers`] recomputes the fixed vectors of
+//! [`KNOWN_ANSWERS`], which independent implementations and refactors can be checked against.
+//!
+//! The generators are seeded from public byte strings: these entry points are for testing only.
+
+use algebra::{transformation::EvaluationDomain, Field, Polynomial};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    BFVCiphertext, BFVContext, BFVError, BFVParameters, BFVPlaintext, BFVPublicKey, BFVScheme,
+    BFVSecretKey, CipherField, PlainField, Share, ThresholdPolicy, DIMENSION_N,
+};
+
+/// The domain separator hashed before the seeds.
+pub const SEED_DOMAIN: &[u8] = b"threshold-zk-LHE/kat/v1";
+
+/// The field arithmetic vectors `(a, b, a + b, a - b, a * b, a / b)` of [`CipherField`].
+pub const CIPHER_FIELD_VECTORS: [[u32; 6]; 5] = [
+    [0, 1, 1, 132120576, 0, 0],
+    [1, 132120576, 0, 2, 132120576, 132120576],
+    [
+        123456789, 98765432, 90101644, 24691357, 115981394, 107090723,
+    ],
+    [132120575, 132120574, 132120572, 1, 6, 44040193],
+    [65536, 65537, 131073, 132120576, 67174368, 86747158],
+];
+
+/// The field arithmetic vectors `(a, b, a + b, a - b, a * b, a / b)` of [`PlainField`].
+pub const PLAIN_FIELD_VECTORS: [[u16; 6]; 3] = [
+    [1, 60, 0, 2, 60, 60],
+    [17, 42, 59, 36, 43, 28],
+    [60, 60, 59, 0, 1, 1],
+];
+
+/// The hex SHA-256 digests of the outputs computed by [`known_answers`].
+pub const KNOWN_ANSWERS: [(&str, &str); 4] = [
+    (
+        "ntt",
+        "c81ac4ceab5c571956180b1130fdb19ebf4e9be8286cb91b1b42d1b42ec6d96a",
+    ),
+    (
+        "bfv-keygen",
+        "226a219e0c3dab39020088ae56895711c8eba803923354e3ccf79d70ac7d0eee",
+    ),
+    (
+        "bfv-encrypt",
+        "9a57eef9ed4162d6b0d9fa79a70c231efa6b524a4fa7b1d38e80273023f98ee6",
+    ),
+    (
+        "shamir",
+        "895e868ed7a4a6a7c441a383aecd55d4de168ad762592ebb886614e2fe6f45a2",
+    ),
+];
+
+/// Returns the generator seeded from `seed`.
+pub fn rng_from_seed(seed: &[u8]) -> ChaCha12Rng {
+    let mut hasher = Sha256::new();
+    hasher.update(SEED_DOMAIN);
+    hasher.update(seed);
+    ChaCha12Rng::from_seed(hasher.finalize().into())
+}
+
+/// Returns the context of the default parameters, whose randomness is seeded from `seed`.
+#[inline]
+pub fn context_from_seed(seed: &[u8]) -> BFVContext {
+    BFVContext::with_rng(BFVParameters::new(), rng_from_seed(seed))
+}
+
+/// Generate the key pair seeded from `seed`.
+#[inline]
+pub fn gen_keypair(seed: &[u8]) -> (BFVSecretKey, BFVPublicKey) {
+    BFVScheme::gen_keypair(&context_from_seed(seed))
+}
+
+/// Encrypt `m` with the randomness seeded from `seed`.
+#[inline]
+pub fn encrypt(
+    pk: &BFVPublicKey,
+    m: &BFVPlaintext,
+    seed: &[u8],
+) -> Result<BFVCiphertext, BFVError> {
+    BFVScheme::encrypt(&context_from_seed(seed), pk, m)
+}
+
+/// Share `secret` with the randomness seeded from `seed`.
+#[inline]
+pub fn secret_sharing(
+    policy: &ThresholdPolicy,
+    secret: &Polynomial<PlainField>,
+    seed: &[u8],
+) -> Vec<Share<PlainField>> {
+    policy.secret_sharing(secret, &mut rng_from_seed(seed))
+}
+
+/// Recompute the outputs of [`KNOWN_ANSWERS`] and return their hex SHA-256 digests.
+pub fn known_answers() -> Vec<(&'static str, String)> {
+    let mut answers = Vec::with_capacity(KNOWN_ANSWERS.len());
+
+    // the evaluations in natural order, which do not depend on the variant of the transform
+    let poly = Polynomial::<CipherField>::random(DIMENSION_N, rng_from_seed(b"ntt"));
+    let domain = EvaluationDomain::<CipherField>::new(DIMENSION_N.trailing_zeros()).unwrap();
+    let evaluations = domain.fft(&poly);
+    answers.push(("ntt", digest(evaluations.iter().map(|x| x.to_bytes()))));
+
+    let (sk, pk) = gen_keypair(b"bfv-keygen");
+    let keys = [sk.to_vec(), pk.to_vec()];
+    answers.push(("bfv-keygen", digest(keys)));
+
+    let msg = Polynomial::<PlainField>::random(DIMENSION_N, rng_from_seed(b"bfv-message"));
+    let c = encrypt(&pk, &BFVPlaintext(msg), b"bfv-encrypt").unwrap();
+    answers.push(("bfv-encrypt", digest([c.to_vec()])));
+
+    let indices = (1..=5).map(PlainField::new).collect();
+    let policy = ThresholdPolicy::new(5, 3, indices);
+    let secret = Polynomial::<PlainField>::random(DIMENSION_N, rng_from_seed(b"shamir-secret"));
+    let shares = secret_sharing(&policy, &secret, b"shamir");
+    let coeffs = shares
+        .iter()
+        .flat_map(|s| s.expose_secret().iter().map(|x| x.get().to_be_bytes()));
+    answers.push(("shamir", digest(coeffs)));
+
+    answers
+}
+
+/// Returns the hex SHA-256 digest of the concatenation of `chunks`.
+fn digest<T: AsRef<[u8]>>(chunks: impl IntoIterator<Item = T>) -> String {
+    let mut hasher = Sha256::new();
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
@@ -0,0 +1,98 @@
+//! Define the key switching of BFV ciphertexts, which moves a ciphertext from a secret key to another.
+
+use algebra::{ntt_add_mul_assign, Basis, Field, NTTPolynomial, Polynomial};
+
+use crate::{BFVCiphertext, BFVContext, BFVError, BFVSecretKey, CipherField};
+
+/// The bits number of the decomposition basis of the key rotation.
+///
+/// A single switch adds the error of about `2^BITS * sqrt(n)` per digit, far within the noise budget.
+const KEY_ROTATION_BASIS_BITS: u32 = 7;
+
+/// Define the key switching key from a secret key `s'` to the secret key `s`,
+/// the encryptions `(B^j * s' + e - a * s, a)` of the powers of the basis `B`.
+///
+/// Generated by [`BFVSecretKey::gen_key_switch_key`], it rotates stored ciphertexts
+/// from an old secret key to a new one without decryption, see [`crate::BFVScheme::rotate_ciphertexts`].
+#[derive(Debug, Clone)]
+pub struct KeySwitchKey {
+    basis: Basis<CipherField>,
+    key: Vec<[NTTPolynomial<CipherField>; 2]>,
+}
+
+impl KeySwitchKey {
+    /// Generate the key switching key from the secret key `old` to the secret key `new`.
+    #[inline]
+    pub fn new(ctx: &BFVContext, old: &BFVSecretKey, new: &BFVSecretKey) -> Self {
+        Self::generate(
+            ctx,
+            old.expose_secret().clone(),
+            new,
+            KEY_ROTATION_BASIS_BITS,
+        )
+    }
+
+    /// Generate the key switching key from the secret polynomial `from` to the secret key `to`,
+    /// decomposing in the basis of `basis_bits` bits.
+    pub(crate) fn generate(
+        ctx: &BFVContext,
+        mut from: Polynomial<CipherField>,
+        to: &BFVSecretKey,
+        basis_bits: u32,
+    ) -> Self {
+        let n = from.coeff_count();
+        let basis = Basis::<CipherField>::new(basis_bits);
+        let radix = CipherField::new(basis.basis());
+        let mut csrng = ctx.csrng_mut();
+
+        let key = (0..basis.decompose_len())
+            .map(|_| {
+                let a = Polynomial::<CipherField>::random(n, &mut *csrng);
+                let e =
+                    Polynomial::<CipherField>::random_with_gaussian(n, &mut *csrng, ctx.sampler());
+                let b = &from + e - &a * to.ntt_secret_key();
+                from.mul_scalar_assign(radix);
+                [b.into_ntt_polynomial(), a.into_ntt_polynomial()]
+            })
+            .collect();
+
+        Self { basis, key }
+    }
+
+    /// Returns the rlwe dimension.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.key[0][0].coeff_count()
+    }
+
+    /// Switch the ciphertext `(c1, c2)` decrypting with `c1 + c2 * s'` to the secret key `s`.
+    pub(crate) fn switch(
+        &self,
+        c1: Polynomial<CipherField>,
+        c2: Polynomial<CipherField>,
+    ) -> BFVCiphertext {
+        let n = c2.coeff_count();
+        let mut acc = [NTTPolynomial::zero(n), NTTPolynomial::zero(n)];
+        for (digit, [b, a]) in c2.decompose(self.basis).into_iter().zip(&self.key) {
+            let digit = digit.into_ntt_polynomial();
+            ntt_add_mul_assign(&mut acc[0], &digit, b);
+            ntt_add_mul_assign(&mut acc[1], &digit, a);
+        }
+        let [b, a] = acc;
+        BFVCiphertext::new(c1 + b.into_native_polynomial(), a.into_native_polynomial())
+    }
+
+    /// Switch the ciphertext to the secret key `s`, checking its dimension.
+    pub(crate) fn rotate(&self, c: &BFVCiphertext) -> Result<BFVCiphertext, BFVError> {
+        if c.polys()
+            .iter()
+            .any(|p| p.coeff_count() != self.dimension())
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the dimension of the ciphertext should be the dimension of the key",
+            ));
+        }
+        let BFVCiphertext { c1, c2 } = c.clone();
+        Ok(self.switch(c1, c2))
+    }
+}
@@ -4,21 +4,66 @@
 //! A simple linearly homomorphic version of BFV.
 //! The underlying scheme only supports additive homomorphism.
 
+mod audit;
 mod ciphertext;
+mod combine_proof;
+mod committee;
+mod compression;
 mod context;
+mod decomposed;
+mod error;
+#[cfg(feature = "kat")]
+pub mod kat;
+mod keyswitch;
+mod lwe;
+mod packing;
+mod parameters;
+mod pcs;
 mod plaintext;
+mod pok;
+pub mod protocol;
 mod publickey;
+mod recovery;
 mod scheme;
+pub mod seal;
 mod secretkey;
+pub mod security;
+mod share;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 mod tpke;
+mod transcript;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod wire;
+mod xof;
 
-pub use ciphertext::{BFVCiphertext, CipherField, DIMENSION_N};
-pub use context::BFVContext;
-pub use plaintext::{BFVPlaintext, PlainField};
-pub use publickey::BFVPublicKey;
-pub use scheme::BFVScheme;
+pub use audit::{AuditEntry, AuditLog};
+pub use ciphertext::{BFVCiphertext, BFVNTTCiphertext, CipherField, Domain, DIMENSION_N};
+pub use combine_proof::{CombineProof, ShareClaim};
+pub use committee::CommitteeDescriptor;
+pub use compression::{CompressedCiphertext, CIPHER_BITS};
+pub use context::{BFVContext, BFVScratch};
+pub use decomposed::DecomposedCiphertext;
+pub use error::BFVError;
+pub use keyswitch::KeySwitchKey;
+pub use lwe::LweCiphertext;
+pub use packing::{AutomorphismKey, PackingKey};
+pub use parameters::{BFVParameters, SecretKeyDistribution};
+pub use pcs::{ColumnOpening, CommitmentState, EvaluationProof, MerklePCS, PolynomialCommitment};
+pub use plaintext::{BFVPlaintext, PlainField, Z2kPlaintext};
+pub use pok::PlaintextKnowledgeProof;
+pub use publickey::{BFVNTTPublicKeyPolys, BFVPublicKey, BFVPublicKeyPolys};
+pub use recovery::{BlindingMessage, ShareRecovery};
+pub use scheme::{BFVScheme, EncryptionWitness};
 pub use secretkey::BFVSecretKey;
-pub use tpke::{ThresholdPKE, ThresholdPKEContext, ThresholdPolicy};
+pub use share::{Aggregation, CiphertextShare, DecryptionShare, ShareRejection};
+pub use tpke::{
+    sss, HierarchicalPolicy, NonceMode, RecipientCiphertext, Share, ShareStream,
+    ThresholdCiphertext, ThresholdPKE, ThresholdPKEContext, ThresholdPolicy,
+};
+pub use transcript::Transcript;
+pub use xof::{hash_to_fields, Blake3, Shake128, Shake256, Xof, XofReader, XofRng};
 
 /// The maximum number of nodes.
 pub const MAX_NODES_NUMBER: usize = 20;
@@ -0,0 +1,41 @@
+//! Define the LWE ciphertext of a single coefficient of a BFV ciphertext.
+
+use serde::{Deserialize, Serialize};
+
+use crate::CipherField;
+
+/// Define the LWE ciphertext `(a, b)` of a single message `m`,
+/// with `b + <a, s> = delta * m + e` for the coefficients `s` of the secret key.
+///
+/// It is extracted from a coefficient of a BFV ciphertext by [`crate::BFVCiphertext::extract_lwe`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LweCiphertext {
+    a: Vec<CipherField>,
+    b: CipherField,
+}
+
+impl LweCiphertext {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(a: Vec<CipherField>, b: CipherField) -> Self {
+        Self { a, b }
+    }
+
+    /// Returns the reference of `a`.
+    #[inline]
+    pub fn a(&self) -> &[CipherField] {
+        &self.a
+    }
+
+    /// Returns `b`.
+    #[inline]
+    pub fn b(&self) -> CipherField {
+        self.b
+    }
+
+    /// Returns the dimension of `a`.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.a.len()
+    }
+}
@@ -0,0 +1,202 @@
+//! Define the ring packing of LWE ciphertexts into a BFV ciphertext.
+//!
+//! The packing follows Chen, Dai, Kim and Song (ACNS 2021): each LWE ciphertext is read as
+//! a BFV ciphertext whose constant coefficient holds its message, and the ciphertexts are merged
+//! pairwise with the automorphisms `τ_{2^l + 1}: X -> X^(2^l + 1)`, which also clear every other coefficient.
+
+use algebra::{Field, Polynomial};
+
+use crate::{
+    BFVCiphertext, BFVContext, BFVError, BFVSecretKey, CipherField, KeySwitchKey, LweCiphertext,
+};
+
+/// The bits number of the decomposition basis of the key switching.
+///
+/// Packing amplifies the key switching noise by up to the rlwe dimension,
+/// which only fits the noise budget of the default parameters with a binary decomposition.
+const KEY_SWITCHING_BASIS_BITS: u32 = 1;
+
+/// Define the key switching key from `τ_k(s)` to `s` of the automorphism `τ_k: X -> X^k`.
+#[derive(Debug, Clone)]
+pub struct AutomorphismKey {
+    degree: usize,
+    key: KeySwitchKey,
+}
+
+impl AutomorphismKey {
+    /// Generate the key of `τ_degree` from the secret key, `degree` being odd and less than `2n`.
+    pub fn new(ctx: &BFVContext, sk: &BFVSecretKey, degree: usize) -> Self {
+        let s = sk.expose_secret();
+        let n = s.coeff_count();
+        assert!(
+            degree % 2 == 1 && degree < 2 * n,
+            "the degree of an automorphism should be odd and less than 2n"
+        );
+
+        let key =
+            KeySwitchKey::generate(ctx, automorphism(s, degree), sk, KEY_SWITCHING_BASIS_BITS);
+        Self { degree, key }
+    }
+
+    /// Returns the degree `k` of the automorphism `τ_k`.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Apply the automorphism to the message of `c`, keeping the secret key.
+    pub(crate) fn apply(&self, c: &BFVCiphertext) -> BFVCiphertext {
+        self.key.switch(
+            automorphism(&c.c1, self.degree),
+            automorphism(&c.c2, self.degree),
+        )
+    }
+}
+
+/// Define the automorphism keys of `τ_{2^l + 1}` for `l` in `1..=log n`,
+/// which pack up to `n` LWE ciphertexts into a BFV ciphertext.
+#[derive(Debug, Clone)]
+pub struct PackingKey {
+    dimension: usize,
+    keys: Vec<AutomorphismKey>,
+}
+
+impl PackingKey {
+    /// Generate the packing key from the secret key.
+    pub fn new(ctx: &BFVContext, sk: &BFVSecretKey) -> Self {
+        let dimension = sk.expose_secret().coeff_count();
+        let keys = (1..=dimension.trailing_zeros())
+            .map(|l| AutomorphismKey::new(ctx, sk, (1 << l) + 1))
+            .collect();
+        Self { dimension, keys }
+    }
+
+    /// Returns the rlwe dimension.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the automorphism keys, the `i`-th one of `τ_{2^(i + 1) + 1}`.
+    #[inline]
+    pub fn automorphism_keys(&self) -> &[AutomorphismKey] {
+        &self.keys
+    }
+
+    /// Pack the LWE ciphertexts, the message of `cts[i]` becoming the `i`-th coefficient.
+    pub(crate) fn pack(&self, cts: &[LweCiphertext]) -> Result<BFVCiphertext, BFVError> {
+        let n = self.dimension;
+        if cts.len() > n {
+            return Err(BFVError::ParameterMismatch(
+                "at most rlwe dimension lwe ciphertexts can be packed",
+            ));
+        }
+        if cts.iter().any(|c| c.dimension() != n) {
+            return Err(BFVError::ParameterMismatch(
+                "the dimension of the lwe ciphertexts should be the rlwe dimension",
+            ));
+        }
+
+        // packing multiplies the messages by `n`, which is cancelled beforehand
+        let n_inv = CipherField::ONE / CipherField::new(n as u32);
+        let mut leaves: Vec<Option<BFVCiphertext>> =
+            cts.iter().map(|c| Some(to_rlwe(c, n_inv))).collect();
+        leaves.resize(n, None);
+
+        Ok(self.pack_tree(leaves).unwrap_or_else(|| {
+            let zero = Polynomial::zero(n);
+            BFVCiphertext::new(zero.clone(), zero)
+        }))
+    }
+
+    /// Merge `2^l` ciphertexts into one whose coefficient `i * n / 2^l` holds `2^l` times
+    /// the message of the `i`-th ciphertext, `None` standing for the encryption of zero.
+    fn pack_tree(&self, cts: Vec<Option<BFVCiphertext>>) -> Option<BFVCiphertext> {
+        if cts.iter().all(Option::is_none) {
+            return None;
+        }
+        if cts.len() == 1 {
+            return cts.into_iter().next().flatten();
+        }
+
+        let level = cts.len().trailing_zeros();
+        let mut even = Vec::with_capacity(cts.len() / 2);
+        let mut odd = Vec::with_capacity(cts.len() / 2);
+        for (i, c) in cts.into_iter().enumerate() {
+            if i % 2 == 0 {
+                even.push(c);
+            } else {
+                odd.push(c);
+            }
+        }
+
+        let shift = self.dimension >> level;
+        let even = self.pack_tree(even);
+        let odd = self
+            .pack_tree(odd)
+            .map(|c| BFVCiphertext::new(mul_monomial(&c.c1, shift), mul_monomial(&c.c2, shift)));
+
+        // `(even + X^shift * odd) + τ(even - X^shift * odd)`
+        let (sum, diff) = match (even, odd) {
+            (None, None) => return None,
+            (Some(even), None) => (even.clone(), even),
+            (None, Some(odd)) => (odd.clone(), BFVCiphertext::new(-odd.c1, -odd.c2)),
+            (Some(even), Some(odd)) => {
+                let BFVCiphertext { c1: e1, c2: e2 } = even;
+                let BFVCiphertext { c1: o1, c2: o2 } = odd;
+                (
+                    BFVCiphertext::new(&e1 + &o1, &e2 + &o2),
+                    BFVCiphertext::new(e1 - o1, e2 - o2),
+                )
+            }
+        };
+        let BFVCiphertext { c1: d1, c2: d2 } = self.keys[level as usize - 1].apply(&diff);
+        let BFVCiphertext { c1: s1, c2: s2 } = sum;
+        Some(BFVCiphertext::new(s1 + d1, s2 + d2))
+    }
+}
+
+/// Read the LWE ciphertext scaled by `scalar` as a BFV ciphertext,
+/// whose constant coefficient decrypts to the message of the LWE ciphertext.
+fn to_rlwe(c: &LweCiphertext, scalar: CipherField) -> BFVCiphertext {
+    let a = c.a();
+    let n = a.len();
+
+    let mut c1 = Polynomial::zero(n);
+    c1[0] = c.b() * scalar;
+    // the constant coefficient of `c2 * s` is `<a, s>`
+    let c2 = (0..n)
+        .map(|k| if k == 0 { a[0] } else { -a[n - k] } * scalar)
+        .collect();
+    BFVCiphertext::new(c1, Polynomial::new(c2))
+}
+
+/// Returns `p(X^degree)` in `F[X]/(X^n + 1)`.
+fn automorphism(p: &Polynomial<CipherField>, degree: usize) -> Polynomial<CipherField> {
+    let n = p.coeff_count();
+    let mut result = Polynomial::zero(n);
+    for (i, &c) in p.iter().enumerate() {
+        let j = i * degree % (2 * n);
+        if j < n {
+            result[j] = c;
+        } else {
+            result[j - n] = -c;
+        }
+    }
+    result
+}
+
+/// Returns `X^shift * p` in `F[X]/(X^n + 1)`, for `shift < n`.
+fn mul_monomial(p: &Polynomial<CipherField>, shift: usize) -> Polynomial<CipherField> {
+    let n = p.coeff_count();
+    let mut result = Polynomial::zero(n);
+    for (i, &c) in p.iter().enumerate() {
+        let j = i + shift;
+        if j < n {
+            result[j] = c;
+        } else {
+            result[j - n] = -c;
+        }
+    }
+    result
+}
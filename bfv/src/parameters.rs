@@ -0,0 +1,191 @@
+//! Define the parameters of BFV.
+
+use core::fmt;
+
+use algebra::{Field, FieldDiscreteGaussianSampler};
+
+use crate::security::{estimate_security, SecurityEstimate, MIN_SECURITY_BITS};
+use crate::{BFVError, CipherField, PlainField, DIMENSION_N};
+
+/// The default standard deviation of the error distribution.
+const DEFAULT_NOISE_STD_DEV: f64 = 3.2;
+
+/// The number of standard deviations the noise is kept within, which a coefficient
+/// exceeds with probability about `2^-29`.
+const NOISE_TAIL_BOUND: f64 = 6.0;
+
+/// The distribution of the coefficients of the secret key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SecretKeyDistribution {
+    /// Uniform in `{0, 1}`, sampled by [`algebra::FieldBinarySampler`].
+    ///
+    /// With the default dimension and modulus, binary secrets are estimated
+    /// slightly below [`MIN_SECURITY_BITS`].
+    Binary,
+    /// `0` with probability `1/2` and `±1` with probability `1/4` each,
+    /// sampled by [`algebra::FieldTernarySampler`].
+    #[default]
+    Ternary,
+    /// The discrete gaussian of the error distribution.
+    Gaussian,
+}
+
+/// Define the parameters of BFV.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BFVParameters {
+    rlwe_dimension: usize,
+    noise_std_dev: f64,
+    secret_key_distribution: SecretKeyDistribution,
+}
+
+impl BFVParameters {
+    /// Create the default parameters, with dimension [`DIMENSION_N`] and ternary secrets.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            rlwe_dimension: DIMENSION_N,
+            noise_std_dev: DEFAULT_NOISE_STD_DEV,
+            secret_key_distribution: SecretKeyDistribution::Ternary,
+        }
+    }
+
+    /// Return the parameters with secret keys sampled from `distribution`.
+    #[inline]
+    pub fn with_secret_key_distribution(mut self, distribution: SecretKeyDistribution) -> Self {
+        self.secret_key_distribution = distribution;
+        self
+    }
+
+    /// Return the parameters with the standard deviation `std_dev` of the error distribution.
+    #[inline]
+    pub fn with_noise_std_dev(mut self, std_dev: f64) -> Self {
+        self.noise_std_dev = std_dev;
+        self
+    }
+
+    /// Returns the rlwe dimension.
+    #[inline]
+    pub fn rlwe_dimension(&self) -> usize {
+        self.rlwe_dimension
+    }
+
+    /// Returns the standard deviation of the error distribution.
+    #[inline]
+    pub fn noise_std_dev(&self) -> f64 {
+        self.noise_std_dev
+    }
+
+    /// Returns the distribution of the secret key.
+    #[inline]
+    pub fn secret_key_distribution(&self) -> SecretKeyDistribution {
+        self.secret_key_distribution
+    }
+
+    /// Returns the modulus `q` of the ciphertexts.
+    #[inline]
+    pub fn cipher_modulus(&self) -> u32 {
+        CipherField::modulus_value()
+    }
+
+    /// Returns the modulus `t` of the plaintexts.
+    #[inline]
+    pub fn plain_modulus(&self) -> u16 {
+        PlainField::modulus_value()
+    }
+
+    /// Returns `q / 2t`, the bound on the noise of a ciphertext below which it decrypts correctly.
+    #[inline]
+    pub fn noise_bound(&self) -> f64 {
+        self.cipher_modulus() as f64 / (2.0 * self.plain_modulus() as f64)
+    }
+
+    /// Returns the standard deviation of the noise `e * u + e1 + e2 * s` of a fresh encryption,
+    /// with the ternary `u` of the encryption and the secret `s` of the key.
+    pub fn fresh_noise_std_dev(&self) -> f64 {
+        // the second moment of a coefficient of the secret, its mean included
+        let secret_moment = match self.secret_key_distribution {
+            SecretKeyDistribution::Binary | SecretKeyDistribution::Ternary => 0.5,
+            SecretKeyDistribution::Gaussian => self.noise_std_dev * self.noise_std_dev,
+        };
+        let n = self.rlwe_dimension as f64;
+        self.noise_std_dev * (1.0 + 0.5 * n + secret_moment * n).sqrt()
+    }
+
+    /// Returns the largest number of independent fresh ciphertexts whose inner product
+    /// with arbitrary scalars keeps the noise below [`BFVParameters::noise_bound`],
+    /// see [`crate::BFVScheme::evaluate_inner_product`].
+    ///
+    /// The scalars are up to `t - 1`, so the standard deviation of the noise of `k` terms
+    /// is `(t - 1) * sqrt(k)` times the fresh one, kept within `6` standard deviations.
+    /// The same ciphertext repeated adds up its noise linearly and allows fewer terms.
+    pub fn max_inner_product_len(&self) -> usize {
+        let term_std_dev = (self.plain_modulus() - 1) as f64 * self.fresh_noise_std_dev();
+        let ratio = self.noise_bound() / (NOISE_TAIL_BOUND * term_std_dev);
+        (ratio * ratio).floor() as usize
+    }
+
+    /// Returns the sampler of the error distribution.
+    #[inline]
+    pub fn sampler(&self) -> FieldDiscreteGaussianSampler {
+        FieldDiscreteGaussianSampler::new(0.0, self.noise_std_dev).unwrap()
+    }
+
+    /// Estimate the security of the parameters with the modulus of [`CipherField`],
+    /// see [`crate::security`].
+    ///
+    /// Returns `None` if the parameters are out of reach of the primal attack.
+    pub fn estimate_security(&self) -> Option<SecurityEstimate> {
+        estimate_security(
+            self.rlwe_dimension,
+            CipherField::modulus_value() as f64,
+            self.noise_std_dev,
+            self.secret_key_distribution,
+        )
+    }
+
+    /// Check that the parameters are well-formed and
+    /// have at least [`MIN_SECURITY_BITS`] bits of estimated security.
+    pub fn validate(&self) -> Result<(), BFVError> {
+        if self.rlwe_dimension != DIMENSION_N {
+            return Err(BFVError::ParameterMismatch(
+                "the rlwe dimension should be the dimension of the ciphertexts",
+            ));
+        }
+        if !(self.noise_std_dev.is_finite() && self.noise_std_dev > 0.0) {
+            return Err(BFVError::ParameterMismatch(
+                "the standard deviation of the error should be positive",
+            ));
+        }
+        match self.estimate_security() {
+            Some(estimate) if estimate.bits < MIN_SECURITY_BITS as f64 => {
+                Err(BFVError::InsecureParameters {
+                    estimated: estimate.bits as u32,
+                    required: MIN_SECURITY_BITS,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for BFVParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BFV(n = {}, q = {}, t = {}, σ = {}, secret = {:?}, max inner product = {})",
+            self.rlwe_dimension,
+            self.cipher_modulus(),
+            self.plain_modulus(),
+            self.noise_std_dev,
+            self.secret_key_distribution,
+            self.max_inner_product_len(),
+        )
+    }
+}
+
+impl Default for BFVParameters {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,369 @@
+//! Define a Merkle-tree based commitment scheme for univariate polynomials.
+//!
+//! It follows the Ligero/Brakedown style: the coefficients are arranged into a matrix,
+//! each row is encoded with a Reed-Solomon code, and the columns of the encoded matrix
+//! are committed in a Merkle tree.
+//! An evaluation `p(z)` is a bilinear form `q1 * M * q2` of the coefficient matrix `M`,
+//! so the prover sends `q1 * M` together with a random combination of the rows,
+//! and the verifier checks them against randomly opened columns.
+//!
+//! Each row is padded with random coefficients before encoding, so the opened columns
+//! are uniformly random, and the random combination of the rows is masked by a committed random row.
+
+use algebra::{utils::dot_product, CsRng, Field, Polynomial, Random};
+use num_traits::ToPrimitive;
+use rand::{distributions::Distribution, Rng};
+use sha2::{Digest, Sha256};
+
+use crate::Transcript;
+
+/// The inverse rate of the Reed-Solomon code.
+const INVERSE_RATE: usize = 4;
+/// The number of opened columns.
+const NUM_QUERIES: usize = 64;
+
+/// The commitment of a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolynomialCommitment {
+    root: [u8; 32],
+    coeff_count: usize,
+}
+
+impl PolynomialCommitment {
+    /// Return the Merkle root.
+    #[inline]
+    pub fn root(&self) -> [u8; 32] {
+        self.root
+    }
+
+    /// Return the coeff count of the committed polynomial.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.coeff_count
+    }
+}
+
+/// The data kept by the prover to open the commitment.
+#[derive(Debug, Clone)]
+pub struct CommitmentState<F: Field> {
+    /// the padded rows of the coefficient matrix, followed by the mask row
+    rows: Vec<Vec<F>>,
+    encoded_rows: Vec<Vec<F>>,
+    tree: MerkleTree,
+    commitment: PolynomialCommitment,
+}
+
+impl<F: Field> CommitmentState<F> {
+    /// Return the commitment.
+    #[inline]
+    pub fn commitment(&self) -> &PolynomialCommitment {
+        &self.commitment
+    }
+}
+
+/// An opened column of the encoded matrix with its Merkle path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnOpening<F: Field> {
+    /// The index of the column.
+    pub index: usize,
+    /// The entries of the column.
+    pub column: Vec<F>,
+    /// The sibling hashes from the leaf to the root.
+    pub path: Vec<[u8; 32]>,
+}
+
+/// The proof of an evaluation of a committed polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvaluationProof<F: Field> {
+    eval_row: Vec<F>,
+    proximity_row: Vec<F>,
+    openings: Vec<ColumnOpening<F>>,
+}
+
+/// Define the polynomial commitment scheme.
+///
+/// The soundness error of an opening is about `(3/4)^64 + n / |F|`,
+/// so the field should be large, such as the ciphertext field.
+/// Note that besides the evaluation, an opening at `z` reveals the `sqrt(n)` values
+/// `q1 * M`, which are linear combinations of the coefficients depending on `z`.
+pub struct MerklePCS;
+
+impl MerklePCS {
+    /// Commit to `poly`.
+    pub fn commit<F, R>(poly: &Polynomial<F>, rng: &mut R) -> CommitmentState<F>
+    where
+        F: Field + Random,
+        R: CsRng,
+    {
+        let coeff_count = poly.coeff_count();
+        let (num_rows, num_cols) = matrix_shape(coeff_count);
+        let code_len = code_len(num_cols);
+        assert!(
+            code_len < F::modulus_value().to_usize().unwrap_or(usize::MAX),
+            "the field is too small for the code"
+        );
+
+        let distribution = F::standard_distribution();
+        let mut coeffs = poly.as_slice().to_vec();
+        coeffs.resize(num_rows * num_cols, F::ZERO);
+        let mut rows: Vec<Vec<F>> = coeffs
+            .chunks_exact(num_cols)
+            .map(|chunk| {
+                let mut row = chunk.to_vec();
+                row.extend(distribution.sample_iter(&mut *rng).take(NUM_QUERIES));
+                row
+            })
+            .collect();
+        rows.push(
+            distribution
+                .sample_iter(&mut *rng)
+                .take(num_cols + NUM_QUERIES)
+                .collect(),
+        );
+        let encoded_rows: Vec<Vec<F>> = rows.iter().map(|row| encode(row, code_len)).collect();
+
+        let leaves = (0..code_len)
+            .map(|j| hash_column(encoded_rows.iter().map(|row| row[j])))
+            .collect();
+        let tree = MerkleTree::new(leaves);
+        let commitment = PolynomialCommitment {
+            root: tree.root(),
+            coeff_count,
+        };
+
+        CommitmentState {
+            rows,
+            encoded_rows,
+            tree,
+            commitment,
+        }
+    }
+
+    /// Open the committed polynomial at `point`, return the evaluation and its proof.
+    pub fn open<F: Field + Random>(
+        state: &CommitmentState<F>,
+        point: F,
+    ) -> (F, EvaluationProof<F>) {
+        let (num_rows, num_cols) = matrix_shape(state.commitment.coeff_count);
+        let (data_rows, mask_row) = state.rows.split_at(num_rows);
+        let (q1, q2) = tensor(point, num_rows, num_cols);
+
+        let eval_row = combine_rows(data_rows, &q1, None);
+        let value = dot_product(&eval_row[..num_cols], &q2);
+
+        let mut transcript = Self::transcript(&state.commitment, point, value, &eval_row);
+        let gamma = transcript.challenge_fields(b"proximity", num_rows);
+        let proximity_row = combine_rows(data_rows, &gamma, Some(&mask_row[0]));
+
+        transcript.append_message(b"proximity row", &to_bytes(&proximity_row));
+        let openings = query_indices(&mut transcript, code_len(num_cols))
+            .into_iter()
+            .map(|index| ColumnOpening {
+                index,
+                column: state.encoded_rows.iter().map(|row| row[index]).collect(),
+                path: state.tree.path(index),
+            })
+            .collect();
+
+        let proof = EvaluationProof {
+            eval_row,
+            proximity_row,
+            openings,
+        };
+        (value, proof)
+    }
+
+    /// Verify that the committed polynomial evaluates to `value` at `point`.
+    pub fn verify<F: Field + Random>(
+        commitment: &PolynomialCommitment,
+        point: F,
+        value: F,
+        proof: &EvaluationProof<F>,
+    ) -> bool {
+        let (num_rows, num_cols) = matrix_shape(commitment.coeff_count);
+        let row_len = num_cols + NUM_QUERIES;
+        let code_len = code_len(num_cols);
+        if proof.eval_row.len() != row_len
+            || proof.proximity_row.len() != row_len
+            || proof.openings.len() != NUM_QUERIES
+        {
+            return false;
+        }
+
+        let (q1, q2) = tensor(point, num_rows, num_cols);
+        if dot_product(&proof.eval_row[..num_cols], &q2) != value {
+            return false;
+        }
+
+        let mut transcript = Self::transcript(commitment, point, value, &proof.eval_row);
+        let gamma: Vec<F> = transcript.challenge_fields(b"proximity", num_rows);
+        transcript.append_message(b"proximity row", &to_bytes(&proof.proximity_row));
+        let indices = query_indices(&mut transcript, code_len);
+
+        let encoded_eval = encode(&proof.eval_row, code_len);
+        let encoded_proximity = encode(&proof.proximity_row, code_len);
+        proof.openings.iter().zip(indices).all(|(opening, index)| {
+            opening.index == index
+                && opening.column.len() == num_rows + 1
+                && MerkleTree::verify(
+                    &commitment.root,
+                    index,
+                    hash_column(opening.column.iter().copied()),
+                    &opening.path,
+                )
+                && dot_product(&opening.column[..num_rows], &q1) == encoded_eval[index]
+                && opening.column[num_rows] + dot_product(&opening.column[..num_rows], &gamma)
+                    == encoded_proximity[index]
+        })
+    }
+
+    fn transcript<F: Field>(
+        commitment: &PolynomialCommitment,
+        point: F,
+        value: F,
+        eval_row: &[F],
+    ) -> Transcript {
+        let mut transcript = Transcript::new(b"threshold-zk-lhe merkle pcs");
+        transcript.append_message(b"root", &commitment.root);
+        transcript.append_message(
+            b"coeff count",
+            &(commitment.coeff_count as u64).to_be_bytes(),
+        );
+        transcript.append_message(b"point", &to_bytes(&[point, value]));
+        transcript.append_message(b"eval row", &to_bytes(eval_row));
+        transcript
+    }
+}
+
+/// Arrange `coeff_count` coefficients into a matrix with a power-of-two number of columns.
+fn matrix_shape(coeff_count: usize) -> (usize, usize) {
+    assert!(coeff_count > 0, "the polynomial should not be empty");
+    let num_cols = 1 << (coeff_count.next_power_of_two().trailing_zeros() / 2);
+    (coeff_count.div_ceil(num_cols), num_cols)
+}
+
+/// Return `(q1, q2)` such that `p(point) = q1 * M * q2`.
+fn tensor<F: Field>(point: F, num_rows: usize, num_cols: usize) -> (Vec<F>, Vec<F>) {
+    let q2 = powers(point, num_cols);
+    let step = q2[num_cols - 1] * point;
+    (powers(step, num_rows), q2)
+}
+
+fn powers<F: Field>(base: F, n: usize) -> Vec<F> {
+    let mut power = F::ONE;
+    (0..n)
+        .map(|_| {
+            let current = power;
+            power *= base;
+            current
+        })
+        .collect()
+}
+
+/// The length of the codeword of a row, which is a power of two for the Merkle tree.
+#[inline]
+fn code_len(num_cols: usize) -> usize {
+    ((num_cols + NUM_QUERIES) * INVERSE_RATE).next_power_of_two()
+}
+
+/// Encode a row as the evaluations of the polynomial with the row as coefficients
+/// at `1, 2, ..., code_len`.
+fn encode<F: Field>(row: &[F], code_len: usize) -> Vec<F> {
+    (1..=code_len)
+        .map(|x| {
+            let x = F::cast_from_usize(x);
+            row.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c)
+        })
+        .collect()
+}
+
+fn combine_rows<F: Field>(rows: &[Vec<F>], scalars: &[F], mask: Option<&[F]>) -> Vec<F> {
+    let mut res = mask.map_or_else(|| vec![F::ZERO; rows[0].len()], <[F]>::to_vec);
+    for (row, &s) in rows.iter().zip(scalars) {
+        res.iter_mut()
+            .zip(row)
+            .for_each(|(r, &v)| r.add_mul_assign(v, s));
+    }
+    res
+}
+
+fn query_indices(transcript: &mut Transcript, num_leaves: usize) -> Vec<usize> {
+    let mut rng = transcript.challenge_rng(b"queries");
+    (0..NUM_QUERIES)
+        .map(|_| rng.gen_range(0..num_leaves))
+        .collect()
+}
+
+fn to_bytes<F: Field>(values: &[F]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|v| (v.cast_into_usize() as u64).to_be_bytes())
+        .collect()
+}
+
+fn hash_column<F: Field>(column: impl Iterator<Item = F>) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0]);
+    column.for_each(|v| hasher.update((v.cast_into_usize() as u64).to_be_bytes()));
+    hasher.finalize().into()
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([1]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// A Merkle tree over a power-of-two number of leaves.
+#[derive(Debug, Clone)]
+struct MerkleTree {
+    /// `nodes[1]` is the root, and the children of `nodes[i]` are `nodes[2i]` and `nodes[2i + 1]`.
+    nodes: Vec<[u8; 32]>,
+}
+
+impl MerkleTree {
+    fn new(leaves: Vec<[u8; 32]>) -> Self {
+        let n = leaves.len();
+        debug_assert!(n.is_power_of_two());
+        let mut nodes = vec![[0; 32]; n];
+        nodes.extend(leaves);
+        for i in (1..n).rev() {
+            nodes[i] = hash_node(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+        Self { nodes }
+    }
+
+    #[inline]
+    fn root(&self) -> [u8; 32] {
+        self.nodes[1]
+    }
+
+    fn path(&self, index: usize) -> Vec<[u8; 32]> {
+        let mut i = index + self.nodes.len() / 2;
+        let mut path = Vec::new();
+        while i > 1 {
+            path.push(self.nodes[i ^ 1]);
+            i >>= 1;
+        }
+        path
+    }
+
+    fn verify(root: &[u8; 32], index: usize, leaf: [u8; 32], path: &[[u8; 32]]) -> bool {
+        if index >> path.len() != 0 {
+            return false;
+        }
+        let node = path
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (level, sibling)| {
+                if (index >> level) & 1 == 0 {
+                    hash_node(&node, sibling)
+                } else {
+                    hash_node(sibling, &node)
+                }
+            });
+        node == *root
+    }
+}
@@ -2,9 +2,12 @@
 
 use algebra::{
     derive::{Field, Prime, Random},
-    Polynomial,
+    Field, Polynomial,
 };
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::BFVError;
 
 /// The field for the plaintext space.
 #[derive(Field, Random, Prime, Serialize, Deserialize)]
@@ -21,4 +24,156 @@ impl BFVPlaintext {
     pub fn new(poly: Polynomial<PlainField>) -> Self {
         Self(poly)
     }
+
+    /// Create the constant polynomial `value` with `dimension` coefficients.
+    #[inline]
+    pub fn constant(dimension: usize, value: PlainField) -> Self {
+        Self::monomial(dimension, 0, value)
+    }
+
+    /// Create the monomial `coeff * X^degree` with `dimension` coefficients, `degree` less than `dimension`.
+    pub fn monomial(dimension: usize, degree: usize, coeff: PlainField) -> Self {
+        assert!(
+            degree < dimension,
+            "the degree should be less than the dimension"
+        );
+        let mut poly = Polynomial::zero(dimension);
+        poly[degree] = coeff;
+        Self(poly)
+    }
+
+    /// Check that the message has `dimension` coefficients, each below the plaintext modulus,
+    /// as a shorter, longer or unreduced message is not encrypted correctly.
+    pub fn validate(&self, dimension: usize) -> Result<(), BFVError> {
+        if self.0.coeff_count() != dimension {
+            return Err(BFVError::ParameterMismatch(
+                "the plaintext should have rlwe_dimension coefficients",
+            ));
+        }
+        if self
+            .0
+            .iter()
+            .any(|x| x.get() >= PlainField::modulus_value())
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the coefficients of the plaintext should be below the plaintext modulus",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Create the message holding `bits` as `0` and `1` coefficients,
+    /// zero padded to `dimension` coefficients.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if there are more bits than `dimension`.
+    pub fn from_bits(bits: &[bool], dimension: usize) -> Result<Self, BFVError> {
+        if bits.len() > dimension {
+            return Err(BFVError::ParameterMismatch(
+                "the bits should not be more than the dimension",
+            ));
+        }
+        let mut poly = Polynomial::zero(dimension);
+        poly.iter_mut()
+            .zip(bits)
+            .for_each(|(c, &bit)| *c = PlainField::new(bit as u16));
+        Ok(Self(poly))
+    }
+
+    /// Read the first `len` coefficients as bits, ignoring the rest.
+    ///
+    /// Return `None` if there are fewer than `len` coefficients or one of them is not a bit.
+    /// The coefficients may be secret, so they are checked in constant time
+    /// and only the validity leaks.
+    pub fn to_bits(&self, len: usize) -> Option<Vec<bool>> {
+        let coeffs = self.0.as_slice().get(..len)?;
+        let mut valid = Choice::from(1);
+        let bits = coeffs
+            .iter()
+            .map(|c| {
+                let one = c.ct_eq(&PlainField::ONE);
+                valid &= one | c.ct_eq(&PlainField::ZERO);
+                one.unwrap_u8() == 1
+            })
+            .collect();
+        bool::from(valid).then_some(bits)
+    }
+}
+
+/// Compares the messages in constant time, for the decrypted secrets such as symmetric keys.
+impl ConstantTimeEq for BFVPlaintext {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// A plaintext in the ring `Z_{2^k}[X]/(X^n + 1)` with the power-of-two plaintext modulus
+/// `t = 2^k`, for byte oriented messages, see [`BFVScheme::encrypt_z2k`].
+///
+/// Unlike [`BFVPlaintext`] it can't be secret shared, as `Z_{2^k}` is not a field.
+///
+/// [`BFVScheme::encrypt_z2k`]: crate::BFVScheme::encrypt_z2k
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Z2kPlaintext {
+    bits: u32,
+    coeffs: Vec<u8>,
+}
+
+impl Z2kPlaintext {
+    /// The largest supported `k`, keeping the noise of the default parameters below `q / 2t`.
+    pub const MAX_BITS: u32 = 8;
+
+    /// Create a new instance with the plaintext modulus `2^bits`.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `bits` is not in `1..=MAX_BITS`
+    /// or a coefficient is not below `2^bits`.
+    pub fn new(bits: u32, coeffs: Vec<u8>) -> Result<Self, BFVError> {
+        if !(1..=Self::MAX_BITS).contains(&bits) {
+            return Err(BFVError::ParameterMismatch(
+                "the bits of the plaintext modulus should be in 1..=8",
+            ));
+        }
+        if coeffs.iter().any(|&c| u32::from(c) >> bits != 0) {
+            return Err(BFVError::ParameterMismatch(
+                "the coefficients of the plaintext should be below the plaintext modulus",
+            ));
+        }
+        Ok(Self { bits, coeffs })
+    }
+
+    /// Create the plaintext modulo `2^8` holding `bytes`, zero padded to `dimension` coefficients.
+    pub fn from_bytes(bytes: &[u8], dimension: usize) -> Result<Self, BFVError> {
+        if bytes.len() > dimension {
+            return Err(BFVError::ParameterMismatch(
+                "the bytes should not be longer than the dimension",
+            ));
+        }
+        let mut coeffs = bytes.to_vec();
+        coeffs.resize(dimension, 0);
+        Self::new(8, coeffs)
+    }
+
+    /// Return `k` of the plaintext modulus `2^k`.
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Return the coefficients.
+    #[inline]
+    pub fn coeffs(&self) -> &[u8] {
+        &self.coeffs
+    }
+
+    /// Return the number of coefficients.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// Create an instance from coefficients already reduced modulo `2^bits`.
+    #[inline]
+    pub(crate) fn from_reduced(bits: u32, coeffs: Vec<u8>) -> Self {
+        Self { bits, coeffs }
+    }
 }
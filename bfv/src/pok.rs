@@ -0,0 +1,284 @@
+//! Define the proof of plaintext knowledge for BFV ciphertexts.
+
+use algebra::{Field, Polynomial};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    protocol::Reader, scheme::lift_plaintext, BFVCiphertext, BFVContext, BFVError, BFVPublicKey,
+    BFVPublicKeyPolys, CipherField, EncryptionWitness, PlainField, Transcript,
+};
+
+/// The number of non-zero coefficients of the challenge.
+const CHALLENGE_WEIGHT: usize = 16;
+/// The bound of the ternary `u`.
+const BOUND_U: i64 = 1;
+/// The bound of the errors sampled from the centered binomial distribution.
+const BOUND_E: i64 = 21;
+/// The bound of `e1`, which also includes the rounding error of scaling the message,
+/// at most `(q mod t) / 2 = 3`.
+const BOUND_E1: i64 = BOUND_E + 3;
+/// The maximum number of rejection sampling attempts.
+const MAX_ATTEMPTS: usize = 256;
+
+/// A non-interactive zero-knowledge proof that the creator of a ciphertext knows
+/// the message and the randomness, see [`EncryptionWitness`].
+///
+/// It is the Fiat-Shamir transform of the sigma protocol with a sparse ternary challenge `c`
+/// and rejection sampling of the responses `z = y + c * w`, which are bounded uniform.
+/// The proof is bound to a `label`, such as a session or a sender id, to prevent replays.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaintextKnowledgeProof {
+    challenge: [u8; 32],
+    z_u: Vec<i64>,
+    z_e1: Vec<i64>,
+    z_e2: Vec<i64>,
+    z_m: Vec<i64>,
+}
+
+impl PlaintextKnowledgeProof {
+    /// Prove the knowledge of `witness` for the ciphertext `c` under `pk`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn prove(
+        ctx: &BFVContext,
+        pk: &BFVPublicKey,
+        c: &BFVCiphertext,
+        witness: &EncryptionWitness,
+        label: &[u8],
+    ) -> Self {
+        let n = ctx.rlwe_dimension();
+        let u = centered(&witness.u);
+        let e1 = centered(&witness.e1);
+        let e2 = centered(&witness.e2);
+        let m = centered(&lift_plaintext(&witness.m.0));
+        let bound_m = bound_m();
+        assert!(
+            in_bound(&u, BOUND_U)
+                && in_bound(&e1, BOUND_E1)
+                && in_bound(&e2, BOUND_E)
+                && in_bound(&m, bound_m),
+            "the witness is out of bound"
+        );
+
+        let mut csrng = ctx.csrng_mut();
+        for _ in 0..MAX_ATTEMPTS {
+            let y_u = sample_mask(n, mask_bound(n, BOUND_U), &mut *csrng);
+            let y_e1 = sample_mask(n, mask_bound(n, BOUND_E1), &mut *csrng);
+            let y_e2 = sample_mask(n, mask_bound(n, BOUND_E), &mut *csrng);
+            let y_m = sample_mask(n, mask_bound(n, bound_m), &mut *csrng);
+
+            let w = linear_map(pk, &y_u, &y_e1, &y_e2, &y_m);
+            let challenge = derive_challenge(pk, c, &w, label);
+            let ch = challenge_poly(&challenge, n);
+
+            let z_u = respond(&y_u, &ch, &u);
+            let z_e1 = respond(&y_e1, &ch, &e1);
+            let z_e2 = respond(&y_e2, &ch, &e2);
+            let z_m = respond(&y_m, &ch, &m);
+
+            if in_bound(&z_u, response_bound(n, BOUND_U))
+                && in_bound(&z_e1, response_bound(n, BOUND_E1))
+                && in_bound(&z_e2, response_bound(n, BOUND_E))
+                && in_bound(&z_m, response_bound(n, bound_m))
+            {
+                return Self {
+                    challenge,
+                    z_u,
+                    z_e1,
+                    z_e2,
+                    z_m,
+                };
+            }
+        }
+        panic!("rejection sampling exceeds the maximum attempts");
+    }
+
+    /// Verify the proof for the ciphertext `c` under `pk` and `label`.
+    ///
+    /// Return `false` if `c` does not have the dimension of `pk`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn verify(&self, pk: &BFVPublicKey, c: &BFVCiphertext, label: &[u8]) -> bool {
+        let BFVPublicKeyPolys { b, a } = pk.polys();
+        let n = b.coeff_count();
+        if n < CHALLENGE_WEIGHT
+            || [a, &c.c1, &c.c2].iter().any(|p| p.coeff_count() != n)
+            || [&self.z_u, &self.z_e1, &self.z_e2, &self.z_m]
+                .iter()
+                .any(|z| z.len() != n)
+            || !in_bound(&self.z_u, response_bound(n, BOUND_U))
+            || !in_bound(&self.z_e1, response_bound(n, BOUND_E1))
+            || !in_bound(&self.z_e2, response_bound(n, BOUND_E))
+            || !in_bound(&self.z_m, response_bound(n, bound_m()))
+        {
+            return false;
+        }
+
+        // w = A * z - c * ct
+        let ch = to_poly(&challenge_poly(&self.challenge, n));
+        let BFVCiphertext { c1: w1, c2: w2 } =
+            linear_map(pk, &self.z_u, &self.z_e1, &self.z_e2, &self.z_m);
+        let w = BFVCiphertext::new(w1 - &ch * &c.c1, w2 - &ch * &c.c2);
+
+        derive_challenge(pk, c, &w, label) == self.challenge
+    }
+
+    /// Serialize to `Vec<u8>`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        self.write(&mut bytes);
+        bytes
+    }
+
+    /// Deserialize from [u8].
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        let mut reader = Reader::new(bytes);
+        Self::read(&mut reader)
+            .filter(|_| reader.is_empty())
+            .ok_or(BFVError::SerializationError("plaintext knowledge proof"))
+    }
+
+    /// Write the layout `|challenge|len|z_u,z_e1,z_e2,z_m|`, the responses as big-endian `i64`.
+    pub(crate) fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.challenge);
+        bytes.extend((self.z_u.len() as u32).to_be_bytes());
+        for z in [&self.z_u, &self.z_e1, &self.z_e2, &self.z_m] {
+            z.iter().for_each(|v| bytes.extend(v.to_be_bytes()));
+        }
+    }
+
+    /// Read the layout of [`Self::write`].
+    pub(crate) fn read(reader: &mut Reader<'_>) -> Option<Self> {
+        let challenge = reader.array()?;
+        let len = reader.u32()? as usize;
+        if reader.remaining() < len.checked_mul(4 * 8)? {
+            return None;
+        }
+        let mut z = || {
+            (0..len)
+                .map(|_| reader.array().map(i64::from_be_bytes))
+                .collect::<Option<Vec<_>>>()
+        };
+        Some(Self {
+            challenge,
+            z_u: z()?,
+            z_e1: z()?,
+            z_e2: z()?,
+            z_m: z()?,
+        })
+    }
+}
+
+/// The bound of the centered message.
+#[inline]
+fn bound_m() -> i64 {
+    (PlainField::modulus_value() as i64 - 1) / 2
+}
+
+/// The bound of the masks for a witness bounded by `bound`,
+/// so that all responses are accepted with a constant probability.
+#[inline]
+fn mask_bound(n: usize, bound: i64) -> i64 {
+    4 * n as i64 * CHALLENGE_WEIGHT as i64 * bound
+}
+
+/// The bound of the accepted responses for a witness bounded by `bound`.
+#[inline]
+fn response_bound(n: usize, bound: i64) -> i64 {
+    mask_bound(n, bound) - CHALLENGE_WEIGHT as i64 * bound
+}
+
+#[inline]
+fn in_bound(v: &[i64], bound: i64) -> bool {
+    v.iter().all(|x| x.abs() <= bound)
+}
+
+fn sample_mask<R: Rng>(n: usize, bound: i64, rng: &mut R) -> Vec<i64> {
+    (0..n).map(|_| rng.gen_range(-bound..=bound)).collect()
+}
+
+/// Compute `(b * u + e1 + delta * m, a * u + e2)`.
+fn linear_map(pk: &BFVPublicKey, u: &[i64], e1: &[i64], e2: &[i64], m: &[i64]) -> BFVCiphertext {
+    let BFVPublicKeyPolys { b, a } = pk.polys();
+    let delta = CipherField::new(CipherField::modulus_value() / PlainField::modulus_value() as u32);
+    let u = to_poly(u);
+    let w1 = b * &u + to_poly(e1) + to_poly(m).mul_scalar(delta);
+    let w2 = a * &u + to_poly(e2);
+    BFVCiphertext::new(w1, w2)
+}
+
+/// Compute `y + c * w` over the integers in the negacyclic ring, where `c` is sparse.
+fn respond(y: &[i64], c: &[i64], w: &[i64]) -> Vec<i64> {
+    let n = y.len();
+    let mut z = y.to_vec();
+    for (i, &ci) in c.iter().enumerate().filter(|(_, &ci)| ci != 0) {
+        for (j, &wj) in w.iter().enumerate() {
+            if i + j < n {
+                z[i + j] += ci * wj;
+            } else {
+                z[i + j - n] -= ci * wj;
+            }
+        }
+    }
+    z
+}
+
+/// Expand the challenge seed into a ternary polynomial with [`CHALLENGE_WEIGHT`] non-zero coefficients.
+///
+/// # Panics
+///
+/// Panics if `n` is less than [`CHALLENGE_WEIGHT`].
+fn challenge_poly(seed: &[u8; 32], n: usize) -> Vec<i64> {
+    assert!(
+        n >= CHALLENGE_WEIGHT,
+        "the dimension should be at least the weight of the challenge"
+    );
+    let mut rng = ChaCha12Rng::from_seed(*seed);
+    let mut c = vec![0; n];
+    let mut count = 0;
+    while count < CHALLENGE_WEIGHT {
+        let i = rng.gen_range(0..n);
+        if c[i] == 0 {
+            c[i] = if rng.gen::<bool>() { 1 } else { -1 };
+            count += 1;
+        }
+    }
+    c
+}
+
+fn derive_challenge(
+    pk: &BFVPublicKey,
+    c: &BFVCiphertext,
+    w: &BFVCiphertext,
+    label: &[u8],
+) -> [u8; 32] {
+    let mut transcript = Transcript::new(b"threshold-zk-lhe plaintext knowledge");
+    transcript.append_message(b"label", label);
+    transcript.append_message(b"public key", &pk.to_vec());
+    transcript.append_message(b"ciphertext", &c.to_vec());
+    transcript.append_message(b"commitment", &w.to_vec());
+    transcript.challenge_bytes(b"challenge")
+}
+
+fn centered(poly: &Polynomial<CipherField>) -> Vec<i64> {
+    let q = CipherField::modulus_value() as i64;
+    poly.iter()
+        .map(|x| {
+            let value = x.cast_into_usize() as i64;
+            if value > q / 2 {
+                value - q
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
+fn to_poly(v: &[i64]) -> Polynomial<CipherField> {
+    let q = CipherField::modulus_value() as i64;
+    Polynomial::new(
+        v.iter()
+            .map(|&x| CipherField::new(x.rem_euclid(q) as u32))
+            .collect(),
+    )
+}
@@ -0,0 +1,371 @@
+//! Define the messages exchanged in the threshold protocols.
+//!
+//! Every message starts with a [`MessageHeader`] and has a canonical binary encoding:
+//! `|version(2)|kind(1)|session_id(32)|sender(2)|payload|`, all integers in big-endian.
+//! Polynomials are encoded as their coefficient count (4) followed by the coefficients.
+
+use algebra::{Field, Polynomial};
+use ed25519_dalek::Signature;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BFVCiphertext, BFVPublicKey, BFVPublicKeyPolys, BlindingMessage, CipherField, CiphertextShare,
+    PlainField,
+};
+
+type F = PlainField;
+
+/// The current version of the protocol messages.
+pub const PROTOCOL_VERSION: u16 = 1;
+
+/// The header shared by all protocol messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageHeader {
+    /// The version of the message format.
+    pub version: u16,
+    /// The session this message belongs to.
+    pub session_id: [u8; 32],
+    /// The index of the sender, `0` for a party outside of the committee such as the encryptor.
+    pub sender: F,
+}
+
+impl MessageHeader {
+    /// Create a new header with the current [`PROTOCOL_VERSION`].
+    #[inline]
+    pub fn new(session_id: [u8; 32], sender: F) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            session_id,
+            sender,
+        }
+    }
+}
+
+/// A typed protocol message with a canonical binary encoding.
+pub trait ProtocolMessage: Sized {
+    /// The tag identifying the kind of the message.
+    const KIND: u8;
+
+    /// Return the header of the message.
+    fn header(&self) -> &MessageHeader;
+
+    /// Append the encoding of the payload to `bytes`.
+    fn encode_payload(&self, bytes: &mut Vec<u8>);
+
+    /// Decode the payload, return `None` if it is malformed.
+    fn decode_payload(header: MessageHeader, reader: &mut Reader<'_>) -> Option<Self>;
+
+    /// Serialize to `Vec<u8>`
+    fn to_vec(&self) -> Vec<u8> {
+        let header = self.header();
+        let mut bytes = vec![];
+        bytes.extend(header.version.to_be_bytes());
+        bytes.push(Self::KIND);
+        bytes.extend(header.session_id);
+        bytes.extend(header.sender.get().to_be_bytes());
+        self.encode_payload(&mut bytes);
+        bytes
+    }
+
+    /// Deserialize from [u8], return `None` if the version or the kind does not match,
+    /// or the bytes are malformed.
+    fn from_vec(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::new(bytes);
+        let version = reader.u16()?;
+        if version != PROTOCOL_VERSION || reader.u8()? != Self::KIND {
+            return None;
+        }
+        let session_id = reader.array()?;
+        let sender = reader.plain()?;
+
+        let message = Self::decode_payload(
+            MessageHeader {
+                version,
+                session_id,
+                sender,
+            },
+            &mut reader,
+        )?;
+        reader.is_empty().then_some(message)
+    }
+}
+
+/// A share of an encrypted message sent from the encryptor to a node, the first round of encryption.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionShareMessage {
+    /// The header.
+    pub header: MessageHeader,
+    /// The index of the receiving node.
+    pub receiver: F,
+    /// The share encrypted under the receiver's public key.
+    pub ct: BFVCiphertext,
+}
+
+/// A share re-encrypted to the receiver by a node, the partial decryption of a message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartialDecryptionMessage {
+    /// The header.
+    pub header: MessageHeader,
+    /// The authenticated share.
+    pub share: CiphertextShare,
+}
+
+/// A blinding message between helpers, the first round of share recovery.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryBlindingRound {
+    /// The header.
+    pub header: MessageHeader,
+    /// The blinding message.
+    pub message: BlindingMessage,
+}
+
+/// An aggregated blinding sent to the new node, the second round of share recovery.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryAggregateRound {
+    /// The header.
+    pub header: MessageHeader,
+    /// The aggregated blinding.
+    pub aggregated: Polynomial<F>,
+}
+
+/// The public key share of a node, the only round of the joint key generation,
+/// see [`crate::ThresholdPKE::gen_key_share`] and [`crate::ThresholdPKE::aggregate_public_keys`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DkgPublicRound {
+    /// The header.
+    pub header: MessageHeader,
+    /// The public key share of the sender.
+    pub public_key: BFVPublicKey,
+}
+
+impl ProtocolMessage for EncryptionShareMessage {
+    const KIND: u8 = 1;
+
+    fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    fn encode_payload(&self, bytes: &mut Vec<u8>) {
+        write_plain(bytes, self.receiver);
+        write_ciphertext(bytes, &self.ct);
+    }
+
+    fn decode_payload(header: MessageHeader, reader: &mut Reader<'_>) -> Option<Self> {
+        Some(Self {
+            header,
+            receiver: reader.plain()?,
+            ct: reader.ciphertext()?,
+        })
+    }
+}
+
+impl ProtocolMessage for PartialDecryptionMessage {
+    const KIND: u8 = 2;
+
+    fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    fn encode_payload(&self, bytes: &mut Vec<u8>) {
+        write_plain(bytes, self.share.index);
+        write_ciphertext(bytes, &self.share.ct);
+        bytes.extend(self.share.sig.to_bytes());
+    }
+
+    fn decode_payload(header: MessageHeader, reader: &mut Reader<'_>) -> Option<Self> {
+        let index = reader.plain()?;
+        let ct = reader.ciphertext()?;
+        let sig = Signature::from_bytes(&reader.array()?);
+        Some(Self {
+            header,
+            share: CiphertextShare { index, ct, sig },
+        })
+    }
+}
+
+impl ProtocolMessage for RecoveryBlindingRound {
+    const KIND: u8 = 3;
+
+    fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    fn encode_payload(&self, bytes: &mut Vec<u8>) {
+        write_plain(bytes, self.message.sender);
+        write_plain(bytes, self.message.receiver);
+        write_plain_poly(bytes, &self.message.blinding);
+    }
+
+    fn decode_payload(header: MessageHeader, reader: &mut Reader<'_>) -> Option<Self> {
+        Some(Self {
+            header,
+            message: BlindingMessage {
+                sender: reader.plain()?,
+                receiver: reader.plain()?,
+                blinding: reader.plain_poly()?,
+            },
+        })
+    }
+}
+
+impl ProtocolMessage for RecoveryAggregateRound {
+    const KIND: u8 = 4;
+
+    fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    fn encode_payload(&self, bytes: &mut Vec<u8>) {
+        write_plain_poly(bytes, &self.aggregated);
+    }
+
+    fn decode_payload(header: MessageHeader, reader: &mut Reader<'_>) -> Option<Self> {
+        Some(Self {
+            header,
+            aggregated: reader.plain_poly()?,
+        })
+    }
+}
+
+impl ProtocolMessage for DkgPublicRound {
+    const KIND: u8 = 5;
+
+    fn header(&self) -> &MessageHeader {
+        &self.header
+    }
+
+    fn encode_payload(&self, bytes: &mut Vec<u8>) {
+        let BFVPublicKeyPolys { b, a } = self.public_key.polys();
+        write_cipher_poly(bytes, b);
+        write_cipher_poly(bytes, a);
+    }
+
+    fn decode_payload(header: MessageHeader, reader: &mut Reader<'_>) -> Option<Self> {
+        let b = reader.cipher_poly()?;
+        let a = reader.cipher_poly()?;
+        Some(Self {
+            header,
+            public_key: BFVPublicKey::new(b, a),
+        })
+    }
+}
+
+pub(crate) fn write_plain(bytes: &mut Vec<u8>, value: F) {
+    bytes.extend(value.get().to_be_bytes());
+}
+
+pub(crate) fn write_plain_poly(bytes: &mut Vec<u8>, poly: &Polynomial<F>) {
+    bytes.extend((poly.coeff_count() as u32).to_be_bytes());
+    poly.iter().for_each(|&v| write_plain(bytes, v));
+}
+
+pub(crate) fn write_cipher_poly(bytes: &mut Vec<u8>, poly: &Polynomial<CipherField>) {
+    bytes.extend((poly.coeff_count() as u32).to_be_bytes());
+    poly.iter().for_each(|v| bytes.extend(v.to_bytes()));
+}
+
+pub(crate) fn write_ciphertext(bytes: &mut Vec<u8>, ct: &BFVCiphertext) {
+    write_cipher_poly(bytes, &ct.c1);
+    write_cipher_poly(bytes, &ct.c2);
+}
+
+/// A reader over the canonical encoding, every method returns `None` on malformed bytes.
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Return `true` if all the bytes have been read.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Return the number of bytes not read yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Read `n` bytes.
+    pub fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    /// Read `N` bytes.
+    pub fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.bytes(N)?.try_into().ok()
+    }
+
+    /// Read a byte.
+    #[inline]
+    pub fn u8(&mut self) -> Option<u8> {
+        self.array::<1>().map(|[v]| v)
+    }
+
+    /// Read a big-endian `u16`.
+    #[inline]
+    pub fn u16(&mut self) -> Option<u16> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    /// Read a big-endian `u32`.
+    #[inline]
+    pub fn u32(&mut self) -> Option<u32> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    /// Read an element of the plaintext field.
+    pub fn plain(&mut self) -> Option<F> {
+        let value = self.u16()?;
+        (value < F::modulus_value()).then(|| F::new(value))
+    }
+
+    /// Read a polynomial over the plaintext field.
+    pub fn plain_poly(&mut self) -> Option<Polynomial<F>> {
+        let len = self.u32()? as usize;
+        if self.bytes.len() < len.checked_mul(2)? {
+            return None;
+        }
+        (0..len)
+            .map(|_| self.plain())
+            .collect::<Option<Vec<F>>>()
+            .map(Polynomial::new)
+    }
+
+    /// Read a polynomial over the ciphertext field.
+    pub fn cipher_poly(&mut self) -> Option<Polynomial<CipherField>> {
+        let len = self.u32()? as usize;
+        if self.bytes.len() < len.checked_mul(4)? {
+            return None;
+        }
+        (0..len)
+            .map(|_| self.cipher())
+            .collect::<Option<Vec<CipherField>>>()
+            .map(Polynomial::new)
+    }
+
+    /// Read an element of the ciphertext field.
+    pub fn cipher(&mut self) -> Option<CipherField> {
+        let value = self.u32()?;
+        (value < CipherField::modulus_value()).then(|| CipherField::new(value))
+    }
+
+    /// Read a BFV ciphertext.
+    pub fn ciphertext(&mut self) -> Option<BFVCiphertext> {
+        let c0 = self.cipher_poly()?;
+        let c1 = self.cipher_poly()?;
+        Some(BFVCiphertext::new(c0, c1))
+    }
+}
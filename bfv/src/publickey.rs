@@ -1,64 +1,180 @@
-//! Define the ciphertext of BFV.
-use algebra::Polynomial;
-use serde::{Deserialize, Serialize};
+//! Define the public key of BFV.
+use std::sync::OnceLock;
 
-use crate::CipherField;
+use algebra::{NTTPolynomial, Polynomial};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-/// Define the public key of BFV.
+use crate::{
+    ciphertext::{read_flagged_pair, read_polynomial_pair, write_polynomial_pair},
+    BFVError, CipherField, Domain,
+};
+
+/// The polynomials `(b, a)` of a [`BFVPublicKey`] in the coefficient domain.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct BFVPublicKey(pub [Polynomial<CipherField>; 2]);
+pub struct BFVPublicKeyPolys {
+    /// `b = e - a * s` with the gaussian noise `e`.
+    pub b: Polynomial<CipherField>,
+    /// The uniformly random `a`.
+    pub a: Polynomial<CipherField>,
+}
+
+/// The polynomials `(b, a)` of a [`BFVPublicKey`] in the NTT domain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BFVNTTPublicKeyPolys {
+    /// The NTT form of `b`.
+    pub b: NTTPolynomial<CipherField>,
+    /// The NTT form of `a`.
+    pub a: NTTPolynomial<CipherField>,
+}
+
+impl From<BFVPublicKeyPolys> for BFVNTTPublicKeyPolys {
+    #[inline]
+    fn from(BFVPublicKeyPolys { b, a }: BFVPublicKeyPolys) -> Self {
+        Self {
+            b: b.into_ntt_polynomial(),
+            a: a.into_ntt_polynomial(),
+        }
+    }
+}
+
+impl From<BFVNTTPublicKeyPolys> for BFVPublicKeyPolys {
+    #[inline]
+    fn from(BFVNTTPublicKeyPolys { b, a }: BFVNTTPublicKeyPolys) -> Self {
+        Self {
+            b: b.into_native_polynomial(),
+            a: a.into_native_polynomial(),
+        }
+    }
+}
+
+/// Define the public key of BFV.
+///
+/// The coefficient and the NTT forms of `(b, a)` are each computed from the other on first use
+/// and kept, so that later encryptions skip the forward transforms, and a key received in the
+/// NTT form skips both transforms until its coefficients are needed.
+#[derive(Clone, Debug)]
+pub struct BFVPublicKey {
+    polys: OnceLock<BFVPublicKeyPolys>,
+    ntt_polys: OnceLock<BFVNTTPublicKeyPolys>,
+}
 
 impl BFVPublicKey {
-    /// Creates a new instance.
+    /// Creates a new instance from the polynomials `(b, a)`.
     #[inline]
-    pub fn new(polys: [Polynomial<CipherField>; 2]) -> Self {
-        Self(polys)
+    pub fn new(b: Polynomial<CipherField>, a: Polynomial<CipherField>) -> Self {
+        Self::from_polys(BFVPublicKeyPolys { b, a })
+    }
+
+    /// Creates a new instance from the polynomials in the coefficient domain.
+    #[inline]
+    pub fn from_polys(polys: BFVPublicKeyPolys) -> Self {
+        Self {
+            polys: OnceLock::from(polys),
+            ntt_polys: OnceLock::new(),
+        }
+    }
+
+    /// Creates a new instance from the polynomials in the NTT domain.
+    #[inline]
+    pub fn from_ntt_polys(ntt_polys: BFVNTTPublicKeyPolys) -> Self {
+        Self {
+            polys: OnceLock::new(),
+            ntt_polys: OnceLock::from(ntt_polys),
+        }
+    }
+
+    /// Returns the polynomials `(b, a)`.
+    #[inline]
+    pub fn polys(&self) -> &BFVPublicKeyPolys {
+        self.polys.get_or_init(|| {
+            self.ntt_polys
+                .get()
+                .expect("one of the forms is set")
+                .clone()
+                .into()
+        })
+    }
+
+    /// Returns the polynomials `(b, a)` in NTT form.
+    #[inline]
+    pub fn ntt_polys(&self) -> &BFVNTTPublicKeyPolys {
+        self.ntt_polys.get_or_init(|| {
+            self.polys
+                .get()
+                .expect("one of the forms is set")
+                .clone()
+                .into()
+        })
     }
 
     /// Serialize to `Vec<u8>`
     pub fn to_vec(&self) -> Vec<u8> {
         // layout: |len0,len1|data0,data1|
+        let BFVPublicKeyPolys { b, a } = self.polys();
         let mut bytes = vec![];
+        write_polynomial_pair(&mut bytes, [b.as_slice(), a.as_slice()]);
+        bytes
+    }
 
-        // length(2)
-        for polys in self.0.iter() {
-            let len = polys.coeff_count() as u32;
-            bytes.extend(len.to_be_bytes());
-        }
-
-        // data
-        for polys in self.0.iter() {
-            for data in polys.iter() {
-                bytes.extend(data.to_bytes());
+    /// Serialize to `Vec<u8>` in `domain`, with the layout `|flag|len0,len1|data0,data1|`.
+    pub fn to_vec_with_domain(&self, domain: Domain) -> Vec<u8> {
+        let mut bytes = vec![domain.flag()];
+        let polys = match domain {
+            Domain::Coefficient => {
+                let BFVPublicKeyPolys { b, a } = self.polys();
+                [b.as_slice(), a.as_slice()]
             }
-        }
-
+            Domain::Evaluation => {
+                let BFVNTTPublicKeyPolys { b, a } = self.ntt_polys();
+                [b.as_slice(), a.as_slice()]
+            }
+        };
+        write_polynomial_pair(&mut bytes, polys);
         bytes
     }
 
     /// Deserialize from [u8]
-    pub fn from_vec(bytes: &[u8]) -> Self {
-        let mut iter = bytes
-            .chunks_exact(4)
-            .map(|chunk| <[u8; 4]>::try_from(chunk).unwrap());
-
-        // length(2)
-        let len0 = u32::from_be_bytes(iter.next().unwrap());
-        let len1 = u32::from_be_bytes(iter.next().unwrap());
-
-        // data
-        let mut data0 = vec![];
-        let mut data1 = vec![];
-        for _ in 0..len0 {
-            data0.push(CipherField::from_bytes(iter.next().unwrap()));
-        }
-        for _ in 0..len1 {
-            data1.push(CipherField::from_bytes(iter.next().unwrap()));
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        read_polynomial_pair(bytes)
+            .map(|[b, a]| Self::new(b, a))
+            .ok_or(BFVError::SerializationError("public key"))
+    }
+
+    /// Deserialize from [u8] serialized by [`Self::to_vec_with_domain`] in either domain,
+    /// keeping the received form.
+    pub fn from_vec_with_domain(bytes: &[u8]) -> Result<Self, BFVError> {
+        match read_flagged_pair(bytes, Domain::Coefficient) {
+            Some((Domain::Coefficient, [b, a])) => {
+                Ok(Self::new(Polynomial::new(b), Polynomial::new(a)))
+            }
+            Some((Domain::Evaluation, [b, a])) => Ok(Self::from_ntt_polys(BFVNTTPublicKeyPolys {
+                b: NTTPolynomial::new(b),
+                a: NTTPolynomial::new(a),
+            })),
+            None => Err(BFVError::SerializationError("public key")),
         }
+    }
+}
+
+impl PartialEq for BFVPublicKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.polys() == other.polys()
+    }
+}
+
+impl Serialize for BFVPublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("BFVPublicKey", self.polys())
+    }
+}
+
+impl<'de> Deserialize<'de> for BFVPublicKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "BFVPublicKey")]
+        struct Polys(BFVPublicKeyPolys);
 
-        Self([
-            Polynomial::<CipherField>::new(data0),
-            Polynomial::<CipherField>::new(data1),
-        ])
+        Polys::deserialize(deserializer).map(|Polys(polys)| Self::from_polys(polys))
     }
 }
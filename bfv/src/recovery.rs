@@ -0,0 +1,165 @@
+//! Define share recovery for a lost node.
+
+use algebra::{CsRng, Polynomial};
+use serde::{Deserialize, Serialize};
+
+use crate::{PlainField, Share, ThresholdPKE, ThresholdPolicy};
+
+type F = PlainField;
+
+/// A blinding message sent from one helper to another during share recovery.
+///
+/// The blindings a helper sends to all the helpers (itself included) sum up to
+/// its Lagrange-weighted share, so a single blinding reveals nothing about the share.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindingMessage {
+    /// The index of the helper who sends the message.
+    pub sender: F,
+    /// The index of the helper who receives the message.
+    pub receiver: F,
+    /// The blinding polynomial.
+    pub blinding: Polynomial<F>,
+}
+
+/// Define the share recovery procedure.
+///
+/// `reconstruction_number` helpers jointly produce the share of a replacement node at `new_index`:
+/// 1. each helper calls [`ShareRecovery::gen_blinding_messages`] and sends the `j`-th message to the `j`-th helper;
+/// 2. each helper calls [`ShareRecovery::aggregate`] on the messages it received and sends the result to the new node;
+/// 3. the new node calls [`ShareRecovery::recover`] on the aggregated messages.
+///
+/// Neither the secret nor any single share is reconstructed along the way.
+#[derive(Debug, Clone)]
+pub struct ShareRecovery {
+    helper_indices: Vec<F>,
+    new_index: F,
+    lagrange_coeffs: Vec<F>,
+}
+
+impl ShareRecovery {
+    /// Create a new instance.
+    /// The number of `helper_indices` should be the reconstruction number of `policy`,
+    /// which is its threshold number unless the policy is packed,
+    /// and `new_index` should be neither a secret point nor one of `helper_indices`.
+    pub fn new(policy: &ThresholdPolicy, helper_indices: Vec<F>, new_index: F) -> Self {
+        assert_eq!(
+            helper_indices.len(),
+            policy.reconstruction_number(),
+            "the number of helpers should be reconstruction_number"
+        );
+        assert!(
+            helper_indices.iter().all(|i| policy.indices().contains(i)),
+            "helper indices should be in the policy"
+        );
+        assert!(
+            !policy.secret_points().contains(&new_index),
+            "new index should not be a secret point"
+        );
+        assert!(
+            !helper_indices.contains(&new_index),
+            "new index should not be a helper index"
+        );
+
+        let lagrange_coeffs = ThresholdPKE::gen_lagrange_coeffs_at(new_index, &helper_indices);
+        Self {
+            helper_indices,
+            new_index,
+            lagrange_coeffs,
+        }
+    }
+
+    /// Return the reference of helper_indices
+    #[inline]
+    pub fn helper_indices(&self) -> &[F] {
+        &self.helper_indices
+    }
+
+    /// Return new_index
+    #[inline]
+    pub fn new_index(&self) -> F {
+        self.new_index
+    }
+
+    /// Generate the blinding messages of the helper at `index` holding `share`,
+    /// one for each helper in the order of `helper_indices`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn gen_blinding_messages<R>(
+        &self,
+        index: F,
+        share: &Share<F>,
+        rng: &mut R,
+    ) -> Vec<BlindingMessage>
+    where
+        R: CsRng,
+    {
+        let position = self.position(index);
+        let mut rest = share
+            .expose_secret()
+            .mul_scalar(self.lagrange_coeffs[position]);
+
+        let mut blindings: Vec<Polynomial<F>> = (1..self.helper_indices.len())
+            .map(|_| {
+                let blinding = Polynomial::<F>::random(share.coeff_count(), &mut *rng);
+                rest -= &blinding;
+                blinding
+            })
+            .collect();
+        blindings.insert(position, rest);
+
+        self.helper_indices
+            .iter()
+            .zip(blindings)
+            .map(|(&receiver, blinding)| BlindingMessage {
+                sender: index,
+                receiver,
+                blinding,
+            })
+            .collect()
+    }
+
+    /// Aggregate the blinding messages received by the helper at `index`,
+    /// the result is sent to the new node.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn aggregate(&self, index: F, messages: &[BlindingMessage]) -> Polynomial<F> {
+        assert_eq!(
+            messages.len(),
+            self.helper_indices.len(),
+            "the number of messages should be threshold_number"
+        );
+        assert!(
+            messages.iter().all(|m| m.receiver == index),
+            "messages should be sent to the helper"
+        );
+        assert!(
+            self.helper_indices
+                .iter()
+                .all(|i| messages.iter().any(|m| m.sender == *i)),
+            "messages should come from every helper"
+        );
+
+        let mut iter = messages.iter();
+        let first = iter.next().unwrap().blinding.clone();
+        iter.fold(first, |acc, m| acc + &m.blinding)
+    }
+
+    /// Recover the share of the new node from the aggregated messages of all helpers.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn recover(&self, aggregated: &[Polynomial<F>]) -> Share<F> {
+        assert_eq!(
+            aggregated.len(),
+            self.helper_indices.len(),
+            "the number of aggregated messages should be threshold_number"
+        );
+
+        let mut iter = aggregated.iter();
+        let first = iter.next().unwrap().clone();
+        Share::new(iter.fold(first, |acc, m| acc + m))
+    }
+
+    fn position(&self, index: F) -> usize {
+        self.helper_indices
+            .iter()
+            .position(|&i| i == index)
+            .expect("index should be a helper index")
+    }
+}
@@ -1,12 +1,43 @@
 //! The linearly homomorphic BFV scheme.
 
-use algebra::{Field, Polynomial};
+use std::borrow::Borrow;
+
+use algebra::{
+    transformation::AbstractNTT,
+    utils::{dot_product, sample_ternary_ntt_poly_into},
+    Basis, CsRng, Field, FieldDiscreteGaussianSampler, NTTField, NTTPolynomial, Polynomial,
+};
+#[cfg(feature = "parallel")]
+use rand::{RngCore, SeedableRng};
+#[cfg(feature = "parallel")]
+use rand_chacha::ChaCha12Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use crate::{
-    plaintext::BFVPlaintext, BFVCiphertext, BFVContext, BFVPublicKey, BFVSecretKey, CipherField,
-    PlainField,
+    plaintext::BFVPlaintext, AutomorphismKey, BFVCiphertext, BFVContext, BFVError,
+    BFVNTTCiphertext, BFVNTTPublicKeyPolys, BFVPublicKey, BFVScratch, BFVSecretKey, CipherField,
+    CompressedCiphertext, DecomposedCiphertext, KeySwitchKey, LweCiphertext, PackingKey,
+    PlainField, Z2kPlaintext,
 };
 
+/// The randomness used to encrypt a message.
+///
+/// For a ciphertext `(c1, c2)` under the public key `(b, a)`,
+/// `c1 = b * u + e1 + delta * m` and `c2 = a * u + e2` with `delta = floor(q / t)`,
+/// where `m` is lifted with centered coefficients.
+#[derive(Clone, Debug)]
+pub struct EncryptionWitness {
+    /// The ternary polynomial multiplied with the public key.
+    pub u: Polynomial<CipherField>,
+    /// The error of the first component, including the rounding error of scaling the message.
+    pub e1: Polynomial<CipherField>,
+    /// The error of the second component.
+    pub e2: Polynomial<CipherField>,
+    /// The message.
+    pub m: BFVPlaintext,
+}
+
 /// Define the BFV scheme.
 pub struct BFVScheme;
 
@@ -26,70 +57,493 @@ impl BFVScheme {
     }
 
     /// Encrypt with public key.
-    pub fn encrypt(ctx: &BFVContext, pk: &BFVPublicKey, m: &BFVPlaintext) -> BFVCiphertext {
-        let BFVPublicKey([b, a]) = pk;
-        let mut csrng = ctx.csrng_mut();
-        let u = Polynomial::<CipherField>::random_with_ternary(ctx.rlwe_dimension(), &mut *csrng);
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `m` is not valid, see [`BFVPlaintext::validate`].
+    #[inline]
+    pub fn encrypt(
+        ctx: &BFVContext,
+        pk: &BFVPublicKey,
+        m: &BFVPlaintext,
+    ) -> Result<BFVCiphertext, BFVError> {
+        Self::encrypt_with_scratch(ctx, pk, m, &mut ctx.scratch_mut())
+    }
 
-        let e1 = Polynomial::<CipherField>::random_with_gaussian(
-            ctx.rlwe_dimension(),
-            &mut *csrng,
+    /// Encrypt with public key, using the caller's `scratch` instead of the context's.
+    #[inline]
+    pub fn encrypt_with_scratch(
+        ctx: &BFVContext,
+        pk: &BFVPublicKey,
+        m: &BFVPlaintext,
+        scratch: &mut BFVScratch,
+    ) -> Result<BFVCiphertext, BFVError> {
+        m.validate(ctx.rlwe_dimension())?;
+        if scratch.m.coeff_count() != ctx.rlwe_dimension() {
+            return Err(BFVError::ParameterMismatch(
+                "the scratch should have rlwe_dimension coefficients",
+            ));
+        }
+        let mut csrng = ctx.csrng_mut();
+        Ok(Self::encrypt_in_scratch(
             ctx.sampler(),
-        );
-
-        let e2 = Polynomial::<CipherField>::random_with_gaussian(
-            ctx.rlwe_dimension(),
+            pk,
+            m,
+            scratch,
             &mut *csrng,
-            ctx.sampler(),
-        );
+        ))
+    }
+
+    /// Encrypt with public key, and return the randomness used in the encryption,
+    /// which is the witness for proving knowledge of the plaintext.
+    pub fn encrypt_with_witness(
+        ctx: &BFVContext,
+        pk: &BFVPublicKey,
+        m: &BFVPlaintext,
+    ) -> Result<(BFVCiphertext, EncryptionWitness), BFVError> {
+        m.validate(ctx.rlwe_dimension())?;
+        let mut scratch = BFVScratch::new(ctx.rlwe_dimension());
+        let c = Self::encrypt_in_scratch(ctx.sampler(), pk, m, &mut scratch, &mut *ctx.csrng_mut());
+        let BFVScratch {
+            e1,
+            e2,
+            m: m_scaled,
+            ntt,
+        } = scratch;
+        let log_n = ntt.coeff_count().trailing_zeros();
+        let u = CipherField::get_ntt_table(log_n)
+            .unwrap()
+            .inverse_transform_inplace(ntt);
 
+        // the rounding error of scaling is folded into e1, so that c1 = b * u + e1 + delta * m
         let t = PlainField::modulus_value() as u64;
         let q = CipherField::modulus_value() as u64;
-        let half_t_minus_1 = (t - 1) / 2;
-        let half_t = t / 2;
-
-        let round = |x: &PlainField| {
-            let value = x.cast_into_usize() as u64;
-            if value > half_t_minus_1 {
-                let minus_value = t - value;
-                // nearest round of (q * value)/t
-                CipherField::from((q - ((q * minus_value + half_t) / t)) as u32)
-            } else {
-                CipherField::from(((q * value + half_t) / t) as u32)
-            }
+        let delta = CipherField::new((q / t) as u32);
+        let rounding_error = m_scaled - lift_plaintext(&m.0).mul_scalar(delta);
+
+        let witness = EncryptionWitness {
+            u,
+            e1: e1 + rounding_error,
+            e2,
+            m: m.clone(),
+        };
+        Ok((c, witness))
+    }
+
+    /// Encrypt the `i`-th message with the `i`-th public key.
+    ///
+    /// With the `parallel` feature the messages are encrypted in parallel,
+    /// each with a generator seeded from the context's generator.
+    pub fn encrypt_many(
+        ctx: &BFVContext,
+        pks: &[BFVPublicKey],
+        msgs: &[BFVPlaintext],
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        if pks.len() != msgs.len() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks and msgs should be equal",
+            ));
+        }
+        for m in msgs {
+            m.validate(ctx.rlwe_dimension())?;
+        }
+
+        #[cfg(feature = "parallel")]
+        let cs = {
+            let (dimension, sampler) = (ctx.rlwe_dimension(), ctx.sampler());
+            let seeds: Vec<[u8; 32]> = {
+                let mut csrng = ctx.csrng_mut();
+                (0..pks.len())
+                    .map(|_| {
+                        let mut seed = [0; 32];
+                        csrng.fill_bytes(&mut seed);
+                        seed
+                    })
+                    .collect()
+            };
+            pks.par_iter()
+                .zip(msgs)
+                .zip(seeds)
+                .map_init(
+                    || BFVScratch::new(dimension),
+                    |scratch, ((pk, m), seed)| {
+                        let mut rng = ChaCha12Rng::from_seed(seed);
+                        Self::encrypt_in_scratch(sampler, pk, m, scratch, &mut rng)
+                    },
+                )
+                .collect::<Vec<_>>()
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let cs = {
+            let (sampler, mut scratch) = (ctx.sampler(), ctx.scratch_mut());
+            let mut csrng = ctx.csrng_mut();
+            pks.iter()
+                .zip(msgs)
+                .map(|(pk, m)| Self::encrypt_in_scratch(sampler, pk, m, &mut scratch, &mut *csrng))
+                .collect::<Vec<_>>()
         };
 
-        let m: Vec<CipherField> = m.0.iter().map(round).collect();
-        let m = Polynomial::from_slice(&m);
+        Ok(cs)
+    }
+
+    /// Encrypt with the transform of `u`, `e1` and `e2` sampled into `scratch`,
+    /// which also holds the scaled message afterwards.
+    fn encrypt_in_scratch<R: CsRng>(
+        sampler: FieldDiscreteGaussianSampler,
+        pk: &BFVPublicKey,
+        m: &BFVPlaintext,
+        scratch: &mut BFVScratch,
+        rng: &mut R,
+    ) -> BFVCiphertext {
+        scale_plaintext_into(&m.0, &mut scratch.m);
+        Self::encrypt_scaled_in_scratch(sampler, pk, scratch, rng)
+    }
+
+    /// Encrypt the message already scaled into `scratch.m`.
+    fn encrypt_scaled_in_scratch<R: CsRng>(
+        sampler: FieldDiscreteGaussianSampler,
+        pk: &BFVPublicKey,
+        scratch: &mut BFVScratch,
+        rng: &mut R,
+    ) -> BFVCiphertext {
+        let BFVNTTPublicKeyPolys { b, a } = pk.ntt_polys();
+        let BFVScratch {
+            e1,
+            e2,
+            m: m_scaled,
+            ntt,
+        } = scratch;
+
+        // u is sampled straight into the transform domain for both products
+        // with the cached transforms of the public key
+        let log_n = ntt.coeff_count().trailing_zeros();
+        let ntt_table = CipherField::get_ntt_table(log_n).unwrap();
+        sample_ternary_ntt_poly_into(ntt, &ntt_table, &mut *rng);
+        e1.random_with_gaussian_inplace(&mut *rng, sampler);
+        e2.random_with_gaussian_inplace(&mut *rng, sampler);
 
-        let c1 = b * &u + e1 + m;
-        let c2 = a * u + e2;
-        BFVCiphertext([c1, c2])
+        let mut c1 = ntt_table.inverse_transform_inplace(b * &*ntt);
+        c1 += &*e1;
+        c1 += &*m_scaled;
+        let mut c2 = ntt_table.inverse_transform_inplace(a * &*ntt);
+        c2 += &*e2;
+        BFVCiphertext::new(c1, c2)
     }
 
     /// Decrypt with secret key.
-    pub fn decrypt(_ctx: &BFVContext, sk: &BFVSecretKey, c: &BFVCiphertext) -> BFVPlaintext {
-        let sk = sk.secret_key();
-        let BFVCiphertext([c1, c2]) = c;
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `c` does not have `rlwe_dimension` coefficients.
+    #[inline]
+    pub fn decrypt(
+        ctx: &BFVContext,
+        sk: &BFVSecretKey,
+        c: &BFVCiphertext,
+    ) -> Result<BFVPlaintext, BFVError> {
+        Self::decrypt_with_scratch(ctx, sk, c, &mut ctx.scratch_mut())
+    }
 
-        let t = PlainField::modulus_value() as u64;
+    /// Decrypt with secret key, using the caller's `scratch` instead of the context's.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `c` or `scratch` does not have
+    /// `rlwe_dimension` coefficients.
+    #[inline]
+    pub fn decrypt_with_scratch(
+        ctx: &BFVContext,
+        sk: &BFVSecretKey,
+        c: &BFVCiphertext,
+        scratch: &mut BFVScratch,
+    ) -> Result<BFVPlaintext, BFVError> {
+        if scratch.m.coeff_count() != ctx.rlwe_dimension() {
+            return Err(BFVError::ParameterMismatch(
+                "the scratch should have rlwe_dimension coefficients",
+            ));
+        }
+        Self::decrypt_in_scratch(sk, c, scratch)
+    }
+
+    /// Decrypt a ciphertext compressed by [`BFVCiphertext::compress`] with secret key.
+    ///
+    /// The rounding error of the second component is multiplied by the secret key,
+    /// so the default parameters need 12 bits, and 14 bits leave a comfortable margin.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `c` does not have `rlwe_dimension` coefficients.
+    #[inline]
+    pub fn decrypt_compressed(
+        ctx: &BFVContext,
+        sk: &BFVSecretKey,
+        c: &CompressedCiphertext,
+    ) -> Result<BFVPlaintext, BFVError> {
+        Self::decrypt_in_scratch(sk, &c.decompress(), &mut ctx.scratch_mut())
+    }
+
+    /// Decrypt a batch of ciphertexts with secret key,
+    /// in parallel with the `parallel` feature.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if any ciphertext does not have
+    /// `rlwe_dimension` coefficients.
+    pub fn decrypt_batch(
+        ctx: &BFVContext,
+        sk: &BFVSecretKey,
+        cs: &[BFVCiphertext],
+    ) -> Result<Vec<BFVPlaintext>, BFVError> {
+        #[cfg(feature = "parallel")]
+        {
+            let dimension = ctx.rlwe_dimension();
+            cs.par_iter()
+                .map_init(
+                    || BFVScratch::new(dimension),
+                    |scratch, c| Self::decrypt_in_scratch(sk, c, scratch),
+                )
+                .collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut scratch = ctx.scratch_mut();
+            cs.iter()
+                .map(|c| Self::decrypt_in_scratch(sk, c, &mut scratch))
+                .collect()
+        }
+    }
+
+    fn decrypt_in_scratch(
+        sk: &BFVSecretKey,
+        c: &BFVCiphertext,
+        scratch: &mut BFVScratch,
+    ) -> Result<BFVPlaintext, BFVError> {
+        Self::phase_in_scratch(sk, c, scratch).map(decode)
+    }
+
+    /// Compute the noisy scaled message `c1 + c2 * s` into `scratch.m`.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `c` does not have the dimension of `scratch`.
+    fn phase_in_scratch<'a>(
+        sk: &BFVSecretKey,
+        c: &BFVCiphertext,
+        scratch: &'a mut BFVScratch,
+    ) -> Result<&'a Polynomial<CipherField>, BFVError> {
+        let BFVCiphertext { c1, c2 } = c;
+        let msg = &mut scratch.m;
+        if c1.coeff_count() != msg.coeff_count() || c2.coeff_count() != msg.coeff_count() {
+            return Err(BFVError::ParameterMismatch(
+                "the ciphertext should have rlwe_dimension coefficients",
+            ));
+        }
+
+        msg.copy_from(c2);
+        *msg *= sk.ntt_secret_key();
+        *msg += c1;
+        Ok(msg)
+    }
+
+    /// Encrypt a message with the power-of-two plaintext modulus `2^m.bits()` with public key,
+    /// scaling it by `q / 2^k` with rounding.
+    ///
+    /// The ciphertext supports the additive operations, e.g. [`BFVScheme::evalute_add`],
+    /// modulo `2^k`, and is decrypted by [`BFVScheme::decrypt_z2k`] with the same `k`.
+    pub fn encrypt_z2k(
+        ctx: &BFVContext,
+        pk: &BFVPublicKey,
+        m: &Z2kPlaintext,
+    ) -> Result<BFVCiphertext, BFVError> {
+        if m.coeff_count() != ctx.rlwe_dimension() {
+            return Err(BFVError::ParameterMismatch(
+                "the plaintext should have rlwe_dimension coefficients",
+            ));
+        }
+        let mut scratch = ctx.scratch_mut();
+        scale_z2k_into(m, &mut scratch.m);
+        let mut csrng = ctx.csrng_mut();
+        Ok(Self::encrypt_scaled_in_scratch(
+            ctx.sampler(),
+            pk,
+            &mut scratch,
+            &mut *csrng,
+        ))
+    }
+
+    /// Decrypt a ciphertext of [`BFVScheme::encrypt_z2k`] with the plaintext modulus `2^bits`
+    /// with secret key, rounding `2^bits * (c1 + c2 * s) / q`.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `c` does not have `rlwe_dimension` coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not in `1..=Z2kPlaintext::MAX_BITS`.
+    pub fn decrypt_z2k(
+        ctx: &BFVContext,
+        sk: &BFVSecretKey,
+        c: &BFVCiphertext,
+        bits: u32,
+    ) -> Result<Z2kPlaintext, BFVError> {
+        assert!(
+            (1..=Z2kPlaintext::MAX_BITS).contains(&bits),
+            "the bits of the plaintext modulus should be in 1..=8"
+        );
+        let mut scratch = ctx.scratch_mut();
+        let msg = Self::phase_in_scratch(sk, c, &mut scratch)?;
         let q = CipherField::modulus_value() as u64;
-        let half_q_minus_1 = (q - 1) / 2;
-        let half_q = q / 2;
-
-        let round = |x: &CipherField| {
-            let value = x.cast_into_usize() as u64;
-            if value > half_q_minus_1 {
-                let minus_value = q - value;
-                // t * value / q
-                PlainField::from((t - (t * minus_value + half_q) / q) as u16)
-            } else {
-                PlainField::from(((t * value + half_q) / q) as u16)
-            }
-        };
-        let msg = c1 + c2 * sk;
-        let msg: Vec<PlainField> = msg.iter().map(round).collect();
-        BFVPlaintext(Polynomial::<PlainField>::from_slice(&msg))
+        let mask = (1u64 << bits) - 1;
+        let coeffs = msg
+            .iter()
+            .map(|x| (((((x.get() as u64) << bits) + q / 2) / q) & mask) as u8)
+            .collect();
+        Ok(Z2kPlaintext::from_reduced(bits, coeffs))
+    }
+
+    /// XOR of two ciphertexts of bits, encrypted by [`BFVScheme::encrypt_z2k`]
+    /// with the plaintext modulus `2`, where XOR is the addition.
+    #[inline]
+    pub fn evaluate_xor(
+        _ctx: &BFVContext,
+        c_lhs: &BFVCiphertext,
+        c_rhs: &BFVCiphertext,
+    ) -> BFVCiphertext {
+        Self::add(c_lhs, c_rhs)
+    }
+
+    /// XOR of a ciphertext of bits, encrypted by [`BFVScheme::encrypt_z2k`]
+    /// with the plaintext modulus `2`, and the public bits `m`, e.g. to negate chosen bits.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `m` has another plaintext modulus than `2`
+    /// or another number of coefficients than `rlwe_dimension`.
+    pub fn evaluate_xor_plain(
+        ctx: &BFVContext,
+        c: &BFVCiphertext,
+        m: &Z2kPlaintext,
+    ) -> Result<BFVCiphertext, BFVError> {
+        if m.bits() != 1 {
+            return Err(BFVError::ParameterMismatch(
+                "the plaintext modulus of bits should be 2",
+            ));
+        }
+        if m.coeff_count() != ctx.rlwe_dimension() {
+            return Err(BFVError::ParameterMismatch(
+                "the plaintext should have rlwe_dimension coefficients",
+            ));
+        }
+        let mut c = c.clone();
+        let mut scratch = ctx.scratch_mut();
+        scale_z2k_into(m, &mut scratch.m);
+        c.c1 += &scratch.m;
+        Ok(c)
+    }
+
+    /// AND of a ciphertext of bits, encrypted by [`BFVScheme::encrypt_z2k`]
+    /// with the plaintext modulus `2`, and the public `bit` for all its coefficients.
+    ///
+    /// The AND of two encrypted bits needs a multiplication of ciphertexts,
+    /// which the linearly homomorphic scheme doesn't support.
+    pub fn evaluate_and_plain(ctx: &BFVContext, c: &BFVCiphertext, bit: bool) -> BFVCiphertext {
+        if bit {
+            c.clone()
+        } else {
+            let n = ctx.rlwe_dimension();
+            BFVCiphertext::new(Polynomial::zero(n), Polynomial::zero(n))
+        }
+    }
+
+    /// Decrypt a ciphertext in the NTT domain with secret key.
+    pub fn decrypt_ntt(ctx: &BFVContext, sk: &BFVSecretKey, c: &BFVNTTCiphertext) -> BFVPlaintext {
+        let BFVNTTCiphertext { c1, c2 } = c;
+        let mut scratch = ctx.scratch_mut();
+        let BFVScratch { m: msg, ntt, .. } = &mut *scratch;
+
+        ntt.copy_from(c2);
+        *ntt *= sk.ntt_secret_key();
+        *ntt += c1;
+        msg.copy_from(&*ntt);
+        let log_n = msg.coeff_count().trailing_zeros();
+        CipherField::get_ntt_table(log_n)
+            .unwrap()
+            .inverse_transform_slice(msg.as_mut_slice());
+        decode(msg)
+    }
+
+    /// Decrypt an LWE ciphertext extracted by [`BFVCiphertext::extract_lwe`] with secret key.
+    pub fn decrypt_lwe(_ctx: &BFVContext, sk: &BFVSecretKey, c: &LweCiphertext) -> PlainField {
+        let s = sk.expose_secret();
+        assert_eq!(
+            c.dimension(),
+            s.coeff_count(),
+            "the dimension of the lwe ciphertext should be the rlwe dimension"
+        );
+        (c.b() + dot_product(c.a(), s.as_slice())).scale_round()
+    }
+
+    /// Pack up to `n` LWE ciphertexts under the secret key of `key` into a ciphertext,
+    /// whose `i`-th coefficient is the message of `cs[i]` and the others are zero.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn pack_lwe(
+        _ctx: &BFVContext,
+        key: &PackingKey,
+        cs: &[LweCiphertext],
+    ) -> Result<BFVCiphertext, BFVError> {
+        key.pack(cs)
+    }
+
+    /// Apply the automorphism `τ_k: X -> X^k` of `key` to the message of the ciphertext.
+    #[inline]
+    pub fn evaluate_automorphism(
+        _ctx: &BFVContext,
+        key: &AutomorphismKey,
+        c: &BFVCiphertext,
+    ) -> BFVCiphertext {
+        key.apply(c)
+    }
+
+    /// Switch the ciphertext to the new secret key of `key`, without decryption.
+    #[inline]
+    pub fn switch_key(
+        _ctx: &BFVContext,
+        key: &KeySwitchKey,
+        c: &BFVCiphertext,
+    ) -> Result<BFVCiphertext, BFVError> {
+        key.rotate(c)
+    }
+
+    /// Migrate a ciphertext under a secret key of `from_ctx` to a secret key of `to_ctx`,
+    /// such as after changing the error or the secret key distribution, without decryption.
+    ///
+    /// The `bridge_key` is generated by [`KeySwitchKey::new`] with `to_ctx`, from the old
+    /// secret key to the new one. All the parameter sets share the ciphertext modulus of
+    /// [`CipherField`], so no modulus switching is needed, and the dimension has to be equal.
+    pub fn migrate(
+        from_ctx: &BFVContext,
+        to_ctx: &BFVContext,
+        bridge_key: &KeySwitchKey,
+        c: &BFVCiphertext,
+    ) -> Result<BFVCiphertext, BFVError> {
+        if from_ctx.rlwe_dimension() != to_ctx.rlwe_dimension()
+            || bridge_key.dimension() != to_ctx.rlwe_dimension()
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the contexts and the bridge key should have the same dimension",
+            ));
+        }
+        if c.polys()
+            .iter()
+            .any(|p| p.coeff_count() != from_ctx.rlwe_dimension())
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the ciphertext should have rlwe_dimension coefficients",
+            ));
+        }
+        bridge_key.rotate(c)
+    }
+
+    /// Rotate stored ciphertexts from the old secret key of `key` to the new one,
+    /// in parallel with the `parallel` feature.
+    pub fn rotate_ciphertexts(
+        _ctx: &BFVContext,
+        key: &KeySwitchKey,
+        cs: &[BFVCiphertext],
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        #[cfg(feature = "parallel")]
+        let iter = cs.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = cs.iter();
+
+        iter.map(|c| key.rotate(c)).collect()
     }
 
     /// Scalar multiplication.
@@ -100,11 +554,110 @@ impl BFVScheme {
         scalar: &PlainField,
         c: &BFVCiphertext,
     ) -> BFVCiphertext {
+        Self::mul_scalar(scalar, c)
+    }
+
+    /// Encrypt `B^i * m` for the powers of the basis `B` of `basis_bits` bits,
+    /// which is multiplied by large scalars with [`Self::evaluate_mul_large_scalar`].
+    pub fn encrypt_decomposed(
+        ctx: &BFVContext,
+        pk: &BFVPublicKey,
+        m: &BFVPlaintext,
+        basis_bits: u32,
+    ) -> Result<DecomposedCiphertext, BFVError> {
+        let basis = Basis::<PlainField>::new(basis_bits);
+        let radix = PlainField::new(basis.basis());
+        let mut scratch = ctx.scratch_mut();
+        let mut power = m.0.clone();
+        let cts = (0..basis.decompose_len())
+            .map(|_| {
+                let c =
+                    Self::encrypt_with_scratch(ctx, pk, &BFVPlaintext(power.clone()), &mut scratch);
+                power.mul_scalar_assign(radix);
+                c
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(DecomposedCiphertext::new(basis, cts))
+    }
+
+    /// Scalar multiplication by the digits of `scalar`, accumulating the shifted ciphertexts of `c`.
+    ///
+    /// The noise grows with the digits rather than with the scalar, see [`DecomposedCiphertext`].
+    pub fn evaluate_mul_large_scalar(
+        _ctx: &BFVContext,
+        scalar: &PlainField,
+        c: &DecomposedCiphertext,
+    ) -> BFVCiphertext {
+        let n = c.ciphertext().c1.coeff_count();
+        let mut acc = BFVCiphertext::new(Polynomial::zero(n), Polynomial::zero(n));
+        for (digit, ct) in c.digits(*scalar).into_iter().zip(c.ciphertexts()) {
+            if digit != PlainField::ZERO {
+                Self::add_mul_scalar_assign(&mut acc, &digit, ct);
+            }
+        }
+        acc
+    }
+
+    /// Scalar multiplication in place.
+    #[inline]
+    pub fn evaluate_mul_scalar_assign(
+        _ctx: &BFVContext,
+        scalar: &PlainField,
+        c: &mut BFVCiphertext,
+    ) {
         let scalar = CipherField::new(scalar.cast_into_usize() as u32);
-        let BFVCiphertext([c1, c2]) = c;
-        let c1 = c1.mul_scalar(scalar);
-        let c2 = c2.mul_scalar(scalar);
-        BFVCiphertext([c1, c2])
+        c.c1.mul_scalar_assign(scalar);
+        c.c2.mul_scalar_assign(scalar);
+    }
+
+    /// Addition of a public plaintext to a ciphertext, without noise growth.
+    #[inline]
+    pub fn evaluate_add_plain(
+        ctx: &BFVContext,
+        c: &BFVCiphertext,
+        m: &BFVPlaintext,
+    ) -> BFVCiphertext {
+        let mut c = c.clone();
+        Self::evaluate_add_plain_assign(ctx, &mut c, m);
+        c
+    }
+
+    /// Addition of a public plaintext into a ciphertext, without noise growth.
+    #[inline]
+    pub fn evaluate_add_plain_assign(ctx: &BFVContext, c: &mut BFVCiphertext, m: &BFVPlaintext) {
+        let BFVCiphertext { c1, .. } = BFVCiphertext::trivial_encrypt(ctx, m);
+        c.c1 += c1;
+    }
+
+    /// Sample a uniformly random mask for [`Self::mask`].
+    #[inline]
+    pub fn gen_mask(ctx: &BFVContext) -> BFVPlaintext {
+        BFVPlaintext(Polynomial::random(
+            ctx.rlwe_dimension(),
+            &mut *ctx.csrng_mut(),
+        ))
+    }
+
+    /// Blind the message of `c` by adding `mask` coefficient-wise, like a one-time pad,
+    /// so that `c` can be handed to a semi-trusted party who may decrypt it.
+    ///
+    /// The message is hidden only if `mask` is uniformly random, see [`Self::gen_mask`],
+    /// and is used once. The noise does not grow.
+    #[inline]
+    pub fn mask(ctx: &BFVContext, c: &BFVCiphertext, mask: &BFVPlaintext) -> BFVCiphertext {
+        Self::evaluate_add_plain(ctx, c, mask)
+    }
+
+    /// Remove `mask` from a ciphertext blinded by [`Self::mask`].
+    ///
+    /// The scaled mask is subtracted exactly, so unmasking a masked ciphertext gives back
+    /// the original one. A ciphertext multiplied by a scalar after masking is unmasked
+    /// with the mask multiplied by the same scalar.
+    pub fn unmask(ctx: &BFVContext, c: &BFVCiphertext, mask: &BFVPlaintext) -> BFVCiphertext {
+        let BFVCiphertext { c1, .. } = BFVCiphertext::trivial_encrypt(ctx, mask);
+        let mut c = c.clone();
+        c.c1 -= c1;
+        c
     }
 
     /// Addition of two ciphertexts.
@@ -114,23 +667,286 @@ impl BFVScheme {
         c_lhs: &BFVCiphertext,
         c_rhs: &BFVCiphertext,
     ) -> BFVCiphertext {
-        let c1 = &c_lhs.0[0] + &c_rhs.0[0];
-        let c2 = &c_lhs.0[1] + &c_rhs.0[1];
-        BFVCiphertext([c1, c2])
+        Self::add(c_lhs, c_rhs)
+    }
+
+    /// Addition of `c_rhs` into `c_lhs`.
+    #[inline]
+    pub fn evaluate_add_assign(
+        _ctx: &BFVContext,
+        c_lhs: &mut BFVCiphertext,
+        c_rhs: &BFVCiphertext,
+    ) {
+        Self::add_assign(c_lhs, c_rhs)
     }
 
     /// Inner Product
+    ///
+    /// The ciphertexts and the scalars are taken from any iterators of the same length,
+    /// so the shares can be combined as they arrive, without collecting them into a slice.
+    /// The terms are computed in parallel with the `parallel` feature.
+    pub fn evaluate_inner_product<'a, C, S>(
+        ctx: &BFVContext,
+        c: C,
+        scalar: S,
+    ) -> Result<BFVCiphertext, BFVError>
+    where
+        C: IntoIterator<Item = &'a BFVCiphertext>,
+        S: IntoIterator,
+        S::Item: Borrow<PlainField>,
+    {
+        let dimension = ctx.rlwe_dimension();
+        let c_zero = || {
+            let zero = Polynomial::<CipherField>::zero(dimension);
+            BFVCiphertext::new(zero.clone(), zero)
+        };
+        let mut c = c.into_iter();
+        let mut scalar = scalar.into_iter();
+        let mut next_term = || match (c.next(), scalar.next()) {
+            (Some(c), _) if c.polys().iter().any(|p| p.coeff_count() != dimension) => {
+                Err(BFVError::ParameterMismatch(
+                    "the ciphertexts should have rlwe_dimension coefficients",
+                ))
+            }
+            (Some(c), Some(s)) => Ok(Some((c, *s.borrow()))),
+            (None, None) => Ok(None),
+            _ => Err(BFVError::ParameterMismatch(
+                "the length of ciphertexts and scalars should be equal",
+            )),
+        };
+
+        #[cfg(feature = "parallel")]
+        let c = {
+            let mut terms = Vec::new();
+            while let Some(term) = next_term()? {
+                terms.push(term);
+            }
+            terms
+                .par_iter()
+                .fold(c_zero, |mut acc, (c, s)| {
+                    Self::add_mul_scalar_assign(&mut acc, s, c);
+                    acc
+                })
+                .reduce(c_zero, |mut lhs, rhs| {
+                    Self::add_assign(&mut lhs, &rhs);
+                    lhs
+                })
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let c = {
+            let mut acc = c_zero();
+            while let Some((c, s)) = next_term()? {
+                Self::add_mul_scalar_assign(&mut acc, &s, c);
+            }
+            acc
+        };
+
+        Ok(c)
+    }
+
+    /// Linear map of a plaintext matrix over a vector of ciphertexts: the `i`-th output is the
+    /// inner product of `matrix[i]` with `cts`.
+    ///
+    /// Each row accumulates its scalar products without modular reduction and reduces every
+    /// coefficient once. The rows are computed in parallel with the `parallel` feature.
+    pub fn evaluate_linear_map(
+        ctx: &BFVContext,
+        matrix: &[Vec<PlainField>],
+        cts: &[BFVCiphertext],
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        if matrix.iter().any(|row| row.len() != cts.len()) {
+            return Err(BFVError::ParameterMismatch(
+                "the length of each row of the matrix and ciphertexts should be equal",
+            ));
+        }
+        let dimension = ctx.rlwe_dimension();
+        if cts
+            .iter()
+            .any(|c| c.polys().iter().any(|p| p.coeff_count() != dimension))
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the ciphertexts should have rlwe_dimension coefficients",
+            ));
+        }
+        let row = |row: &Vec<PlainField>| Self::lazy_inner_product(dimension, row, cts);
+
+        #[cfg(feature = "parallel")]
+        let c = matrix.par_iter().map(row).collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let c = matrix.iter().map(row).collect();
+
+        Ok(c)
+    }
+
+    /// Scalar multiplication in the NTT domain.
     #[inline]
-    pub fn evaluate_inner_product(
+    pub fn evaluate_mul_scalar_ntt(
+        _ctx: &BFVContext,
+        scalar: &PlainField,
+        c: &BFVNTTCiphertext,
+    ) -> BFVNTTCiphertext {
+        let scalar = CipherField::new(scalar.cast_into_usize() as u32);
+        BFVNTTCiphertext::new(c.c1.mul_scalar(scalar), c.c2.mul_scalar(scalar))
+    }
+
+    /// Addition of two ciphertexts in the NTT domain.
+    #[inline]
+    pub fn evaluate_add_ntt(
+        _ctx: &BFVContext,
+        c_lhs: &BFVNTTCiphertext,
+        c_rhs: &BFVNTTCiphertext,
+    ) -> BFVNTTCiphertext {
+        let c1 = &c_lhs.c1 + &c_rhs.c1;
+        let c2 = &c_lhs.c2 + &c_rhs.c2;
+        BFVNTTCiphertext::new(c1, c2)
+    }
+
+    /// Inner Product in the NTT domain, without any transform.
+    pub fn evaluate_inner_product_ntt(
         ctx: &BFVContext,
-        c: &[BFVCiphertext],
+        c: &[BFVNTTCiphertext],
+        scalar: &[PlainField],
+    ) -> Result<BFVNTTCiphertext, BFVError> {
+        if c.len() != scalar.len() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of ciphertexts and scalars should be equal",
+            ));
+        }
+        let dimension = ctx.rlwe_dimension();
+        if c.iter()
+            .any(|c| c.c1.coeff_count() != dimension || c.c2.coeff_count() != dimension)
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the ciphertexts should have rlwe_dimension coefficients",
+            ));
+        }
+        let mut acc = BFVNTTCiphertext::new(
+            NTTPolynomial::zero(dimension),
+            NTTPolynomial::zero(dimension),
+        );
+        for (c, s) in c.iter().zip(scalar) {
+            let s = CipherField::new(s.cast_into_usize() as u32);
+            for (acc, c) in [(&mut acc.c1, &c.c1), (&mut acc.c2, &c.c2)] {
+                acc.iter_mut()
+                    .zip(c.iter())
+                    .for_each(|(a, &b)| a.add_mul_assign(b, s));
+            }
+        }
+        Ok(acc)
+    }
+
+    fn mul_scalar(scalar: &PlainField, c: &BFVCiphertext) -> BFVCiphertext {
+        let scalar = CipherField::new(scalar.cast_into_usize() as u32);
+        let BFVCiphertext { c1, c2 } = c;
+        let c1 = c1.mul_scalar(scalar);
+        let c2 = c2.mul_scalar(scalar);
+        BFVCiphertext::new(c1, c2)
+    }
+
+    fn add_assign(c_lhs: &mut BFVCiphertext, c_rhs: &BFVCiphertext) {
+        for (lhs, rhs) in [(&mut c_lhs.c1, &c_rhs.c1), (&mut c_lhs.c2, &c_rhs.c2)] {
+            *lhs += rhs;
+        }
+    }
+
+    /// Performs `acc += scalar * c` without allocating.
+    fn add_mul_scalar_assign(acc: &mut BFVCiphertext, scalar: &PlainField, c: &BFVCiphertext) {
+        let scalar = CipherField::new(scalar.cast_into_usize() as u32);
+        for (acc, c) in [(&mut acc.c1, &c.c1), (&mut acc.c2, &c.c2)] {
+            acc.iter_mut()
+                .zip(c.iter())
+                .for_each(|(a, &b)| a.add_mul_assign(b, scalar));
+        }
+    }
+
+    /// Computes `<scalar, c>` in `u64` accumulators, reducing each coefficient once at the end.
+    ///
+    /// A term is below `(t - 1) * (q - 1) < 2^33`, so the accumulators hold up to `2^31` terms.
+    fn lazy_inner_product(
+        dimension: usize,
         scalar: &[PlainField],
+        c: &[BFVCiphertext],
     ) -> BFVCiphertext {
-        assert_eq!(c.len(), scalar.len());
-        let zero = Polynomial::<CipherField>::zero(ctx.rlwe_dimension());
-        let c_zero = BFVCiphertext([zero.clone(), zero]);
-        c.iter().zip(scalar.iter()).fold(c_zero, |acc, (c, s)| {
-            BFVScheme::evalute_add(ctx, &acc, &BFVScheme::evaluate_mul_scalar(ctx, s, c))
-        })
+        let q = CipherField::modulus_value() as u64;
+        let mut acc = [vec![0u64; dimension], vec![0u64; dimension]];
+        for (s, c) in scalar.iter().zip(c) {
+            let s = s.cast_into_usize() as u64;
+            if s == 0 {
+                continue;
+            }
+            for (acc, c) in acc.iter_mut().zip(c.polys()) {
+                acc.iter_mut()
+                    .zip(c.iter())
+                    .for_each(|(a, b)| *a += s * b.get() as u64);
+            }
+        }
+        let [c1, c2] = acc.map(|acc| {
+            Polynomial::new(
+                acc.into_iter()
+                    .map(|a| CipherField::new((a % q) as u32))
+                    .collect(),
+            )
+        });
+        BFVCiphertext::new(c1, c2)
     }
+
+    fn add(c_lhs: &BFVCiphertext, c_rhs: &BFVCiphertext) -> BFVCiphertext {
+        let c1 = &c_lhs.c1 + &c_rhs.c1;
+        let c2 = &c_lhs.c2 + &c_rhs.c2;
+        BFVCiphertext::new(c1, c2)
+    }
+}
+
+/// Scale the message by `q / t` with rounding, lifting it with centered coefficients.
+pub(crate) fn scale_plaintext_into(
+    m: &Polynomial<PlainField>,
+    m_scaled: &mut Polynomial<CipherField>,
+) {
+    // a shorter message would leave the previous message's coefficients in the scratch
+    assert_eq!(
+        m.coeff_count(),
+        m_scaled.coeff_count(),
+        "the plaintext should have the dimension of the scratch"
+    );
+    m_scaled
+        .iter_mut()
+        .zip(m.iter())
+        .for_each(|(y, x)| *y = x.scale_round());
+}
+
+/// Scale the message by `q / 2^k` with rounding, for the plaintext modulus `2^k`.
+fn scale_z2k_into(m: &Z2kPlaintext, m_scaled: &mut Polynomial<CipherField>) {
+    let q = CipherField::modulus_value() as u64;
+    let half = 1u64 << (m.bits() - 1);
+    m_scaled
+        .iter_mut()
+        .zip(m.coeffs())
+        .for_each(|(y, &x)| *y = CipherField::new(((x as u64 * q + half) >> m.bits()) as u32));
+}
+
+/// Round the noisy `c1 + c2 * s` to the message.
+fn decode(msg: &Polynomial<CipherField>) -> BFVPlaintext {
+    BFVPlaintext(Polynomial::new(
+        msg.iter().map(|x| x.scale_round()).collect(),
+    ))
+}
+
+/// Lift a plaintext polynomial into the ciphertext space with centered coefficients.
+pub(crate) fn lift_plaintext(m: &Polynomial<PlainField>) -> Polynomial<CipherField> {
+    let t = PlainField::modulus_value() as u32;
+    let q = CipherField::modulus_value();
+    let data = m
+        .iter()
+        .map(|x| {
+            let value = x.cast_into_usize() as u32;
+            if value > (t - 1) / 2 {
+                CipherField::new(q - (t - value))
+            } else {
+                CipherField::new(value)
+            }
+        })
+        .collect();
+    Polynomial::new(data)
 }
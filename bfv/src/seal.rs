@@ -0,0 +1,219 @@
+//! Conversion from and to the serialization of Microsoft SEAL (version 4).
+//!
+//! Only matching parameter sets are supported: BFV with `poly_modulus_degree` [`DIMENSION_N`],
+//! the single coefficient modulus of [`CipherField`] and the plain modulus of [`PlainField`].
+//! SEAL objects have to be saved with `compr_mode_type::none`, and ciphertexts must not be in NTT form.
+//!
+//! Every SEAL object starts with a 16 bytes header
+//! `|magic(2)|header_size(1)|version_major(1)|version_minor(1)|compr_mode(1)|reserved(2)|size(8)|`,
+//! all integers in little-endian, where `size` is the byte length of the object including the header.
+
+use algebra::{Field, Polynomial};
+
+use crate::{protocol::Reader, BFVCiphertext, CipherField, PlainField, DIMENSION_N};
+
+const SEAL_MAGIC: u16 = 0xA15E;
+const SEAL_HEADER_SIZE: u8 = 0x10;
+const SEAL_VERSION_MAJOR: u8 = 4;
+const SEAL_VERSION_MINOR: u8 = 1;
+const SCHEME_BFV: u8 = 0x1;
+
+/// The encryption parameters of a SEAL context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealParameters {
+    /// The degree of the polynomial modulus.
+    pub poly_modulus_degree: u64,
+    /// The coefficient moduli.
+    pub coeff_modulus: Vec<u64>,
+    /// The plaintext modulus.
+    pub plain_modulus: u64,
+}
+
+impl SealParameters {
+    /// Return the parameters matching this crate, to set up a compatible SEAL context.
+    pub fn matching() -> Self {
+        Self {
+            poly_modulus_degree: DIMENSION_N as u64,
+            coeff_modulus: vec![CipherField::modulus_value() as u64],
+            plain_modulus: PlainField::modulus_value() as u64,
+        }
+    }
+
+    /// Return `true` if the parameters match this crate.
+    #[inline]
+    pub fn is_matching(&self) -> bool {
+        *self == Self::matching()
+    }
+
+    /// Serialize as SEAL's `EncryptionParameters::save`.
+    pub fn save(&self) -> Vec<u8> {
+        let mut body = vec![SCHEME_BFV];
+        body.extend(self.poly_modulus_degree.to_le_bytes());
+        body.extend((self.coeff_modulus.len() as u64).to_le_bytes());
+        for &modulus in self.coeff_modulus.iter().chain([&self.plain_modulus]) {
+            body.extend(with_header(&modulus.to_le_bytes()));
+        }
+        with_header(&body)
+    }
+
+    /// Deserialize from SEAL's `EncryptionParameters::save`,
+    /// return `None` if the bytes are malformed or the scheme is not BFV.
+    pub fn load(bytes: &[u8]) -> Option<Self> {
+        let mut reader = open(bytes)?;
+        if reader.u8()? != SCHEME_BFV {
+            return None;
+        }
+        let poly_modulus_degree = u64_le(&mut reader)?;
+        let count = u64_le(&mut reader)?;
+        if count as usize > reader.remaining() / 24 {
+            return None;
+        }
+        let coeff_modulus = (0..count)
+            .map(|_| load_modulus(&mut reader))
+            .collect::<Option<Vec<u64>>>()?;
+        let plain_modulus = load_modulus(&mut reader)?;
+
+        reader.is_empty().then_some(Self {
+            poly_modulus_degree,
+            coeff_modulus,
+            plain_modulus,
+        })
+    }
+}
+
+/// A BFV ciphertext with the `parms_id` of the SEAL context it belongs to.
+///
+/// The `parms_id` is a hash SEAL computes from the parameters; it is carried over as is,
+/// so take it from `SEALContext::first_parms_id` when exporting a ciphertext of this crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SealCiphertext {
+    /// The `parms_id` of the ciphertext.
+    pub parms_id: [u64; 4],
+    /// The ciphertext.
+    pub ct: BFVCiphertext,
+}
+
+impl SealCiphertext {
+    /// Serialize as SEAL's `Ciphertext::save`.
+    pub fn save(&self) -> Vec<u8> {
+        let mut body = vec![];
+        self.parms_id
+            .iter()
+            .for_each(|v| body.extend(v.to_le_bytes()));
+        // not in ntt form
+        body.push(0);
+        // size
+        body.extend(2u64.to_le_bytes());
+        body.extend((DIMENSION_N as u64).to_le_bytes());
+        // coeff_modulus_size
+        body.extend(1u64.to_le_bytes());
+        // scale
+        body.extend(1.0f64.to_le_bytes());
+        // correction_factor
+        body.extend(1u64.to_le_bytes());
+
+        let mut data = ((2 * DIMENSION_N) as u64).to_le_bytes().to_vec();
+        for poly in self.ct.polys() {
+            poly.iter()
+                .for_each(|v| data.extend((v.get() as u64).to_le_bytes()));
+        }
+        body.extend(with_header(&data));
+
+        with_header(&body)
+    }
+
+    /// Deserialize from SEAL's `Ciphertext::save`, return `None` if the bytes are malformed
+    /// or the ciphertext does not match the parameters of this crate.
+    pub fn load(bytes: &[u8]) -> Option<Self> {
+        let mut reader = open(bytes)?;
+        let parms_id = [
+            u64_le(&mut reader)?,
+            u64_le(&mut reader)?,
+            u64_le(&mut reader)?,
+            u64_le(&mut reader)?,
+        ];
+        let is_ntt_form = reader.u8()?;
+        let size = u64_le(&mut reader)?;
+        let poly_modulus_degree = u64_le(&mut reader)?;
+        let coeff_modulus_size = u64_le(&mut reader)?;
+        let _scale = reader.array::<8>()?;
+        let correction_factor = u64_le(&mut reader)?;
+        if is_ntt_form != 0
+            || size != 2
+            || poly_modulus_degree != DIMENSION_N as u64
+            || coeff_modulus_size != 1
+            || correction_factor != 1
+        {
+            return None;
+        }
+
+        let data = reader.bytes(reader.remaining())?;
+        let mut data_reader = open(data)?;
+        if u64_le(&mut data_reader)? != (2 * DIMENSION_N) as u64 {
+            return None;
+        }
+        let mut poly = || -> Option<Polynomial<CipherField>> {
+            (0..DIMENSION_N)
+                .map(|_| {
+                    let value = u64_le(&mut data_reader)?;
+                    (value < CipherField::modulus_value() as u64)
+                        .then(|| CipherField::new(value as u32))
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(Polynomial::new)
+        };
+        let c0 = poly()?;
+        let c1 = poly()?;
+
+        data_reader.is_empty().then_some(Self {
+            parms_id,
+            ct: BFVCiphertext::new(c0, c1),
+        })
+    }
+}
+
+/// Prepend a SEAL header to `body`.
+fn with_header(body: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16 + body.len());
+    bytes.extend(SEAL_MAGIC.to_le_bytes());
+    bytes.push(SEAL_HEADER_SIZE);
+    bytes.push(SEAL_VERSION_MAJOR);
+    bytes.push(SEAL_VERSION_MINOR);
+    // compr_mode_type::none
+    bytes.push(0);
+    // reserved
+    bytes.extend(0u16.to_le_bytes());
+    bytes.extend(((16 + body.len()) as u64).to_le_bytes());
+    bytes.extend(body);
+    bytes
+}
+
+/// Check the SEAL header of `bytes` which must span the whole object,
+/// and return a reader over the members.
+fn open(bytes: &[u8]) -> Option<Reader<'_>> {
+    let mut reader = Reader::new(bytes);
+    let magic = u16::from_le_bytes(reader.array()?);
+    let header_size = reader.u8()?;
+    let version_major = reader.u8()?;
+    let _version_minor = reader.u8()?;
+    let compr_mode = reader.u8()?;
+    let _reserved = reader.array::<2>()?;
+    let size = u64_le(&mut reader)?;
+    (magic == SEAL_MAGIC
+        && header_size == SEAL_HEADER_SIZE
+        && version_major == SEAL_VERSION_MAJOR
+        && compr_mode == 0
+        && size == bytes.len() as u64)
+        .then_some(reader)
+}
+
+fn load_modulus(reader: &mut Reader<'_>) -> Option<u64> {
+    let object: [u8; 24] = reader.array()?;
+    let mut object = open(&object)?;
+    u64_le(&mut object)
+}
+
+#[inline]
+fn u64_le(reader: &mut Reader<'_>) -> Option<u64> {
+    reader.array().map(u64::from_le_bytes)
+}
@@ -1,47 +1,114 @@
 //! The secret key of BFV.
-use crate::{context::BFVContext, BFVPublicKey, CipherField};
-use algebra::Polynomial;
+use std::{fmt, sync::OnceLock};
+
+use crate::{
+    context::BFVContext, protocol::Reader, BFVError, BFVPublicKey, CipherField, KeySwitchKey,
+    PackingKey, SecretKeyDistribution,
+};
+use algebra::{FieldBinarySampler, FieldTernarySampler, NTTPolynomial, Polynomial};
 use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
 
 /// Define the secret key of BFV.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+///
+/// The NTT form of the key is computed on first use and kept for later decryptions.
+///
+/// The `Debug` output only shows the length of the key,
+/// the key itself is accessed with [`BFVSecretKey::expose_secret`].
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BFVSecretKey {
     ternary_key: Polynomial<CipherField>,
+    #[serde(skip)]
+    ntt_key: OnceLock<NTTPolynomial<CipherField>>,
 }
 
 impl BFVSecretKey {
-    /// Generate a new BFV secret key with ternary distribution.
+    /// Generate a new BFV secret key with the distribution of the context's parameters.
     pub fn new(ctx: &BFVContext) -> Self {
+        let n = ctx.rlwe_dimension();
         let mut csrng = ctx.csrng_mut();
-        let poly =
-            Polynomial::<CipherField>::random_with_ternary(ctx.rlwe_dimension(), &mut *csrng);
-        Self { ternary_key: poly }
+        let poly = match ctx.secret_key_distribution() {
+            SecretKeyDistribution::Binary => {
+                Polynomial::random_with_distribution(n, &mut *csrng, FieldBinarySampler)
+            }
+            SecretKeyDistribution::Ternary => {
+                Polynomial::random_with_distribution(n, &mut *csrng, FieldTernarySampler)
+            }
+            SecretKeyDistribution::Gaussian => {
+                Polynomial::random_with_distribution(n, &mut *csrng, ctx.sampler())
+            }
+        };
+        Self::from_poly(poly)
     }
+
+    /// Create an instance from the secret key polynomial.
+    #[inline]
+    pub(crate) fn from_poly(ternary_key: Polynomial<CipherField>) -> Self {
+        Self {
+            ternary_key,
+            ntt_key: OnceLock::new(),
+        }
+    }
+
     /// Returns the reference of secret key.
     #[inline]
-    pub fn secret_key(&self) -> &Polynomial<CipherField> {
+    pub fn expose_secret(&self) -> &Polynomial<CipherField> {
         &self.ternary_key
     }
 
+    /// Returns the reference of secret key.
+    #[deprecated(note = "use `expose_secret`, which marks the access to the key in the code")]
+    #[inline]
+    pub fn secret_key(&self) -> &Polynomial<CipherField> {
+        self.expose_secret()
+    }
+
+    /// Returns the reference of secret key in NTT form.
+    #[inline]
+    pub fn ntt_secret_key(&self) -> &NTTPolynomial<CipherField> {
+        self.ntt_key
+            .get_or_init(|| self.ternary_key.clone().into_ntt_polynomial())
+    }
+
     /// Generate a public key of BFV using the secret key.
     pub fn gen_pubkey(&self, ctx: &BFVContext) -> BFVPublicKey {
-        let mut csrng = ctx.csrng_mut();
-        let a = Polynomial::<CipherField>::random(ctx.rlwe_dimension(), &mut *csrng);
+        let a = Polynomial::<CipherField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+        self.gen_pubkey_with(ctx, a)
+    }
 
+    /// Generate a public key `(a * s + e, -a)` with the given polynomial `a`.
+    ///
+    /// The public keys of secret keys sharing `a` add up to the public key of the sum of the secret keys,
+    /// see [`crate::ThresholdPKE::aggregate_public_keys`].
+    pub fn gen_pubkey_with(&self, ctx: &BFVContext, a: Polynomial<CipherField>) -> BFVPublicKey {
         let e = Polynomial::<CipherField>::random_with_gaussian(
             ctx.rlwe_dimension(),
-            &mut *csrng,
+            &mut *ctx.csrng_mut(),
             ctx.sampler(),
         );
-        let b = &a * self.secret_key() + e;
-        BFVPublicKey::new([b, -a])
+        let b = &a * self.ntt_secret_key() + e;
+        BFVPublicKey::new(b, -a)
+    }
+
+    /// Generate the automorphism keys which pack LWE ciphertexts under the secret key,
+    /// see [`crate::BFVScheme::pack_lwe`].
+    #[inline]
+    pub fn gen_packing_key(&self, ctx: &BFVContext) -> PackingKey {
+        PackingKey::new(ctx, self)
+    }
+
+    /// Generate the key switching key from this secret key to `new`,
+    /// which rotates ciphertexts to the new key, see [`crate::BFVScheme::rotate_ciphertexts`].
+    #[inline]
+    pub fn gen_key_switch_key(&self, ctx: &BFVContext, new: &BFVSecretKey) -> KeySwitchKey {
+        KeySwitchKey::new(ctx, self, new)
     }
 
     /// Serialize to `Vec<u8>`
     pub fn to_vec(&self) -> Vec<u8> {
         let mut bytes = vec![];
 
-        for data in self.secret_key().iter() {
+        for data in self.expose_secret().iter() {
             bytes.extend(data.to_bytes());
         }
 
@@ -49,17 +116,30 @@ impl BFVSecretKey {
     }
 
     /// Deserialize from [u8]
-    pub fn from_vec(bytes: &[u8]) -> Self {
-        let iter = bytes
-            .chunks_exact(4)
-            .map(|chunk| <[u8; 4]>::try_from(chunk).unwrap());
-
-        let mut data = vec![];
-        for v in iter {
-            data.push(CipherField::from_bytes(v));
-        }
-        Self {
-            ternary_key: Polynomial::<CipherField>::new(data),
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        let mut reader = Reader::new(bytes);
+        let mut data = Vec::with_capacity(bytes.len() / 4);
+        while !reader.is_empty() {
+            let value = reader
+                .cipher()
+                .ok_or(BFVError::SerializationError("secret key"))?;
+            data.push(value);
         }
+        Ok(Self::from_poly(Polynomial::<CipherField>::new(data)))
+    }
+}
+
+impl PartialEq for BFVSecretKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ternary_key.ct_eq(&other.ternary_key).into()
+    }
+}
+
+impl fmt::Debug for BFVSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BFVSecretKey")
+            .field("len", &self.ternary_key.coeff_count())
+            .finish_non_exhaustive()
     }
 }
@@ -0,0 +1,84 @@
+//! Estimate the bit-security of RLWE parameters.
+//!
+//! The estimate is the cost of the primal attack on the embedding lattice of `m` samples,
+//! solved as a unique-SVP instance by BKZ with block size `β`. Following the condition of
+//! Alkim, Ducas, Pöppelmann and Schwabe (2016), BKZ-`β` succeeds in dimension `d = m + n + 1` when
+//!
+//! `σ·sqrt(β) <= δ^(2β - d - 1) · Vol^(1/d)`,
+//!
+//! with `δ` the root Hermite factor of BKZ-`β`. A small secret of standard deviation `σ_s`
+//! is rescaled to the error (Bai and Galbraith), which gives `Vol = q^m · (σ / σ_s)^n`.
+//! The cost of BKZ-`β` is `2^(0.292β + 16.4) · 8d`, the sieving model used by the
+//! homomorphic encryption security standard, whose tables this estimate reproduces.
+
+use crate::SecretKeyDistribution;
+
+/// The minimum bits of security accepted by [`BFVParameters::validate`](crate::BFVParameters::validate).
+pub const MIN_SECURITY_BITS: u32 = 128;
+
+/// The smallest block size considered, below which the cost model does not apply.
+const MIN_BLOCK_SIZE: usize = 40;
+
+/// The result of a security estimate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecurityEstimate {
+    /// The base 2 logarithm of the cost of the cheapest attack.
+    pub bits: f64,
+    /// The block size of BKZ in the cheapest attack.
+    pub block_size: usize,
+    /// The number of samples used by the cheapest attack.
+    pub samples: usize,
+}
+
+/// Estimate the bit-security of RLWE with dimension `n`, modulus `q`, error standard deviation
+/// `std_dev` and secrets sampled from `distribution`, the attacker being given up to `2n` samples.
+///
+/// Returns `None` if no block size breaks the parameters, i.e. the error is too large to be
+/// distinguished by the primal attack.
+pub fn estimate_security(
+    n: usize,
+    q: f64,
+    std_dev: f64,
+    distribution: SecretKeyDistribution,
+) -> Option<SecurityEstimate> {
+    assert!(n > 0 && q > 1.0 && std_dev > 0.0);
+    let secret_std_dev = match distribution {
+        // the mean of 1/2 is removed by the attacker
+        SecretKeyDistribution::Binary => 0.5,
+        SecretKeyDistribution::Ternary => core::f64::consts::FRAC_1_SQRT_2,
+        SecretKeyDistribution::Gaussian => std_dev,
+    };
+
+    let log_q = q.ln();
+    let log_scale = (std_dev / secret_std_dev).ln();
+    (1..=2 * n)
+        .filter_map(|m| {
+            let d = m + n + 1;
+            let log_volume = (m as f64 * log_q + n as f64 * log_scale) / d as f64;
+            (MIN_BLOCK_SIZE..d)
+                .find(|&beta| {
+                    let lhs = std_dev.ln() + 0.5 * (beta as f64).ln();
+                    let rhs = (2 * beta) as f64 - d as f64 - 1.0;
+                    lhs <= rhs * log_root_hermite_factor(beta) + log_volume
+                })
+                .map(|beta| SecurityEstimate {
+                    bits: bkz_cost(beta, d),
+                    block_size: beta,
+                    samples: m,
+                })
+        })
+        .min_by(|a, b| a.bits.total_cmp(&b.bits))
+}
+
+/// The natural logarithm of the root Hermite factor of BKZ-`β`,
+/// `δ = ((πβ)^(1/β) · β / (2πe))^(1 / (2(β - 1)))`.
+fn log_root_hermite_factor(beta: usize) -> f64 {
+    use core::f64::consts::{E, PI};
+    let beta = beta as f64;
+    ((PI * beta).ln() / beta + (beta / (2.0 * PI * E)).ln()) / (2.0 * (beta - 1.0))
+}
+
+/// The base 2 logarithm of the cost of BKZ-`β` in dimension `d`.
+fn bkz_cost(beta: usize, d: usize) -> f64 {
+    0.292 * beta as f64 + 16.4 + (8.0 * d as f64).log2()
+}
@@ -0,0 +1,159 @@
+//! Define authenticated ciphertext shares and decryption shares.
+
+use algebra::Field;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    protocol::{write_ciphertext, write_plain, Reader},
+    BFVCiphertext, BFVError, PlainField, PlaintextKnowledgeProof,
+};
+
+type F = PlainField;
+
+/// A ciphertext share bound to the index of its node, a message id and a parameter digest.
+///
+/// The signature covers all of them, so a share can not be replayed
+/// in another session or combined as the share of another node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiphertextShare {
+    /// The index of the node this share belongs to.
+    pub index: F,
+    /// The encrypted share.
+    pub ct: BFVCiphertext,
+    /// The signature of the node over the share.
+    pub sig: Signature,
+}
+
+impl CiphertextShare {
+    /// Sign `ct` as the share of the node at `index`.
+    pub fn sign(
+        signing_key: &SigningKey,
+        index: F,
+        ct: BFVCiphertext,
+        message_id: &[u8],
+        parameter_digest: &[u8; 32],
+    ) -> Self {
+        let sig = signing_key.sign(&Self::signed_bytes(
+            index,
+            &ct,
+            message_id,
+            parameter_digest,
+        ));
+        Self { index, ct, sig }
+    }
+
+    /// Verify the share against the node's `verifying_key`, `message_id` and `parameter_digest`.
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey,
+        message_id: &[u8],
+        parameter_digest: &[u8; 32],
+    ) -> bool {
+        let bytes = Self::signed_bytes(self.index, &self.ct, message_id, parameter_digest);
+        verifying_key.verify(&bytes, &self.sig).is_ok()
+    }
+
+    fn signed_bytes(
+        index: F,
+        ct: &BFVCiphertext,
+        message_id: &[u8],
+        parameter_digest: &[u8; 32],
+    ) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(b"threshold-zk-lhe ciphertext share");
+        hasher.update(parameter_digest);
+        hasher.update((message_id.len() as u64).to_be_bytes());
+        hasher.update(message_id);
+        hasher.update(index.get().to_be_bytes());
+        hasher.update(ct.to_vec());
+        hasher.finalize().into()
+    }
+}
+
+/// The partial decryption of a node, its share of the message re-encrypted to the receiver,
+/// with an optional proof that the node knows the re-encrypted share.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecryptionShare {
+    /// The index of the node this share belongs to.
+    pub index: F,
+    /// The re-encrypted share.
+    pub value: BFVCiphertext,
+    /// The proof of knowledge of the plaintext of `value`.
+    pub proof: Option<PlaintextKnowledgeProof>,
+}
+
+impl DecryptionShare {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(index: F, value: BFVCiphertext, proof: Option<PlaintextKnowledgeProof>) -> Self {
+        Self {
+            index,
+            value,
+            proof,
+        }
+    }
+
+    /// Serialize to `Vec<u8>`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        // layout: |index|len0,data0,len1,data1|has proof|proof|
+        let mut bytes = vec![];
+        write_plain(&mut bytes, self.index);
+        write_ciphertext(&mut bytes, &self.value);
+        match &self.proof {
+            Some(proof) => {
+                bytes.push(1);
+                proof.write(&mut bytes);
+            }
+            None => bytes.push(0),
+        }
+        bytes
+    }
+
+    /// Deserialize from [u8].
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        Self::read(bytes).ok_or(BFVError::SerializationError("decryption share"))
+    }
+
+    fn read(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::new(bytes);
+        let index = reader.plain()?;
+        let value = reader.ciphertext()?;
+        let proof = match reader.u8()? {
+            0 => None,
+            1 => Some(PlaintextKnowledgeProof::read(&mut reader)?),
+            _ => return None,
+        };
+        reader.is_empty().then_some(Self {
+            index,
+            value,
+            proof,
+        })
+    }
+}
+
+/// The reason a decryption share is rejected by [`crate::ThresholdPKE::aggregate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareRejection {
+    /// The index is not the index of a node of the policy.
+    UnknownIndex,
+    /// A share of the same index is accepted before.
+    Duplicate,
+    /// The dimension of the share is not the rlwe dimension.
+    Malformed,
+    /// The proof fails verification.
+    InvalidProof,
+}
+
+/// The combined ciphertext of [`crate::ThresholdPKE::aggregate`],
+/// with the indices of the accepted shares and the rejected ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aggregation {
+    /// The combination of the accepted shares, encrypting the message to the receiver.
+    pub ciphertext: BFVCiphertext,
+    /// The indices of the accepted shares.
+    pub accepted: Vec<F>,
+    /// The indices of the rejected shares, with the reasons.
+    pub rejected: Vec<(F, ShareRejection)>,
+}
@@ -0,0 +1,93 @@
+//! Generators of ciphertexts and threshold policies for fuzzing and property tests.
+//!
+//! [`BFVCiphertext`] and [`ThresholdPolicy`] implement [`arbitrary::Arbitrary`] and
+//! [`proptest::arbitrary::Arbitrary`], on top of the fields and polynomials of `algebra`.
+
+use algebra::test_utils::{
+    arbitrary::{self, Arbitrary, Unstructured},
+    polynomial_strategy,
+    proptest::{
+        self,
+        sample::subsequence,
+        strategy::{BoxedStrategy, Just, Strategy},
+    },
+};
+use algebra::{Field, Polynomial};
+
+use crate::{
+    BFVCiphertext, CipherField, PlainField, ThresholdPolicy, DIMENSION_N, MAX_NODES_NUMBER,
+};
+
+/// The candidate indices of the parties, all the nonzero elements of [`PlainField`].
+fn candidate_indices() -> Vec<PlainField> {
+    (1..PlainField::modulus_value())
+        .map(PlainField::new)
+        .collect()
+}
+
+impl<'a> Arbitrary<'a> for BFVCiphertext {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut poly = || -> arbitrary::Result<Polynomial<CipherField>> {
+            (0..DIMENSION_N)
+                .map(|_| CipherField::arbitrary(u))
+                .collect::<arbitrary::Result<Vec<_>>>()
+                .map(Polynomial::new)
+        };
+        Ok(BFVCiphertext::new(poly()?, poly()?))
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for BFVCiphertext {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (
+            polynomial_strategy(DIMENSION_N),
+            polynomial_strategy(DIMENSION_N),
+        )
+            .prop_map(|(c0, c1)| BFVCiphertext::new(c0, c1))
+            .boxed()
+    }
+}
+
+impl<'a> Arbitrary<'a> for ThresholdPolicy {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let total_number = u.int_in_range(1..=MAX_NODES_NUMBER)?;
+        let threshold_number = u.int_in_range(1..=total_number)?;
+
+        // a partial Fisher-Yates shuffle picks distinct nonzero indices
+        let mut candidates = candidate_indices();
+        for i in 0..total_number {
+            let j = u.int_in_range(i..=candidates.len() - 1)?;
+            candidates.swap(i, j);
+        }
+        candidates.truncate(total_number);
+
+        Ok(ThresholdPolicy::new(
+            total_number,
+            threshold_number,
+            candidates,
+        ))
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for ThresholdPolicy {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (1..=MAX_NODES_NUMBER)
+            .prop_flat_map(|total_number| {
+                (
+                    Just(total_number),
+                    1..=total_number,
+                    subsequence(candidate_indices(), total_number).prop_shuffle(),
+                )
+            })
+            .prop_map(|(total_number, threshold_number, indices)| {
+                ThresholdPolicy::new(total_number, threshold_number, indices)
+            })
+            .boxed()
+    }
+}
@@ -1,13 +1,23 @@
 //! Define threshold pke with BFV.
 
-use algebra::{Field, Polynomial};
-use chacha20poly1305::{aead::Aead, AeadCore, ChaCha20Poly1305, Key, KeyInit, Nonce};
+use std::fmt;
+
+pub use algebra::sss::{self, Share, ShareStream};
+use algebra::{utils::sample_distinct_nonzero_field_vec, CsRng, Field, Polynomial};
+use chacha20poly1305::{
+    aead::{Aead, Payload},
+    AeadCore, ChaCha20Poly1305, Key, KeyInit, Nonce,
+};
+use ed25519_dalek::{SigningKey, VerifyingKey};
 use itybity::IntoBitIterator;
-use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::{
-    BFVCiphertext, BFVContext, BFVPlaintext, BFVPublicKey, BFVScheme, BFVSecretKey, PlainField,
-    DIMENSION_N, MAX_NODES_NUMBER,
+    protocol::Reader, Aggregation, AuditLog, BFVCiphertext, BFVContext, BFVError, BFVParameters,
+    BFVPlaintext, BFVPublicKey, BFVPublicKeyPolys, BFVScheme, BFVSecretKey, CipherField,
+    CiphertextShare, CombineProof, DecryptionShare, PlainField, PlaintextKnowledgeProof, Shake128,
+    ShareRejection, Xof, XofReader, XofRng, DIMENSION_N, MAX_NODES_NUMBER,
 };
 
 type F = PlainField;
@@ -18,6 +28,7 @@ pub struct ThresholdPolicy {
     total_number: usize,
     threshold_number: usize,
     indices: Vec<F>,
+    pack_number: usize,
 }
 
 impl ThresholdPolicy {
@@ -44,9 +55,60 @@ impl ThresholdPolicy {
             total_number,
             threshold_number,
             indices,
+            pack_number: 1,
         }
     }
 
+    /// Create a new instance with `total_number` distinct random nonzero indices.
+    pub fn random<R: CsRng>(total_number: usize, threshold_number: usize, rng: &mut R) -> Self {
+        let indices = sample_distinct_nonzero_field_vec(total_number, rng);
+        Self::new(total_number, threshold_number, indices)
+    }
+
+    /// Create a new instance with packed (ramp) secret sharing.
+    ///
+    /// Each sharing polynomial carries `pack_number` secrets at distinct evaluation points,
+    /// so `pack_number` messages only cost one share per party.
+    /// Any `threshold_number - 1` parties still learn nothing about the secrets,
+    /// while reconstruction needs `threshold_number + pack_number - 1` shares.
+    ///
+    /// The evaluation points of the secrets and of the randomness are
+    /// `0, -1, -2, ..., -(threshold_number + pack_number - 2)`,
+    /// so `indices` should not contain any of them.
+    pub fn new_packed(
+        total_number: usize,
+        threshold_number: usize,
+        indices: Vec<F>,
+        pack_number: usize,
+    ) -> Self {
+        assert!(pack_number >= 1, "pack number should be at least 1");
+        assert!(
+            threshold_number >= 1,
+            "threshold number should be at least 1"
+        );
+        assert!(
+            threshold_number + pack_number - 1 <= total_number,
+            "reconstruction number exceeds total number"
+        );
+
+        let mut policy = Self::new(total_number, threshold_number, indices);
+        policy.pack_number = pack_number;
+
+        assert!(
+            policy.base_points().len() < F::modulus_value() as usize,
+            "pack number is too large for the plaintext field"
+        );
+        assert!(
+            policy
+                .base_points()
+                .iter()
+                .all(|point| !policy.indices.contains(point)),
+            "indices should not contain the evaluation points of secrets"
+        );
+
+        policy
+    }
+
     /// Return total_number
     #[inline]
     pub fn total_number(&self) -> usize {
@@ -65,23 +127,427 @@ impl ThresholdPolicy {
         &self.indices
     }
 
+    /// Return a policy over the same nodes with a different threshold_number and without packing.
+    pub fn with_threshold(&self, threshold_number: usize) -> Self {
+        assert!(
+            threshold_number >= 1,
+            "threshold number should be at least 1"
+        );
+        assert!(
+            threshold_number <= self.total_number,
+            "threshold number exceeds total number"
+        );
+        Self {
+            threshold_number,
+            pack_number: 1,
+            ..self.clone()
+        }
+    }
+
+    /// Return pack_number, the number of secrets carried by one sharing polynomial.
+    #[inline]
+    pub fn pack_number(&self) -> usize {
+        self.pack_number
+    }
+
+    /// Return the number of shares needed to reconstruct packed secrets,
+    /// which is `threshold_number + pack_number - 1`.
+    #[inline]
+    pub fn reconstruction_number(&self) -> usize {
+        self.threshold_number + self.pack_number - 1
+    }
+
+    /// Return the evaluation points of the packed secrets, `0, -1, ..., -(pack_number - 1)`.
+    #[inline]
+    pub fn secret_points(&self) -> Vec<F> {
+        (0..self.pack_number)
+            .map(|i| -F::cast_from_usize(i))
+            .collect()
+    }
+
+    /// The points at which the sharing polynomial is fixed,
+    /// the secret points followed by `threshold_number - 1` points for randomness.
+    fn base_points(&self) -> Vec<F> {
+        (0..self.reconstruction_number())
+            .map(|i| -F::cast_from_usize(i))
+            .collect()
+    }
+
     /// Securely sharing a message using Shamir secret sharing.
-    pub fn secret_sharing<R>(&self, secret: &Polynomial<F>, rng: &mut R) -> Vec<Polynomial<F>>
+    ///
+    /// The `Debug` output of the shares only shows their length, see [`Share`].
+    #[inline]
+    pub fn secret_sharing<R>(&self, secret: &Polynomial<F>, rng: &mut R) -> Vec<Share<F>>
+    where
+        R: CsRng,
+    {
+        self.secret_sharing_iter(secret, rng).collect()
+    }
+
+    /// Securely sharing a message using Shamir secret sharing,
+    /// yielding the share of one party at a time.
+    ///
+    /// Only the `threshold_number - 1` random coefficients of the sharing polynomials
+    /// are kept, so the memory does not grow with `total_number`.
+    pub fn secret_sharing_iter<'a, R>(
+        &'a self,
+        secret: &'a Polynomial<F>,
+        rng: &mut R,
+    ) -> ShareStream<'a, F>
+    where
+        R: CsRng,
+    {
+        assert!(
+            self.threshold_number >= 1,
+            "threshold number should be at least 1"
+        );
+        sss::share(secret, self.threshold_number, &self.indices, rng)
+    }
+
+    /// Securely sharing many messages using Shamir secret sharing,
+    /// where the `j`-th entry holds the shares of `secrets[j]` as [`Self::secret_sharing`] does.
+    ///
+    /// The powers of the indices, i.e. the vandermonde matrix, are computed once for all
+    /// messages, and with the `parallel` feature the messages are shared in parallel
+    /// after sampling all the randomness.
+    pub fn secret_sharing_batch<R>(
+        &self,
+        secrets: &[Polynomial<F>],
+        rng: &mut R,
+    ) -> Vec<Vec<Share<F>>>
     where
-        R: Rng + CryptoRng,
+        R: CsRng,
     {
-        let mut res = vec![vec![F::ZERO; secret.coeff_count()]; self.total_number];
+        assert!(
+            self.threshold_number >= 1,
+            "threshold number should be at least 1"
+        );
+        // the powers `x, x^2, ..., x^(threshold_number - 1)` of every index
+        let vandermonde: Vec<Vec<F>> = self
+            .indices
+            .iter()
+            .map(|&x| {
+                core::iter::successors(Some(x), |&p| Some(p * x))
+                    .take(self.threshold_number - 1)
+                    .collect()
+            })
+            .collect();
+        let randomness: Vec<Vec<Polynomial<F>>> = secrets
+            .iter()
+            .map(|secret| {
+                (1..self.threshold_number)
+                    .map(|_| Polynomial::<F>::random(secret.coeff_count(), &mut *rng))
+                    .collect()
+            })
+            .collect();
+
+        let share_one = |(secret, randomness): (&Polynomial<F>, &Vec<Polynomial<F>>)| {
+            vandermonde
+                .iter()
+                .map(|powers| {
+                    let mut share = secret.clone();
+                    for (r, &power) in randomness.iter().zip(powers) {
+                        share
+                            .iter_mut()
+                            .zip(r.iter())
+                            .for_each(|(s, &r)| s.add_mul_assign(r, power));
+                    }
+                    Share::new(share)
+                })
+                .collect()
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            secrets.par_iter().zip(&randomness).map(share_one).collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            secrets.iter().zip(&randomness).map(share_one).collect()
+        }
+    }
+
+    /// Reconstruct the secret from `shares` at `chosen_indices`, correcting corrupted shares.
+    ///
+    /// With `n` shares, up to `(n - threshold_number) / 2` corrupted shares are corrected
+    /// by Berlekamp-Welch decoding, and the indices of the corrupted shares are returned
+    /// along with the secret. Return `None` if there are more corrupted shares than that.
+    pub fn reconstruct_with_correction(
+        &self,
+        shares: &[Polynomial<F>],
+        chosen_indices: &[F],
+    ) -> Option<(Polynomial<F>, Vec<F>)> {
+        assert_eq!(self.pack_number, 1, "packed shares are not supported");
+        assert!(
+            shares.len() >= self.threshold_number,
+            "the number of shares is less than threshold_number"
+        );
+        sss::reconstruct_with_correction(shares, chosen_indices, self.threshold_number)
+    }
 
-        for (i, m) in secret.iter().enumerate() {
-            let mut poly = Polynomial::<F>::random(self.threshold_number, &mut *rng);
-            poly[0] = *m;
+    /// Securely sharing `pack_number` messages at once using packed Shamir secret sharing.
+    ///
+    /// The sharing polynomial of each coefficient takes the messages' coefficients at
+    /// [`ThresholdPolicy::secret_points`] and random values at `threshold_number - 1` further points.
+    pub fn secret_sharing_packed<R>(&self, secrets: &[Polynomial<F>], rng: &mut R) -> Vec<Share<F>>
+    where
+        R: CsRng,
+    {
+        assert_eq!(
+            secrets.len(),
+            self.pack_number,
+            "the number of secrets should be pack_number"
+        );
+        let coeff_count = secrets[0].coeff_count();
+        assert!(
+            secrets.iter().all(|s| s.coeff_count() == coeff_count),
+            "secrets should have the same coeff count"
+        );
+
+        let base_points = self.base_points();
+        let randomness: Vec<Polynomial<F>> = (self.pack_number..base_points.len())
+            .map(|_| Polynomial::<F>::random(coeff_count, &mut *rng))
+            .collect();
+        let values: Vec<&Polynomial<F>> = secrets.iter().chain(randomness.iter()).collect();
 
-            for (j, &point) in self.indices.iter().enumerate() {
-                res[j][i] = poly.evaluate(point);
+        self.indices
+            .iter()
+            .map(|&point| {
+                let coeffs = ThresholdPKE::gen_lagrange_coeffs_at(point, &base_points);
+                let mut share = Polynomial::<F>::zero(coeff_count);
+                for (value, &coeff) in values.iter().zip(coeffs.iter()) {
+                    share
+                        .iter_mut()
+                        .zip(value.iter())
+                        .for_each(|(s, &v)| s.add_mul_assign(v, coeff));
+                }
+                Share::new(share)
+            })
+            .collect()
+    }
+}
+
+/// Define a two-level threshold policy, where each share of the top policy
+/// is shared again to a sub-committee with its own policy,
+/// e.g. 3 of 5 departments, each of which 2 of 3 officers.
+#[derive(Debug, Clone)]
+pub struct HierarchicalPolicy {
+    top: ThresholdPolicy,
+    subs: Vec<ThresholdPolicy>,
+}
+
+impl HierarchicalPolicy {
+    /// Create a new instance, where `subs[i]` is the policy of the sub-committee
+    /// holding the share of `top.indices()[i]`.
+    pub fn new(top: ThresholdPolicy, subs: Vec<ThresholdPolicy>) -> Self {
+        assert_eq!(
+            subs.len(),
+            top.total_number(),
+            "the number of sub policies should be consistent with total_number"
+        );
+        assert!(
+            core::iter::once(&top)
+                .chain(&subs)
+                .all(|policy| policy.pack_number() == 1),
+            "packed policies are not supported"
+        );
+        Self { top, subs }
+    }
+
+    /// Return the reference of the top policy
+    #[inline]
+    pub fn top(&self) -> &ThresholdPolicy {
+        &self.top
+    }
+
+    /// Return the reference of the sub policies
+    #[inline]
+    pub fn subs(&self) -> &[ThresholdPolicy] {
+        &self.subs
+    }
+
+    /// Return the number of parties over all sub-committees.
+    #[inline]
+    pub fn total_number(&self) -> usize {
+        self.subs.iter().map(ThresholdPolicy::total_number).sum()
+    }
+
+    /// Securely sharing a message through both levels,
+    /// the `j`-th share of the `i`-th vector belongs to party `j` of sub-committee `i`.
+    pub fn secret_sharing<R>(&self, secret: &Polynomial<F>, rng: &mut R) -> Vec<Vec<Share<F>>>
+    where
+        R: CsRng,
+    {
+        let shares = self.top.secret_sharing(secret, &mut *rng);
+        shares
+            .iter()
+            .zip(&self.subs)
+            .map(|(share, sub)| sub.secret_sharing(share.expose_secret(), &mut *rng))
+            .collect()
+    }
+
+    /// Compute the coefficients reconstructing the secret through both levels.
+    ///
+    /// `chosen` lists the chosen top indices, each with the chosen indices of its sub-committee.
+    /// The share of the `j`-th sub index of the `i`-th entry is weighted by `λ_i * μ_ij`,
+    /// where `λ` and `μ` are the lagrange coefficients of the top and the sub level,
+    /// and the coefficients are returned flattened in this order.
+    ///
+    /// Return [`BFVError::InvalidShare`] if the indices of a level are repeated
+    /// or not in its policy, and [`BFVError::NotEnoughShares`] if a level is below its threshold.
+    pub fn gen_lagrange_coeffs(&self, chosen: &[(F, Vec<F>)]) -> Result<Vec<F>, BFVError> {
+        if chosen.len() < self.top.threshold_number() {
+            return Err(BFVError::NotEnoughShares {
+                required: self.top.threshold_number(),
+                given: chosen.len(),
+            });
+        }
+        let top_indices: Vec<F> = chosen.iter().map(|(index, _)| *index).collect();
+        let top_coeffs = ThresholdPKE::gen_lagrange_coeffs(&top_indices)?;
+
+        let mut coeffs = Vec::with_capacity(chosen.iter().map(|(_, sub)| sub.len()).sum());
+        for ((index, sub_indices), top_coeff) in chosen.iter().zip(top_coeffs) {
+            let position = self
+                .top
+                .indices()
+                .iter()
+                .position(|x| x == index)
+                .ok_or(BFVError::InvalidShare)?;
+            let sub = &self.subs[position];
+            if sub_indices.iter().any(|x| !sub.indices().contains(x)) {
+                return Err(BFVError::InvalidShare);
+            }
+            if sub_indices.len() < sub.threshold_number() {
+                return Err(BFVError::NotEnoughShares {
+                    required: sub.threshold_number(),
+                    given: sub_indices.len(),
+                });
             }
+            coeffs.extend(
+                ThresholdPKE::gen_lagrange_coeffs(sub_indices)?
+                    .into_iter()
+                    .map(|coeff| coeff * top_coeff),
+            );
+        }
+        Ok(coeffs)
+    }
+}
+
+/// Define the ciphertext of threshold pke with its threshold embedded,
+/// so that the threshold can be chosen per ciphertext at encryption time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdCiphertext {
+    threshold_number: usize,
+    shares: Vec<BFVCiphertext>,
+}
+
+impl ThresholdCiphertext {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(threshold_number: usize, shares: Vec<BFVCiphertext>) -> Self {
+        Self {
+            threshold_number,
+            shares,
+        }
+    }
+
+    /// Return threshold_number
+    #[inline]
+    pub fn threshold_number(&self) -> usize {
+        self.threshold_number
+    }
+
+    /// Return the reference of the encrypted shares
+    #[inline]
+    pub fn shares(&self) -> &[BFVCiphertext] {
+        &self.shares
+    }
+
+    /// Serialize to `Vec<u8>`
+    pub fn to_vec(&self) -> Vec<u8> {
+        // layout: |threshold,count|len0,len1,...|data0,data1,...|
+        let shares: Vec<Vec<u8>> = self.shares.iter().map(BFVCiphertext::to_vec).collect();
+
+        let mut bytes = vec![];
+        bytes.extend((self.threshold_number as u32).to_be_bytes());
+        bytes.extend((shares.len() as u32).to_be_bytes());
+        for share in shares.iter() {
+            bytes.extend((share.len() as u32).to_be_bytes());
+        }
+        for share in shares {
+            bytes.extend(share);
         }
 
-        res.into_iter().map(Polynomial::new).collect()
+        bytes
+    }
+
+    /// Deserialize from [u8]
+    pub fn from_vec(bytes: &[u8]) -> Result<Self, BFVError> {
+        let malformed = BFVError::SerializationError("threshold ciphertext");
+        let mut reader = Reader::new(bytes);
+        let threshold_number = reader.u32().ok_or(malformed.clone())? as usize;
+        let count = reader.u32().ok_or(malformed.clone())? as usize;
+        let lens = (0..count)
+            .map(|_| reader.u32().map(|len| len as usize))
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(malformed.clone())?;
+
+        let shares = lens
+            .into_iter()
+            .map(|len| {
+                let share = reader.bytes(len).ok_or(malformed.clone())?;
+                BFVCiphertext::from_vec(share)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !reader.is_empty() {
+            return Err(malformed);
+        }
+
+        Ok(Self {
+            threshold_number,
+            shares,
+        })
+    }
+}
+
+/// Define the ciphertext of one node in the per-recipient hybrid encryption,
+/// see [`ThresholdPKE::encrypt_bytes_per_recipient`].
+///
+/// It holds the node's share of the message, encrypted under a content key of its own,
+/// and that content key encrypted to the node's public key alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipientCiphertext {
+    index: F,
+    wrapped_key: BFVCiphertext,
+    nonce: Nonce,
+    payload: Vec<u8>,
+}
+
+impl RecipientCiphertext {
+    /// Return the index of the node.
+    #[inline]
+    pub fn index(&self) -> F {
+        self.index
+    }
+
+    /// Return the content key encrypted to the node's public key.
+    #[inline]
+    pub fn wrapped_key(&self) -> &BFVCiphertext {
+        &self.wrapped_key
+    }
+
+    /// Return the nonce of the symmetric encryption.
+    #[inline]
+    pub fn nonce(&self) -> &Nonce {
+        &self.nonce
+    }
+
+    /// Return the node's share of the message encrypted under the content key.
+    #[inline]
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
     }
 }
 
@@ -90,6 +556,7 @@ impl ThresholdPolicy {
 pub struct ThresholdPKEContext {
     bfv_ctx: BFVContext,
     policy: ThresholdPolicy,
+    crs_seed: Option<[u8; 32]>,
 }
 
 impl ThresholdPKEContext {
@@ -98,7 +565,64 @@ impl ThresholdPKEContext {
     pub fn new(total_number: usize, threshold_number: usize, indices: Vec<F>) -> Self {
         let bfv_ctx = BFVContext::new();
         let policy = ThresholdPolicy::new(total_number, threshold_number, indices);
-        Self { bfv_ctx, policy }
+        Self {
+            bfv_ctx,
+            policy,
+            crs_seed: None,
+        }
+    }
+
+    /// Create a new instance with packed secret sharing,
+    /// see [`ThresholdPolicy::new_packed`].
+    #[inline]
+    pub fn new_packed(
+        total_number: usize,
+        threshold_number: usize,
+        indices: Vec<F>,
+        pack_number: usize,
+    ) -> Self {
+        let bfv_ctx = BFVContext::new();
+        let policy =
+            ThresholdPolicy::new_packed(total_number, threshold_number, indices, pack_number);
+        Self {
+            bfv_ctx,
+            policy,
+            crs_seed: None,
+        }
+    }
+
+    /// Return the context with a BFV context of the given parameters.
+    #[inline]
+    pub fn with_parameters(mut self, params: BFVParameters) -> Self {
+        self.bfv_ctx = BFVContext::with_parameters(params);
+        self
+    }
+
+    /// Return the context with a common reference seed agreed by all parties,
+    /// from which the public randomness is derived instead of being sampled by a dealer,
+    /// see [`Self::crs_rng`].
+    #[inline]
+    pub fn with_crs_seed(mut self, seed: [u8; 32]) -> Self {
+        self.crs_seed = Some(seed);
+        self
+    }
+
+    /// Return the common reference seed, if any.
+    #[inline]
+    pub fn crs_seed(&self) -> Option<&[u8; 32]> {
+        self.crs_seed.as_ref()
+    }
+
+    /// Return the generator of the public randomness labelled `label`,
+    /// expanded from the common reference seed with SHAKE-128,
+    /// or `None` without a common reference seed.
+    ///
+    /// Every party holding the same seed derives the same randomness for the same label.
+    pub fn crs_rng(&self, label: &[u8]) -> Option<XofRng<<Shake128 as Xof>::Reader>> {
+        self.crs_seed.map(|seed| {
+            let domain = [b"threshold-zk-lhe crs/".as_slice(), label].concat();
+            XofRng(Shake128::expand(&domain, &seed))
+        })
     }
 
     /// Return the reference of BFV context
@@ -112,7 +636,73 @@ impl ThresholdPKEContext {
     pub fn policy(&self) -> &ThresholdPolicy {
         &self.policy
     }
+
+    /// Return the digest of the scheme parameters and the policy,
+    /// which binds the authenticated ciphertext shares to this context.
+    pub fn parameter_digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update((self.bfv_ctx.rlwe_dimension() as u64).to_be_bytes());
+        hasher.update(CipherField::modulus_value().to_be_bytes());
+        hasher.update(F::modulus_value().to_be_bytes());
+        hasher.update((self.policy.total_number as u64).to_be_bytes());
+        hasher.update((self.policy.threshold_number as u64).to_be_bytes());
+        hasher.update((self.policy.pack_number as u64).to_be_bytes());
+        for index in self.policy.indices.iter() {
+            hasher.update(index.get().to_be_bytes());
+        }
+        if let Some(seed) = &self.crs_seed {
+            hasher.update(seed);
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// How the nonce of the hybrid encryption is chosen,
+/// see [`ThresholdPKE::encrypt_bytes_with_nonce`].
+///
+/// [`NonceMode::Random`] draws the nonce from the rng of the context, and so is only as sound as that rng.
+/// [`NonceMode::Derived`] derives it from a long-term secret instead, for systems that cannot rely on
+/// the quality of per-message randomness for the uniqueness of nonces.
+/// Either way the symmetric key and the randomness of the key encryption are sampled fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonceMode<'a> {
+    /// Sample a fresh nonce from the rng of the context.
+    Random,
+    /// Derive the nonce through the XOF from the caller's secret `key` and the `message_id`.
+    ///
+    /// Repeating a message id repeats the nonce, but never under the same symmetric key,
+    /// since that is sampled per message. Still, message ids should be unique for the `key`,
+    /// and `key` must be uniformly random and kept secret.
+    Derived {
+        /// The caller's secret key, shared by all its messages.
+        key: &'a [u8; 32],
+        /// The id of the message, which should be unique for the `key`.
+        message_id: &'a [u8],
+    },
+}
+
+impl NonceMode<'_> {
+    /// Derive the nonce of [`NonceMode::Derived`], returning `None` for [`NonceMode::Random`].
+    ///
+    /// The parameter digest of the context is bound too,
+    /// so the same message id gets other nonces under other parameters.
+    fn derive(self, ctx: &ThresholdPKEContext) -> Option<Nonce> {
+        let NonceMode::Derived { key, message_id } = self else {
+            return None;
+        };
+        let mut xof = Shake128::default();
+        xof.update(b"threshold-zk-lhe derived hybrid encryption nonce");
+        xof.update(key);
+        xof.update(&ctx.parameter_digest());
+        xof.update(&(message_id.len() as u64).to_be_bytes());
+        xof.update(message_id);
+
+        let mut nonce = Nonce::default();
+        xof.finalize_xof().read(&mut nonce);
+        Some(nonce)
+    }
 }
+
 /// Define the threshold pke scheme.
 pub struct ThresholdPKE;
 
@@ -127,163 +717,1065 @@ impl ThresholdPKE {
         ThresholdPKEContext::new(total_number, threshold_number, indices)
     }
 
-    /// Compute lagrange coefficients.
-    pub fn gen_lagrange_coeffs(chosen_indices: &[F]) -> Vec<F> {
-        assert!(
-            !chosen_indices.contains(&F::ZERO),
-            "indices should not contain 0"
-        );
-        let mut lagrange_coeff = vec![F::ZERO; chosen_indices.len()];
-
-        for (i, point) in chosen_indices.iter().enumerate() {
-            let mut points_without_i = chosen_indices.to_vec();
-            points_without_i.retain(|x| *x != *point);
+    /// Generate threshold pke context with packed secret sharing.
+    #[inline]
+    pub fn gen_packed_context(
+        total_number: usize,
+        threshold_number: usize,
+        indices: Vec<F>,
+        pack_number: usize,
+    ) -> ThresholdPKEContext {
+        ThresholdPKEContext::new_packed(total_number, threshold_number, indices, pack_number)
+    }
 
-            let numerator = points_without_i.iter().fold(F::ONE, |acc, &x| acc * (-x));
-            let denominator = points_without_i
-                .iter()
-                .fold(F::ONE, |acc, &x| acc * (*point - x));
-            lagrange_coeff[i] = numerator / denominator;
+    /// Compute lagrange coefficients, see [`sss::lagrange_coeffs`].
+    ///
+    /// The indices may come from an untrusted combiner, so [`BFVError::InvalidShare`]
+    /// is returned if they contain `0` or a repeated index.
+    #[inline]
+    pub fn gen_lagrange_coeffs(chosen_indices: &[F]) -> Result<Vec<F>, BFVError> {
+        for (i, index) in chosen_indices.iter().enumerate() {
+            if *index == F::ZERO || chosen_indices[..i].contains(index) {
+                return Err(BFVError::InvalidShare);
+            }
         }
+        Ok(sss::lagrange_coeffs(chosen_indices))
+    }
 
-        lagrange_coeff
+    /// Compute lagrange coefficients for evaluating the shared polynomial at `point`.
+    ///
+    /// For `point = 0` this is exactly [`ThresholdPKE::gen_lagrange_coeffs`],
+    /// other points allow to homomorphically compute the share of any index,
+    /// which is used for share recovery and resharing, see [`sss::lagrange_coeffs_at`].
+    #[inline]
+    pub fn gen_lagrange_coeffs_at(point: F, chosen_indices: &[F]) -> Vec<F> {
+        sss::lagrange_coeffs_at(point, chosen_indices)
     }
 
     /// Generate key pair.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn gen_keypair(ctx: &ThresholdPKEContext) -> (BFVSecretKey, BFVPublicKey) {
         BFVScheme::gen_keypair(ctx.bfv_ctx())
     }
 
+    /// Sample the polynomial `a` shared by the public key shares of the joint key generation.
+    ///
+    /// With a common reference seed, see [`ThresholdPKEContext::with_crs_seed`],
+    /// every party derives the same `a` by itself,
+    /// otherwise it is sampled by a dealer and sent to every party.
+    #[inline]
+    pub fn gen_common_polynomial(ctx: &ThresholdPKEContext) -> Polynomial<CipherField> {
+        let bfv_ctx = ctx.bfv_ctx();
+        match ctx.crs_rng(b"a") {
+            Some(rng) => Polynomial::random(bfv_ctx.rlwe_dimension(), rng),
+            None => Polynomial::random(bfv_ctx.rlwe_dimension(), &mut *bfv_ctx.csrng_mut()),
+        }
+    }
+
+    /// Generate the key share of a party in the joint key generation,
+    /// the secret key `s_i` and the public key share `(a * s_i + e_i, -a)`.
+    ///
+    /// The shares are aggregated by [`Self::aggregate_public_keys`] into the public key
+    /// of `s = Σ s_i`, which is never computed by anyone.
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn gen_key_share(
+        ctx: &ThresholdPKEContext,
+        a: &Polynomial<CipherField>,
+    ) -> (BFVSecretKey, BFVPublicKey) {
+        let sk = BFVSecretKey::new(ctx.bfv_ctx());
+        let pk = sk.gen_pubkey_with(ctx.bfv_ctx(), a.clone());
+        (sk, pk)
+    }
+
+    /// Aggregate the public key shares of the joint key generation into the joint public key
+    /// `(Σ b_i, -a)`, whose error is the sum of the errors of the shares.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if no share is given,
+    /// or if the shares do not have the same polynomial `a` and the rlwe dimension.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn aggregate_public_keys(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+    ) -> Result<BFVPublicKey, BFVError> {
+        let Some((first, rest)) = pks.split_first() else {
+            return Err(BFVError::ParameterMismatch(
+                "at least one public key share should be given",
+            ));
+        };
+        let BFVPublicKeyPolys { b, a } = first.polys();
+        if a.coeff_count() != ctx.bfv_ctx().rlwe_dimension() {
+            return Err(BFVError::ParameterMismatch(
+                "the dimension of the public key shares should be the rlwe dimension",
+            ));
+        }
+        if rest.iter().any(|pk| &pk.polys().a != a) {
+            return Err(BFVError::ParameterMismatch(
+                "the public key shares should have the same polynomial a",
+            ));
+        }
+
+        let b = rest.iter().fold(b.clone(), |mut b, pk| {
+            b += &pk.polys().b;
+            b
+        });
+        Ok(BFVPublicKey::new(b, a.clone()))
+    }
+
     /// Encrypt a message, where the message is a polynomial.
     /// First secret sharing the message according to the policy.
     /// Encrypt each share using all the pk's of the parties.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn encrypt(
         ctx: &ThresholdPKEContext,
-        pks: &Vec<BFVPublicKey>,
+        pks: &[BFVPublicKey],
         m: &BFVPlaintext,
-    ) -> Vec<BFVCiphertext> {
-        assert_eq!(
-            pks.len(),
-            ctx.policy.total_number(),
-            "the length of pks should be total_number"
-        );
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
         let polys = ctx
             .policy
             .secret_sharing(&m.0, &mut *ctx.bfv_ctx().csrng_mut());
+        let shares: Vec<BFVPlaintext> = polys
+            .into_iter()
+            .map(|share| BFVPlaintext(share.into_secret()))
+            .collect();
+        BFVScheme::encrypt_many(ctx.bfv_ctx(), pks, &shares)
+    }
+
+    /// Encrypt many messages to the nodes, where the `j`-th entry holds the ciphertexts of
+    /// `msgs[j]` as [`Self::encrypt`] does, sharing them at once with
+    /// [`ThresholdPolicy::secret_sharing_batch`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_batch(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        msgs: &[BFVPlaintext],
+    ) -> Result<Vec<Vec<BFVCiphertext>>, BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
+        let msgs: Vec<Polynomial<F>> = msgs.iter().map(|m| m.0.clone()).collect();
+        let polys = ctx
+            .policy
+            .secret_sharing_batch(&msgs, &mut *ctx.bfv_ctx().csrng_mut());
         polys
             .into_iter()
-            .zip(pks)
-            .map(|(x, pk)| BFVScheme::encrypt(ctx.bfv_ctx(), pk, &BFVPlaintext(x)))
+            .map(|polys| {
+                let shares: Vec<BFVPlaintext> = polys
+                    .into_iter()
+                    .map(|share| BFVPlaintext(share.into_secret()))
+                    .collect();
+                BFVScheme::encrypt_many(ctx.bfv_ctx(), pks, &shares)
+            })
             .collect()
     }
 
+    /// Encrypt a message to the subset of the nodes at `subset_indices`, such as the nodes
+    /// currently online, where `pks` are the public keys of all the nodes of the policy.
+    ///
+    /// The message is shared with the threshold of the policy at the subset's own indices,
+    /// so the `i`-th returned ciphertext is the share of `subset_indices[i]`,
+    /// and any `threshold_number` of them are combined by [`Self::combine`] as usual.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_for_subset(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        subset_indices: &[F],
+        m: &BFVPlaintext,
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
+        let threshold_number = ctx.policy.threshold_number();
+        if subset_indices.len() < threshold_number {
+            return Err(BFVError::NotEnoughShares {
+                required: threshold_number,
+                given: subset_indices.len(),
+            });
+        }
+        let mut subset_pks = Vec::with_capacity(subset_indices.len());
+        for (i, index) in subset_indices.iter().enumerate() {
+            if subset_indices[..i].contains(index) {
+                return Err(BFVError::ParameterMismatch(
+                    "subset_indices should not contain repeated index",
+                ));
+            }
+            let position = ctx.policy.indices().iter().position(|x| x == index).ok_or(
+                BFVError::ParameterMismatch("subset_indices should be in the policy"),
+            )?;
+            subset_pks.push(pks[position].clone());
+        }
+
+        let policy = ThresholdPolicy::new(
+            subset_indices.len(),
+            threshold_number,
+            subset_indices.to_vec(),
+        );
+        let polys = policy.secret_sharing(&m.0, &mut *ctx.bfv_ctx().csrng_mut());
+        let shares: Vec<BFVPlaintext> = polys
+            .into_iter()
+            .map(|share| BFVPlaintext(share.into_secret()))
+            .collect();
+        BFVScheme::encrypt_many(ctx.bfv_ctx(), &subset_pks, &shares)
+    }
+
+    /// Encrypt a message like [`Self::encrypt`], and additionally output parity shares
+    /// at `parity_indices`, the `i`-th encrypted to `parity_pks[i]`.
+    ///
+    /// The shares of the nodes and the parity shares are evaluations of the same sharing
+    /// polynomials, i.e. a Reed-Solomon codeword, so any `threshold_number` of them are
+    /// combined by [`Self::combine`] with their indices, and the message stays recoverable
+    /// even if some nodes never receive their shares.
+    /// A parity share is as sensitive as the share of a node, so are its holders.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_with_parity(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        parity_indices: &[F],
+        parity_pks: &[BFVPublicKey],
+        m: &BFVPlaintext,
+    ) -> Result<(Vec<BFVCiphertext>, Vec<BFVCiphertext>), BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
+        if parity_pks.len() != parity_indices.len() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of parity_pks and parity_indices should be equal",
+            ));
+        }
+        for (i, index) in parity_indices.iter().enumerate() {
+            if *index == F::ZERO
+                || parity_indices[..i].contains(index)
+                || ctx.policy.indices().contains(index)
+            {
+                return Err(BFVError::ParameterMismatch(
+                    "parity_indices should be distinct nonzero indices outside the policy",
+                ));
+            }
+        }
+
+        let indices: Vec<F> = ctx
+            .policy
+            .indices()
+            .iter()
+            .chain(parity_indices)
+            .copied()
+            .collect();
+        let shares: Vec<BFVPlaintext> = sss::share(
+            &m.0,
+            ctx.policy.threshold_number(),
+            &indices,
+            &mut *ctx.bfv_ctx().csrng_mut(),
+        )
+        .map(|share| BFVPlaintext(share.into_secret()))
+        .collect();
+        let (node_shares, parity_shares) = shares.split_at(pks.len());
+        Ok((
+            BFVScheme::encrypt_many(ctx.bfv_ctx(), pks, node_shares)?,
+            BFVScheme::encrypt_many(ctx.bfv_ctx(), parity_pks, parity_shares)?,
+        ))
+    }
+
+    /// Encrypt a message with a threshold chosen at encryption time,
+    /// instead of the threshold of the context's policy.
+    /// The threshold is embedded in the returned ciphertext.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_with_threshold(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        m: &BFVPlaintext,
+        threshold_number: usize,
+    ) -> Result<ThresholdCiphertext, BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
+        let policy = ctx.policy.with_threshold(threshold_number);
+        let polys = policy.secret_sharing(&m.0, &mut *ctx.bfv_ctx().csrng_mut());
+        let shares: Vec<BFVPlaintext> = polys
+            .into_iter()
+            .map(|share| BFVPlaintext(share.into_secret()))
+            .collect();
+        Ok(ThresholdCiphertext::new(
+            threshold_number,
+            BFVScheme::encrypt_many(ctx.bfv_ctx(), pks, &shares)?,
+        ))
+    }
+
+    /// Encrypt `pack_number` messages at once, where each message is a polynomial.
+    /// First packed secret sharing the messages according to the policy.
+    /// Encrypt each share using all the pk's of the parties.
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_packed(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        msgs: &[BFVPlaintext],
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
+        let msgs: Vec<Polynomial<F>> = msgs.iter().map(|m| m.0.clone()).collect();
+        let polys = ctx
+            .policy
+            .secret_sharing_packed(&msgs, &mut *ctx.bfv_ctx().csrng_mut());
+        let shares: Vec<BFVPlaintext> = polys
+            .into_iter()
+            .map(|share| BFVPlaintext(share.into_secret()))
+            .collect();
+        BFVScheme::encrypt_many(ctx.bfv_ctx(), pks, &shares)
+    }
+
     /// Encrypt a message, where the message consists of bytes.
     /// Note that we use a hybrid encryption, meaning use public key to encryt a symmetric key, and use the symmetric key to encryt the bytes with an AEAD algorithm.
+    ///
+    /// The bytes are authenticated together with `aad`, such as the message id, and the parameter digest
+    /// of the context, which also covers the policy, so they only decrypt with the same `aad` and context.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn encrypt_bytes(
         ctx: &ThresholdPKEContext,
-        pks: &Vec<BFVPublicKey>,
+        pks: &[BFVPublicKey],
         m: &[u8],
-    ) -> (Vec<BFVCiphertext>, Nonce, Vec<u8>) {
-        let sym_key = ChaCha20Poly1305::generate_key(&mut *ctx.bfv_ctx().csrng_mut());
+        aad: &[u8],
+    ) -> Result<(Vec<BFVCiphertext>, Nonce, Vec<u8>), BFVError> {
+        ThresholdPKE::encrypt_bytes_with_nonce(ctx, pks, m, aad, NonceMode::Random)
+    }
 
-        let key = BFVPlaintext(to_poly::<DIMENSION_N>(sym_key));
-        let c1 = ThresholdPKE::encrypt(ctx, pks, &key);
+    /// Encrypt a message of bytes like [`ThresholdPKE::encrypt_bytes`],
+    /// choosing the nonce by `mode`.
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_bytes_with_nonce(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        m: &[u8],
+        aad: &[u8],
+        mode: NonceMode<'_>,
+    ) -> Result<(Vec<BFVCiphertext>, Nonce, Vec<u8>), BFVError> {
+        let mut csrng = ctx.bfv_ctx().csrng_mut();
+        let sym_key = SymmetricKey(ChaCha20Poly1305::generate_key(&mut *csrng));
+        let nonce = match mode.derive(ctx) {
+            Some(nonce) => nonce,
+            None => ChaCha20Poly1305::generate_nonce(&mut *csrng),
+        };
+        drop(csrng);
+        let c1 = ThresholdPKE::encrypt(ctx, pks, &to_plaintext::<DIMENSION_N>(&sym_key))?;
 
-        let cipher = ChaCha20Poly1305::new(&sym_key);
-        let nonce = ChaCha20Poly1305::generate_nonce(&mut *ctx.bfv_ctx().csrng_mut());
-        let c2 = cipher.encrypt(&nonce, m).unwrap();
+        let cipher = ChaCha20Poly1305::new(sym_key.expose_secret());
+        let aad = associated_data(ctx, aad);
+        let c2 = cipher.encrypt(&nonce, Payload { msg: m, aad: &aad })?;
 
-        (c1, nonce, c2)
+        Ok((c1, nonce, c2))
     }
 
-    /// Decrypt the ciphertext.
+    /// Decrypt the ciphertext, see [`BFVScheme::decrypt`].
     #[inline]
     pub fn decrypt(
         ctx: &ThresholdPKEContext,
         sk: &BFVSecretKey,
         c: &BFVCiphertext,
-    ) -> BFVPlaintext {
+    ) -> Result<BFVPlaintext, BFVError> {
         BFVScheme::decrypt(ctx.bfv_ctx(), sk, c)
     }
 
+    /// Decrypt a batch of ciphertexts, in parallel with the `parallel` feature.
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn decrypt_batch(
+        ctx: &ThresholdPKEContext,
+        sk: &BFVSecretKey,
+        cs: &[BFVCiphertext],
+    ) -> Result<Vec<BFVPlaintext>, BFVError> {
+        BFVScheme::decrypt_batch(ctx.bfv_ctx(), sk, cs)
+    }
+
     /// Decrypt the ciphertext into bytes.
+    ///
+    /// Return [`BFVError::DecryptionFailure`] if the decrypted symmetric key is malformed,
+    /// and [`BFVError::AeadFailure`] if the bytes fail authentication,
+    /// including when `aad` or the parameters differ from the encryption.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn decrypt_bytes(
         ctx: &ThresholdPKEContext,
         sk: &BFVSecretKey,
         c1: &BFVCiphertext,
         nonce: &Nonce,
         c2: &[u8],
-    ) -> Vec<u8> {
-        let key = ThresholdPKE::decrypt(ctx, sk, c1);
-        let sym_key = to_bits(key.0);
+        aad: &[u8],
+    ) -> Result<Vec<u8>, BFVError> {
+        let key = ThresholdPKE::decrypt(ctx, sk, c1)?;
+        let sym_key = to_key(&key).ok_or(BFVError::DecryptionFailure)?;
+
+        let cipher = ChaCha20Poly1305::new(sym_key.expose_secret());
+
+        let aad = associated_data(ctx, aad);
+        Ok(cipher.decrypt(nonce, Payload { msg: c2, aad: &aad })?)
+    }
+
+    /// Encrypt a message of bytes with an independent content key per node,
+    /// instead of sharing a single symmetric key as [`ThresholdPKE::encrypt_bytes`] does.
+    ///
+    /// The bytes themselves are secret shared according to the policy, and the share of
+    /// each node is encrypted under a fresh content key which is encrypted to the node's
+    /// public key alone. A content key therefore reveals a single share, and the message
+    /// stays hidden below the threshold even if content keys leak.
+    ///
+    /// The `i`-th returned ciphertext is for the node of the `i`-th index of the policy.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_bytes_per_recipient(
+        ctx: &ThresholdPKEContext,
+        pks: &[BFVPublicKey],
+        m: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<RecipientCiphertext>, BFVError> {
+        if pks.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of pks should be total_number",
+            ));
+        }
+        let secret = Polynomial::new(bytes_to_digits(m));
+        let shares = ctx
+            .policy
+            .secret_sharing(&secret, &mut *ctx.bfv_ctx().csrng_mut());
+
+        shares
+            .into_iter()
+            .zip(pks)
+            .zip(ctx.policy.indices())
+            .map(|((share, pk), &index)| {
+                let content_key = SymmetricKey(ChaCha20Poly1305::generate_key(
+                    &mut *ctx.bfv_ctx().csrng_mut(),
+                ));
+                let key = to_plaintext::<DIMENSION_N>(&content_key);
+                let wrapped_key = BFVScheme::encrypt(ctx.bfv_ctx(), pk, &key)?;
+
+                // every coefficient of the share is below `t`, so it fits a byte
+                let share: Vec<u8> = share
+                    .expose_secret()
+                    .iter()
+                    .map(|c| c.get() as u8)
+                    .collect();
+                let cipher = ChaCha20Poly1305::new(content_key.expose_secret());
+                let nonce = ChaCha20Poly1305::generate_nonce(&mut *ctx.bfv_ctx().csrng_mut());
+                let aad = recipient_associated_data(ctx, aad, index);
+                let payload = cipher.encrypt(
+                    &nonce,
+                    Payload {
+                        msg: &share,
+                        aad: &aad,
+                    },
+                )?;
+
+                Ok(RecipientCiphertext {
+                    index,
+                    wrapped_key,
+                    nonce,
+                    payload,
+                })
+            })
+            .collect()
+    }
+
+    /// Decrypt the node's share of the message in `c`, whose content key is encrypted
+    /// to `sk` in `wrapped_key`: either [`RecipientCiphertext::wrapped_key`] itself for the node,
+    /// or its re-encryption by [`ThresholdPKE::re_encrypt`] for the receiver.
+    ///
+    /// Return [`BFVError::DecryptionFailure`] if the decrypted content key is malformed,
+    /// and [`BFVError::AeadFailure`] if the share fails authentication.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn decrypt_recipient_share(
+        ctx: &ThresholdPKEContext,
+        sk: &BFVSecretKey,
+        wrapped_key: &BFVCiphertext,
+        c: &RecipientCiphertext,
+        aad: &[u8],
+    ) -> Result<Polynomial<F>, BFVError> {
+        let key = ThresholdPKE::decrypt(ctx, sk, wrapped_key)?;
+        let content_key = to_key(&key).ok_or(BFVError::DecryptionFailure)?;
 
-        let cipher = ChaCha20Poly1305::new(&sym_key);
+        let cipher = ChaCha20Poly1305::new(content_key.expose_secret());
+        let aad = recipient_associated_data(ctx, aad, c.index);
+        let share = cipher.decrypt(
+            &c.nonce,
+            Payload {
+                msg: &c.payload,
+                aad: &aad,
+            },
+        )?;
 
-        cipher.decrypt(nonce, c2).unwrap()
+        share
+            .into_iter()
+            .map(|b| (u16::from(b) < F::modulus_value()).then(|| F::new(b.into())))
+            .collect::<Option<Vec<F>>>()
+            .map(Polynomial::new)
+            .ok_or(BFVError::DecryptionFailure)
+    }
+
+    /// Reconstruct the message of [`ThresholdPKE::encrypt_bytes_per_recipient`] from the shares
+    /// decrypted by [`ThresholdPKE::decrypt_recipient_share`] at `chosen_indices`.
+    ///
+    /// Return [`BFVError::InvalidShare`] if any index is repeated or is not from a node of the policy,
+    /// and [`BFVError::DecryptionFailure`] if the reconstructed message is malformed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn combine_bytes(
+        ctx: &ThresholdPKEContext,
+        shares: &[Polynomial<F>],
+        chosen_indices: &[F],
+    ) -> Result<Vec<u8>, BFVError> {
+        if shares.len() != chosen_indices.len() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of shares and chosen_indices should be equal",
+            ));
+        }
+        if shares.len() < ctx.policy.threshold_number() {
+            return Err(BFVError::NotEnoughShares {
+                required: ctx.policy.threshold_number(),
+                given: shares.len(),
+            });
+        }
+        for (i, index) in chosen_indices.iter().enumerate() {
+            if !ctx.policy.indices().contains(index) || chosen_indices[..i].contains(index) {
+                return Err(BFVError::InvalidShare);
+            }
+        }
+        if shares
+            .iter()
+            .any(|s| s.coeff_count() != shares[0].coeff_count())
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the shares should have the same length",
+            ));
+        }
+
+        let secret = sss::reconstruct(shares, chosen_indices);
+        digits_to_bytes(secret.as_slice()).ok_or(BFVError::DecryptionFailure)
     }
 
     /// Re-encrypt the ciphertext.
     /// First decrypt the ciphertext `c` with `sk`
     /// Encrypt the above message with `pk_new`.
+    ///
+    /// Return [`BFVError::ParameterMismatch`] if `c` does not have `rlwe_dimension` coefficients.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn re_encrypt(
         ctx: &ThresholdPKEContext,
         c: &BFVCiphertext,
         sk: &BFVSecretKey,
         pk_new: &BFVPublicKey,
-    ) -> BFVCiphertext {
-        let m = Self::decrypt(ctx, sk, c);
+    ) -> Result<BFVCiphertext, BFVError> {
+        let m = Self::decrypt(ctx, sk, c)?;
         BFVScheme::encrypt(ctx.bfv_ctx(), pk_new, &m)
     }
 
+    /// Re-encrypt the share of the node at `index`, and sign it with the node's `signing_key`
+    /// bound to `message_id` and the context's parameters.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn re_encrypt_authenticated(
+        ctx: &ThresholdPKEContext,
+        c: &BFVCiphertext,
+        sk: &BFVSecretKey,
+        pk_new: &BFVPublicKey,
+        index: F,
+        message_id: &[u8],
+        signing_key: &SigningKey,
+    ) -> Result<CiphertextShare, BFVError> {
+        let ct = Self::re_encrypt(ctx, c, sk, pk_new)?;
+        Ok(CiphertextShare::sign(
+            signing_key,
+            index,
+            ct,
+            message_id,
+            &ctx.parameter_digest(),
+        ))
+    }
+
+    /// Re-encrypt the share of the node at `index` to `pk_new`, proving the knowledge of the
+    /// re-encrypted share for `label`, such as the session id.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn gen_decryption_share(
+        ctx: &ThresholdPKEContext,
+        c: &BFVCiphertext,
+        sk: &BFVSecretKey,
+        pk_new: &BFVPublicKey,
+        index: F,
+        label: &[u8],
+    ) -> Result<DecryptionShare, BFVError> {
+        let m = Self::decrypt(ctx, sk, c)?;
+        let (value, witness) = BFVScheme::encrypt_with_witness(ctx.bfv_ctx(), pk_new, &m)?;
+        let proof = PlaintextKnowledgeProof::prove(ctx.bfv_ctx(), pk_new, &value, &witness, label);
+        Ok(DecryptionShare::new(index, value, Some(proof)))
+    }
+
+    /// Aggregate the decryption shares re-encrypted to `pk_new` into the encryption of the message.
+    ///
+    /// A share is rejected if its index is not in the policy or is repeated, if it is malformed,
+    /// or if its proof fails verification for `label`; shares without a proof are not verified.
+    /// Return [`BFVError::NotEnoughShares`] if fewer shares than the threshold are accepted.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn aggregate(
+        ctx: &ThresholdPKEContext,
+        shares: &[DecryptionShare],
+        pk_new: &BFVPublicKey,
+        label: &[u8],
+    ) -> Result<Aggregation, BFVError> {
+        let dimension = ctx.bfv_ctx().rlwe_dimension();
+        let mut accepted = Vec::with_capacity(shares.len());
+        let mut ctxts = Vec::with_capacity(shares.len());
+        let mut rejected = Vec::new();
+        for share in shares {
+            let rejection = if !ctx.policy.indices().contains(&share.index) {
+                Some(ShareRejection::UnknownIndex)
+            } else if accepted.contains(&share.index) {
+                Some(ShareRejection::Duplicate)
+            } else if share
+                .value
+                .polys()
+                .iter()
+                .any(|p| p.coeff_count() != dimension)
+            {
+                Some(ShareRejection::Malformed)
+            } else if share
+                .proof
+                .as_ref()
+                .is_some_and(|proof| !proof.verify(pk_new, &share.value, label))
+            {
+                Some(ShareRejection::InvalidProof)
+            } else {
+                None
+            };
+            match rejection {
+                Some(rejection) => rejected.push((share.index, rejection)),
+                None => {
+                    accepted.push(share.index);
+                    ctxts.push(share.value.clone());
+                }
+            }
+        }
+
+        let required = ctx.policy.threshold_number();
+        if accepted.len() < required {
+            return Err(BFVError::NotEnoughShares {
+                required,
+                given: accepted.len(),
+            });
+        }
+        let ciphertext = Self::combine(ctx, &ctxts, &accepted)?;
+        Ok(Aggregation {
+            ciphertext,
+            accepted,
+            rejected,
+        })
+    }
+
+    /// Aggregate the decryption shares as [`Self::aggregate`], recording the receiver, the label,
+    /// the shares with their proofs, the rejected indices and the result into `log`.
+    pub fn aggregate_audited(
+        ctx: &ThresholdPKEContext,
+        shares: &[DecryptionShare],
+        pk_new: &BFVPublicKey,
+        label: &[u8],
+        log: &mut AuditLog,
+    ) -> Result<Aggregation, BFVError> {
+        log.record("receiver", &pk_new.to_vec());
+        log.record("label", label);
+        for share in shares {
+            log.record("decryption share", &share.to_vec());
+        }
+
+        let aggregation = Self::aggregate(ctx, shares, pk_new, label)?;
+        for &(index, rejection) in &aggregation.rejected {
+            let mut bytes = index.get().to_be_bytes().to_vec();
+            bytes.push(rejection as u8);
+            log.record("rejected", &bytes);
+        }
+        log.record("combined", &aggregation.ciphertext.to_vec());
+        Ok(aggregation)
+    }
+
+    /// Combine the ciphertext as [`Self::combine`], recording the shares with their indices
+    /// and the result into `log`.
+    pub fn combine_audited(
+        ctx: &ThresholdPKEContext,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+        log: &mut AuditLog,
+    ) -> Result<BFVCiphertext, BFVError> {
+        for (c, index) in ctxts.iter().zip(chosen_indices) {
+            let mut bytes = index.get().to_be_bytes().to_vec();
+            bytes.extend(c.to_vec());
+            log.record("share", &bytes);
+        }
+
+        let c = Self::combine(ctx, ctxts, chosen_indices)?;
+        log.record("combined", &c.to_vec());
+        Ok(c)
+    }
+
+    /// Combine the authenticated ciphertext shares.
+    /// `verifying_keys` are the keys of the nodes in the order of the policy's indices.
+    /// Return [`BFVError::InvalidShare`] if any share is repeated, is not from a node of the policy,
+    /// or fails verification for `message_id` and the context's parameters.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn combine_authenticated(
+        ctx: &ThresholdPKEContext,
+        shares: &[CiphertextShare],
+        verifying_keys: &[VerifyingKey],
+        message_id: &[u8],
+    ) -> Result<BFVCiphertext, BFVError> {
+        if verifying_keys.len() != ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of verifying_keys should be total_number",
+            ));
+        }
+        let digest = ctx.parameter_digest();
+
+        let mut chosen_indices = Vec::with_capacity(shares.len());
+        for share in shares {
+            let position = ctx
+                .policy
+                .indices()
+                .iter()
+                .position(|&i| i == share.index)
+                .ok_or(BFVError::InvalidShare)?;
+            if chosen_indices.contains(&share.index)
+                || !share.verify(&verifying_keys[position], message_id, &digest)
+            {
+                return Err(BFVError::InvalidShare);
+            }
+            chosen_indices.push(share.index);
+        }
+        if chosen_indices.len() < ctx.policy.threshold_number() {
+            return Err(BFVError::NotEnoughShares {
+                required: ctx.policy.threshold_number(),
+                given: chosen_indices.len(),
+            });
+        }
+
+        let lagrange_coeff = Self::gen_lagrange_coeffs(&chosen_indices)?;
+        BFVScheme::evaluate_inner_product(
+            ctx.bfv_ctx(),
+            shares.iter().map(|s| &s.ct),
+            &lagrange_coeff,
+        )
+    }
+
     /// Combine the ciphertext.
     /// Homomorphically compute the Shamir reconstruction method.
+    ///
+    /// Return [`BFVError::NotEnoughShares`] if fewer shares than the threshold are given,
+    /// and [`BFVError::InvalidShare`] if an index is `0` or repeated.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn combine(
         ctx: &ThresholdPKEContext,
         ctxts: &[BFVCiphertext],
         chosen_indices: &[F],
-    ) -> BFVCiphertext {
-        assert_eq!(
-            ctxts.len(),
-            chosen_indices.len(),
-            "the length of ctxts and chosen_indices should be equal"
-        );
-        let lagrange_coeff = Self::gen_lagrange_coeffs(chosen_indices);
+    ) -> Result<BFVCiphertext, BFVError> {
+        Self::combine_at_threshold(ctx, ctxts, chosen_indices, ctx.policy.threshold_number())
+    }
+
+    fn combine_at_threshold(
+        ctx: &ThresholdPKEContext,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+        threshold_number: usize,
+    ) -> Result<BFVCiphertext, BFVError> {
+        if ctxts.len() != chosen_indices.len() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of ctxts and chosen_indices should be equal",
+            ));
+        }
+        if chosen_indices.len() < threshold_number {
+            return Err(BFVError::NotEnoughShares {
+                required: threshold_number,
+                given: chosen_indices.len(),
+            });
+        }
+        let lagrange_coeff = Self::gen_lagrange_coeffs(chosen_indices)?;
         BFVScheme::evaluate_inner_product(ctx.bfv_ctx(), ctxts, &lagrange_coeff)
     }
+
+    /// Combine the ciphertext, and prove that the combination is computed correctly,
+    /// so that the combiner needs not to be trusted.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn combine_with_proof(
+        ctx: &ThresholdPKEContext,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+    ) -> Result<(BFVCiphertext, CombineProof), BFVError> {
+        let c = Self::combine(ctx, ctxts, chosen_indices)?;
+        let proof = CombineProof::prove(ctxts, chosen_indices, &c);
+        Ok((c, proof))
+    }
+
+    /// Verify that `c` is the combination of `ctxts` at `chosen_indices`.
+    ///
+    /// The indices come from the combiner, so `false` is returned unless they are
+    /// distinct indices of the policy.
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn verify_combine(
+        ctx: &ThresholdPKEContext,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+        c: &BFVCiphertext,
+        proof: &CombineProof,
+    ) -> bool {
+        chosen_indices
+            .iter()
+            .all(|index| ctx.policy.indices().contains(index))
+            && proof.verify(ctxts, chosen_indices, c)
+    }
+
+    /// Combine the re-encrypted shares of a [`ThresholdCiphertext`],
+    /// checking that there are enough shares for its embedded threshold.
+    ///
+    /// The embedded threshold may come from the wire, so [`BFVError::ParameterMismatch`]
+    /// is returned unless it is in `[1, total_number]` of the policy.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn combine_with_threshold(
+        ctx: &ThresholdPKEContext,
+        c: &ThresholdCiphertext,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+    ) -> Result<BFVCiphertext, BFVError> {
+        if c.threshold_number() == 0 || c.threshold_number() > ctx.policy.total_number() {
+            return Err(BFVError::ParameterMismatch(
+                "the threshold of the ciphertext should be in [1, total_number]",
+            ));
+        }
+        Self::combine_at_threshold(ctx, ctxts, chosen_indices, c.threshold_number())
+    }
+
+    /// Encrypt a message under a [`HierarchicalPolicy`],
+    /// where `pks[i][j]` is the public key of party `j` of sub-committee `i`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn encrypt_hierarchical(
+        ctx: &ThresholdPKEContext,
+        policy: &HierarchicalPolicy,
+        pks: &[Vec<BFVPublicKey>],
+        m: &BFVPlaintext,
+    ) -> Result<Vec<Vec<BFVCiphertext>>, BFVError> {
+        if pks.len() != policy.subs().len()
+            || pks
+                .iter()
+                .zip(policy.subs())
+                .any(|(pks, sub)| pks.len() != sub.total_number())
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the shape of pks should be consistent with the policy",
+            ));
+        }
+        let shares = policy.secret_sharing(&m.0, &mut *ctx.bfv_ctx().csrng_mut());
+        shares
+            .into_iter()
+            .zip(pks)
+            .map(|(polys, pks)| {
+                let shares: Vec<BFVPlaintext> = polys
+                    .into_iter()
+                    .map(|share| BFVPlaintext(share.into_secret()))
+                    .collect();
+                BFVScheme::encrypt_many(ctx.bfv_ctx(), pks, &shares)
+            })
+            .collect()
+    }
+
+    /// Combine the re-encrypted shares of a [`HierarchicalPolicy`] through both levels,
+    /// where `ctxts[i][j]` is the share of the `j`-th sub index of `chosen[i]`,
+    /// see [`HierarchicalPolicy::gen_lagrange_coeffs`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn combine_hierarchical(
+        ctx: &ThresholdPKEContext,
+        policy: &HierarchicalPolicy,
+        ctxts: &[Vec<BFVCiphertext>],
+        chosen: &[(F, Vec<F>)],
+    ) -> Result<BFVCiphertext, BFVError> {
+        if ctxts.len() != chosen.len()
+            || ctxts
+                .iter()
+                .zip(chosen)
+                .any(|(c, (_, sub_indices))| c.len() != sub_indices.len())
+        {
+            return Err(BFVError::ParameterMismatch(
+                "the shape of ctxts and chosen should be equal",
+            ));
+        }
+        let coeffs = policy.gen_lagrange_coeffs(chosen)?;
+        BFVScheme::evaluate_inner_product(ctx.bfv_ctx(), ctxts.iter().flatten(), &coeffs)
+    }
+
+    /// Decrypt the re-encrypted shares one by one and reconstruct the message,
+    /// correcting and identifying corrupted shares,
+    /// see [`ThresholdPolicy::reconstruct_with_correction`].
+    /// Return [`BFVError::DecryptionFailure`] if there are too many corrupted shares.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn decrypt_with_correction(
+        ctx: &ThresholdPKEContext,
+        sk: &BFVSecretKey,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+    ) -> Result<(BFVPlaintext, Vec<F>), BFVError> {
+        let shares: Vec<Polynomial<F>> = ctxts
+            .iter()
+            .map(|c| Self::decrypt(ctx, sk, c).map(|m| m.0))
+            .collect::<Result<_, _>>()?;
+        ctx.policy
+            .reconstruct_with_correction(&shares, chosen_indices)
+            .map(|(m, corrupted)| (BFVPlaintext(m), corrupted))
+            .ok_or(BFVError::DecryptionFailure)
+    }
+
+    /// Combine the ciphertext of packed shares.
+    /// Homomorphically interpolate the shared polynomial at every secret point,
+    /// returning `pack_number` ciphertexts of the messages in order.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn combine_packed(
+        ctx: &ThresholdPKEContext,
+        ctxts: &[BFVCiphertext],
+        chosen_indices: &[F],
+    ) -> Result<Vec<BFVCiphertext>, BFVError> {
+        if ctxts.len() != chosen_indices.len() {
+            return Err(BFVError::ParameterMismatch(
+                "the length of ctxts and chosen_indices should be equal",
+            ));
+        }
+        if chosen_indices.len() < ctx.policy.reconstruction_number() {
+            return Err(BFVError::NotEnoughShares {
+                required: ctx.policy.reconstruction_number(),
+                given: chosen_indices.len(),
+            });
+        }
+        ctx.policy
+            .secret_points()
+            .into_iter()
+            .map(|point| {
+                let lagrange_coeff = Self::gen_lagrange_coeffs_at(point, chosen_indices);
+                BFVScheme::evaluate_inner_product(ctx.bfv_ctx(), ctxts, &lagrange_coeff)
+            })
+            .collect()
+    }
 }
 
-// Transfer a symmetric secret key into a polynomial with length N with 0 paddings.
-fn to_poly<const N: usize>(key: Key) -> Polynomial<PlainField> {
-    let poly = key.into_lsb0_vec();
-    assert!(N >= poly.len());
-    let mut poly: Vec<PlainField> = poly
-        .into_iter()
-        .map(|x| if x { PlainField::ONE } else { PlainField::ZERO })
-        .collect();
-    poly.resize(N, PlainField::ZERO);
-    Polynomial::from_slice(&poly)
+/// The symmetric key of the hybrid encryption, whose `Debug` output only shows its length.
+#[derive(Clone)]
+pub(crate) struct SymmetricKey(Key);
+
+impl SymmetricKey {
+    /// Returns the reference of the key.
+    #[inline]
+    pub(crate) fn expose_secret(&self) -> &Key {
+        &self.0
+    }
 }
 
-// Transfer a polynomial into a symmetric key.
-fn to_bits(poly: Polynomial<PlainField>) -> Key {
-    let (key, _) = poly.as_slice().split_at(256);
-    let key: Vec<u8> = key
-        .chunks(8)
+impl fmt::Debug for SymmetricKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SymmetricKey")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The associated data of the hybrid encryption, binding `aad` to the domain and the context.
+fn associated_data(ctx: &ThresholdPKEContext, aad: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"threshold-zk-lhe hybrid encryption");
+    hasher.update(ctx.parameter_digest());
+    hasher.update((aad.len() as u64).to_be_bytes());
+    hasher.update(aad);
+    hasher.finalize().into()
+}
+
+/// The associated data of the per-recipient hybrid encryption, which also binds the index
+/// of the node, so that the shares of different nodes can't be swapped.
+fn recipient_associated_data(ctx: &ThresholdPKEContext, aad: &[u8], index: F) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"threshold-zk-lhe per-recipient encryption");
+    hasher.update(ctx.parameter_digest());
+    hasher.update(index.get().to_be_bytes());
+    hasher.update((aad.len() as u64).to_be_bytes());
+    hasher.update(aad);
+    hasher.finalize().into()
+}
+
+/// The number of bits of the message carried by a coefficient of the shared secret
+/// in the per-recipient hybrid encryption, as `2^5 < t`.
+const DIGIT_BITS: usize = 5;
+
+// Split bytes into little endian digits of `DIGIT_BITS` bits, the last one padded with 0.
+fn bytes_to_digits(bytes: &[u8]) -> Vec<F> {
+    let bits = bytes.iter().copied().into_lsb0_vec();
+    bits.chunks(DIGIT_BITS)
         .map(|x| {
-            let mut value = 0;
-            for (i, &bit) in x.iter().enumerate() {
-                if bit == PlainField::ONE {
-                    value |= 1 << i;
-                }
+            F::new(
+                x.iter()
+                    .enumerate()
+                    .fold(0, |value, (i, &bit)| value | (u16::from(bit) << i)),
+            )
+        })
+        .collect()
+}
+
+// Join digits of `DIGIT_BITS` bits into bytes, return `None` if a digit is out of range
+// or the digits are not the ones of `bytes_to_digits`.
+fn digits_to_bytes(digits: &[F]) -> Option<Vec<u8>> {
+    let len = digits.len() * DIGIT_BITS / 8;
+    if (len * 8).div_ceil(DIGIT_BITS) != digits.len() {
+        return None;
+    }
+    let mut bytes = vec![0u8; len];
+    for (i, digit) in digits.iter().enumerate() {
+        let digit = digit.get();
+        if digit >> DIGIT_BITS != 0 {
+            return None;
+        }
+        for j in 0..DIGIT_BITS {
+            let bit = ((digit >> j) & 1) as u8;
+            let pos = i * DIGIT_BITS + j;
+            if pos < len * 8 {
+                bytes[pos / 8] |= bit << (pos % 8);
+            } else if bit != 0 {
+                return None;
             }
-            value
+        }
+    }
+    Some(bytes)
+}
+
+// Transfer a symmetric secret key into a plaintext with `N` coefficients with 0 paddings.
+fn to_plaintext<const N: usize>(key: &SymmetricKey) -> BFVPlaintext {
+    BFVPlaintext::from_bits(&key.expose_secret().into_lsb0_vec(), N)
+        .expect("the key has fewer bits than the dimension")
+}
+
+// Transfer a plaintext into a symmetric key, return `None` if a coefficient is not a bit.
+fn to_key(m: &BFVPlaintext) -> Option<SymmetricKey> {
+    let bits = m.to_bits(256)?;
+    let key: Vec<u8> = bits
+        .chunks(8)
+        .map(|x| {
+            x.iter()
+                .enumerate()
+                .fold(0, |value, (i, &bit)| value | ((bit as u8) << i))
         })
         .collect();
-    *Key::from_slice(&key)
+    Some(SymmetricKey(*Key::from_slice(&key)))
 }
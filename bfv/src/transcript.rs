@@ -0,0 +1,70 @@
+//! Define the Fiat-Shamir transcript for non-interactive proofs.
+
+use algebra::{Field, Random};
+use rand::{distributions::Distribution, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{Shake128, Xof, XofReader};
+
+/// A transcript absorbing the public messages of a proof,
+/// and deriving the verifier's challenges from them by squeezing the [`Xof`] `X`.
+#[derive(Debug, Clone)]
+pub struct Transcript<X = Shake128> {
+    xof: X,
+}
+
+impl Transcript {
+    /// Create a new instance with a domain separation `label`, over SHAKE-128.
+    #[inline]
+    pub fn new(label: &[u8]) -> Self {
+        Self::with_xof(label)
+    }
+}
+
+impl<X: Xof> Transcript<X> {
+    /// Create a new instance with a domain separation `label`, over the [`Xof`] `X`.
+    pub fn with_xof(label: &[u8]) -> Self {
+        let mut transcript = Self { xof: X::default() };
+        transcript.append_message(b"domain", label);
+        transcript
+    }
+
+    /// Append a `message` with its `label`.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.xof.update(&(label.len() as u64).to_be_bytes());
+        self.xof.update(label);
+        self.xof.update(&(message.len() as u64).to_be_bytes());
+        self.xof.update(message);
+    }
+
+    /// Derive 32 bytes from everything appended so far,
+    /// the derived bytes are appended to the transcript as well.
+    pub fn challenge_bytes(&mut self, label: &[u8]) -> [u8; 32] {
+        self.append_message(b"challenge", label);
+        let mut bytes = [0; 32];
+        self.xof.clone().finalize_xof().read(&mut bytes);
+        self.xof.update(&bytes);
+        bytes
+    }
+
+    /// Derive a random generator from everything appended so far.
+    #[inline]
+    pub fn challenge_rng(&mut self, label: &[u8]) -> ChaCha12Rng {
+        ChaCha12Rng::from_seed(self.challenge_bytes(label))
+    }
+
+    /// Derive a uniformly random field element.
+    #[inline]
+    pub fn challenge_field<F: Field + Random>(&mut self, label: &[u8]) -> F {
+        F::standard_distribution().sample(&mut self.challenge_rng(label))
+    }
+
+    /// Derive `n` uniformly random field elements.
+    #[inline]
+    pub fn challenge_fields<F: Field + Random>(&mut self, label: &[u8], n: usize) -> Vec<F> {
+        F::standard_distribution()
+            .sample_iter(self.challenge_rng(label))
+            .take(n)
+            .collect()
+    }
+}
@@ -0,0 +1,225 @@
+//! JavaScript bindings of the threshold pke scheme.
+//!
+//! Keys and ciphertexts cross the boundary as `Uint8Array`s in the encodings of
+//! [`BFVSecretKey::to_vec`], [`BFVPublicKey::to_vec`] and [`BFVCiphertext::to_vec`].
+
+use algebra::Field;
+use chacha20poly1305::Nonce;
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    BFVCiphertext, BFVPublicKey, BFVSecretKey, PlainField, ThresholdPKE, ThresholdPKEContext,
+    DIMENSION_N, MAX_NODES_NUMBER,
+};
+
+type F = PlainField;
+
+/// The threshold pke context of a committee.
+#[wasm_bindgen(js_name = ThresholdContext)]
+pub struct WasmThresholdContext(ThresholdPKEContext);
+
+/// A key pair of a node or of a receiver.
+#[wasm_bindgen(js_name = KeyPair)]
+pub struct WasmKeyPair {
+    sk: BFVSecretKey,
+    pk: BFVPublicKey,
+}
+
+/// A message encrypted to a committee by [`ThresholdPKE::encrypt_bytes`].
+#[wasm_bindgen(js_name = EncryptedMessage)]
+pub struct WasmEncryptedMessage {
+    shares: Vec<BFVCiphertext>,
+    nonce: Nonce,
+    payload: Vec<u8>,
+}
+
+#[wasm_bindgen(js_class = ThresholdContext)]
+impl WasmThresholdContext {
+    /// Create the context of a committee of `total_number` nodes at `indices`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        total_number: usize,
+        threshold_number: usize,
+        indices: Vec<u16>,
+    ) -> Result<WasmThresholdContext, JsError> {
+        if indices.len() != total_number
+            || total_number > MAX_NODES_NUMBER
+            || threshold_number == 0
+            || threshold_number > total_number
+        {
+            return Err(JsError::new("invalid committee size"));
+        }
+        let indices = indices
+            .into_iter()
+            .map(|i| plain(i).filter(|i| *i != F::ZERO))
+            .collect::<Option<Vec<F>>>()
+            .ok_or_else(|| JsError::new("invalid node index"))?;
+
+        Ok(Self(ThresholdPKE::gen_context(
+            total_number,
+            threshold_number,
+            indices,
+        )))
+    }
+
+    /// Generate a key pair.
+    #[wasm_bindgen(js_name = genKeypair)]
+    pub fn gen_keypair(&self) -> WasmKeyPair {
+        let (sk, pk) = ThresholdPKE::gen_keypair(&self.0);
+        WasmKeyPair { sk, pk }
+    }
+
+    /// Encrypt `message` to the committee whose public keys are `public_keys`, in the order of the indices,
+    /// authenticating `aad` such as the message id.
+    #[wasm_bindgen(js_name = encryptBytes)]
+    pub fn encrypt_bytes(
+        &self,
+        public_keys: Array,
+        message: &[u8],
+        aad: &[u8],
+    ) -> Result<WasmEncryptedMessage, JsError> {
+        let pks = public_keys
+            .iter()
+            .map(|pk| public_key(&Uint8Array::from(pk).to_vec()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (shares, nonce, payload) = ThresholdPKE::encrypt_bytes(&self.0, &pks, message, aad)?;
+        Ok(WasmEncryptedMessage {
+            shares,
+            nonce,
+            payload,
+        })
+    }
+
+    /// Decrypt the `share` of a node with its `secret_key`, and encrypt it to the receiver's `public_key`.
+    #[wasm_bindgen(js_name = reEncrypt)]
+    pub fn re_encrypt(
+        &self,
+        share: &[u8],
+        secret_key: &[u8],
+        public_key: &[u8],
+    ) -> Result<Vec<u8>, JsError> {
+        let c = ThresholdPKE::re_encrypt(
+            &self.0,
+            &ciphertext(share)?,
+            &self::secret_key(secret_key)?,
+            &self::public_key(public_key)?,
+        )?;
+        Ok(c.to_vec())
+    }
+
+    /// Combine the re-encrypted shares of the nodes at `chosen_indices`.
+    pub fn combine(&self, shares: Array, chosen_indices: Vec<u16>) -> Result<Vec<u8>, JsError> {
+        let ctxts = shares
+            .iter()
+            .map(|c| ciphertext(&Uint8Array::from(c).to_vec()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let chosen_indices = chosen_indices
+            .into_iter()
+            .map(|i| plain(i).filter(|i| self.0.policy().indices().contains(i)))
+            .collect::<Option<Vec<F>>>()
+            .ok_or_else(|| JsError::new("invalid node index"))?;
+        if ctxts.len() != chosen_indices.len()
+            || chosen_indices.len() < self.0.policy().threshold_number()
+        {
+            return Err(JsError::new("not enough shares"));
+        }
+
+        Ok(ThresholdPKE::combine(&self.0, &ctxts, &chosen_indices)?.to_vec())
+    }
+
+    /// Decrypt the combined `ciphertext` of an [`WasmEncryptedMessage`] with the receiver's `secret_key`
+    /// and the `aad` of the encryption.
+    #[wasm_bindgen(js_name = decryptBytes)]
+    pub fn decrypt_bytes(
+        &self,
+        secret_key: &[u8],
+        ciphertext: &[u8],
+        nonce: &[u8],
+        payload: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, JsError> {
+        if nonce.len() != 12 {
+            return Err(JsError::new("invalid nonce"));
+        }
+        Ok(ThresholdPKE::decrypt_bytes(
+            &self.0,
+            &self::secret_key(secret_key)?,
+            &self::ciphertext(ciphertext)?,
+            Nonce::from_slice(nonce),
+            payload,
+            aad,
+        )?)
+    }
+}
+
+#[wasm_bindgen(js_class = KeyPair)]
+impl WasmKeyPair {
+    /// Return the encoded secret key.
+    #[wasm_bindgen(getter, js_name = secretKey)]
+    pub fn secret_key(&self) -> Vec<u8> {
+        self.sk.to_vec()
+    }
+
+    /// Return the encoded public key.
+    #[wasm_bindgen(getter, js_name = publicKey)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.pk.to_vec()
+    }
+}
+
+#[wasm_bindgen(js_class = EncryptedMessage)]
+impl WasmEncryptedMessage {
+    /// Return the encoded shares, one for each node in the order of the indices.
+    #[wasm_bindgen(getter)]
+    pub fn shares(&self) -> Array {
+        self.shares
+            .iter()
+            .map(|c| Uint8Array::from(c.to_vec().as_slice()))
+            .collect()
+    }
+
+    /// Return the nonce of the symmetric encryption.
+    #[wasm_bindgen(getter)]
+    pub fn nonce(&self) -> Vec<u8> {
+        self.nonce.to_vec()
+    }
+
+    /// Return the symmetrically encrypted message.
+    #[wasm_bindgen(getter)]
+    pub fn payload(&self) -> Vec<u8> {
+        self.payload.clone()
+    }
+}
+
+fn plain(value: u16) -> Option<F> {
+    (value < F::modulus_value()).then(|| F::new(value))
+}
+
+fn secret_key(bytes: &[u8]) -> Result<BFVSecretKey, JsError> {
+    if bytes.len() != DIMENSION_N * 4 {
+        return Err(JsError::new("malformed secret key"));
+    }
+    Ok(BFVSecretKey::from_vec(bytes)?)
+}
+
+fn public_key(bytes: &[u8]) -> Result<BFVPublicKey, JsError> {
+    if !is_polynomial_pair(bytes) {
+        return Err(JsError::new("malformed public key"));
+    }
+    Ok(BFVPublicKey::from_vec(bytes)?)
+}
+
+fn ciphertext(bytes: &[u8]) -> Result<BFVCiphertext, JsError> {
+    if !is_polynomial_pair(bytes) {
+        return Err(JsError::new("malformed ciphertext"));
+    }
+    Ok(BFVCiphertext::from_vec(bytes)?)
+}
+
+/// Check the layout `|len0,len1|data0,data1|` with both lengths equal to [`DIMENSION_N`].
+fn is_polynomial_pair(bytes: &[u8]) -> bool {
+    let len = (DIMENSION_N as u32).to_be_bytes();
+    bytes.len() == 8 + DIMENSION_N * 8 && bytes[..4] == len && bytes[4..8] == len
+}
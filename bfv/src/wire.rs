@@ -0,0 +1,152 @@
+//! Define the canonical wire format of ciphertexts, keys and shares.
+//!
+//! Every object is encoded as `|magic(4)|version(2)|tag(1)|parameter_digest(32)|length(4)|body|`,
+//! all integers in big-endian, where `length` is the byte length of `body`.
+//! Inside the body, polynomials are encoded as their coefficient count (4) followed by the coefficients,
+//! the same as in [`crate::protocol`]. The format does not depend on serde, so it stays stable
+//! across serde versions and can be implemented independently.
+
+use ed25519_dalek::Signature;
+
+use crate::{
+    protocol::{write_cipher_poly, write_ciphertext, write_plain, Reader},
+    BFVCiphertext, BFVPublicKey, BFVPublicKeyPolys, BFVSecretKey, CiphertextShare,
+    ThresholdCiphertext,
+};
+
+/// The magic bytes at the start of every encoding.
+pub const WIRE_MAGIC: [u8; 4] = *b"ZLHE";
+
+/// The current version of the wire format.
+pub const WIRE_VERSION: u16 = 1;
+
+/// An object with a canonical wire encoding bound to the parameters it is used with.
+///
+/// The `parameter_digest` is usually [`crate::ThresholdPKEContext::parameter_digest`],
+/// decoding fails if it differs from the one the object was encoded with.
+pub trait WireFormat: Sized {
+    /// The tag identifying the type of the object.
+    const TAG: u8;
+
+    /// Append the encoding of the body to `bytes`.
+    fn encode_body(&self, bytes: &mut Vec<u8>);
+
+    /// Decode the body, return `None` if it is malformed.
+    fn decode_body(reader: &mut Reader<'_>) -> Option<Self>;
+
+    /// Encode to the wire format.
+    fn to_wire(&self, parameter_digest: &[u8; 32]) -> Vec<u8> {
+        let mut body = vec![];
+        self.encode_body(&mut body);
+
+        let mut bytes = Vec::with_capacity(43 + body.len());
+        bytes.extend(WIRE_MAGIC);
+        bytes.extend(WIRE_VERSION.to_be_bytes());
+        bytes.push(Self::TAG);
+        bytes.extend(parameter_digest);
+        bytes.extend((body.len() as u32).to_be_bytes());
+        bytes.extend(body);
+        bytes
+    }
+
+    /// Decode from the wire format, return `None` if the magic, the version, the tag
+    /// or the parameter digest does not match, or the bytes are malformed.
+    fn from_wire(bytes: &[u8], parameter_digest: &[u8; 32]) -> Option<Self> {
+        let mut reader = Reader::new(bytes);
+        if reader.array()? != WIRE_MAGIC
+            || reader.u16()? != WIRE_VERSION
+            || reader.u8()? != Self::TAG
+            || &reader.array::<32>()? != parameter_digest
+        {
+            return None;
+        }
+
+        let length = reader.u32()? as usize;
+        if reader.remaining() != length {
+            return None;
+        }
+        let object = Self::decode_body(&mut reader)?;
+        reader.is_empty().then_some(object)
+    }
+}
+
+impl WireFormat for BFVCiphertext {
+    const TAG: u8 = 1;
+
+    fn encode_body(&self, bytes: &mut Vec<u8>) {
+        write_ciphertext(bytes, self);
+    }
+
+    fn decode_body(reader: &mut Reader<'_>) -> Option<Self> {
+        reader.ciphertext()
+    }
+}
+
+impl WireFormat for BFVPublicKey {
+    const TAG: u8 = 2;
+
+    fn encode_body(&self, bytes: &mut Vec<u8>) {
+        let BFVPublicKeyPolys { b, a } = self.polys();
+        write_cipher_poly(bytes, b);
+        write_cipher_poly(bytes, a);
+    }
+
+    fn decode_body(reader: &mut Reader<'_>) -> Option<Self> {
+        let b = reader.cipher_poly()?;
+        let a = reader.cipher_poly()?;
+        Some(BFVPublicKey::new(b, a))
+    }
+}
+
+impl WireFormat for BFVSecretKey {
+    const TAG: u8 = 3;
+
+    fn encode_body(&self, bytes: &mut Vec<u8>) {
+        write_cipher_poly(bytes, self.expose_secret());
+    }
+
+    fn decode_body(reader: &mut Reader<'_>) -> Option<Self> {
+        reader.cipher_poly().map(BFVSecretKey::from_poly)
+    }
+}
+
+impl WireFormat for CiphertextShare {
+    const TAG: u8 = 4;
+
+    fn encode_body(&self, bytes: &mut Vec<u8>) {
+        write_plain(bytes, self.index);
+        write_ciphertext(bytes, &self.ct);
+        bytes.extend(self.sig.to_bytes());
+    }
+
+    fn decode_body(reader: &mut Reader<'_>) -> Option<Self> {
+        let index = reader.plain()?;
+        let ct = reader.ciphertext()?;
+        let sig = Signature::from_bytes(&reader.array()?);
+        Some(CiphertextShare { index, ct, sig })
+    }
+}
+
+impl WireFormat for ThresholdCiphertext {
+    const TAG: u8 = 5;
+
+    fn encode_body(&self, bytes: &mut Vec<u8>) {
+        bytes.extend((self.threshold_number() as u32).to_be_bytes());
+        bytes.extend((self.shares().len() as u32).to_be_bytes());
+        self.shares()
+            .iter()
+            .for_each(|ct| write_ciphertext(bytes, ct));
+    }
+
+    fn decode_body(reader: &mut Reader<'_>) -> Option<Self> {
+        let threshold_number = reader.u32()? as usize;
+        let count = reader.u32()? as usize;
+        if threshold_number == 0 || threshold_number > count {
+            return None;
+        }
+        let shares = (0..count)
+            .map(|_| reader.ciphertext())
+            .collect::<Option<Vec<_>>>()?;
+        Some(ThresholdCiphertext::new(threshold_number, shares))
+    }
+}
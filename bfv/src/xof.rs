@@ -0,0 +1,154 @@
+//! Define the extendable output functions used to expand seeds and derive challenges.
+
+use algebra::{Field, Random};
+use rand::{distributions::Distribution, CryptoRng, RngCore};
+use sha3::digest::{ExtendableOutput, Update};
+
+/// An extendable output function, absorbing bytes and squeezing an unbounded output stream.
+///
+/// The backends are [`Shake128`], [`Shake256`] and [`Blake3`],
+/// so deployments can standardize on an approved primitive.
+pub trait Xof: Default + Clone {
+    /// The reader squeezing the output stream.
+    type Reader: XofReader;
+
+    /// Absorb `data`.
+    fn update(&mut self, data: &[u8]);
+
+    /// Finish absorbing and return the reader of the output stream.
+    fn finalize_xof(self) -> Self::Reader;
+
+    /// Absorb `domain` and `data` with their lengths, and return the reader of the output stream.
+    fn expand(domain: &[u8], data: &[u8]) -> Self::Reader {
+        let mut xof = Self::default();
+        xof.update(&(domain.len() as u64).to_be_bytes());
+        xof.update(domain);
+        xof.update(&(data.len() as u64).to_be_bytes());
+        xof.update(data);
+        xof.finalize_xof()
+    }
+}
+
+/// The reader of the output stream of an [`Xof`].
+pub trait XofReader {
+    /// Fill `buf` with the next bytes of the output stream.
+    fn read(&mut self, buf: &mut [u8]);
+}
+
+/// SHAKE-128 of FIPS 202.
+#[derive(Debug, Clone, Default)]
+pub struct Shake128(sha3::Shake128);
+
+/// SHAKE-256 of FIPS 202.
+#[derive(Debug, Clone, Default)]
+pub struct Shake256(sha3::Shake256);
+
+/// BLAKE3 in its extendable output mode.
+#[derive(Debug, Clone, Default)]
+pub struct Blake3(blake3::Hasher);
+
+impl Xof for Shake128 {
+    type Reader = sha3::Shake128Reader;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Update::update(&mut self.0, data);
+    }
+
+    #[inline]
+    fn finalize_xof(self) -> Self::Reader {
+        self.0.finalize_xof()
+    }
+}
+
+impl Xof for Shake256 {
+    type Reader = sha3::Shake256Reader;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Update::update(&mut self.0, data);
+    }
+
+    #[inline]
+    fn finalize_xof(self) -> Self::Reader {
+        self.0.finalize_xof()
+    }
+}
+
+impl Xof for Blake3 {
+    type Reader = blake3::OutputReader;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    #[inline]
+    fn finalize_xof(self) -> Self::Reader {
+        self.0.finalize_xof()
+    }
+}
+
+impl XofReader for sha3::Shake128Reader {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) {
+        sha3::digest::XofReader::read(self, buf);
+    }
+}
+
+impl XofReader for sha3::Shake256Reader {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) {
+        sha3::digest::XofReader::read(self, buf);
+    }
+}
+
+impl XofReader for blake3::OutputReader {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) {
+        self.fill(buf);
+    }
+}
+
+/// A random generator drawing its bytes from the output stream of an [`Xof`],
+/// so that the samplers of `algebra` expand a seed deterministically.
+#[derive(Debug, Clone)]
+pub struct XofRng<R: XofReader>(pub R);
+
+impl<R: XofReader> RngCore for XofRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0; 4];
+        self.0.read(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        self.0.read(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.read(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.read(dest);
+        Ok(())
+    }
+}
+
+impl<R: XofReader> CryptoRng for XofRng<R> {}
+
+/// Hash `data` to `n` uniformly random field elements under the separation `domain`,
+/// by rejection sampling from the output stream of `X`.
+pub fn hash_to_fields<X: Xof, F: Field + Random>(domain: &[u8], data: &[u8], n: usize) -> Vec<F> {
+    F::standard_distribution()
+        .sample_iter(XofRng(X::expand(domain, data)))
+        .take(n)
+        .collect()
+}
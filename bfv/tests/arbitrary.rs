@@ -0,0 +1,45 @@
+#![cfg(feature = "test-utils")]
+
+mod tests {
+    use algebra::test_utils::{
+        arbitrary::{Arbitrary, Unstructured},
+        proptest::prelude::*,
+    };
+    use bfv::{BFVCiphertext, ThresholdPolicy, DIMENSION_N, MAX_NODES_NUMBER};
+
+    #[test]
+    fn arbitrary_test() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1 << 16).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        let ciphertext = BFVCiphertext::arbitrary(&mut u).unwrap();
+        assert_eq!(ciphertext.c1.coeff_count(), DIMENSION_N);
+        assert_eq!(
+            BFVCiphertext::from_vec(&ciphertext.to_vec()),
+            Ok(ciphertext)
+        );
+
+        let policy = ThresholdPolicy::arbitrary(&mut u).unwrap();
+        assert!(policy.threshold_number() <= policy.total_number());
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn ciphertext_roundtrip_test(ciphertext: BFVCiphertext) {
+            prop_assert_eq!(BFVCiphertext::from_vec(&ciphertext.to_vec()), Ok(ciphertext));
+        }
+
+        #[test]
+        fn policy_test(policy: ThresholdPolicy) {
+            prop_assert!(policy.total_number() <= MAX_NODES_NUMBER);
+            prop_assert!(policy.threshold_number() >= 1);
+            prop_assert!(policy.threshold_number() <= policy.total_number());
+            let indices = policy.indices();
+            for (i, index) in indices.iter().enumerate() {
+                prop_assert!(!indices[..i].contains(index));
+            }
+        }
+    }
+}
@@ -1,6 +1,12 @@
 mod tests {
-    use algebra::Polynomial;
-    use bfv::{BFVCiphertext, BFVPlaintext, BFVScheme, PlainField};
+    use algebra::{ConstantTimeEq, Field, NTTPolynomial, Polynomial};
+    use bfv::security::{estimate_security, MIN_SECURITY_BITS};
+    use bfv::{
+        AutomorphismKey, BFVCiphertext, BFVContext, BFVError, BFVNTTCiphertext, BFVParameters,
+        BFVPlaintext, BFVPublicKey, BFVPublicKeyPolys, BFVScheme, BFVScratch, BFVSecretKey,
+        CipherField, CompressedCiphertext, Domain, PlainField, PlaintextKnowledgeProof,
+        SecretKeyDistribution, Z2kPlaintext, CIPHER_BITS, MAX_NODES_NUMBER,
+    };
 
     #[test]
     fn bfv_enc_dec_test() {
@@ -11,13 +17,538 @@ mod tests {
             let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
             let msg = BFVPlaintext(msg);
 
-            let c = BFVScheme::encrypt(&ctx, &pk, &msg);
+            let c = BFVScheme::encrypt(&ctx, &pk, &msg).unwrap();
 
-            let m = BFVScheme::decrypt(&ctx, &sk, &c);
+            let m = BFVScheme::decrypt(&ctx, &sk, &c).unwrap();
             assert_eq!(msg, m);
+            assert!(bool::from(msg.ct_eq(&m)));
         }
     }
 
+    #[test]
+    fn bfv_enc_dec_with_scratch_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let mut scratch = BFVScratch::new(ctx.rlwe_dimension());
+
+        for _ in 0..100 {
+            let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+            let msg = BFVPlaintext(msg);
+
+            let c = BFVScheme::encrypt_with_scratch(&ctx, &pk, &msg, &mut scratch).unwrap();
+
+            let m = BFVScheme::decrypt_with_scratch(&ctx, &sk, &c, &mut scratch).unwrap();
+            assert_eq!(msg, m);
+        }
+
+        let n = ctx.rlwe_dimension();
+        let mismatch = |r| matches!(r, Err(BFVError::ParameterMismatch(_)));
+        let c = BFVCiphertext::new(Polynomial::zero(n / 2), Polynomial::zero(n / 2));
+        assert!(mismatch(BFVScheme::decrypt_with_scratch(
+            &ctx,
+            &sk,
+            &c,
+            &mut scratch
+        )));
+        assert!(mismatch(BFVScheme::decrypt(&ctx, &sk, &c)));
+        assert!(matches!(
+            BFVScheme::decrypt_z2k(&ctx, &sk, &c, 8),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+        assert!(matches!(
+            BFVScheme::decrypt_batch(&ctx, &sk, std::slice::from_ref(&c)),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+        let c = BFVCiphertext::new(Polynomial::zero(n), Polynomial::zero(n + 1));
+        assert!(mismatch(BFVScheme::decrypt_with_scratch(
+            &ctx,
+            &sk,
+            &c,
+            &mut scratch
+        )));
+        let c = BFVCiphertext::new(Polynomial::zero(n), Polynomial::zero(n));
+        assert!(mismatch(BFVScheme::decrypt_with_scratch(
+            &ctx,
+            &sk,
+            &c,
+            &mut BFVScratch::new(n / 2)
+        )));
+    }
+
+    #[test]
+    fn bfv_encrypt_invalid_plaintext_test() {
+        let ctx = BFVScheme::gen_context();
+        let (_, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+        let mismatch = |r| matches!(r, Err(BFVError::ParameterMismatch(_)));
+
+        let short = BFVPlaintext(Polynomial::zero(n - 1));
+        let long = BFVPlaintext(Polynomial::zero(n + 1));
+        let mut unreduced = BFVPlaintext(Polynomial::zero(n));
+        unreduced.0[3] = PlainField::new(PlainField::modulus_value());
+        for m in [&short, &long, &unreduced] {
+            assert!(m.validate(n).is_err());
+            assert!(mismatch(BFVScheme::encrypt(&ctx, &pk, m)));
+            assert!(BFVScheme::encrypt_with_witness(&ctx, &pk, m).is_err());
+            assert!(BFVScheme::encrypt_many(
+                &ctx,
+                std::slice::from_ref(&pk),
+                std::slice::from_ref(m)
+            )
+            .is_err());
+        }
+
+        let m = BFVPlaintext::constant(n, PlainField::new(5));
+        assert_eq!(m.validate(n), Ok(()));
+        let mut scratch = BFVScratch::new(n / 2);
+        assert!(mismatch(BFVScheme::encrypt_with_scratch(
+            &ctx,
+            &pk,
+            &m,
+            &mut scratch
+        )));
+    }
+
+    #[test]
+    fn bfv_domain_serialization_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let m = BFVPlaintext(Polynomial::<PlainField>::random(
+            ctx.rlwe_dimension(),
+            &mut *ctx.csrng_mut(),
+        ));
+        let c = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
+        let ntt_c = BFVNTTCiphertext::from(c.clone());
+
+        for domain in [Domain::Coefficient, Domain::Evaluation] {
+            let bytes = c.to_vec_with_domain(domain);
+            assert_eq!(bytes, ntt_c.to_vec_with_domain(domain));
+            assert_eq!(BFVCiphertext::from_vec_with_domain(&bytes), Ok(c.clone()));
+            assert_eq!(
+                BFVNTTCiphertext::from_vec_with_domain(&bytes),
+                Ok(ntt_c.clone())
+            );
+
+            let bytes = pk.to_vec_with_domain(domain);
+            let received = BFVPublicKey::from_vec_with_domain(&bytes).unwrap();
+            assert_eq!(received.ntt_polys(), pk.ntt_polys());
+            assert_eq!(received, pk);
+            let c = BFVScheme::encrypt(&ctx, &received, &m).unwrap();
+            assert_eq!(BFVScheme::decrypt(&ctx, &sk, &c).unwrap(), m);
+        }
+        // the coefficient layout is the flag followed by the plain one
+        assert_eq!(c.to_vec_with_domain(Domain::Coefficient)[1..], c.to_vec());
+
+        let mut bytes = c.to_vec_with_domain(Domain::Evaluation);
+        bytes[0] = 2;
+        assert!(BFVCiphertext::from_vec_with_domain(&bytes).is_err());
+        assert!(BFVPublicKey::from_vec_with_domain(&bytes).is_err());
+        assert!(BFVNTTCiphertext::from_vec_with_domain(&[]).is_err());
+
+        // the evaluation domain only accepts pairs of the rlwe dimension
+        let short = BFVNTTCiphertext::new(
+            NTTPolynomial::new(ntt_c.c1.as_slice()[..512].to_vec()),
+            NTTPolynomial::new(ntt_c.c2.as_slice()[..512].to_vec()),
+        );
+        let bytes = short.to_vec_with_domain(Domain::Evaluation);
+        assert!(BFVNTTCiphertext::from_vec_with_domain(&bytes).is_err());
+        assert!(BFVCiphertext::from_vec_with_domain(&bytes).is_err());
+        assert!(BFVPublicKey::from_vec_with_domain(&bytes).is_err());
+        let mut bytes = ntt_c.to_vec_with_domain(Domain::Evaluation);
+        bytes.truncate(bytes.len() - 4);
+        bytes[5..9].copy_from_slice(&1023u32.to_be_bytes());
+        assert!(BFVNTTCiphertext::from_vec_with_domain(&bytes).is_err());
+
+        // so does the coefficient domain when it has to be transformed
+        let tiny = BFVCiphertext::new(
+            Polynomial::new(c.c1.as_slice()[..3].to_vec()),
+            Polynomial::new(c.c2.as_slice()[..3].to_vec()),
+        );
+        let bytes = tiny.to_vec_with_domain(Domain::Coefficient);
+        assert_eq!(BFVCiphertext::from_vec_with_domain(&bytes), Ok(tiny));
+        assert_eq!(
+            BFVNTTCiphertext::from_vec_with_domain(&bytes),
+            Err(BFVError::SerializationError("ciphertext"))
+        );
+    }
+
+    #[test]
+    fn bfv_key_ntt_form_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+
+        let (polys, ntt_polys) = (pk.polys(), pk.ntt_polys());
+        assert_eq!(ntt_polys.b.clone().into_native_polynomial(), polys.b);
+        assert_eq!(ntt_polys.a.clone().into_native_polynomial(), polys.a);
+        assert_eq!(&BFVPublicKeyPolys::from(ntt_polys.clone()), polys);
+        assert_eq!(
+            &sk.ntt_secret_key().clone().into_native_polynomial(),
+            sk.expose_secret()
+        );
+        assert_eq!(BFVPublicKey::from_vec(&pk.to_vec()), Ok(pk));
+    }
+
+    #[test]
+    fn bfv_compress_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+        let msg = BFVPlaintext(msg);
+        let c = BFVScheme::encrypt(&ctx, &pk, &msg).unwrap();
+
+        assert_eq!(c.compress(CIPHER_BITS).decompress(), c);
+        for bits in [14, 16, 20] {
+            let compressed = c.compress(bits);
+            assert_eq!(
+                BFVScheme::decrypt_compressed(&ctx, &sk, &compressed).unwrap(),
+                msg
+            );
+
+            let bytes = compressed.to_vec();
+            assert_eq!(bytes.len(), 9 + ctx.rlwe_dimension() * bits as usize / 4);
+            assert_eq!(CompressedCiphertext::from_vec(&bytes), Ok(compressed));
+            assert!(CompressedCiphertext::from_vec(&bytes[..bytes.len() - 1]).is_err());
+        }
+        assert_ne!(
+            BFVScheme::decrypt_compressed(&ctx, &sk, &c.compress(4)).unwrap(),
+            msg
+        );
+    }
+
+    #[test]
+    fn bfv_key_rotation_test() {
+        let ctx = BFVScheme::gen_context();
+        let (old_sk, old_pk) = BFVScheme::gen_keypair(&ctx);
+        let new_sk = BFVSecretKey::new(&ctx);
+        let key = old_sk.gen_key_switch_key(&ctx, &new_sk);
+
+        let msgs: Vec<_> = (0..4)
+            .map(|_| {
+                let msg =
+                    Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+                BFVPlaintext(msg)
+            })
+            .collect();
+        let cs: Vec<_> = msgs
+            .iter()
+            .map(|m| BFVScheme::encrypt(&ctx, &old_pk, m).unwrap())
+            .collect();
+
+        let rotated = BFVScheme::rotate_ciphertexts(&ctx, &key, &cs).unwrap();
+        assert_eq!(
+            BFVScheme::decrypt_batch(&ctx, &new_sk, &rotated).unwrap(),
+            msgs
+        );
+        assert_ne!(
+            BFVScheme::decrypt(&ctx, &old_sk, &rotated[0]).unwrap(),
+            msgs[0]
+        );
+
+        // rotated ciphertexts keep the homomorphism
+        let sum = BFVScheme::evalute_add(&ctx, &rotated[0], &rotated[1]);
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &new_sk, &sum).unwrap(),
+            BFVPlaintext(&msgs[0].0 + &msgs[1].0)
+        );
+
+        let short = BFVCiphertext::new(Polynomial::zero(8), Polynomial::zero(8));
+        assert!(matches!(
+            BFVScheme::switch_key(&ctx, &key, &short),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn bfv_migrate_test() {
+        let from_ctx = BFVScheme::gen_context();
+        let to_ctx = BFVContext::with_parameters(
+            BFVParameters::new()
+                .with_secret_key_distribution(SecretKeyDistribution::Gaussian)
+                .with_noise_std_dev(3.5),
+        );
+        let (old_sk, old_pk) = BFVScheme::gen_keypair(&from_ctx);
+        let new_sk = BFVSecretKey::new(&to_ctx);
+        let bridge_key = old_sk.gen_key_switch_key(&to_ctx, &new_sk);
+
+        let m = BFVPlaintext(Polynomial::random(
+            from_ctx.rlwe_dimension(),
+            &mut *from_ctx.csrng_mut(),
+        ));
+        let c = BFVScheme::encrypt(&from_ctx, &old_pk, &m).unwrap();
+        let migrated = BFVScheme::migrate(&from_ctx, &to_ctx, &bridge_key, &c).unwrap();
+        assert_eq!(BFVScheme::decrypt(&to_ctx, &new_sk, &migrated).unwrap(), m);
+
+        let short = BFVCiphertext::new(Polynomial::zero(8), Polynomial::zero(8));
+        assert!(matches!(
+            BFVScheme::migrate(&from_ctx, &to_ctx, &bridge_key, &short),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    /// Returns the largest noise `|c1 + c2 * s - delta * m|` of the coefficients of `c`.
+    fn max_noise(sk: &BFVSecretKey, c: &BFVCiphertext, m: &BFVPlaintext) -> i64 {
+        let q = CipherField::modulus_value() as i64;
+        let t = PlainField::modulus_value() as i64;
+        let BFVCiphertext { c1, c2 } = c;
+        let phase = c2 * sk.ntt_secret_key() + c1;
+        phase
+            .iter()
+            .zip(m.0.iter())
+            .map(|(x, m)| {
+                let e = (x.get() as i64 - (m.get() as i64 * q + t / 2) / t).rem_euclid(q);
+                e.min(q - e)
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn bfv_mul_large_scalar_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+        let msg = BFVPlaintext(msg);
+
+        let decomposed = BFVScheme::encrypt_decomposed(&ctx, &pk, &msg, 1).unwrap();
+        assert_eq!(decomposed.ciphertexts().len(), 6);
+        for (i, c) in decomposed.ciphertexts().iter().enumerate() {
+            let power = PlainField::new(1 << i);
+            assert_eq!(
+                BFVScheme::decrypt(&ctx, &sk, c).unwrap(),
+                BFVPlaintext(msg.0.mul_scalar(power))
+            );
+        }
+
+        let scalar = PlainField::new(PlainField::modulus_value() - 1);
+        let expected = BFVPlaintext(msg.0.mul_scalar(scalar));
+        let large = BFVScheme::evaluate_mul_large_scalar(&ctx, &scalar, &decomposed);
+        let direct = BFVScheme::evaluate_mul_scalar(&ctx, &scalar, decomposed.ciphertext());
+        assert_eq!(BFVScheme::decrypt(&ctx, &sk, &large).unwrap(), expected);
+        assert_eq!(BFVScheme::decrypt(&ctx, &sk, &direct).unwrap(), expected);
+        assert!(max_noise(&sk, &large, &expected) < max_noise(&sk, &direct, &expected));
+
+        let zero = BFVScheme::evaluate_mul_large_scalar(&ctx, &PlainField::ZERO, &decomposed);
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &sk, &zero).unwrap(),
+            BFVPlaintext(Polynomial::zero(ctx.rlwe_dimension()))
+        );
+    }
+
+    #[test]
+    fn bfv_extract_lwe_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+
+        let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+        let c = BFVScheme::encrypt(&ctx, &pk, &BFVPlaintext(msg.clone())).unwrap();
+        for index in [0, 1, 500, ctx.rlwe_dimension() - 1] {
+            let lwe = c.extract_lwe(index);
+            assert_eq!(lwe.dimension(), ctx.rlwe_dimension());
+            assert_eq!(BFVScheme::decrypt_lwe(&ctx, &sk, &lwe), msg[index]);
+        }
+    }
+
+    #[test]
+    fn bfv_pack_lwe_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let packing_key = sk.gen_packing_key(&ctx);
+        let n = ctx.rlwe_dimension();
+
+        let msgs: Vec<_> = (0..3)
+            .map(|_| Polynomial::<PlainField>::random(n, &mut *ctx.csrng_mut()))
+            .collect();
+        let lwes: Vec<_> = msgs
+            .iter()
+            .zip([0, 7, n - 1])
+            .flat_map(|(m, index)| {
+                let c = BFVScheme::encrypt(&ctx, &pk, &BFVPlaintext(m.clone())).unwrap();
+                [c.extract_lwe(index), c.extract_lwe(index / 2)]
+            })
+            .collect();
+
+        let c = BFVScheme::pack_lwe(&ctx, &packing_key, &lwes).unwrap();
+        let mut expected = Polynomial::zero(n);
+        for (i, (m, index)) in msgs.iter().zip([0, 7, n - 1]).enumerate() {
+            expected[2 * i] = m[index];
+            expected[2 * i + 1] = m[index / 2];
+        }
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &sk, &c).unwrap(),
+            BFVPlaintext(expected)
+        );
+
+        let empty = BFVScheme::pack_lwe(&ctx, &packing_key, &[]).unwrap();
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &sk, &empty).unwrap(),
+            BFVPlaintext(Polynomial::zero(n))
+        );
+        assert!(matches!(
+            BFVScheme::pack_lwe(&ctx, &packing_key, &vec![lwes[0].clone(); n + 1]),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn bfv_automorphism_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+        let key = AutomorphismKey::new(&ctx, &sk, 5);
+        assert_eq!(key.degree(), 5);
+
+        let msg = Polynomial::<PlainField>::random(n, &mut *ctx.csrng_mut());
+        let c = BFVScheme::encrypt(&ctx, &pk, &BFVPlaintext(msg.clone())).unwrap();
+        let c = BFVScheme::evaluate_automorphism(&ctx, &key, &c);
+
+        let mut expected = Polynomial::zero(n);
+        for (i, &m) in msg.iter().enumerate() {
+            let j = i * 5 % (2 * n);
+            if j < n {
+                expected[j] = m;
+            } else {
+                expected[j - n] = -m;
+            }
+        }
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &sk, &c).unwrap(),
+            BFVPlaintext(expected)
+        );
+    }
+
+    #[test]
+    fn bfv_secret_key_distribution_test() {
+        let q = CipherField::modulus_value() as i64;
+        for distribution in [
+            SecretKeyDistribution::Binary,
+            SecretKeyDistribution::Ternary,
+            SecretKeyDistribution::Gaussian,
+        ] {
+            let params = BFVParameters::new().with_secret_key_distribution(distribution);
+            let ctx = BFVContext::with_parameters(params);
+            assert_eq!(ctx.secret_key_distribution(), distribution);
+
+            let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+            let range = match distribution {
+                SecretKeyDistribution::Binary => 0..=1,
+                SecretKeyDistribution::Ternary => -1..=1,
+                SecretKeyDistribution::Gaussian => {
+                    let bound = (6.0 * params.noise_std_dev()) as i64;
+                    -bound..=bound
+                }
+            };
+            assert!(sk.expose_secret().iter().all(|s| {
+                let s = s.get() as i64;
+                range.contains(&if s > q / 2 { s - q } else { s })
+            }));
+
+            let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+            let msg = BFVPlaintext(msg);
+            let c = BFVScheme::encrypt(&ctx, &pk, &msg).unwrap();
+            assert_eq!(BFVScheme::decrypt(&ctx, &sk, &c).unwrap(), msg);
+        }
+    }
+
+    #[test]
+    fn bfv_security_estimate_test() {
+        let params = BFVParameters::new();
+        let estimate = params.estimate_security().unwrap();
+        assert!(estimate.bits >= MIN_SECURITY_BITS as f64);
+        assert!(params.validate().is_ok());
+
+        // the standard allows n = 1024 with log q = 27 and n = 2048 with log q = 54
+        let ternary = SecretKeyDistribution::Ternary;
+        let q = 2f64.powi(54);
+        let bits = |n, q| estimate_security(n, q, 3.2, ternary).unwrap().bits;
+        assert!((bits(2048, q) - 128.0).abs() < 4.0);
+        assert!(bits(1024, q) < 80.0);
+
+        // smaller secrets and errors are weaker
+        let binary = params.with_secret_key_distribution(SecretKeyDistribution::Binary);
+        let gaussian = params.with_secret_key_distribution(SecretKeyDistribution::Gaussian);
+        assert!(binary.estimate_security().unwrap().bits < estimate.bits);
+        assert!(gaussian.estimate_security().unwrap().bits > estimate.bits);
+        assert!(matches!(
+            params.with_noise_std_dev(1.0).validate(),
+            Err(BFVError::InsecureParameters { required: 128, .. })
+        ));
+        assert!(params.with_noise_std_dev(0.0).validate().is_err());
+    }
+
+    #[test]
+    fn bfv_context_parameters_test() {
+        let ctx = BFVScheme::gen_context();
+        assert_eq!(ctx.cipher_modulus(), 132120577);
+        assert_eq!(ctx.plain_modulus(), 61);
+        assert_eq!(ctx.noise_std_dev(), 3.2);
+        assert_eq!(
+            ctx.secret_key_distribution(),
+            SecretKeyDistribution::Ternary
+        );
+        assert_eq!(
+            ctx.to_string(),
+            format!(
+                "BFV(n = 1024, q = 132120577, t = 61, σ = 3.2, secret = Ternary, max inner product = {})",
+                ctx.max_inner_product_len()
+            )
+        );
+
+        // the advised length of inner products with the largest scalars still decrypts
+        let len = ctx.max_inner_product_len();
+        assert!(len >= MAX_NODES_NUMBER);
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let msg = BFVPlaintext(Polynomial::random(
+            ctx.rlwe_dimension(),
+            &mut *ctx.csrng_mut(),
+        ));
+        let cts: Vec<_> = (0..len)
+            .map(|_| BFVScheme::encrypt(&ctx, &pk, &msg).unwrap())
+            .collect();
+        let scalars = vec![PlainField::NEG_ONE; len];
+        let c = BFVScheme::evaluate_inner_product(&ctx, &cts, &scalars).unwrap();
+        let expected = msg
+            .0
+            .mul_scalar(PlainField::NEG_ONE * PlainField::new(len as u16));
+        assert_eq!(BFVScheme::decrypt(&ctx, &sk, &c).unwrap().0, expected);
+
+        // noisier parameters allow shorter inner products
+        let noisy = ctx.parameters().with_noise_std_dev(6.4);
+        assert!(noisy.max_inner_product_len() < len / 2);
+        assert!(noisy.noise_bound() == ctx.noise_bound());
+    }
+
+    #[test]
+    fn bfv_secret_key_debug_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, _) = BFVScheme::gen_keypair(&ctx);
+        assert_eq!(
+            format!("{sk:?}"),
+            format!("BFVSecretKey {{ len: {}, .. }}", ctx.rlwe_dimension())
+        );
+
+        #[allow(deprecated)]
+        let key = sk.secret_key();
+        assert_eq!(key, sk.expose_secret());
+    }
+
+    #[test]
+    fn bfv_encrypt_many_decrypt_batch_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+
+        let msgs: Vec<BFVPlaintext> = (0..16)
+            .map(|_| {
+                BFVPlaintext(Polynomial::<PlainField>::random(
+                    ctx.rlwe_dimension(),
+                    &mut *ctx.csrng_mut(),
+                ))
+            })
+            .collect();
+
+        let cs = BFVScheme::encrypt_many(&ctx, &vec![pk; msgs.len()], &msgs).unwrap();
+        assert_eq!(BFVScheme::decrypt_batch(&ctx, &sk, &cs).unwrap(), msgs);
+    }
+
     #[test]
     fn bfv_add_test() {
         let ctx = BFVScheme::gen_context();
@@ -33,15 +564,255 @@ mod tests {
 
             let m_add = BFVPlaintext(m1_poly + m2_poly);
 
-            let c1 = BFVScheme::encrypt(&ctx, &pk, &m1);
-            let c2 = BFVScheme::encrypt(&ctx, &pk, &m2);
+            let c1 = BFVScheme::encrypt(&ctx, &pk, &m1).unwrap();
+            let c2 = BFVScheme::encrypt(&ctx, &pk, &m2).unwrap();
             let c3 = BFVScheme::evalute_add(&ctx, &c1, &c2);
 
-            let m3 = BFVScheme::decrypt(&ctx, &sk, &c3);
+            let m3 = BFVScheme::decrypt(&ctx, &sk, &c3).unwrap();
             assert_eq!(m3, m_add);
+
+            let mut c4 = c1;
+            BFVScheme::evaluate_add_assign(&ctx, &mut c4, &c2);
+            assert_eq!(c4, c3);
         }
     }
 
+    #[test]
+    fn bfv_trivial_encrypt_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+        for _ in 0..100 {
+            let m_poly = Polynomial::<PlainField>::random(n, &mut *ctx.csrng_mut());
+            let m = BFVPlaintext(m_poly.clone());
+
+            let trivial = BFVCiphertext::trivial_encrypt(&ctx, &m);
+            assert_eq!(trivial, BFVCiphertext::trivial_encrypt(&ctx, &m));
+            assert_eq!(BFVScheme::decrypt(&ctx, &sk, &trivial).unwrap(), m);
+
+            let value = PlainField::random(&mut *ctx.csrng_mut());
+            let degree = rand::random::<usize>() % n;
+            let constant = BFVPlaintext::constant(n, value);
+            let monomial = BFVPlaintext::monomial(n, degree, value);
+            assert_eq!(monomial.0[degree], value);
+
+            let c = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
+            let c_add = BFVScheme::evaluate_add_plain(&ctx, &c, &constant);
+            assert_eq!(
+                BFVScheme::decrypt(&ctx, &sk, &c_add).unwrap(),
+                BFVPlaintext(m_poly.clone() + constant.0)
+            );
+
+            let mut c_assign = c.clone();
+            BFVScheme::evaluate_add_plain_assign(&ctx, &mut c_assign, &monomial);
+            assert_eq!(
+                BFVScheme::decrypt(&ctx, &sk, &c_assign).unwrap(),
+                BFVPlaintext(m_poly + monomial.0)
+            );
+        }
+    }
+
+    #[test]
+    fn bfv_z2k_test() {
+        use rand::Rng;
+
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+
+        let bytes: Vec<u8> = (0..n).map(|_| ctx.csrng_mut().gen()).collect();
+        let m = Z2kPlaintext::from_bytes(&bytes, n).unwrap();
+        let c = BFVScheme::encrypt_z2k(&ctx, &pk, &m).unwrap();
+        assert_eq!(BFVScheme::decrypt_z2k(&ctx, &sk, &c, 8).unwrap(), m);
+
+        // the sum wraps around modulo 2^8
+        let other: Vec<u8> = (0..n).map(|_| ctx.csrng_mut().gen()).collect();
+        let other_c =
+            BFVScheme::encrypt_z2k(&ctx, &pk, &Z2kPlaintext::from_bytes(&other, n).unwrap())
+                .unwrap();
+        let sum = BFVScheme::evalute_add(&ctx, &c, &other_c);
+        let expected: Vec<u8> = bytes
+            .iter()
+            .zip(&other)
+            .map(|(&a, &b)| a.wrapping_add(b))
+            .collect();
+        assert_eq!(
+            BFVScheme::decrypt_z2k(&ctx, &sk, &sum, 8).unwrap().coeffs(),
+            expected
+        );
+
+        let bits = 3;
+        let coeffs: Vec<u8> = (0..n).map(|_| ctx.csrng_mut().gen_range(0..8)).collect();
+        let m = Z2kPlaintext::new(bits, coeffs).unwrap();
+        let c = BFVScheme::encrypt_z2k(&ctx, &pk, &m).unwrap();
+        assert_eq!(BFVScheme::decrypt_z2k(&ctx, &sk, &c, bits).unwrap(), m);
+
+        assert!(Z2kPlaintext::new(3, vec![8]).is_err());
+        assert!(Z2kPlaintext::new(9, vec![0]).is_err());
+        assert!(Z2kPlaintext::from_bytes(&[0; 2], 1).is_err());
+        assert!(matches!(
+            BFVScheme::encrypt_z2k(&ctx, &pk, &Z2kPlaintext::from_bytes(&[], n - 1).unwrap()),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn bfv_plaintext_bits_test() {
+        use rand::Rng;
+
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+
+        let bits: Vec<bool> = (0..300).map(|_| ctx.csrng_mut().gen()).collect();
+        let m = BFVPlaintext::from_bits(&bits, n).unwrap();
+        assert_eq!(m.0.coeff_count(), n);
+        assert!(m.0[bits.len()..].iter().all(|&c| c == PlainField::ZERO));
+
+        let c = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
+        let res = BFVScheme::decrypt(&ctx, &sk, &c).unwrap();
+        assert_eq!(res.to_bits(bits.len()).unwrap(), bits);
+        assert_eq!(res.to_bits(n).unwrap().len(), n);
+        assert_eq!(res.to_bits(n + 1), None);
+
+        let mut not_bits = m.clone();
+        not_bits.0[3] = PlainField::new(2);
+        assert_eq!(not_bits.to_bits(3).unwrap(), bits[..3]);
+        assert_eq!(not_bits.to_bits(4), None);
+
+        assert!(matches!(
+            BFVPlaintext::from_bits(&bits, 299),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn bfv_boolean_test() {
+        use rand::Rng;
+
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+        let random_bits =
+            || -> Vec<u8> { (0..n).map(|_| ctx.csrng_mut().gen_range(0..2)).collect() };
+
+        let (a, b, p) = (random_bits(), random_bits(), random_bits());
+        let encrypt = |bits: &[u8]| {
+            BFVScheme::encrypt_z2k(&ctx, &pk, &Z2kPlaintext::new(1, bits.to_vec()).unwrap())
+                .unwrap()
+        };
+        let (ca, cb) = (encrypt(&a), encrypt(&b));
+
+        // the xor of many ciphertexts
+        let mut acc = BFVScheme::evaluate_xor(&ctx, &ca, &cb);
+        for _ in 0..10 {
+            acc = BFVScheme::evaluate_xor(&ctx, &acc, &ca);
+        }
+        let expected: Vec<u8> = a.iter().zip(&b).map(|(&a, &b)| a ^ b).collect();
+        assert_eq!(
+            BFVScheme::decrypt_z2k(&ctx, &sk, &acc, 1).unwrap().coeffs(),
+            expected
+        );
+
+        let p = Z2kPlaintext::new(1, p).unwrap();
+        let c = BFVScheme::evaluate_xor_plain(&ctx, &ca, &p).unwrap();
+        let expected: Vec<u8> = a.iter().zip(p.coeffs()).map(|(&a, &p)| a ^ p).collect();
+        assert_eq!(
+            BFVScheme::decrypt_z2k(&ctx, &sk, &c, 1).unwrap().coeffs(),
+            expected
+        );
+
+        let c = BFVScheme::evaluate_and_plain(&ctx, &ca, true);
+        assert_eq!(
+            BFVScheme::decrypt_z2k(&ctx, &sk, &c, 1).unwrap().coeffs(),
+            a
+        );
+        let c = BFVScheme::evaluate_and_plain(&ctx, &ca, false);
+        assert!(BFVScheme::decrypt_z2k(&ctx, &sk, &c, 1)
+            .unwrap()
+            .coeffs()
+            .iter()
+            .all(|&x| x == 0));
+
+        assert!(matches!(
+            BFVScheme::evaluate_xor_plain(&ctx, &ca, &Z2kPlaintext::new(2, vec![0; n]).unwrap()),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn bfv_mask_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+        let m = BFVPlaintext(Polynomial::<PlainField>::random(n, &mut *ctx.csrng_mut()));
+        let c = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
+
+        let mask = BFVScheme::gen_mask(&ctx);
+        let masked = BFVScheme::mask(&ctx, &c, &mask);
+        // the semi-trusted party only sees the blinded message
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &sk, &masked).unwrap(),
+            BFVPlaintext(m.0.clone() + &mask.0)
+        );
+        assert_eq!(BFVScheme::unmask(&ctx, &masked, &mask), c);
+
+        // unmask after a scalar multiplication with the scaled mask
+        let scalar = PlainField::new(7);
+        let scaled = BFVScheme::evaluate_mul_scalar(&ctx, &scalar, &masked);
+        let scaled_mask = BFVPlaintext(mask.0.mul_scalar(scalar));
+        assert_eq!(
+            BFVScheme::decrypt(&ctx, &sk, &BFVScheme::unmask(&ctx, &scaled, &scaled_mask)).unwrap(),
+            BFVPlaintext(m.0.mul_scalar(scalar))
+        );
+    }
+
+    #[test]
+    fn bfv_linear_map_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let n = ctx.rlwe_dimension();
+        let (rows, cols) = (4, 6);
+
+        let msgs: Vec<_> = (0..cols)
+            .map(|_| Polynomial::<PlainField>::random(n, &mut *ctx.csrng_mut()))
+            .collect();
+        let cts: Vec<_> = msgs
+            .iter()
+            .map(|m| BFVScheme::encrypt(&ctx, &pk, &BFVPlaintext(m.clone())).unwrap())
+            .collect();
+        let mut matrix: Vec<Vec<_>> = (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| PlainField::random(&mut *ctx.csrng_mut()))
+                    .collect()
+            })
+            .collect();
+        matrix[0][1] = PlainField::ZERO;
+
+        let out = BFVScheme::evaluate_linear_map(&ctx, &matrix, &cts).unwrap();
+        assert_eq!(out.len(), rows);
+        for (row, c) in matrix.iter().zip(&out) {
+            let expected = BFVScheme::evaluate_inner_product(&ctx, &cts, row).unwrap();
+            assert_eq!(c, &expected);
+
+            let m = msgs
+                .iter()
+                .zip(row)
+                .fold(Polynomial::zero(n), |acc, (m, s)| acc + m.mul_scalar(*s));
+            assert_eq!(BFVScheme::decrypt(&ctx, &sk, c).unwrap(), BFVPlaintext(m));
+        }
+
+        assert!(BFVScheme::evaluate_linear_map(&ctx, &[], &cts)
+            .unwrap()
+            .is_empty());
+        matrix[2].pop();
+        assert!(matches!(
+            BFVScheme::evaluate_linear_map(&ctx, &matrix, &cts),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
     #[test]
     fn bfv_mul_scalar_test() {
         let ctx = BFVScheme::gen_context();
@@ -51,15 +822,19 @@ mod tests {
                 Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
             let m = BFVPlaintext(m_poly.clone());
 
-            let c = BFVScheme::encrypt(&ctx, &pk, &m);
+            let c = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
 
             let scalar = PlainField::random(&mut *ctx.csrng_mut());
             let m_scalar = BFVPlaintext(m_poly.mul_scalar(scalar));
 
             let c_scalar = BFVScheme::evaluate_mul_scalar(&ctx, &scalar, &c);
 
-            let m_res = BFVScheme::decrypt(&ctx, &sk, &c_scalar);
+            let m_res = BFVScheme::decrypt(&ctx, &sk, &c_scalar).unwrap();
             assert_eq!(m_scalar, m_res);
+
+            let mut c = c;
+            BFVScheme::evaluate_mul_scalar_assign(&ctx, &scalar, &mut c);
+            assert_eq!(c, c_scalar);
         }
     }
 
@@ -91,13 +866,112 @@ mod tests {
 
             let ctxts: Vec<BFVCiphertext> = msgs
                 .iter()
-                .map(|m| BFVScheme::encrypt(&ctx, &pk, m))
+                .map(|m| BFVScheme::encrypt(&ctx, &pk, m).unwrap())
                 .collect();
 
-            let c_ip = BFVScheme::evaluate_inner_product(&ctx, &ctxts, &scalars);
-            let m_res = BFVScheme::decrypt(&ctx, &sk, &c_ip);
+            let c_ip = BFVScheme::evaluate_inner_product(&ctx, &ctxts, &scalars).unwrap();
+            let m_res = BFVScheme::decrypt(&ctx, &sk, &c_ip).unwrap();
 
             assert_eq!(m_res, m_ip);
+
+            // the terms may come from any iterators, such as shares arriving one by one
+            let c_stream = BFVScheme::evaluate_inner_product(
+                &ctx,
+                ctxts.iter().rev(),
+                scalars.iter().rev().copied(),
+            )
+            .unwrap();
+            assert_eq!(c_stream, c_ip);
+            assert!(matches!(
+                BFVScheme::evaluate_inner_product(&ctx, &ctxts, &scalars[1..]),
+                Err(BFVError::ParameterMismatch(_))
+            ));
+
+            // a short share is rejected rather than truncating the others
+            let mut short = ctxts.clone();
+            short[0].c2 = Polynomial::zero(ctx.rlwe_dimension() / 2);
+            assert!(matches!(
+                BFVScheme::evaluate_inner_product(&ctx, &short, &scalars),
+                Err(BFVError::ParameterMismatch(_))
+            ));
+            assert!(matches!(
+                BFVScheme::evaluate_linear_map(&ctx, &[scalars.clone()], &short),
+                Err(BFVError::ParameterMismatch(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn bfv_ntt_inner_product_test() {
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        const N: usize = 20;
+
+        let msgs: Vec<BFVPlaintext> = (0..N)
+            .map(|_| {
+                BFVPlaintext(Polynomial::<PlainField>::random(
+                    ctx.rlwe_dimension(),
+                    &mut *ctx.csrng_mut(),
+                ))
+            })
+            .collect();
+        let scalars: Vec<PlainField> = (0..N)
+            .map(|_| PlainField::random(&mut *ctx.csrng_mut()))
+            .collect();
+
+        let ctxts: Vec<BFVCiphertext> = msgs
+            .iter()
+            .map(|m| BFVScheme::encrypt(&ctx, &pk, m).unwrap())
+            .collect();
+        let ntt_ctxts: Vec<BFVNTTCiphertext> =
+            ctxts.iter().cloned().map(BFVNTTCiphertext::from).collect();
+
+        let c_ip = BFVScheme::evaluate_inner_product(&ctx, &ctxts, &scalars).unwrap();
+        let c_ip_ntt = BFVScheme::evaluate_inner_product_ntt(&ctx, &ntt_ctxts, &scalars).unwrap();
+        assert_eq!(BFVCiphertext::from(c_ip_ntt.clone()), c_ip);
+        assert_eq!(
+            BFVScheme::decrypt_ntt(&ctx, &sk, &c_ip_ntt),
+            BFVScheme::decrypt(&ctx, &sk, &c_ip).unwrap()
+        );
+
+        let c = BFVScheme::evaluate_add_ntt(
+            &ctx,
+            &BFVScheme::evaluate_mul_scalar_ntt(&ctx, &scalars[0], &ntt_ctxts[0]),
+            &ntt_ctxts[1],
+        );
+        let m = msgs[0].0.mul_scalar(scalars[0]) + &msgs[1].0;
+        assert_eq!(BFVScheme::decrypt_ntt(&ctx, &sk, &c), BFVPlaintext(m));
+    }
+
+    #[test]
+    fn plaintext_knowledge_proof_test() {
+        let ctx = BFVScheme::gen_context();
+        let (_, pk) = BFVScheme::gen_keypair(&ctx);
+        let (_, pk_other) = BFVScheme::gen_keypair(&ctx);
+
+        let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+        let msg = BFVPlaintext(msg);
+        let (c, witness) = BFVScheme::encrypt_with_witness(&ctx, &pk, &msg).unwrap();
+        assert_eq!(witness.m, msg);
+
+        let proof = PlaintextKnowledgeProof::prove(&ctx, &pk, &c, &witness, b"sender 1");
+        assert!(proof.verify(&pk, &c, b"sender 1"));
+        assert!(!proof.verify(&pk, &c, b"sender 2"));
+        assert!(!proof.verify(&pk_other, &c, b"sender 1"));
+
+        let c_other = BFVScheme::encrypt(&ctx, &pk, &msg).unwrap();
+        assert!(!proof.verify(&pk, &c_other, b"sender 1"));
+
+        // the responses match the short ciphertext, which must be rejected without panicking
+        for n in [0, 4] {
+            let mut bytes = vec![0; 32];
+            bytes.extend((n as u32).to_be_bytes());
+            bytes.extend(vec![0; 4 * 8 * n]);
+            let short_proof = PlaintextKnowledgeProof::from_vec(&bytes).unwrap();
+            let short = BFVCiphertext::new(Polynomial::zero(n), Polynomial::zero(n));
+            assert!(!short_proof.verify(&pk, &short, b"sender 1"));
         }
+        let skewed = BFVCiphertext::new(c.c1.clone(), Polynomial::zero(ctx.rlwe_dimension() / 2));
+        assert!(!proof.verify(&pk, &skewed, b"sender 1"));
     }
 }
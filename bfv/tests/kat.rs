@@ -0,0 +1,48 @@
+#![cfg(feature = "kat")]
+
+mod tests {
+    use algebra::{Field, Polynomial};
+    use bfv::{kat, BFVPlaintext, BFVScheme, CipherField, PlainField};
+
+    #[test]
+    fn kat_field_test() {
+        for [a, b, sum, diff, prod, quot] in kat::CIPHER_FIELD_VECTORS {
+            let (a, b) = (CipherField::new(a), CipherField::new(b));
+            assert_eq!((a + b).get(), sum);
+            assert_eq!((a - b).get(), diff);
+            assert_eq!((a * b).get(), prod);
+            assert_eq!((a / b).get(), quot);
+        }
+        for [a, b, sum, diff, prod, quot] in kat::PLAIN_FIELD_VECTORS {
+            let (a, b) = (PlainField::new(a), PlainField::new(b));
+            assert_eq!((a + b).get(), sum);
+            assert_eq!((a - b).get(), diff);
+            assert_eq!((a * b).get(), prod);
+            assert_eq!((a / b).get(), quot);
+        }
+    }
+
+    #[test]
+    fn kat_known_answers_test() {
+        let answers = kat::known_answers();
+        let expected: Vec<_> = kat::KNOWN_ANSWERS
+            .iter()
+            .map(|&(name, digest)| (name, digest.to_string()))
+            .collect();
+        assert_eq!(answers, expected);
+    }
+
+    #[test]
+    fn kat_deterministic_test() {
+        let (sk, pk) = kat::gen_keypair(b"seed");
+        assert_eq!(kat::gen_keypair(b"seed").0, sk);
+        assert_ne!(kat::gen_keypair(b"other seed").0, sk);
+
+        let ctx = kat::context_from_seed(b"message");
+        let msg = Polynomial::<PlainField>::random(ctx.rlwe_dimension(), &mut *ctx.csrng_mut());
+        let msg = BFVPlaintext(msg);
+        let c = kat::encrypt(&pk, &msg, b"encrypt").unwrap();
+        assert_eq!(kat::encrypt(&pk, &msg, b"encrypt").unwrap(), c);
+        assert_eq!(BFVScheme::decrypt(&ctx, &sk, &c).unwrap(), msg);
+    }
+}
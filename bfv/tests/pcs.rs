@@ -0,0 +1,38 @@
+mod tests {
+    use algebra::{Field, Polynomial};
+    use bfv::{CipherField, MerklePCS};
+    use rand::thread_rng;
+
+    type F = CipherField;
+
+    #[test]
+    fn merkle_pcs_test() {
+        let mut rng = thread_rng();
+        for coeff_count in [1, 7, 1024] {
+            let poly = Polynomial::<F>::random(coeff_count, &mut rng);
+            let state = MerklePCS::commit(&poly, &mut rng);
+            let commitment = *state.commitment();
+            let point = F::random(&mut rng);
+
+            let (value, proof) = MerklePCS::open(&state, point);
+            assert_eq!(value, poly.evaluate(point));
+            assert!(MerklePCS::verify(&commitment, point, value, &proof));
+            assert!(!MerklePCS::verify(
+                &commitment,
+                point,
+                value + F::ONE,
+                &proof
+            ));
+            assert!(!MerklePCS::verify(
+                &commitment,
+                point + F::ONE,
+                value,
+                &proof
+            ));
+
+            let other =
+                MerklePCS::commit(&Polynomial::<F>::random(coeff_count, &mut rng), &mut rng);
+            assert!(!MerklePCS::verify(other.commitment(), point, value, &proof));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+mod tests {
+    use algebra::{Field, Polynomial};
+    use bfv::{
+        protocol::{
+            DkgPublicRound, EncryptionShareMessage, MessageHeader, PartialDecryptionMessage,
+            ProtocolMessage, RecoveryAggregateRound, PROTOCOL_VERSION,
+        },
+        BFVPlaintext, BFVScheme, CiphertextShare, PlainField, DIMENSION_N,
+    };
+    use ed25519_dalek::SigningKey;
+    use rand::thread_rng;
+
+    type F = PlainField;
+
+    #[test]
+    fn protocol_message_test() {
+        let mut rng = thread_rng();
+        let ctx = BFVScheme::gen_context();
+        let (_, pk) = BFVScheme::gen_keypair(&ctx);
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let ct = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
+        let header = MessageHeader::new([7; 32], F::new(2));
+
+        let msg = EncryptionShareMessage {
+            header,
+            receiver: F::new(3),
+            ct: ct.clone(),
+        };
+        let bytes = msg.to_vec();
+        assert_eq!(EncryptionShareMessage::from_vec(&bytes), Some(msg));
+        assert_eq!(u16::from_be_bytes([bytes[0], bytes[1]]), PROTOCOL_VERSION);
+
+        // the kind is checked
+        assert!(PartialDecryptionMessage::from_vec(&bytes).is_none());
+        // truncated or trailing bytes are rejected
+        assert!(EncryptionShareMessage::from_vec(&bytes[..bytes.len() - 1]).is_none());
+        let mut longer = bytes.clone();
+        longer.push(0);
+        assert!(EncryptionShareMessage::from_vec(&longer).is_none());
+
+        let signing_key = SigningKey::generate(&mut rng);
+        let msg = PartialDecryptionMessage {
+            header,
+            share: CiphertextShare::sign(&signing_key, F::new(2), ct, b"id", &[0; 32]),
+        };
+        assert_eq!(PartialDecryptionMessage::from_vec(&msg.to_vec()), Some(msg));
+
+        let msg = RecoveryAggregateRound {
+            header,
+            aggregated: Polynomial::random(16, &mut rng),
+        };
+        let mut bytes = msg.to_vec();
+        assert_eq!(RecoveryAggregateRound::from_vec(&bytes), Some(msg));
+        // a length beyond the bytes is rejected
+        bytes[37..41].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(RecoveryAggregateRound::from_vec(&bytes).is_none());
+
+        let msg = DkgPublicRound {
+            header,
+            public_key: pk,
+        };
+        assert_eq!(DkgPublicRound::from_vec(&msg.to_vec()), Some(msg));
+    }
+}
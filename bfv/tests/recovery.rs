@@ -0,0 +1,73 @@
+mod tests {
+    use algebra::{Field, Polynomial};
+    use bfv::{PlainField, Share, ShareRecovery, ThresholdPolicy};
+    use rand::thread_rng;
+
+    type F = PlainField;
+
+    /// Run the recovery protocol of `helper_indices`, whose shares are `helper_shares`.
+    fn recover(
+        policy: &ThresholdPolicy,
+        helper_indices: &[F],
+        helper_shares: &[Share<F>],
+        new_index: F,
+    ) -> Share<F> {
+        let mut rng = thread_rng();
+        let recovery = ShareRecovery::new(policy, helper_indices.to_vec(), new_index);
+
+        let messages: Vec<_> = helper_indices
+            .iter()
+            .zip(helper_shares)
+            .map(|(&i, share)| recovery.gen_blinding_messages(i, share, &mut rng))
+            .collect();
+
+        let aggregated: Vec<_> = helper_indices
+            .iter()
+            .enumerate()
+            .map(|(j, &i)| {
+                let received: Vec<_> = messages.iter().map(|m| m[j].clone()).collect();
+                recovery.aggregate(i, &received)
+            })
+            .collect();
+
+        recovery.recover(&aggregated)
+    }
+
+    #[test]
+    fn share_recovery_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4)];
+        let policy = ThresholdPolicy::new(4, 3, indices.to_vec());
+
+        let secret = Polynomial::<F>::random(16, &mut rng);
+        let shares = policy.secret_sharing(&secret, &mut rng);
+
+        // node 4 is lost, nodes 1, 2 and 3 help to recover its share.
+        let share = recover(&policy, &indices[..3], &shares[..3], F::new(4));
+        assert_eq!(share, shares[3]);
+    }
+
+    #[test]
+    fn packed_share_recovery_test() {
+        let mut rng = thread_rng();
+        let indices: Vec<F> = (1..=5).map(F::new).collect();
+        let policy = ThresholdPolicy::new_packed(5, 2, indices.clone(), 2);
+
+        let secrets: Vec<_> = (0..2)
+            .map(|_| Polynomial::<F>::random(16, &mut rng))
+            .collect();
+        let shares = policy.secret_sharing_packed(&secrets, &mut rng);
+
+        // the sharing polynomial has degree 2, so 3 helpers are needed
+        let share = recover(&policy, &indices[..3], &shares[..3], F::new(5));
+        assert_eq!(share, shares[4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of helpers should be reconstruction_number")]
+    fn packed_share_recovery_threshold_helpers_test() {
+        let indices: Vec<F> = (1..=5).map(F::new).collect();
+        let policy = ThresholdPolicy::new_packed(5, 2, indices.clone(), 2);
+        ShareRecovery::new(&policy, indices[..2].to_vec(), F::new(5));
+    }
+}
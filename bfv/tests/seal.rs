@@ -0,0 +1,61 @@
+mod tests {
+    use algebra::Polynomial;
+    use bfv::{
+        seal::{SealCiphertext, SealParameters},
+        BFVPlaintext, BFVScheme, DIMENSION_N,
+    };
+    use rand::thread_rng;
+
+    #[test]
+    fn seal_parameters_test() {
+        let params = SealParameters::matching();
+        let bytes = params.save();
+
+        // header: magic, header size, version 4.1, no compression, reserved, size
+        assert_eq!(bytes[..8], [0x5e, 0xa1, 0x10, 4, 1, 0, 0, 0]);
+        assert_eq!(u64::from_le_bytes(bytes[8..16].try_into().unwrap()), 81);
+        assert_eq!(bytes.len(), 81);
+
+        let loaded = SealParameters::load(&bytes).unwrap();
+        assert!(loaded.is_matching());
+        assert_eq!(loaded, params);
+
+        let mut other = params.clone();
+        other.coeff_modulus.push(1152921504606830593);
+        let loaded = SealParameters::load(&other.save()).unwrap();
+        assert!(!loaded.is_matching());
+
+        // compressed objects are not supported
+        let mut compressed = bytes;
+        compressed[5] = 2;
+        assert!(SealParameters::load(&compressed).is_none());
+    }
+
+    #[test]
+    fn seal_ciphertext_test() {
+        let mut rng = thread_rng();
+        let ctx = BFVScheme::gen_context();
+        let (sk, pk) = BFVScheme::gen_keypair(&ctx);
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let ct = BFVScheme::encrypt(&ctx, &pk, &m).unwrap();
+
+        let seal_ct = SealCiphertext {
+            parms_id: [1, 2, 3, 4],
+            ct,
+        };
+        let bytes = seal_ct.save();
+        assert_eq!(bytes.len(), 16 + 73 + 16 + 8 + 2 * DIMENSION_N * 8);
+
+        let loaded = SealCiphertext::load(&bytes).unwrap();
+        assert_eq!(loaded, seal_ct);
+        assert_eq!(BFVScheme::decrypt(&ctx, &sk, &loaded.ct).unwrap(), m);
+
+        // ciphertexts in ntt form are rejected
+        let mut ntt_form = bytes.clone();
+        ntt_form[16 + 32] = 1;
+        assert!(SealCiphertext::load(&ntt_form).is_none());
+
+        // truncated objects are rejected
+        assert!(SealCiphertext::load(&bytes[..bytes.len() - 8]).is_none());
+    }
+}
@@ -1,6 +1,13 @@
 mod tests {
-    use algebra::Field;
-    use bfv::{PlainField, ThresholdPKE};
+    use algebra::{Field, Polynomial};
+    use bfv::{
+        sss, AuditLog, BFVCiphertext, BFVError, BFVPlaintext, BFVScheme, BFVSecretKey, CipherField,
+        CombineProof, CommitteeDescriptor, DecryptionShare, HierarchicalPolicy, NonceMode,
+        PlainField, Share, ShareRejection, ThresholdCiphertext, ThresholdPKE, ThresholdPolicy,
+        DIMENSION_N,
+    };
+    use ed25519_dalek::SigningKey;
+    use rand::thread_rng;
 
     type F = PlainField;
 
@@ -21,19 +28,1015 @@ mod tests {
 
         let pks = [pk1, pk2, pk3].to_vec();
 
-        let (vec_c, nonce, c_bytes) = ThresholdPKE::encrypt_bytes(&ctx, &pks, msg_bytes);
+        let aad = b"message id";
+        let (vec_c, nonce, c_bytes) =
+            ThresholdPKE::encrypt_bytes(&ctx, &pks, msg_bytes, aad).unwrap();
 
-        let c1 = ThresholdPKE::re_encrypt(&ctx, &vec_c[0], &sk1, &pk);
-        let c2 = ThresholdPKE::re_encrypt(&ctx, &vec_c[1], &sk2, &pk);
-        let c3 = ThresholdPKE::re_encrypt(&ctx, &vec_c[2], &sk3, &pk);
+        let c1 = ThresholdPKE::re_encrypt(&ctx, &vec_c[0], &sk1, &pk).unwrap();
+        let c2 = ThresholdPKE::re_encrypt(&ctx, &vec_c[1], &sk2, &pk).unwrap();
+        let c3 = ThresholdPKE::re_encrypt(&ctx, &vec_c[2], &sk3, &pk).unwrap();
 
         let ctxts = [c1, c2, c3].to_vec();
         let chosen_indices = [F::new(1), F::new(2), F::new(3)].to_vec();
 
-        let c = ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices);
+        let c = ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices).unwrap();
 
-        let m_res = ThresholdPKE::decrypt_bytes(&ctx, &sk, &c, &nonce, &c_bytes);
+        let m_res = ThresholdPKE::decrypt_bytes(&ctx, &sk, &c, &nonce, &c_bytes, aad).unwrap();
 
         assert_eq!(msg_bytes, m_res.as_slice());
+
+        // the bytes do not decrypt with another aad or in another context
+        assert_eq!(
+            ThresholdPKE::decrypt_bytes(&ctx, &sk, &c, &nonce, &c_bytes, b"other id"),
+            Err(BFVError::AeadFailure)
+        );
+        let other_ctx = ThresholdPKE::gen_context(total_number, 3, indices.to_vec());
+        assert_eq!(
+            ThresholdPKE::decrypt_bytes(&other_ctx, &sk, &c, &nonce, &c_bytes, aad),
+            Err(BFVError::AeadFailure)
+        );
+    }
+
+    #[test]
+    fn nonce_mode_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let key = [7u8; 32];
+        let derived = |message_id: &'static [u8]| NonceMode::Derived {
+            key: &key,
+            message_id,
+        };
+
+        let mut outputs = Vec::new();
+        for (mode, m) in [
+            (derived(b"message id"), &b"message"[..]),
+            (derived(b"message id"), b"message"),
+            (derived(b"other id"), b"message"),
+            (derived(b"message id"), b"other message"),
+            (NonceMode::Random, b"message"),
+        ] {
+            let (vec_c, nonce, c_bytes) =
+                ThresholdPKE::encrypt_bytes_with_nonce(&ctx, &pks, m, b"", mode).unwrap();
+
+            let ctxts: Vec<_> = vec_c
+                .iter()
+                .zip(&keys)
+                .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&ctx, c, sk_i, &pk).unwrap())
+                .collect();
+            let c = ThresholdPKE::combine(&ctx, &ctxts[..2], &indices[..2]).unwrap();
+            assert_eq!(
+                ThresholdPKE::decrypt_bytes(&ctx, &sk, &c, &nonce, &c_bytes, b"").unwrap(),
+                m
+            );
+            outputs.push((vec_c, nonce, c_bytes));
+        }
+        // the derived nonce only depends on the id, the rest of the encryption stays randomized
+        assert_eq!(outputs[0].1, outputs[1].1);
+        assert_eq!(outputs[0].1, outputs[3].1);
+        assert_ne!(outputs[0].1, outputs[2].1);
+        assert_ne!(outputs[0].0, outputs[1].0);
+        assert_ne!(outputs[0].2, outputs[1].2);
+    }
+
+    #[test]
+    fn encrypt_bytes_per_recipient_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let msg = b"a message spanning a few digits";
+        let cs = ThresholdPKE::encrypt_bytes_per_recipient(&ctx, &pks, msg, b"id").unwrap();
+        assert_eq!(cs.len(), 3);
+        assert!(cs.iter().zip(indices).all(|(c, i)| c.index() == i));
+        // the content keys are independent
+        assert_ne!(cs[0].wrapped_key(), cs[1].wrapped_key());
+
+        // the nodes re-encrypt their content keys to the receiver
+        let shares: Vec<_> = cs
+            .iter()
+            .zip(&keys)
+            .map(|(c, (sk_i, _))| {
+                let wrapped_key =
+                    ThresholdPKE::re_encrypt(&ctx, c.wrapped_key(), sk_i, &pk).unwrap();
+                ThresholdPKE::decrypt_recipient_share(&ctx, &sk, &wrapped_key, c, b"id").unwrap()
+            })
+            .collect();
+        assert_eq!(
+            ThresholdPKE::combine_bytes(&ctx, &shares[1..], &indices[1..]).unwrap(),
+            msg
+        );
+        // a node opens its own share, but a single share is below the threshold
+        let own = ThresholdPKE::decrypt_recipient_share(
+            &ctx,
+            &keys[0].0,
+            cs[0].wrapped_key(),
+            &cs[0],
+            b"id",
+        )
+        .unwrap();
+        assert_eq!(own, shares[0]);
+        assert_eq!(
+            ThresholdPKE::combine_bytes(&ctx, &shares[..1], &indices[..1]),
+            Err(BFVError::NotEnoughShares {
+                required: 2,
+                given: 1
+            })
+        );
+        assert_eq!(
+            ThresholdPKE::combine_bytes(&ctx, &shares[..2], &[indices[0], indices[0]]),
+            Err(BFVError::InvalidShare)
+        );
+
+        // the share is bound to the aad and to its own content key
+        assert_eq!(
+            ThresholdPKE::decrypt_recipient_share(
+                &ctx,
+                &keys[0].0,
+                cs[0].wrapped_key(),
+                &cs[0],
+                b""
+            ),
+            Err(BFVError::AeadFailure)
+        );
+        let forged = ThresholdPKE::encrypt_bytes_per_recipient(&ctx, &pks, msg, b"id").unwrap();
+        assert_eq!(
+            ThresholdPKE::decrypt_recipient_share(
+                &ctx,
+                &keys[0].0,
+                forged[0].wrapped_key(),
+                &cs[0],
+                b"id"
+            ),
+            Err(BFVError::AeadFailure)
+        );
+
+        let empty = ThresholdPKE::encrypt_bytes_per_recipient(&ctx, &pks, b"", b"").unwrap();
+        let shares: Vec<_> = empty
+            .iter()
+            .zip(&keys)
+            .map(|(c, (sk_i, _))| {
+                ThresholdPKE::decrypt_recipient_share(&ctx, sk_i, c.wrapped_key(), c, b"").unwrap()
+            })
+            .collect();
+        assert!(ThresholdPKE::combine_bytes(&ctx, &shares, &indices)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn random_policy_test() {
+        let mut rng = thread_rng();
+        let policy = ThresholdPolicy::random(5, 3, &mut rng);
+        let indices = policy.indices();
+        assert_eq!(indices.len(), 5);
+        assert!(indices
+            .iter()
+            .enumerate()
+            .all(|(i, x)| *x != F::ZERO && !indices[..i].contains(x)));
+
+        let secret = Polynomial::<F>::random(16, &mut rng);
+        let shares = policy.secret_sharing(&secret, &mut rng);
+        let coeffs = ThresholdPKE::gen_lagrange_coeffs(&indices[2..]).unwrap();
+        let recovered = shares[2..]
+            .iter()
+            .zip(coeffs)
+            .fold(Polynomial::zero(16), |acc, (s, c)| {
+                acc + s.expose_secret().mul_scalar(c)
+            });
+        assert_eq!(recovered, secret);
+    }
+
+    #[test]
+    fn lagrange_coeffs_at_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4)];
+        let policy = ThresholdPolicy::new(4, 3, indices.to_vec());
+
+        let secret = Polynomial::<F>::random(16, &mut rng);
+        let shares = policy.secret_sharing(&secret, &mut rng);
+
+        let chosen_indices = [F::new(1), F::new(2), F::new(3)];
+        let coeffs = ThresholdPKE::gen_lagrange_coeffs_at(F::new(4), &chosen_indices);
+        let share = shares[..3]
+            .iter()
+            .zip(coeffs)
+            .fold(Polynomial::zero(16), |acc, (s, c)| {
+                acc + s.expose_secret().mul_scalar(c)
+            });
+        assert_eq!(&share, shares[3].expose_secret());
+
+        let coeffs = ThresholdPKE::gen_lagrange_coeffs_at(F::ZERO, &chosen_indices);
+        assert_eq!(
+            coeffs,
+            ThresholdPKE::gen_lagrange_coeffs(&chosen_indices).unwrap()
+        );
+    }
+
+    #[test]
+    fn streaming_secret_sharing_test() {
+        let mut rng = thread_rng();
+        let indices: Vec<F> = (1..=5).map(F::new).collect();
+        let policy = ThresholdPolicy::new(5, 3, indices.clone());
+
+        let secret = Polynomial::<F>::random(16, &mut rng);
+        let stream = policy.secret_sharing_iter(&secret, &mut rng);
+        assert_eq!(stream.len(), 5);
+        assert_eq!(
+            format!("{stream:?}"),
+            "ShareStream { parties: 5, coeff_count: 16, .. }"
+        );
+        let first = stream.clone().next().unwrap();
+        assert_eq!(format!("{first:?}"), "Share { coeff_count: 16, .. }");
+
+        // writing into a reused buffer gives the shares yielded by the iterator
+        let mut buffer = vec![F::ZERO; 16];
+        let shares: Vec<Polynomial<F>> = stream.clone().map(Share::into_secret).collect();
+        for (party, share) in shares.iter().enumerate() {
+            stream.share_into(party, &mut buffer);
+            assert_eq!(buffer, share.as_slice());
+        }
+
+        let coeffs = ThresholdPKE::gen_lagrange_coeffs(&indices[1..4]).unwrap();
+        let recovered = shares[1..4]
+            .iter()
+            .zip(coeffs)
+            .fold(Polynomial::zero(16), |acc, (s, c)| acc + s.mul_scalar(c));
+        assert_eq!(recovered, secret);
+    }
+
+    #[test]
+    fn packed_tpke_test() {
+        let mut rng = thread_rng();
+        let total_number = 4;
+        let threshold_number = 2;
+        let pack_number = 2;
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4)];
+
+        let ctx = ThresholdPKE::gen_packed_context(
+            total_number,
+            threshold_number,
+            indices.to_vec(),
+            pack_number,
+        );
+        assert_eq!(ctx.policy().reconstruction_number(), 3);
+
+        let keys: Vec<_> = (0..total_number)
+            .map(|_| ThresholdPKE::gen_keypair(&ctx))
+            .collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let msgs: Vec<BFVPlaintext> = (0..pack_number)
+            .map(|_| BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng)))
+            .collect();
+        let vec_c = ThresholdPKE::encrypt_packed(&ctx, &pks, &msgs).unwrap();
+        assert_eq!(vec_c.len(), total_number);
+
+        let ctxts: Vec<_> = vec_c[1..]
+            .iter()
+            .zip(&keys[1..])
+            .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&ctx, c, sk_i, &pk).unwrap())
+            .collect();
+        let chosen_indices = [F::new(2), F::new(3), F::new(4)];
+
+        let cs = ThresholdPKE::combine_packed(&ctx, &ctxts, &chosen_indices).unwrap();
+        assert_eq!(cs.len(), pack_number);
+        for (c, m) in cs.iter().zip(msgs) {
+            assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, c).unwrap(), m);
+        }
+    }
+
+    #[test]
+    fn reconstruct_with_correction_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4), F::new(5)];
+        let policy = ThresholdPolicy::new(5, 3, indices.to_vec());
+
+        let secret = Polynomial::<F>::random(16, &mut rng);
+        let mut shares: Vec<_> = policy
+            .secret_sharing(&secret, &mut rng)
+            .into_iter()
+            .map(Share::into_secret)
+            .collect();
+
+        let (res, corrupted) = policy
+            .reconstruct_with_correction(&shares, &indices)
+            .unwrap();
+        assert_eq!(res, secret);
+        assert!(corrupted.is_empty());
+
+        shares[3][0] += F::ONE;
+        shares[3][7] += F::new(5);
+        let (res, corrupted) = policy
+            .reconstruct_with_correction(&shares, &indices)
+            .unwrap();
+        assert_eq!(res, secret);
+        assert_eq!(corrupted, [F::new(4)]);
+    }
+
+    #[test]
+    fn encrypt_for_subset_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4), F::new(5)];
+        let ctx = ThresholdPKE::gen_context(5, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..5).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        // only the nodes 2, 4 and 5 are online
+        let subset = [F::new(4), F::new(2), F::new(5)];
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let c = ThresholdPKE::encrypt_for_subset(&ctx, &pks, &subset, &m).unwrap();
+        assert_eq!(c.len(), 3);
+
+        let ctxts: Vec<_> = [(0, 3), (2, 4)]
+            .iter()
+            .map(|&(i, node)| ThresholdPKE::re_encrypt(&ctx, &c[i], &keys[node].0, &pk).unwrap())
+            .collect();
+        let chosen_indices = [subset[0], subset[2]];
+        let res = ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices).unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &res).unwrap(), m);
+
+        assert_eq!(
+            ThresholdPKE::encrypt_for_subset(&ctx, &pks, &subset[..1], &m),
+            Err(BFVError::NotEnoughShares {
+                required: 2,
+                given: 1
+            })
+        );
+        for subset in [[F::new(2), F::new(6)], [F::new(2), F::new(2)]] {
+            assert!(matches!(
+                ThresholdPKE::encrypt_for_subset(&ctx, &pks, &subset, &m),
+                Err(BFVError::ParameterMismatch(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn encrypt_with_parity_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..5).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let parity_indices = [F::new(7), F::new(8)];
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let (c, parity) =
+            ThresholdPKE::encrypt_with_parity(&ctx, &pks[..3], &parity_indices, &pks[3..], &m)
+                .unwrap();
+        assert_eq!((c.len(), parity.len()), (3, 2));
+
+        // the nodes 1 and 3 never received their shares
+        let ctxts = [
+            ThresholdPKE::re_encrypt(&ctx, &c[1], &keys[1].0, &pk).unwrap(),
+            ThresholdPKE::re_encrypt(&ctx, &parity[1], &keys[4].0, &pk).unwrap(),
+        ];
+        let chosen_indices = [indices[1], parity_indices[1]];
+        let res = ThresholdPKE::combine(&ctx, &ctxts, &chosen_indices).unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &res).unwrap(), m);
+
+        for parity_indices in [
+            [F::new(2), F::new(7)],
+            [F::new(7), F::new(7)],
+            [F::ZERO, F::new(7)],
+        ] {
+            assert!(matches!(
+                ThresholdPKE::encrypt_with_parity(&ctx, &pks[..3], &parity_indices, &pks[3..], &m),
+                Err(BFVError::ParameterMismatch(_))
+            ));
+        }
+        assert!(matches!(
+            ThresholdPKE::encrypt_with_parity(&ctx, &pks[..3], &parity_indices, &pks[4..], &m),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn secret_sharing_batch_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4)];
+        let policy = ThresholdPolicy::new(4, 3, indices.to_vec());
+
+        let secrets: Vec<_> = (0..5).map(|_| Polynomial::random(8, &mut rng)).collect();
+        let shares = policy.secret_sharing_batch(&secrets, &mut rng);
+        assert_eq!(shares.len(), secrets.len());
+        for (secret, shares) in secrets.iter().zip(&shares) {
+            assert_eq!(shares.len(), 4);
+            let shares: Vec<_> = shares.iter().map(|s| s.expose_secret().clone()).collect();
+            assert_eq!(sss::reconstruct(&shares[..3], &indices[..3]), *secret);
+            assert!(sss::verify_shares(&shares, &indices, 3));
+        }
+
+        let ctx = ThresholdPKE::gen_context(4, 3, indices.to_vec());
+        let keys: Vec<_> = (0..4).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+        let msgs: Vec<_> = (0..2)
+            .map(|_| BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng)))
+            .collect();
+        let cs = ThresholdPKE::encrypt_batch(&ctx, &pks, &msgs).unwrap();
+        for (c, m) in cs.iter().zip(&msgs) {
+            let ctxts: Vec<_> = c[1..]
+                .iter()
+                .zip(&keys[1..])
+                .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&ctx, c, sk_i, &pk).unwrap())
+                .collect();
+            let res = ThresholdPKE::combine(&ctx, &ctxts, &indices[1..]).unwrap();
+            assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &res).unwrap(), *m);
+        }
+    }
+
+    #[test]
+    fn committee_descriptor_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+        let authority = SigningKey::generate(&mut rng);
+
+        let pks: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx).1).collect();
+        let ids: Vec<Vec<u8>> = [b"alice", b"bob__", b"carol"]
+            .map(|id| id.to_vec())
+            .to_vec();
+        let committee =
+            CommitteeDescriptor::sign(&authority, &ctx, ids.clone(), pks.clone(), 7).unwrap();
+
+        let digest = ctx.parameter_digest();
+        let verifying_key = authority.verifying_key();
+        assert!(committee.verify(&verifying_key, &digest, 7));
+        assert!(!committee.verify(&verifying_key, &digest, 8));
+        assert!(!committee.verify(&SigningKey::generate(&mut rng).verifying_key(), &digest, 7));
+        let other_ctx = ThresholdPKE::gen_context(3, 3, indices.to_vec());
+        assert!(!committee.verify(&verifying_key, &other_ctx.parameter_digest(), 7));
+        assert_eq!(committee.pk_of(b"bob__"), Some(&pks[1]));
+        assert_eq!(committee.pk_of(b"dave_"), None);
+
+        // a substituted key or id breaks the signature
+        let mut forged = committee.clone();
+        forged.pks.swap(0, 1);
+        assert!(!forged.verify(&verifying_key, &digest, 7));
+        let mut forged = committee.clone();
+        forged.party_ids[0] = b"mallory".to_vec();
+        assert!(!forged.verify(&verifying_key, &digest, 7));
+
+        assert!(ThresholdPKE::encrypt(
+            &ctx,
+            &committee.pks,
+            &BFVPlaintext(Polynomial::zero(DIMENSION_N))
+        )
+        .is_ok());
+        assert!(matches!(
+            CommitteeDescriptor::sign(&authority, &ctx, ids, pks[..2].to_vec(), 7),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn dynamic_threshold_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3), F::new(4)];
+        let ctx = ThresholdPKE::gen_context(4, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..4).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let c = ThresholdPKE::encrypt_with_threshold(&ctx, &pks, &m, 3).unwrap();
+        assert_eq!(c.threshold_number(), 3);
+        assert_eq!(ThresholdCiphertext::from_vec(&c.to_vec()), Ok(c.clone()));
+
+        let ctxts: Vec<_> = c.shares()[..3]
+            .iter()
+            .zip(&keys)
+            .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&ctx, c, sk_i, &pk).unwrap())
+            .collect();
+        let chosen_indices = [F::new(1), F::new(2), F::new(3)];
+
+        let res = ThresholdPKE::combine_with_threshold(&ctx, &c, &ctxts, &chosen_indices).unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &res).unwrap(), m);
+
+        // the threshold encryption of a packed context shares without packing
+        let packed_ctx = ThresholdPKE::gen_packed_context(4, 2, indices.to_vec(), 2);
+        let policy = packed_ctx.policy().with_threshold(4);
+        assert_eq!(policy.pack_number(), 1);
+        assert_eq!(policy.reconstruction_number(), 4);
+        let c = ThresholdPKE::encrypt_with_threshold(&packed_ctx, &pks, &m, 4).unwrap();
+        let ctxts: Vec<_> = c
+            .shares()
+            .iter()
+            .zip(&keys)
+            .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&packed_ctx, c, sk_i, &pk).unwrap())
+            .collect();
+        let res = ThresholdPKE::combine_with_threshold(&packed_ctx, &c, &ctxts, &indices).unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&packed_ctx, &sk, &res).unwrap(), m);
+    }
+
+    #[test]
+    fn hierarchical_tpke_test() {
+        let mut rng = thread_rng();
+        // 3 of 5 departments, each 2 of 3 officers
+        let top = ThresholdPolicy::random(5, 3, &mut rng);
+        let subs = (0..5)
+            .map(|_| ThresholdPolicy::random(3, 2, &mut rng))
+            .collect();
+        let policy = HierarchicalPolicy::new(top.clone(), subs);
+        assert_eq!(policy.total_number(), 15);
+
+        let ctx = ThresholdPKE::gen_context(5, 3, top.indices().to_vec());
+        let keys: Vec<Vec<_>> = (0..5)
+            .map(|_| (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect())
+            .collect();
+        let pks: Vec<Vec<_>> = keys
+            .iter()
+            .map(|keys| keys.iter().map(|(_, pk)| pk.clone()).collect())
+            .collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let c = ThresholdPKE::encrypt_hierarchical(&ctx, &policy, &pks, &m).unwrap();
+
+        // departments 1, 2 and 4, with officers 0 and 2, 1 and 2, 0 and 1
+        let chosen_officers = [(1, [0, 2]), (2, [1, 2]), (4, [0, 1])];
+        let chosen: Vec<(F, Vec<F>)> = chosen_officers
+            .iter()
+            .map(|&(i, officers)| {
+                let sub = &policy.subs()[i];
+                (
+                    top.indices()[i],
+                    officers.iter().map(|&j| sub.indices()[j]).collect(),
+                )
+            })
+            .collect();
+        let ctxts: Vec<Vec<BFVCiphertext>> = chosen_officers
+            .iter()
+            .map(|&(i, officers)| {
+                officers
+                    .iter()
+                    .map(|&j| ThresholdPKE::re_encrypt(&ctx, &c[i][j], &keys[i][j].0, &pk).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let res = ThresholdPKE::combine_hierarchical(&ctx, &policy, &ctxts, &chosen).unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &res).unwrap(), m);
+
+        // not enough departments, or not enough officers in one department
+        assert_eq!(
+            ThresholdPKE::combine_hierarchical(&ctx, &policy, &ctxts[..2], &chosen[..2]),
+            Err(BFVError::NotEnoughShares {
+                required: 3,
+                given: 2
+            })
+        );
+        let mut few = chosen.clone();
+        few[0].1.truncate(1);
+        let mut few_ctxts = ctxts.clone();
+        few_ctxts[0].truncate(1);
+        assert_eq!(
+            ThresholdPKE::combine_hierarchical(&ctx, &policy, &few_ctxts, &few),
+            Err(BFVError::NotEnoughShares {
+                required: 2,
+                given: 1
+            })
+        );
+
+        // repeated or foreign indices on either level
+        let invalid = |chosen: &[(F, Vec<F>)]| policy.gen_lagrange_coeffs(chosen);
+        let mut repeated_top = chosen.clone();
+        repeated_top[1].0 = repeated_top[0].0;
+        assert_eq!(invalid(&repeated_top), Err(BFVError::InvalidShare));
+        let mut foreign_top = chosen.clone();
+        foreign_top[0].0 = F::ZERO;
+        assert_eq!(invalid(&foreign_top), Err(BFVError::InvalidShare));
+        let mut repeated_sub = chosen.clone();
+        repeated_sub[0].1[1] = repeated_sub[0].1[0];
+        assert_eq!(invalid(&repeated_sub), Err(BFVError::InvalidShare));
+        let mut foreign_sub = chosen.clone();
+        foreign_sub[0].1[0] = (1..)
+            .map(F::new)
+            .find(|x| !policy.subs()[1].indices().contains(x))
+            .unwrap();
+        assert_eq!(invalid(&foreign_sub), Err(BFVError::InvalidShare));
+    }
+
+    #[test]
+    fn authenticated_combine_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let signing_keys: Vec<_> = (0..3).map(|_| SigningKey::generate(&mut rng)).collect();
+        let verifying_keys: Vec<_> = signing_keys.iter().map(|k| k.verifying_key()).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let vec_c = ThresholdPKE::encrypt(&ctx, &pks, &m).unwrap();
+
+        let message_id = b"message 0";
+        let shares: Vec<_> = (0..2)
+            .map(|i| {
+                ThresholdPKE::re_encrypt_authenticated(
+                    &ctx,
+                    &vec_c[i],
+                    &keys[i].0,
+                    &pk,
+                    indices[i],
+                    message_id,
+                    &signing_keys[i],
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let c = ThresholdPKE::combine_authenticated(&ctx, &shares, &verifying_keys, message_id)
+            .unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &c).unwrap(), m);
+
+        assert_eq!(
+            ThresholdPKE::combine_authenticated(&ctx, &shares, &verifying_keys, b"message 1"),
+            Err(BFVError::InvalidShare)
+        );
+
+        let mut mixed = shares.clone();
+        mixed[1].index = indices[2];
+        assert_eq!(
+            ThresholdPKE::combine_authenticated(&ctx, &mixed, &verifying_keys, message_id),
+            Err(BFVError::InvalidShare)
+        );
+
+        assert_eq!(
+            ThresholdPKE::combine_authenticated(&ctx, &shares[..1], &verifying_keys, message_id),
+            Err(BFVError::NotEnoughShares {
+                required: 2,
+                given: 1
+            })
+        );
+    }
+
+    #[test]
+    fn combine_proof_test() {
+        let mut rng = thread_rng();
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 3, indices.to_vec());
+
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (_, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let ctxts: Vec<_> = ThresholdPKE::encrypt(&ctx, &pks, &m)
+            .unwrap()
+            .iter()
+            .zip(&keys)
+            .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&ctx, c, sk_i, &pk).unwrap())
+            .collect();
+
+        let (c, proof) = ThresholdPKE::combine_with_proof(&ctx, &ctxts, &indices).unwrap();
+        assert!(ThresholdPKE::verify_combine(
+            &ctx, &ctxts, &indices, &c, &proof
+        ));
+
+        // the proof is checked from the digest of the shares, up to a claim on their evaluation
+        let digest = CombineProof::shares_digest(&ctxts);
+        let claim = proof.reduce(&digest, &indices, &c).unwrap();
+        assert!(claim.verify(&ctxts));
+        assert!(!claim.verify(&ctxts[..2]));
+        let other = proof.reduce(&[0; 32], &indices, &c);
+        assert!(other.is_none_or(|claim| !claim.verify(&ctxts)));
+
+        let mut wrong = c.clone();
+        wrong.c2[5] += CipherField::ONE;
+        assert!(!ThresholdPKE::verify_combine(
+            &ctx, &ctxts, &indices, &wrong, &proof
+        ));
+
+        // a malicious combiner may choose repeated, zero or foreign indices
+        let repeated = [F::new(1), F::new(1), F::new(3)];
+        assert!(!ThresholdPKE::verify_combine(
+            &ctx, &ctxts, &repeated, &c, &proof
+        ));
+        assert!(proof.reduce(&digest, &repeated, &c).is_none());
+        let zero = [F::ZERO, F::new(2), F::new(3)];
+        assert!(!proof.verify(&ctxts, &zero, &c));
+        assert!(proof.reduce(&digest, &zero, &c).is_none());
+        let foreign = [F::new(1), F::new(2), F::new(4)];
+        assert!(!ThresholdPKE::verify_combine(
+            &ctx, &ctxts, &foreign, &c, &proof
+        ));
+
+        let swapped = [ctxts[1].clone(), ctxts[0].clone(), ctxts[2].clone()];
+        let (wrong, proof) = ThresholdPKE::combine_with_proof(&ctx, &swapped, &indices).unwrap();
+        assert!(!ThresholdPKE::verify_combine(
+            &ctx, &ctxts, &indices, &wrong, &proof
+        ));
+    }
+
+    #[test]
+    fn joint_keygen_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 3, indices.to_vec());
+
+        let a = ThresholdPKE::gen_common_polynomial(&ctx);
+        let shares: Vec<_> = (0..3)
+            .map(|_| ThresholdPKE::gen_key_share(&ctx, &a))
+            .collect();
+        let pks: Vec<_> = shares.iter().map(|(_, pk)| pk.clone()).collect();
+        let pk = ThresholdPKE::aggregate_public_keys(&ctx, &pks).unwrap();
+
+        // only a test may add up the secret keys
+        let s = shares.iter().fold(
+            Polynomial::<CipherField>::zero(DIMENSION_N),
+            |s, (sk, _)| s + sk.expose_secret(),
+        );
+        let bytes: Vec<u8> = s.iter().flat_map(|x| x.to_bytes()).collect();
+        let sk = BFVSecretKey::from_vec(&bytes).unwrap();
+
+        let msg = Polynomial::<PlainField>::random(DIMENSION_N, thread_rng());
+        let msg = BFVPlaintext(msg);
+        let c = BFVScheme::encrypt(ctx.bfv_ctx(), &pk, &msg).unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk, &c).unwrap(), msg);
+        assert_ne!(ThresholdPKE::decrypt(&ctx, &shares[0].0, &c).unwrap(), msg);
+
+        assert!(matches!(
+            ThresholdPKE::aggregate_public_keys(&ctx, &[]),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+        let (_, other) = ThresholdPKE::gen_keypair(&ctx);
+        assert!(matches!(
+            ThresholdPKE::aggregate_public_keys(&ctx, &[pks[0].clone(), other]),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn crs_common_polynomial_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let seed = [7; 32];
+        let ctx = ThresholdPKE::gen_context(3, 3, indices.to_vec()).with_crs_seed(seed);
+        let other = ThresholdPKE::gen_context(3, 3, indices.to_vec()).with_crs_seed(seed);
+        assert_eq!(ctx.crs_seed(), Some(&seed));
+
+        // every party derives the same `a` from the seed
+        let a = ThresholdPKE::gen_common_polynomial(&ctx);
+        assert_eq!(a, ThresholdPKE::gen_common_polynomial(&other));
+        assert_eq!(ctx.parameter_digest(), other.parameter_digest());
+
+        let different = ThresholdPKE::gen_context(3, 3, indices.to_vec()).with_crs_seed([8; 32]);
+        assert_ne!(a, ThresholdPKE::gen_common_polynomial(&different));
+        assert_ne!(ctx.parameter_digest(), different.parameter_digest());
+        let unseeded = ThresholdPKE::gen_context(3, 3, indices.to_vec());
+        assert!(unseeded.crs_rng(b"a").is_none());
+        assert_ne!(ctx.parameter_digest(), unseeded.parameter_digest());
+
+        // the keys generated against it aggregate into a working public key
+        let shares: Vec<_> = (0..3)
+            .map(|_| ThresholdPKE::gen_key_share(&ctx, &a))
+            .collect();
+        let pks: Vec<_> = shares.iter().map(|(_, pk)| pk.clone()).collect();
+        assert!(ThresholdPKE::aggregate_public_keys(&other, &pks).is_ok());
+    }
+
+    #[test]
+    fn decryption_share_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+        let (sk_new, pk_new) = ThresholdPKE::gen_keypair(&ctx);
+
+        let msg = BFVPlaintext(Polynomial::random(DIMENSION_N, thread_rng()));
+        let cs = ThresholdPKE::encrypt(&ctx, &pks, &msg).unwrap();
+        let label = b"session";
+        let shares: Vec<_> = cs
+            .iter()
+            .zip(&keys)
+            .zip(indices)
+            .map(|((c, (sk, _)), index)| {
+                ThresholdPKE::gen_decryption_share(&ctx, c, sk, &pk_new, index, label).unwrap()
+            })
+            .collect();
+
+        let bytes = shares[0].to_vec();
+        assert_eq!(DecryptionShare::from_vec(&bytes), Ok(shares[0].clone()));
+        assert!(DecryptionShare::from_vec(&bytes[..bytes.len() - 1]).is_err());
+
+        // a forged share, a repeated one and one of an unknown node are rejected
+        let mut forged = shares[0].clone();
+        forged.value = shares[1].value.clone();
+        let mut unknown = shares[2].clone();
+        unknown.index = F::new(4);
+        let submitted = [
+            forged,
+            shares[1].clone(),
+            shares[1].clone(),
+            unknown,
+            shares[2].clone(),
+        ];
+        let aggregation = ThresholdPKE::aggregate(&ctx, &submitted, &pk_new, label).unwrap();
+        assert_eq!(aggregation.accepted, indices[1..]);
+        assert_eq!(
+            aggregation.rejected,
+            [
+                (F::new(1), ShareRejection::InvalidProof),
+                (F::new(2), ShareRejection::Duplicate),
+                (F::new(4), ShareRejection::UnknownIndex),
+            ]
+        );
+        assert_eq!(
+            ThresholdPKE::decrypt(&ctx, &sk_new, &aggregation.ciphertext).unwrap(),
+            msg
+        );
+
+        assert_eq!(
+            ThresholdPKE::aggregate(&ctx, &submitted[..3], &pk_new, b"other session"),
+            Err(BFVError::NotEnoughShares {
+                required: 2,
+                given: 0
+            })
+        );
+    }
+
+    #[test]
+    fn audit_log_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+        let (sk_new, pk_new) = ThresholdPKE::gen_keypair(&ctx);
+
+        let msg = BFVPlaintext(Polynomial::random(DIMENSION_N, thread_rng()));
+        let cs = ThresholdPKE::encrypt(&ctx, &pks, &msg).unwrap();
+        let shares: Vec<_> = cs
+            .iter()
+            .zip(&keys)
+            .zip(indices)
+            .map(|((c, (sk, _)), index)| {
+                ThresholdPKE::gen_decryption_share(&ctx, c, sk, &pk_new, index, b"session").unwrap()
+            })
+            .collect();
+
+        let mut log = AuditLog::new(&ctx, b"session");
+        let aggregation =
+            ThresholdPKE::aggregate_audited(&ctx, &shares, &pk_new, b"session", &mut log).unwrap();
+        assert_eq!(
+            ThresholdPKE::decrypt(&ctx, &sk_new, &aggregation.ciphertext).unwrap(),
+            msg
+        );
+        let labels: Vec<_> = log.entries().iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "session",
+                "parameters",
+                "receiver",
+                "label",
+                "decryption share",
+                "decryption share",
+                "decryption share",
+                "combined"
+            ]
+        );
+        assert_eq!(AuditLog::digest_entries(log.entries()), log.digest());
+
+        // a third party replaying the session gets the same digest
+        let mut replay = AuditLog::new(&ctx, b"session");
+        ThresholdPKE::aggregate_audited(&ctx, &shares, &pk_new, b"session", &mut replay).unwrap();
+        assert_eq!(replay.digest(), log.digest());
+
+        let mut other = AuditLog::new(&ctx, b"session");
+        ThresholdPKE::aggregate_audited(&ctx, &shares[1..], &pk_new, b"session", &mut other)
+            .unwrap();
+        assert_ne!(other.digest(), log.digest());
+
+        let mut combined = AuditLog::new(&ctx, b"session");
+        let c = ThresholdPKE::combine_audited(
+            &ctx,
+            &[shares[0].value.clone(), shares[1].value.clone()],
+            &indices[..2],
+            &mut combined,
+        )
+        .unwrap();
+        assert_eq!(ThresholdPKE::decrypt(&ctx, &sk_new, &c).unwrap(), msg);
+        assert_eq!(combined.entries().len(), 5);
+    }
+
+    #[test]
+    fn error_test() {
+        let indices = [F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices.to_vec());
+
+        let keys: Vec<_> = (0..3).map(|_| ThresholdPKE::gen_keypair(&ctx)).collect();
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| pk.clone()).collect();
+
+        assert!(matches!(
+            ThresholdPKE::encrypt_bytes(&ctx, &pks[..2], b"message", b""),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+
+        let (vec_c, nonce, mut c_bytes) =
+            ThresholdPKE::encrypt_bytes(&ctx, &pks, b"message", b"").unwrap();
+        let ctxts: Vec<_> = vec_c
+            .iter()
+            .zip(&keys)
+            .map(|(c, (sk_i, _))| ThresholdPKE::re_encrypt(&ctx, c, sk_i, &pk).unwrap())
+            .collect();
+        assert!(matches!(
+            ThresholdPKE::combine(&ctx, &ctxts, &indices[..2]),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+
+        assert_eq!(
+            ThresholdPKE::combine(&ctx, &ctxts[..1], &indices[..1]),
+            Err(BFVError::NotEnoughShares {
+                required: 2,
+                given: 1
+            })
+        );
+        for chosen_indices in [[indices[0], indices[0]], [F::ZERO, indices[1]]] {
+            assert_eq!(
+                ThresholdPKE::combine(&ctx, &ctxts[..2], &chosen_indices),
+                Err(BFVError::InvalidShare)
+            );
+            assert_eq!(
+                ThresholdPKE::gen_lagrange_coeffs(&chosen_indices),
+                Err(BFVError::InvalidShare)
+            );
+        }
+        let short = BFVCiphertext::new(
+            Polynomial::zero(DIMENSION_N / 2),
+            Polynomial::zero(DIMENSION_N / 2),
+        );
+        assert!(matches!(
+            ThresholdPKE::combine(&ctx, &[ctxts[0].clone(), short.clone()], &indices[..2]),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+        assert!(matches!(
+            ThresholdPKE::decrypt(&ctx, &sk, &short),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+        assert!(matches!(
+            ThresholdPKE::re_encrypt(&ctx, &short, &keys[0].0, &pk),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+
+        let c = ThresholdPKE::combine(&ctx, &ctxts[..2], &indices[..2]).unwrap();
+        c_bytes[0] ^= 1;
+        assert_eq!(
+            ThresholdPKE::decrypt_bytes(&ctx, &sk, &c, &nonce, &c_bytes, b""),
+            Err(BFVError::AeadFailure)
+        );
+
+        let threshold_c = ThresholdPKE::encrypt_with_threshold(
+            &ctx,
+            &pks,
+            &BFVPlaintext(Polynomial::zero(DIMENSION_N)),
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            ThresholdPKE::combine_with_threshold(&ctx, &threshold_c, &ctxts[..2], &indices[..2]),
+            Err(BFVError::NotEnoughShares {
+                required: 3,
+                given: 2
+            })
+        );
+        assert!(matches!(
+            ThresholdPKE::combine_with_threshold(&ctx, &threshold_c, &ctxts[..3], &indices[..2]),
+            Err(BFVError::ParameterMismatch(_))
+        ));
+        for threshold_number in [0, 4] {
+            let malformed =
+                ThresholdCiphertext::new(threshold_number, threshold_c.shares().to_vec());
+            assert!(matches!(
+                ThresholdPKE::combine_with_threshold(&ctx, &malformed, &ctxts[..2], &indices[..2]),
+                Err(BFVError::ParameterMismatch(_))
+            ));
+        }
+
+        let bytes = c.to_vec();
+        assert!(matches!(
+            BFVCiphertext::from_vec(&bytes[..bytes.len() - 1]),
+            Err(BFVError::SerializationError(_))
+        ));
+        let bytes = threshold_c.to_vec();
+        assert!(matches!(
+            ThresholdCiphertext::from_vec(&bytes[..bytes.len() - 4]),
+            Err(BFVError::SerializationError(_))
+        ));
     }
 }
@@ -0,0 +1,140 @@
+mod tests {
+    use algebra::{Field, Polynomial};
+    use bfv::{
+        wire::{WireFormat, WIRE_VERSION},
+        BFVCiphertext, BFVPlaintext, BFVPublicKey, BFVSecretKey, CipherField, CiphertextShare,
+        PlainField, ThresholdCiphertext, ThresholdPKE, DIMENSION_N,
+    };
+    use ed25519_dalek::{Signature, SigningKey};
+    use rand::thread_rng;
+
+    type F = PlainField;
+
+    const DIGEST: [u8; 32] = [0xab; 32];
+
+    fn hex(s: &str) -> Vec<u8> {
+        let s: String = s.split_whitespace().collect();
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn header(tag: &str, length: &str) -> String {
+        format!("5a4c4845 0001 {tag} {} {length}", "ab".repeat(32))
+    }
+
+    fn poly(values: &[u32]) -> Polynomial<CipherField> {
+        Polynomial::new(values.iter().map(|&v| CipherField::new(v)).collect())
+    }
+
+    fn small_ciphertext() -> BFVCiphertext {
+        BFVCiphertext::new(poly(&[1, 2]), poly(&[3, 132120576]))
+    }
+
+    const SMALL_CIPHERTEXT_BODY: &str = "00000002 00000001 00000002 00000002 00000003 07e00000";
+
+    #[test]
+    fn wire_golden_vectors_test() {
+        let ct = small_ciphertext();
+        let expected = hex(&(header("01", "00000018") + SMALL_CIPHERTEXT_BODY));
+        assert_eq!(ct.to_wire(&DIGEST), expected);
+        assert_eq!(
+            BFVCiphertext::from_wire(&expected, &DIGEST),
+            Some(ct.clone())
+        );
+
+        let pk = BFVPublicKey::new(poly(&[5]), poly(&[6]));
+        let expected = hex(&(header("02", "00000010") + "00000001 00000005 00000001 00000006"));
+        assert_eq!(pk.to_wire(&DIGEST), expected);
+        assert_eq!(BFVPublicKey::from_wire(&expected, &DIGEST), Some(pk));
+
+        let sk = BFVSecretKey::from_vec(&hex("00000001 07e00000 00000000")).unwrap();
+        let expected = hex(&(header("03", "00000010") + "00000003 00000001 07e00000 00000000"));
+        assert_eq!(sk.to_wire(&DIGEST), expected);
+        assert_eq!(BFVSecretKey::from_wire(&expected, &DIGEST), Some(sk));
+
+        let share = CiphertextShare {
+            index: F::new(2),
+            ct: ct.clone(),
+            sig: Signature::from_bytes(&[0x11; 64]),
+        };
+        let expected =
+            hex(&(header("04", "0000005a") + "0002" + SMALL_CIPHERTEXT_BODY + &"11".repeat(64)));
+        assert_eq!(share.to_wire(&DIGEST), expected);
+        assert_eq!(CiphertextShare::from_wire(&expected, &DIGEST), Some(share));
+
+        let tct = ThresholdCiphertext::new(2, vec![ct.clone(), ct]);
+        let expected = hex(&(header("05", "00000038")
+            + "00000002 00000002"
+            + SMALL_CIPHERTEXT_BODY
+            + SMALL_CIPHERTEXT_BODY));
+        assert_eq!(tct.to_wire(&DIGEST), expected);
+        assert_eq!(
+            ThresholdCiphertext::from_wire(&expected, &DIGEST),
+            Some(tct)
+        );
+    }
+
+    #[test]
+    fn wire_rejection_test() {
+        let bytes = small_ciphertext().to_wire(&DIGEST);
+
+        // another parameter set, type or version
+        assert!(BFVCiphertext::from_wire(&bytes, &[0; 32]).is_none());
+        assert!(BFVPublicKey::from_wire(&bytes, &DIGEST).is_none());
+        let mut other_version = bytes.clone();
+        other_version[4..6].copy_from_slice(&(WIRE_VERSION + 1).to_be_bytes());
+        assert!(BFVCiphertext::from_wire(&other_version, &DIGEST).is_none());
+
+        // truncated or trailing bytes
+        assert!(BFVCiphertext::from_wire(&bytes[..bytes.len() - 1], &DIGEST).is_none());
+        let mut longer = bytes.clone();
+        longer.push(0);
+        assert!(BFVCiphertext::from_wire(&longer, &DIGEST).is_none());
+
+        // a coefficient out of the field
+        let mut non_canonical = bytes;
+        let len = non_canonical.len();
+        non_canonical[len - 4..].copy_from_slice(&132120577u32.to_be_bytes());
+        assert!(BFVCiphertext::from_wire(&non_canonical, &DIGEST).is_none());
+    }
+
+    #[test]
+    fn wire_round_trip_test() {
+        let mut rng = thread_rng();
+        let indices = vec![F::new(1), F::new(2), F::new(3)];
+        let ctx = ThresholdPKE::gen_context(3, 2, indices);
+        let digest = ctx.parameter_digest();
+
+        let (sk, pk) = ThresholdPKE::gen_keypair(&ctx);
+        let m = BFVPlaintext(Polynomial::random(DIMENSION_N, &mut rng));
+        let ct = ThresholdPKE::encrypt_with_threshold(&ctx, &vec![pk.clone(); 3], &m, 2).unwrap();
+
+        assert_eq!(
+            BFVSecretKey::from_wire(&sk.to_wire(&digest), &digest),
+            Some(sk)
+        );
+        assert_eq!(
+            BFVPublicKey::from_wire(&pk.to_wire(&digest), &digest),
+            Some(pk)
+        );
+        assert_eq!(
+            ThresholdCiphertext::from_wire(&ct.to_wire(&digest), &digest),
+            Some(ct.clone())
+        );
+
+        let signing_key = SigningKey::generate(&mut rng);
+        let share = CiphertextShare::sign(
+            &signing_key,
+            F::new(1),
+            ct.shares()[0].clone(),
+            b"id",
+            &digest,
+        );
+        assert_eq!(
+            CiphertextShare::from_wire(&share.to_wire(&digest), &digest),
+            Some(share)
+        );
+    }
+}
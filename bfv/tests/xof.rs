@@ -0,0 +1,73 @@
+mod tests {
+    use bfv::{
+        hash_to_fields, Blake3, CipherField, Shake128, Shake256, Transcript, Xof, XofReader,
+    };
+
+    fn squeeze<X: Xof>(n: usize) -> Vec<u8> {
+        let mut bytes = vec![0; n];
+        X::default().finalize_xof().read(&mut bytes);
+        bytes
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn xof_backends_test() {
+        // the outputs on the empty input
+        assert_eq!(
+            hex(&squeeze::<Shake128>(16)),
+            "7f9c2ba4e88f827d616045507605853e"
+        );
+        assert_eq!(
+            hex(&squeeze::<Shake256>(16)),
+            "46b9dd2b0ba88d13233b3feb743eeb24"
+        );
+        assert_eq!(
+            hex(&squeeze::<Blake3>(16)),
+            "af1349b9f5f9a1a6a0404dea36dcc949"
+        );
+
+        // reading in pieces gives the same stream
+        let mut reader = Shake256::default().finalize_xof();
+        let mut bytes = vec![0; 16];
+        reader.read(&mut bytes[..5]);
+        reader.read(&mut bytes[5..]);
+        assert_eq!(bytes, squeeze::<Shake256>(16));
+    }
+
+    #[test]
+    fn hash_to_fields_test() {
+        let a = hash_to_fields::<Shake128, CipherField>(b"domain", b"data", 64);
+        assert_eq!(a.len(), 64);
+        assert_eq!(
+            a,
+            hash_to_fields::<Shake128, CipherField>(b"domain", b"data", 64)
+        );
+        assert_ne!(
+            a,
+            hash_to_fields::<Shake128, CipherField>(b"other", b"data", 64)
+        );
+        assert_ne!(
+            a,
+            hash_to_fields::<Blake3, CipherField>(b"domain", b"data", 64)
+        );
+    }
+
+    #[test]
+    fn transcript_xof_test() {
+        let mut t1 = Transcript::new(b"test");
+        let mut t2 = Transcript::<Shake128>::with_xof(b"test");
+        let mut t3 = Transcript::<Blake3>::with_xof(b"test");
+        t1.append_message(b"m", b"message");
+        t2.append_message(b"m", b"message");
+        t3.append_message(b"m", b"message");
+
+        let c = t1.challenge_bytes(b"c");
+        assert_eq!(c, t2.challenge_bytes(b"c"));
+        assert_ne!(c, t3.challenge_bytes(b"c"));
+        // the challenges are absorbed, so the next one differs
+        assert_ne!(c, t1.challenge_bytes(b"c"));
+    }
+}